@@ -19,7 +19,7 @@ use frame_system::pallet_prelude::*;
 use pallet_nfts::{CollectionConfig, CollectionSettings, MintSettings, NextCollectionId, Incrementable};
 use sp_std::convert::TryInto;
 use sp_runtime::traits::{AtLeast32BitUnsigned, Hash, StaticLookup};
-use gamedao_traits::ControlTrait;
+use gamedao_traits::{ControlTrait, OrgPermission};
 #[cfg(feature = "runtime-benchmarks")]
 use gamedao_traits::ControlBenchmarkingTrait;
 use orml_traits::{MultiCurrency, MultiReservableCurrency};
@@ -56,7 +56,11 @@ impl<CollectionId: From<u32>, ItemId: From<u32>> BattlepassHelper<CollectionId,
 pub mod pallet {
 	use super::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -332,8 +336,8 @@ pub mod pallet {
 			let creator = ensure_signed(origin)?;
 			// check if Org is active
 			ensure!(T::Control::is_org_active(&org_id), Error::<T>::OrgUnknownOrInactive);
-			// check permissions (prime)
-			ensure!(Self::is_prime(&org_id, creator.clone())?, Error::<T>::AuthorizationError);
+			// check permissions (prime or ManageBattlepass permission)
+			ensure!(Self::is_manager(&org_id, creator.clone())?, Error::<T>::AuthorizationError);
 			let (battlepass_count, _, _) = Self::get_battlepass_info(&org_id);
 			let new_season = battlepass_count + 1;
 
@@ -381,7 +385,7 @@ pub mod pallet {
 			// check if Org is active
 			ensure!(T::Control::is_org_active(&battlepass.org_id), Error::<T>::OrgUnknownOrInactive);
 			// check permissions (prime)
-			ensure!(Self::is_prime_or_bot(&battlepass.org_id, creator)?, Error::<T>::AuthorizationError);
+			ensure!(Self::is_manager_or_bot(&battlepass.org_id, creator)?, Error::<T>::AuthorizationError);
 
 			if name_changed { battlepass.name = name.clone().unwrap() }
 			if price_changed { battlepass.price = price.unwrap() }
@@ -425,7 +429,7 @@ pub mod pallet {
 			let org_id = battlepass.org_id;
 			ensure!(T::Control::is_org_active(&org_id), Error::<T>::OrgUnknownOrInactive);
 			// check permissions (prime, bot)
-			ensure!(Self::is_prime_or_bot(&org_id, caller.clone())?, Error::<T>::AuthorizationError);
+			ensure!(Self::is_manager_or_bot(&org_id, caller.clone())?, Error::<T>::AuthorizationError);
 			// check if user has access to Battlepass
 			ensure!(!Self::is_battlepass_member(claimer.clone(), battlepass.collection_id), Error::<T>::BattlepassOwnershipExists);
 
@@ -456,8 +460,8 @@ pub mod pallet {
 			ensure!(Self::check_battlepass_state(battlepass_id, BattlepassState::DRAFT)?, Error::<T>::BattlepassStateWrong);
 			// check if Org is active
 			ensure!(T::Control::is_org_active(&battlepass.org_id), Error::<T>::OrgUnknownOrInactive);
-			// check permissions (is_prime_or_bot)
-			ensure!(Self::is_prime_or_bot(&battlepass.org_id, sender.clone())?, Error::<T>::AuthorizationError);
+			// check permissions (is_manager_or_bot)
+			ensure!(Self::is_manager_or_bot(&battlepass.org_id, sender.clone())?, Error::<T>::AuthorizationError);
 			// check if there is no active battlepass for the Org
 			let (_, maybe_active, _) = Self::get_battlepass_info(&battlepass.org_id);
 			ensure!(maybe_active.is_none(), Error::<T>::BattlepassExists);
@@ -488,8 +492,8 @@ pub mod pallet {
 			let battlepass = Self::get_battlepass(battlepass_id).ok_or(Error::<T>::BattlepassUnknown)?;
 			// check if Battlepass in ACTIVE state
 			ensure!(Self::check_battlepass_state(battlepass_id, BattlepassState::ACTIVE)?, Error::<T>::BattlepassStateWrong);
-			// check permissions (is_prime_or_bot)
-			ensure!(Self::is_prime_or_bot(&battlepass.org_id, sender.clone())?, Error::<T>::AuthorizationError);
+			// check permissions (is_manager_or_bot)
+			ensure!(Self::is_manager_or_bot(&battlepass.org_id, sender.clone())?, Error::<T>::AuthorizationError);
 
 			Self::change_battlepass_state(battlepass.org_id, battlepass_id, BattlepassState::ENDED)?;
 
@@ -523,7 +527,7 @@ pub mod pallet {
 			// check if Org is active
 			ensure!(T::Control::is_org_active(&battlepass.org_id), Error::<T>::OrgUnknownOrInactive);
 			// check permissions (prime, bot)
-			ensure!(Self::is_prime_or_bot(&battlepass.org_id, sender.clone())?, Error::<T>::AuthorizationError);
+			ensure!(Self::is_manager_or_bot(&battlepass.org_id, sender.clone())?, Error::<T>::AuthorizationError);
 			// check if user has access to Battlepass
 			ensure!(Self::is_battlepass_member(account.clone(), battlepass.collection_id), Error::<T>::BattlepassOwnershipDoesntExist);
 
@@ -565,7 +569,7 @@ pub mod pallet {
 			// check if Org is active
 			ensure!(T::Control::is_org_active(&battlepass.org_id), Error::<T>::OrgUnknownOrInactive);
 			// check permissions (prime, bot)
-			ensure!(Self::is_prime_or_bot(&battlepass.org_id, caller)?, Error::<T>::AuthorizationError);
+			ensure!(Self::is_manager_or_bot(&battlepass.org_id, caller)?, Error::<T>::AuthorizationError);
 
 			let prime = T::Control::org_prime_account(&battlepass.org_id).ok_or(Error::<T>::OrgPrimeUnknown)?;
 			let collection_id = Self::create_collection(prime, max, cid.clone())?;
@@ -614,7 +618,7 @@ pub mod pallet {
 			// check if Org is active
 			ensure!(T::Control::is_org_active(&battlepass.org_id), Error::<T>::OrgUnknownOrInactive);
 			// check permissions (prime, bot)
-			ensure!(Self::is_prime_or_bot(&battlepass.org_id, caller)?, Error::<T>::AuthorizationError);
+			ensure!(Self::is_manager_or_bot(&battlepass.org_id, caller)?, Error::<T>::AuthorizationError);
 
 			if name_changed { reward.name = name.clone().unwrap() };
 			if transferable_changed { reward.transferable = transferable.unwrap() };
@@ -652,7 +656,7 @@ pub mod pallet {
 			// check if Battlepass exists
 			let battlepass = Self::get_battlepass(reward.battlepass_id).ok_or(Error::<T>::BattlepassUnknown)?;
 			// check permissions (prime, bot)
-			ensure!(Self::is_prime_or_bot(&battlepass.org_id, caller)?, Error::<T>::AuthorizationError);
+			ensure!(Self::is_manager_or_bot(&battlepass.org_id, caller)?, Error::<T>::AuthorizationError);
 
 			let state = RewardState::INACTIVE;
 
@@ -699,7 +703,7 @@ pub mod pallet {
 			// check if Org is active
 			ensure!(T::Control::is_org_active(&battlepass.org_id), Error::<T>::OrgUnknownOrInactive);
 			// check permissions (prime or bot)
-			ensure!(Self::is_prime_or_bot(&battlepass.org_id, caller)?, Error::<T>::AuthorizationError);
+			ensure!(Self::is_manager_or_bot(&battlepass.org_id, caller)?, Error::<T>::AuthorizationError);
 			// check if user has access to Battlepass
 			ensure!(Self::is_battlepass_member(claimer.clone(), battlepass.collection_id), Error::<T>::BattlepassOwnershipDoesntExist);
 
@@ -736,7 +740,7 @@ pub mod pallet {
 			// check if Org is active
 			ensure!(T::Control::is_org_active(&battlepass.org_id), Error::<T>::OrgUnknownOrInactive);
 			// check permissions (prime, bot)
-			ensure!(Self::is_prime_or_bot(&battlepass.org_id, sender)?, Error::<T>::AuthorizationError);
+			ensure!(Self::is_manager_or_bot(&battlepass.org_id, sender)?, Error::<T>::AuthorizationError);
 
 			Levels::<T>::insert(battlepass_id, level, points);
 
@@ -766,7 +770,7 @@ pub mod pallet {
 			// check if Org is active
 			ensure!(T::Control::is_org_active(&battlepass.org_id), Error::<T>::OrgUnknownOrInactive);
 			// check permissions (prime, bot)
-			ensure!(Self::is_prime_or_bot(&battlepass.org_id, sender)?, Error::<T>::AuthorizationError);
+			ensure!(Self::is_manager_or_bot(&battlepass.org_id, sender)?, Error::<T>::AuthorizationError);
 			// check if Level exists
 			ensure!(Levels::<T>::contains_key(battlepass_id, level), Error::<T>::LevelUnknown);
 
@@ -797,7 +801,7 @@ pub mod pallet {
 			// check if Org is active
 			ensure!(T::Control::is_org_active(&battlepass.org_id), Error::<T>::OrgUnknownOrInactive);
 			// check permissions (prime or root)
-			ensure!(Self::is_prime_or_root(&battlepass.org_id, origin)?, Error::<T>::AuthorizationError);
+			ensure!(Self::is_manager_or_root(&battlepass.org_id, origin)?, Error::<T>::AuthorizationError);
 
 			BattlepassInfoByOrg::<T>::try_mutate(battlepass.org_id, |info| -> Result<(), DispatchError> {
 				if let Some(inf) = info {
@@ -822,20 +826,26 @@ impl<T: Config> Pallet<T> {
 		Ok(who == prime)
 	}
 
+	/// Prime or an org member with ManageBattlepass permission.
+	fn is_manager(org_id: &T::Hash, who: T::AccountId) -> Result<bool, DispatchError> {
+		Ok(Self::is_prime(org_id, who.clone())? ||
+			T::Control::has_org_permission(org_id, &who, OrgPermission::ManageBattlepass))
+	}
+
 	fn is_bot(org_id: &T::Hash, who: T::AccountId) -> Result<bool, DispatchError> {
 		let (_, _, bot) = Self::get_battlepass_info(org_id);
 		Ok(Some(who) == bot)
 	}
 
-	fn is_prime_or_bot(org_id: &T::Hash, who: T::AccountId) -> Result<bool, DispatchError> {
-		Ok(Self::is_prime(org_id, who.clone())? || Self::is_bot(org_id, who)?)
+	fn is_manager_or_bot(org_id: &T::Hash, who: T::AccountId) -> Result<bool, DispatchError> {
+		Ok(Self::is_manager(org_id, who.clone())? || Self::is_bot(org_id, who)?)
 	}
 
-	fn is_prime_or_root(org_id: &T::Hash, who: T::RuntimeOrigin) -> Result<bool, DispatchError> {
+	fn is_manager_or_root(org_id: &T::Hash, who: T::RuntimeOrigin) -> Result<bool, DispatchError> {
 		match who.into() {
 			Ok(RawOrigin::Root) => Ok(true),
 			Ok(RawOrigin::Signed(t)) => {
-				Self::is_prime(org_id, t)
+				Self::is_manager(org_id, t)
 			},
 			_ => Ok(false)
 		}
@@ -2,7 +2,7 @@
 
 use crate as gamedao_battlepass;
 use frame_support::{construct_runtime, parameter_types, PalletId,
-	traits::{AsEnsureOriginWithArg, Nothing, GenesisBuild},
+	traits::{AsEnsureOriginWithArg, ConstU64, Nothing, GenesisBuild},
 	pallet_prelude::*,
 };
 use frame_system;
@@ -206,20 +206,41 @@ parameter_types! {
 	pub const NativeTokenId: CurrencyId = NATIVE_TOKEN_ID;
 	pub const ProtocolTokenId: CurrencyId = PROTOCOL_TOKEN_ID;
 	pub const PaymentTokenId: CurrencyId = PAYMENT_TOKEN_ID;
+	pub TreasuryCurrencies: Vec<CurrencyId> = vec![PROTOCOL_TOKEN_ID, PAYMENT_TOKEN_ID];
 	pub const MinimumDeposit: Balance = 5 * DOLLARS;
 	pub const ControlPalletId: PalletId = PalletId(*b"gd/cntrl");
 	pub const MaxMembers: u32 = 1000;
 }
+#[cfg(feature = "runtime-benchmarks")]
+pub struct InvitationSigner;
+#[cfg(feature = "runtime-benchmarks")]
+impl gamedao_control::InvitationSigner<AccountId, Signature> for InvitationSigner {
+	fn sign(message: &[u8]) -> (AccountId, Signature) {
+		(ALICE, TestSignature(ALICE, message.to_vec()))
+	}
+}
 impl gamedao_control::Config for Test {
 	type Balance = Balance;
 	type CurrencyId = CurrencyId;
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Currencies;
+	type Governance = ();
+	type Sense = ();
+	type OffchainSignature = Signature;
+	type OffchainPublic = AccountPublic;
+	#[cfg(feature = "runtime-benchmarks")]
+	type InvitationSigner = InvitationSigner;
 	type MaxMembers = MaxMembers;
+	type MaxMembersProcessing = ConstU32<100>;
+	type MaxStreams = ConstU32<10>;
 	type ProtocolTokenId = ProtocolTokenId;
 	type PaymentTokenId = PaymentTokenId;
+	type TreasuryCurrencies = TreasuryCurrencies;
 	type MinimumDeposit = MinimumDeposit;
+	type SubscriptionPeriod = ConstU64<100>;
+	type SubscriptionGracePeriod = ConstU64<10>;
+	type PrimeHandoverPeriod = ConstU64<100>;
 	type PalletId = ControlPalletId;
 	type StringLimit = ConstU32<256>;
 }
@@ -262,4 +283,4 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	.assimilate_storage(&mut t)
 	.unwrap();
 	t.into()
-}
\ No newline at end of file
+}
@@ -29,7 +29,7 @@ fn create_org() -> H256 {
 	assert_ok!(
 		Control::create_org(
 			Origin::signed(ALICE), org.name, org.cid, org.org_type, org.access_model,
-			org.fee_model, None, org.membership_fee, None, None, None
+			org.fee_model, None, org.membership_fee, None, None, None, None, None
 	));
 
     org_id
@@ -91,7 +91,7 @@ fn create_reward(battlepass_id: H256) -> H256 {
 
 fn add_member(org_id: H256, account: AccountId) {
     assert_ok!(
-        Control::add_member(Origin::signed(account), org_id, account)
+        Control::add_member(Origin::signed(account), org_id, account, None)
     );
 }
 
@@ -141,7 +141,7 @@ fn create_battlepass_test(){
         
         // Should not create if origin is not a Prime
         assert_ok!(
-            Control::add_member(Origin::signed(not_creator), org_id, not_creator)
+            Control::add_member(Origin::signed(not_creator), org_id, not_creator, None)
         );
         assert_noop!(
             Battlepass::create_battlepass(Origin::signed(not_creator), org_id, string(), string(), 10),
@@ -259,7 +259,7 @@ fn update_battlepass_test() {
 
         // Should not update if origin is not a Prime
         assert_ok!(
-            Control::add_member(Origin::signed(not_creator), org_id, not_creator)
+            Control::add_member(Origin::signed(not_creator), org_id, not_creator, None)
         );
         assert_noop!(
             Battlepass::update_battlepass(Origin::signed(not_creator), battlepass_id, Some(new_name.clone()), Some(new_cid.clone()), Some(new_price.clone())),
@@ -356,7 +356,7 @@ fn activate_battlepass_test() {
 
         // Should not activate if origin is not a Prime
         assert_ok!(
-            Control::add_member(Origin::signed(not_creator), org_id, not_creator)
+            Control::add_member(Origin::signed(not_creator), org_id, not_creator, None)
         );
         assert_noop!(
             Battlepass::activate_battlepass(Origin::signed(not_creator), battlepass_id),
@@ -422,7 +422,7 @@ fn deactivate_battlepass_test() {
             Battlepass::activate_battlepass(Origin::signed(creator), battlepass_id)
         );
         assert_ok!(
-            Control::add_member(Origin::signed(not_creator), org_id, not_creator)
+            Control::add_member(Origin::signed(not_creator), org_id, not_creator, None)
         );
         assert_noop!(
             Battlepass::conclude_battlepass(Origin::signed(not_creator), battlepass_id),
@@ -626,7 +626,7 @@ fn set_points_test() {
 
         // Should not set if origin is not a Prime
         assert_ok!(
-            Control::add_member(Origin::signed(not_creator), org_id, not_creator)
+            Control::add_member(Origin::signed(not_creator), org_id, not_creator, None)
         );
         assert_noop!(
             Battlepass::set_points(Origin::signed(not_creator), battlepass_id, not_creator, 10),
@@ -711,7 +711,7 @@ fn create_reward_test() {
 
         // Should not create if origin is not a Prime
         assert_ok!(
-            Control::add_member(Origin::signed(not_creator), org_id, not_creator)
+            Control::add_member(Origin::signed(not_creator), org_id, not_creator, None)
         );
         assert_noop!(
             Battlepass::create_reward(Origin::signed(not_creator), battlepass_id, string(), string(), Some(1), 1, true),
@@ -853,7 +853,7 @@ fn update_reward_test() {
 
         // Should not update if origin is not a Prime
         assert_ok!(
-            Control::add_member(Origin::signed(not_creator), org_id, not_creator)
+            Control::add_member(Origin::signed(not_creator), org_id, not_creator, None)
         );
         assert_noop!(
             Battlepass::update_reward(Origin::signed(not_creator), reward_id, Some(new_name.clone()), Some(new_cid.clone()), Some(new_transferable.clone())),
@@ -961,7 +961,7 @@ fn disable_reward_test() {
 
         // Should not disable if origin is not a Prime
         assert_ok!(
-            Control::add_member(Origin::signed(not_creator), org_id, not_creator)
+            Control::add_member(Origin::signed(not_creator), org_id, not_creator, None)
         );
         assert_noop!(
             Battlepass::disable_reward(Origin::signed(not_creator), reward_id),
@@ -3,7 +3,9 @@
 use super::*;
 use crate::Pallet as Control;
 
+use codec::Encode;
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Hooks;
 use frame_system::RawOrigin;
 use sp_runtime::{DispatchError, traits::SaturatedConversion};
 use sp_std::vec;
@@ -28,6 +30,49 @@ fn fund_accounts<T: Config>(account_ids: &Vec<T::AccountId>) -> Result<(), Dispa
 	Ok(())
 }
 
+fn create_streams<T: Config>(caller: &T::AccountId, org_id: T::Hash, count: u32) -> Result<(), DispatchError> {
+	let start = frame_system::Pallet::<T>::block_number();
+	for i in 0..count {
+		Pallet::<T>::create_stream(
+			RawOrigin::Signed(caller.clone()).into(), org_id, account("beneficiary", i, SEED),
+			T::PaymentTokenId::get(), 100_u32.saturated_into(), One::one(), start, Some(start + 100_u32.into())
+		)?;
+	}
+	Ok(())
+}
+
+fn vault_rules<T: Config>() -> VaultRules<T> {
+	types::VaultRules {
+		roles: BoundedVec::truncate_from(vec![OrgRole::Treasurer]),
+		limits: BoundedVec::truncate_from(vec![(T::PaymentTokenId::get(), DEPOSIT_AMOUNT.saturated_into())]),
+		period: 100_u32.into(),
+	}
+}
+
+/// Creates the org vaults, the last one is named `name`.
+fn create_vaults<T: Config>(org_id: T::Hash, count: u32, name: String<T>) -> Result<VaultId, DispatchError> {
+	for i in 1..count {
+		let vault_name = BoundedVec::truncate_from(i.encode());
+		Pallet::<T>::create_vault(RawOrigin::Root.into(), org_id, vault_name, vault_rules::<T>())?;
+	}
+	Pallet::<T>::create_vault(RawOrigin::Root.into(), org_id, name, vault_rules::<T>())?;
+	Ok(OrgVaultCount::<T>::get(org_id))
+}
+
+/// Org membership fee is transferred to the org treasury.
+fn set_transfer_fee<T: Config>(org_id: T::Hash) {
+	Orgs::<T>::mutate(org_id, |org| {
+		if let Some(org) = org {
+			org.fee_model = FeeModel::Transfer;
+			org.membership_fee = Some(100_u32.saturated_into());
+		}
+	});
+}
+
+fn named_seats<T: Config>(count: u32) -> BoundedVec<T::AccountId, MaxNamedSeats> {
+	BoundedVec::truncate_from((0..count).map(|i| account("joiner", i, SEED)).collect())
+}
+
 
 benchmarks! {
 
@@ -39,7 +84,7 @@ benchmarks! {
 	}: 	_(
 		RawOrigin::Signed(caller), text.clone(), text.clone(),
 		OrgType::Individual, AccessModel::Open, FeeModel::NoFees,
-		None, None, None, None, None
+		None, None, None, None, None, None, None
 	)
 	verify {
 		assert!(OrgCount::<T>::get() == count + 1);
@@ -51,9 +96,17 @@ benchmarks! {
 		let text = BoundedVec::truncate_from((0..255).collect());
 
 		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		// Switch from Hybrid type removes the spending thresholds
+		Pallet::<T>::update_org(
+			RawOrigin::Root.into(), org_id, None, None, None, Some(OrgType::Hybrid), None, None, None, None
+		)?;
+		// Prime proposes the handover to another member
+		let member: T::AccountId = account("member", 0, SEED);
+		fund_account::<T>(&member)?;
+		<Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::fill_org_with_members(&org_id, vec![member.clone()])?;
 		let name = Some(text.clone());
 		let cid = Some(text.clone());
-		let prime_id = Some(caller.clone());
+		let prime_id = Some(member);
 		let org_type = Some(OrgType::Individual);
 		let access_model = Some(AccessModel::Voting);
 		let member_limit = Some(100 as MemberLimit);
@@ -93,27 +146,21 @@ benchmarks! {
 	}
 
 	add_member {
-		let r in 1 .. T::MaxMembers::get()-1;
-
-		// Prepare org creator and members
+		// Prepare org creator and member
 		let creator: T::AccountId = whitelisted_caller();
 		let member: T::AccountId = account("member", 0, SEED);
-		let accounts: Vec<T::AccountId> = (1..r)
-			.collect::<Vec<u32>>()
-			.iter()
-			.map(|i| account("member", *i, SEED))
-			.collect();
 		fund_accounts::<T>(&vec![creator.clone(), member.clone()])?;
-		fund_accounts::<T>(&accounts)?;
-
-		// Create org and fill with members
 		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(creator.clone()).unwrap();
-		Pallet::<T>::fill_org_with_members(&org_id, accounts)?;
+		// Worst case: the fee is paid from an unnamed seat after all named seats are checked
+		set_transfer_fee::<T>(org_id);
+		Pallet::<T>::sponsor_seats(
+			RawOrigin::Signed(creator.clone()).into(), org_id, MaxNamedSeats::get() + 1, named_seats::<T>(MaxNamedSeats::get())
+		)?;
 
-	}: _(RawOrigin::Signed(creator), org_id, member.clone())
+	}: _(RawOrigin::Signed(creator.clone()), org_id, member.clone(), Some(creator.clone()))
 
 	verify {
-		assert!(Members::<T>::get(&org_id).contains(&member));
+		assert!(MemberStates::<T>::contains_key(&org_id, &member));
 	}
 
 	update_member_state {
@@ -133,26 +180,77 @@ benchmarks! {
 	}
 
 	remove_member {
-		let r in 1 .. T::MaxMembers::get()-1;
+		// Prepare org creator and member
+		let creator: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("member", 0, SEED);
+		fund_accounts::<T>(&vec![creator.clone(), member.clone()])?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(creator.clone()).unwrap();
+		Pallet::<T>::fill_org_with_members(&org_id, vec![member.clone()])?;
 
-		// Prepare org creator and members
+	}: _(RawOrigin::Signed(creator), org_id, member.clone())
+
+	verify {
+		assert!(!MemberStates::<T>::contains_key(&org_id, &member));
+	}
+
+	kick_member {
+		// Worst case: reserved membership fee is slashed
 		let creator: T::AccountId = whitelisted_caller();
-		fund_account::<T>(&creator)?;
+		let member: T::AccountId = account("member", 0, SEED);
+		fund_accounts::<T>(&vec![creator.clone(), member.clone()])?;
 		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(creator.clone()).unwrap();
-		let accounts: Vec<T::AccountId> = (1..r+1)
-			.collect::<Vec<u32>>()
-			.iter()
-			.map(|i| account("member", *i, SEED))
-			.collect();
-		fund_accounts::<T>(&accounts)?;
+		let fee: T::Balance = 100_u32.saturated_into();
+		Pallet::<T>::update_org(
+			RawOrigin::Root.into(), org_id, None, None, None, None, None, None, Some(FeeModel::Reserve), Some(fee)
+		)?;
+		Pallet::<T>::fill_org_with_members(&org_id, vec![member.clone()])?;
+		T::Currency::reserve(Orgs::<T>::get(org_id).unwrap().gov_currency, &member, fee)?;
+		let reason = BoundedVec::truncate_from((0..255).collect());
 
-		// Add members to org
-		Pallet::<T>::fill_org_with_members(&org_id, accounts.clone())?;
+	}: _(RawOrigin::Signed(creator), org_id, member.clone(), reason, true)
 
-	}: _(RawOrigin::Signed(creator), org_id, accounts[0].clone())
+	verify {
+		assert!(MemberStates::<T>::get(org_id, member) == MemberState::Kicked);
+	}
+
+	ban_member {
+		// Worst case: reserved membership fee is slashed
+		let creator: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("member", 0, SEED);
+		fund_accounts::<T>(&vec![creator.clone(), member.clone()])?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(creator.clone()).unwrap();
+		let fee: T::Balance = 100_u32.saturated_into();
+		Pallet::<T>::update_org(
+			RawOrigin::Root.into(), org_id, None, None, None, None, None, None, Some(FeeModel::Reserve), Some(fee)
+		)?;
+		Pallet::<T>::fill_org_with_members(&org_id, vec![member.clone()])?;
+		T::Currency::reserve(Orgs::<T>::get(org_id).unwrap().gov_currency, &member, fee)?;
+		let reason = BoundedVec::truncate_from((0..255).collect());
+
+	}: _(RawOrigin::Signed(creator), org_id, member.clone(), reason, true)
+
+	verify {
+		assert!(MemberStates::<T>::get(org_id, member) == MemberState::Banned);
+	}
+
+	leave_org {
+		// Worst case: reserved membership fee is unreserved
+		let creator: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("member", 0, SEED);
+		fund_accounts::<T>(&vec![creator.clone(), member.clone()])?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(creator.clone()).unwrap();
+		let fee: T::Balance = 100_u32.saturated_into();
+		Pallet::<T>::update_org(
+			RawOrigin::Root.into(), org_id, None, None, None, None, None, None, Some(FeeModel::Reserve), Some(fee)
+		)?;
+		Pallet::<T>::fill_org_with_members(&org_id, vec![member.clone()])?;
+		T::Currency::reserve(Orgs::<T>::get(org_id).unwrap().gov_currency, &member, fee)?;
+		let reason = BoundedVec::truncate_from((0..255).collect());
+
+	}: _(RawOrigin::Signed(member.clone()), org_id, reason)
 
 	verify {
-		assert!(!Members::<T>::get(&org_id).contains(&accounts[0]));
+		assert!(MemberStates::<T>::get(org_id, member) == MemberState::Exited);
 	}
 
 	spend_funds {
@@ -164,6 +262,8 @@ benchmarks! {
 		let currency_id = T::PaymentTokenId::get();
 		let amount: T::Balance = 300_000_000_000_00_u128.saturated_into();
 		fund_account::<T>(&treasury_id)?;
+		// Worst case: committed funds are calculated for the max number of streams
+		create_streams::<T>(&caller, org_id, T::MaxStreams::get())?;
 
 	}: _(RawOrigin::Signed(caller), org_id, currency_id, beneficiary.clone(), amount)
 
@@ -171,5 +271,450 @@ benchmarks! {
 		assert!(T::Currency::free_balance(currency_id, &beneficiary) == amount);
 	}
 
+	create_stream {
+		let caller: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		fund_account::<T>(&OrgTreasury::<T>::get(org_id).unwrap())?;
+		create_streams::<T>(&caller, org_id, T::MaxStreams::get() - 1)?;
+		let start = frame_system::Pallet::<T>::block_number();
+
+	}: _(
+		RawOrigin::Signed(caller), org_id, beneficiary, T::PaymentTokenId::get(),
+		100_u32.saturated_into(), One::one(), start, Some(start + 100_u32.into())
+	)
+
+	verify {
+		assert!(Streams::<T>::iter_prefix(org_id).count() as u32 == T::MaxStreams::get());
+	}
+
+	claim_stream {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		fund_account::<T>(&OrgTreasury::<T>::get(org_id).unwrap())?;
+		create_streams::<T>(&caller, org_id, 1)?;
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let block_number = frame_system::Pallet::<T>::block_number() + 10_u32.into();
+		frame_system::Pallet::<T>::set_block_number(block_number);
+
+	}: _(RawOrigin::Signed(beneficiary.clone()), org_id, 0)
+
+	verify {
+		assert!(Streams::<T>::get(org_id, 0).unwrap().claimed_until == block_number);
+	}
+
+	cancel_stream {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		fund_account::<T>(&OrgTreasury::<T>::get(org_id).unwrap())?;
+		create_streams::<T>(&caller, org_id, 1)?;
+		let block_number = frame_system::Pallet::<T>::block_number() + 10_u32.into();
+		frame_system::Pallet::<T>::set_block_number(block_number);
+
+	}: _(RawOrigin::Signed(caller), org_id, 0)
+
+	verify {
+		assert!(!Streams::<T>::contains_key(org_id, 0));
+	}
+
+	grant_role {
+		// Worst case: caller is an admin, not the prime
+		let creator: T::AccountId = whitelisted_caller();
+		let admin: T::AccountId = account("admin", 0, SEED);
+		let member: T::AccountId = account("member", 1, SEED);
+		fund_accounts::<T>(&vec![creator.clone(), admin.clone(), member.clone()])?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(creator.clone()).unwrap();
+		Pallet::<T>::fill_org_with_members(&org_id, vec![admin.clone(), member.clone()])?;
+		Pallet::<T>::grant_role(RawOrigin::Root.into(), org_id, admin.clone(), OrgRole::Admin)?;
+
+	}: _(RawOrigin::Signed(admin), org_id, member.clone(), OrgRole::Treasurer)
+
+	verify {
+		assert!(MemberRoles::<T>::get(org_id, member).contains(&OrgRole::Treasurer));
+	}
+
+	revoke_role {
+		let creator: T::AccountId = whitelisted_caller();
+		let admin: T::AccountId = account("admin", 0, SEED);
+		let member: T::AccountId = account("member", 1, SEED);
+		fund_accounts::<T>(&vec![creator.clone(), admin.clone(), member.clone()])?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(creator.clone()).unwrap();
+		Pallet::<T>::fill_org_with_members(&org_id, vec![admin.clone(), member.clone()])?;
+		Pallet::<T>::grant_role(RawOrigin::Root.into(), org_id, admin.clone(), OrgRole::Admin)?;
+		Pallet::<T>::grant_role(RawOrigin::Root.into(), org_id, member.clone(), OrgRole::Treasurer)?;
+
+	}: _(RawOrigin::Signed(admin), org_id, member.clone(), OrgRole::Treasurer)
+
+	verify {
+		assert!(!MemberRoles::<T>::get(org_id, member).contains(&OrgRole::Treasurer));
+	}
+
+	set_role_permissions {
+		let creator: T::AccountId = whitelisted_caller();
+		let admin: T::AccountId = account("admin", 0, SEED);
+		fund_accounts::<T>(&vec![creator.clone(), admin.clone()])?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(creator.clone()).unwrap();
+		Pallet::<T>::fill_org_with_members(&org_id, vec![admin.clone()])?;
+		Pallet::<T>::grant_role(RawOrigin::Root.into(), org_id, admin.clone(), OrgRole::Admin)?;
+		let permissions = BoundedVec::truncate_from(vec![OrgPermission::SpendFunds, OrgPermission::CreateCampaign]);
+
+	}: _(RawOrigin::Signed(admin), org_id, OrgRole::Treasurer, permissions)
+
+	verify {
+		assert!(RolePermissions::<T>::get(org_id, OrgRole::Treasurer).is_some());
+	}
+
+	dissolve_org {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+
+	}: _(RawOrigin::Signed(caller), org_id)
+
+	verify {
+		assert!(OrgStates::<T>::get(org_id) == OrgState::Dissolving);
+	}
+
+	on_initialize {
+		let m in 0 .. T::MaxMembersProcessing::get() / 2;
+		let o in 1 .. 10;
+
+		let creator: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&creator)?;
+		let members: Vec<T::AccountId> = (0..m).map(|i| account("member", i, SEED)).collect();
+		fund_accounts::<T>(&members)?;
+		for i in 0..o {
+			let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(creator.clone()).unwrap();
+			if i == 0 {
+				Pallet::<T>::fill_org_with_members(&org_id, members.clone())?;
+			}
+			Pallet::<T>::dissolve_org(RawOrigin::Root.into(), org_id)?;
+		}
+		// Members are counted, worst case is the settlement
+		Pallet::<T>::process_dissolutions(u32::MAX);
+
+	}: { Pallet::<T>::on_initialize(T::BlockNumber::from(1u32)); }
+
+	verify {
+		assert!(OrgDissolutions::<T>::iter().count() < o as usize);
+	}
+
+	redeem_invitation {
+		// Worst case: invitee pays the membership fee
+		let (inviter, _) = T::InvitationSigner::sign(&[]);
+		let invitee: T::AccountId = account("member", 0, SEED);
+		fund_accounts::<T>(&vec![inviter.clone(), invitee.clone()])?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(inviter.clone()).unwrap();
+		Pallet::<T>::update_org(
+			RawOrigin::Root.into(), org_id, None, None, None, None, None, None,
+			Some(FeeModel::Transfer), Some(100_u32.saturated_into())
+		)?;
+		let invitation = Invitation::<T> {
+			org_id, invitee: Some(invitee.clone()), expires: 100_u32.into(), nonce: 0, fee_waived: false
+		};
+		let (_, signature) = T::InvitationSigner::sign(&Pallet::<T>::invitation_payload(&invitation));
+
+	}: _(RawOrigin::Signed(invitee.clone()), invitation, signature, inviter)
+
+	verify {
+		assert!(MemberStates::<T>::get(org_id, invitee) == MemberState::Active);
+	}
+
+	pay_subscription {
+		// Worst case: lapsed member becomes active again
+		let creator: T::AccountId = whitelisted_caller();
+		let member: T::AccountId = account("member", 0, SEED);
+		fund_accounts::<T>(&vec![creator.clone(), member.clone()])?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(creator.clone()).unwrap();
+		Pallet::<T>::update_org(
+			RawOrigin::Root.into(), org_id, None, None, None, None, None, None,
+			Some(FeeModel::Subscription), Some(100_u32.saturated_into())
+		)?;
+		Pallet::<T>::fill_org_with_members(&org_id, vec![member.clone()])?;
+		Pallet::<T>::do_update_member(org_id, member.clone(), MemberState::Inactive)?;
+
+	}: _(RawOrigin::Signed(member.clone()), org_id)
+
+	verify {
+		assert!(MemberStates::<T>::get(org_id, member) == MemberState::Active);
+	}
+
+	set_subscription_period {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		Pallet::<T>::update_org(
+			RawOrigin::Root.into(), org_id, None, None, None, None, None, None,
+			Some(FeeModel::Subscription), Some(100_u32.saturated_into())
+		)?;
+		let period: T::BlockNumber = 100_u32.into();
+	}: _(RawOrigin::Signed(caller), org_id, period)
+
+	verify {
+		assert!(OrgSubscriptionPeriod::<T>::get(org_id) == Some(period));
+	}
+
+	collect_subscriptions {
+		let s in 0 .. 100;
+
+		let creator: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&creator)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(creator.clone()).unwrap();
+		Pallet::<T>::update_org(
+			RawOrigin::Root.into(), org_id, None, None, None, None, None, None,
+			Some(FeeModel::Subscription), Some(100_u32.saturated_into())
+		)?;
+		let members: Vec<T::AccountId> = (0..s).map(|i| account("member", i, SEED)).collect();
+		fund_accounts::<T>(&members)?;
+		Pallet::<T>::fill_org_with_members(&org_id, members)?;
+		// First periods are paid at join, the next fees are due one period later
+		let block_number = frame_system::Pallet::<T>::block_number() + T::SubscriptionPeriod::get();
+		frame_system::Pallet::<T>::set_block_number(block_number);
+
+	}: { Pallet::<T>::process_subscriptions(block_number, s); }
+
+	verify {
+		assert!(SubscriptionDues::<T>::iter_prefix(block_number).next().is_none());
+	}
+
+	add_sub_org {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let parent_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		for i in 1..MaxSubOrgs::get() {
+			OrgChildren::<T>::insert(parent_id, T::Hashing::hash_of(&i), ());
+		}
+
+	}: _(RawOrigin::Signed(caller), parent_id, org_id, true)
+
+	verify {
+		assert!(OrgParent::<T>::get(org_id) == Some(parent_id));
+	}
+
+	remove_sub_org {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let parent_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		Pallet::<T>::do_add_sub_org(parent_id, org_id, true)?;
+
+	}: _(RawOrigin::Signed(caller), org_id)
+
+	verify {
+		assert!(!OrgParent::<T>::contains_key(org_id));
+	}
+
+	fund_sub_org {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let parent_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		Pallet::<T>::do_add_sub_org(parent_id, org_id, false)?;
+		fund_account::<T>(&OrgTreasury::<T>::get(parent_id).unwrap())?;
+		let amount: T::Balance = 1_000_u32.saturated_into();
+
+	}: _(RawOrigin::Signed(caller), parent_id, org_id, T::PaymentTokenId::get(), amount)
+
+	verify {
+		let treasury_id = OrgTreasury::<T>::get(org_id).unwrap();
+		assert!(T::Currency::free_balance(T::PaymentTokenId::get(), &treasury_id) >= amount);
+	}
+
+	claw_back_funds {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let parent_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		Pallet::<T>::do_add_sub_org(parent_id, org_id, false)?;
+		fund_account::<T>(&OrgTreasury::<T>::get(org_id).unwrap())?;
+		let amount: T::Balance = 1_000_u32.saturated_into();
+		SubOrgFunding::<T>::insert(org_id, (parent_id, T::PaymentTokenId::get()), amount);
+
+	}: _(RawOrigin::Signed(caller), parent_id, org_id, T::PaymentTokenId::get(), amount)
+
+	verify {
+		let parent_treasury_id = OrgTreasury::<T>::get(parent_id).unwrap();
+		assert!(T::Currency::free_balance(T::PaymentTokenId::get(), &parent_treasury_id) >= amount);
+	}
+
+	set_spend_threshold {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		Pallet::<T>::update_org(
+			RawOrigin::Root.into(), org_id, None, None, None, Some(OrgType::Hybrid), None, None, None, None
+		)?;
+		Pallet::<T>::set_spend_threshold(
+			RawOrigin::Root.into(), org_id, T::PaymentTokenId::get(), Some(1_000_u32.saturated_into())
+		)?;
+		let threshold: T::Balance = 100_u32.saturated_into();
+
+	}: _(RawOrigin::Signed(caller), org_id, T::PaymentTokenId::get(), Some(threshold))
+
+	verify {
+		assert!(OrgSpendThresholds::<T>::get(org_id).contains(&(T::PaymentTokenId::get(), threshold)));
+	}
+
+	set_min_reputation {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		Orgs::<T>::mutate(org_id, |org| if let Some(org) = org { org.org_type = OrgType::Company });
+
+	}: _(RawOrigin::Signed(caller), org_id, 100)
+
+	verify {
+		assert!(OrgMinReputation::<T>::get(org_id) == 100);
+	}
+
+	accept_prime_handover {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		let member: T::AccountId = account("member", 0, SEED);
+		fund_account::<T>(&member)?;
+		<Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::fill_org_with_members(&org_id, vec![member.clone()])?;
+		Pallet::<T>::update_org(
+			RawOrigin::Signed(caller).into(), org_id, None, None, Some(member.clone()), None, None, None, None, None
+		)?;
+
+	}: _(RawOrigin::Signed(member.clone()), org_id)
+
+	verify {
+		assert!(Orgs::<T>::get(org_id).unwrap().prime == member);
+	}
+
+	cancel_prime_handover {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		let member: T::AccountId = account("member", 0, SEED);
+		fund_account::<T>(&member)?;
+		<Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::fill_org_with_members(&org_id, vec![member.clone()])?;
+		Pallet::<T>::update_org(
+			RawOrigin::Signed(caller.clone()).into(), org_id, None, None, Some(member), None, None, None, None, None
+		)?;
+
+	}: _(RawOrigin::Signed(caller), org_id)
+
+	verify {
+		assert!(!PrimeHandovers::<T>::contains_key(org_id));
+	}
+
+	recall_prime {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		let members: Vec<T::AccountId> = (0..2).map(|i| account("member", i, SEED)).collect();
+		fund_accounts::<T>(&members)?;
+		<Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::fill_org_with_members(&org_id, members.clone())?;
+		Pallet::<T>::update_org(
+			RawOrigin::Root.into(), org_id, None, None, None, Some(OrgType::Dao), None, None, None, None
+		)?;
+
+	}: _(RawOrigin::Signed(members[0].clone()), org_id, members[1].clone())
+
+	verify {
+		assert!(Orgs::<T>::get(org_id).unwrap().prime == caller);
+	}
+
+	create_vault {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		// Worst case: name uniqueness is checked against the max number of vaults
+		create_vaults::<T>(org_id, MaxVaults::get() - 1, BoundedVec::truncate_from(b"operations".to_vec()))?;
+		let name: String<T> = BoundedVec::truncate_from(b"prize-pool".to_vec());
+
+	}: _(RawOrigin::Signed(caller), org_id, name, vault_rules::<T>())
+
+	verify {
+		assert!(OrgVaults::<T>::contains_key(org_id, MaxVaults::get()));
+	}
+
+	set_vault_rules {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		let vault_id = create_vaults::<T>(org_id, 1, BoundedVec::truncate_from(b"operations".to_vec()))?;
+		let mut rules = vault_rules::<T>();
+		rules.roles = BoundedVec::truncate_from(vec![OrgRole::Admin, OrgRole::Treasurer]);
+
+	}: _(RawOrigin::Signed(caller), org_id, vault_id, rules.clone())
+
+	verify {
+		assert!(OrgVaults::<T>::get(org_id, vault_id).unwrap().rules == rules);
+	}
+
+	spend_from_vault {
+		// Worst case: spender is a member with the vault role, spending is limited
+		let caller: T::AccountId = whitelisted_caller();
+		let treasurer: T::AccountId = account("treasurer", 0, SEED);
+		let beneficiary: T::AccountId = account("beneficiary", 1, SEED);
+		fund_accounts::<T>(&vec![caller.clone(), treasurer.clone()])?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		Pallet::<T>::fill_org_with_members(&org_id, vec![treasurer.clone()])?;
+		Pallet::<T>::grant_role(RawOrigin::Root.into(), org_id, treasurer.clone(), OrgRole::Treasurer)?;
+		let vault_id = create_vaults::<T>(org_id, 1, BoundedVec::truncate_from(b"operations".to_vec()))?;
+		fund_account::<T>(&OrgVaults::<T>::get(org_id, vault_id).unwrap().account)?;
+		let currency_id = T::PaymentTokenId::get();
+		let amount: T::Balance = 300_000_000_000_00_u128.saturated_into();
+
+	}: _(RawOrigin::Signed(treasurer), org_id, vault_id, currency_id, beneficiary.clone(), amount)
+
+	verify {
+		assert!(T::Currency::free_balance(currency_id, &beneficiary) == amount);
+	}
+
+	transfer_between_vaults {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		let treasury_id = OrgTreasury::<T>::get(&org_id).unwrap();
+		fund_account::<T>(&treasury_id)?;
+		let vault_id = create_vaults::<T>(org_id, 1, BoundedVec::truncate_from(b"operations".to_vec()))?;
+		let vault_account = OrgVaults::<T>::get(org_id, vault_id).unwrap().account;
+		let currency_id = T::PaymentTokenId::get();
+		let amount: T::Balance = 300_000_000_000_00_u128.saturated_into();
+		// Worst case: committed funds of the main treasury are calculated for the max number of streams
+		create_streams::<T>(&caller, org_id, T::MaxStreams::get())?;
+
+	}: _(RawOrigin::Signed(caller), org_id, None, Some(vault_id), currency_id, amount)
+
+	verify {
+		assert!(T::Currency::free_balance(currency_id, &vault_account) == amount);
+	}
+
+	sponsor_seats {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		set_transfer_fee::<T>(org_id);
+		let seats = MaxNamedSeats::get();
+
+	}: _(RawOrigin::Signed(caller.clone()), org_id, seats, named_seats::<T>(seats))
+
+	verify {
+		assert!(SponsorPools::<T>::get(org_id, &caller).unwrap().seats == seats);
+	}
+
+	refund_seats {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = <Pallet::<T> as ControlBenchmarkingTrait<T::AccountId, T::Hash>>::create_org(caller.clone()).unwrap();
+		set_transfer_fee::<T>(org_id);
+		let seats = MaxNamedSeats::get();
+		Pallet::<T>::sponsor_seats(RawOrigin::Signed(caller.clone()).into(), org_id, seats, named_seats::<T>(seats))?;
+
+	}: _(RawOrigin::Signed(caller.clone()), org_id)
+
+	verify {
+		assert!(!SponsorPools::<T>::contains_key(org_id, &caller));
+	}
+
 	impl_benchmark_test_suite!(Control, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -18,26 +18,31 @@ pub mod types;
 mod mock;
 mod tests;
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 
 use codec::Codec;
 use frame_support::{dispatch::{DispatchResult, DispatchError, RawOrigin},
-	ensure, PalletId, traits::Get, BoundedVec, transactional,
+	ensure, log, PalletId, traits::{BalanceStatus, Get, StorageVersion}, BoundedVec, transactional,
 };
-use frame_system::ensure_root;
+use frame_system::{ensure_root, ensure_signed};
 #[cfg(feature = "runtime-benchmarks")]
 use gamedao_traits::ControlBenchmarkingTrait;
-use gamedao_traits::ControlTrait;
+use gamedao_traits::{ControlTrait, GovernanceTrait, OrgAction, SenseTrait, TreasuryTrait};
 use orml_traits::{MultiCurrency, MultiReservableCurrency};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{AccountIdConversion, AtLeast32BitUnsigned, Hash, BadOrigin},
+	traits::{AccountIdConversion, AtLeast32BitUnsigned, Hash, BadOrigin, IdentifyAccount, One, SaturatedConversion, Saturating, Verify, Zero},
 	ArithmeticError::Overflow};
 use sp_std::{fmt::Debug, convert::TryInto, vec, vec::Vec};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
-use types::{OrgType, AccessModel, FeeModel, OrgState, MemberState, MemberLimit};
+use types::{
+	OrgType, AccessModel, FeeModel, OrgState, MemberState, MemberLimit,
+	OrgRole, OrgPermission, MaxRoles, MaxPermissions, MaxDissolutionCurrencies, DissolutionPhase, MaxOrgDepth, MaxSubOrgs, MaxSubtreeOrgs,
+	MaxSpendThresholds, MaxVaults, MaxVaultLimits, VaultId, MaxNamedSeats
+};
 
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -49,13 +54,53 @@ type Org<T> = types::Org<
 	<T as frame_system::Config>::BlockNumber, String<T>
 >;
 
+type Dissolution<T> = types::Dissolution<
+	<T as frame_system::Config>::AccountId, <T as pallet::Config>::Balance, <T as pallet::Config>::CurrencyId
+>;
+
+type Invitation<T> = types::Invitation<
+	<T as frame_system::Config>::Hash, <T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber
+>;
+
+type Stream<T> = types::Stream<
+	<T as frame_system::Config>::AccountId, <T as pallet::Config>::Balance, <T as pallet::Config>::CurrencyId,
+	<T as frame_system::Config>::BlockNumber
+>;
+
+type VaultRules<T> = types::VaultRules<
+	<T as pallet::Config>::Balance, <T as pallet::Config>::CurrencyId, <T as frame_system::Config>::BlockNumber
+>;
+
+type SponsorPool<T> = types::SponsorPool<
+	<T as frame_system::Config>::AccountId, <T as pallet::Config>::Balance, <T as pallet::Config>::CurrencyId
+>;
+
+type Vault<T> = types::Vault<
+	<T as frame_system::Config>::AccountId, <T as pallet::Config>::Balance, <T as pallet::Config>::CurrencyId,
+	<T as frame_system::Config>::BlockNumber, String<T>
+>;
+
+/// Domain tag of the signed invitation payload.
+pub const INVITATION_TAG: &[u8; 18] = b"gamedao/invitation";
+
+/// Signs the org invitations in benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait InvitationSigner<AccountId, Signature> {
+	/// Returns the signer account and its signature of the message.
+	fn sign(message: &[u8]) -> (AccountId, Signature);
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -90,14 +135,38 @@ pub mod pallet {
 		type Currency: MultiCurrency<Self::AccountId, CurrencyId = Self::CurrencyId, Balance = Self::Balance>
 			+ MultiReservableCurrency<Self::AccountId>;
 
+		/// Governance interface used to create the org members votings.
+		type Governance: GovernanceTrait<Self::AccountId, Self::Balance, Self::CurrencyId, Self::Hash>;
+
+		/// Identity and reputation of the accounts, required to join Company orgs.
+		type Sense: SenseTrait<Self::AccountId>;
+
+		/// Off-chain signature of the org invitations.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// Public key of the org invitations signer.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type InvitationSigner: InvitationSigner<Self::AccountId, Self::OffchainSignature>;
+
 		/// The ID for this pallet.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
-		/// The max number of members per one org.
+		/// The default max number of members per one org.
 		#[pallet::constant]
 		type MaxMembers: Get<u32>;
 
+		/// The max number of members for processing in one block (batch size)
+		/// during Org dissolution and subscription fees collection.
+		#[pallet::constant]
+		type MaxMembersProcessing: Get<u32>;
+
+		/// The max number of active treasury payment streams per org.
+		#[pallet::constant]
+		type MaxStreams: Get<u32>;
+
 		/// The CurrencyId which is used as a protokol token.
 		#[pallet::constant]
 		type ProtocolTokenId: Get<Self::CurrencyId>;
@@ -106,10 +175,25 @@ pub mod pallet {
 		#[pallet::constant]
 		type PaymentTokenId: Get<Self::CurrencyId>;
 
+		/// Currencies held by the org treasuries besides the org currencies, shared on the org dissolution.
+		type TreasuryCurrencies: Get<Vec<Self::CurrencyId>>;
+
 		/// The min amount of the deposit which is locked during Org creation (in Protocol tokens).
 		#[pallet::constant]
 		type MinimumDeposit: Get<Self::Balance>;
 
+		/// The default period after which the subscription fee is collected again.
+		#[pallet::constant]
+		type SubscriptionPeriod: Get<Self::BlockNumber>;
+
+		/// The period after the unpaid subscription fee when a member is still active.
+		#[pallet::constant]
+		type SubscriptionGracePeriod: Get<Self::BlockNumber>;
+
+		/// The period during which the proposed prime can accept the org handover.
+		#[pallet::constant]
+		type PrimeHandoverPeriod: Get<Self::BlockNumber>;
+
 		/// The maximum length of a name or cid stored on-chain.
 		#[pallet::constant]
 		type StringLimit: Get<u32>;
@@ -129,13 +213,6 @@ pub mod pallet {
 	pub(super) type OrgStates<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::Hash, OrgState, ValueQuery, GetDefault>;
 
-	/// Org members list by org id.
-	///
-	/// Members: map Hash => Vec<AccountId>
-	#[pallet::storage]
-	pub(super) type Members<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash,
-		BoundedVec<T::AccountId, T::MaxMembers>, ValueQuery>;
-
 	/// Org members count by org id.
 	///
 	/// OrgMemberCount: map Hash => MemberLimit
@@ -143,6 +220,7 @@ pub mod pallet {
 	pub(super) type OrgMemberCount<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, MemberLimit, ValueQuery>;
 
 	/// Member state (Inactive | Active ...) by org Hash and member account.
+	/// Existing entry means that the account is an org member.
 	///
 	/// MemberStates: map Hash, AccountId => MemberState
 	#[pallet::storage]
@@ -155,6 +233,167 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type OrgTreasury<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, T::AccountId, OptionQuery>;
 
+	/// Roles granted to the org member.
+	///
+	/// MemberRoles: map Hash, AccountId => BoundedVec<OrgRole, MaxRoles>
+	#[pallet::storage]
+	pub(super) type MemberRoles<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::Hash,
+		Blake2_128Concat, T::AccountId, BoundedVec<OrgRole, MaxRoles>, ValueQuery>;
+
+	/// Permissions of the org role. Role defaults are used if not set.
+	///
+	/// RolePermissions: map Hash, OrgRole => BoundedVec<OrgPermission, MaxPermissions>
+	#[pallet::storage]
+	pub(super) type RolePermissions<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::Hash,
+		Blake2_128Concat, OrgRole, BoundedVec<OrgPermission, MaxPermissions>, OptionQuery>;
+
+	/// Creation deposit of an Org, returned to the creator on dissolution.
+	///
+	/// OrgDeposits: map Hash => Balance
+	#[pallet::storage]
+	pub(super) type OrgDeposits<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, T::Balance, ValueQuery>;
+
+	/// Dissolving orgs with their settlement progress.
+	///
+	/// OrgDissolutions: map Hash => Dissolution
+	#[pallet::storage]
+	pub(super) type OrgDissolutions<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, Dissolution<T>, OptionQuery>;
+
+	/// Last processed dissolving org, processing continues after it.
+	///
+	/// DissolutionCursor: Hash
+	#[pallet::storage]
+	pub(super) type DissolutionCursor<T: Config> = StorageValue<_, T::Hash, OptionQuery>;
+
+	/// Block until which the member's subscription is paid.
+	///
+	/// MemberSubscriptions: map Hash, AccountId => BlockNumber
+	#[pallet::storage]
+	pub(super) type MemberSubscriptions<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::Hash,
+		Blake2_128Concat, T::AccountId, T::BlockNumber, OptionQuery>;
+
+	/// Subscriptions to be collected in block x.
+	///
+	/// SubscriptionDues: map BlockNumber, (Hash, AccountId) => ()
+	#[pallet::storage]
+	pub(super) type SubscriptionDues<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::BlockNumber,
+		Blake2_128Concat, (T::Hash, T::AccountId), (), OptionQuery>;
+
+	/// Next block to collect the subscriptions for, set once the first subscription is scheduled.
+	///
+	/// SubscriptionDuesCursor: BlockNumber
+	#[pallet::storage]
+	pub(super) type SubscriptionDuesCursor<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	/// Subscription period of the org, the default period is used if not set.
+	///
+	/// OrgSubscriptionPeriod: map Hash => BlockNumber
+	#[pallet::storage]
+	pub(super) type OrgSubscriptionPeriod<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, T::BlockNumber, OptionQuery>;
+
+	/// Parent org of a sub-org.
+	///
+	/// OrgParent: map Hash => Hash
+	#[pallet::storage]
+	pub(super) type OrgParent<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, T::Hash, OptionQuery>;
+
+	/// Sub-orgs of an org.
+	///
+	/// OrgChildren: map Hash, Hash => ()
+	#[pallet::storage]
+	pub(super) type OrgChildren<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::Hash,
+		Blake2_128Concat, T::Hash, (), OptionQuery>;
+
+	/// Sub-orgs which accept the parent org members only.
+	///
+	/// OrgParentMembersOnly: map Hash => bool
+	#[pallet::storage]
+	pub(super) type OrgParentMembersOnly<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, bool, ValueQuery>;
+
+	/// Funds given to the sub-org by its ancestor org, which can be clawed back.
+	///
+	/// SubOrgFunding: map Hash, (Hash, CurrencyId) => Balance
+	#[pallet::storage]
+	pub(super) type SubOrgFunding<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::Hash,
+		Blake2_128Concat, (T::Hash, T::CurrencyId), T::Balance, ValueQuery>;
+
+	/// Redeemed invitations by inviter and nonce.
+	///
+	/// UsedInvitations: map Hash, (AccountId, u64) => ()
+	#[pallet::storage]
+	pub(super) type UsedInvitations<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::Hash,
+		Blake2_128Concat, (T::AccountId, u64), (), OptionQuery>;
+
+	/// Org treasury payment streams.
+	///
+	/// Streams: map Hash, u32 => Stream
+	#[pallet::storage]
+	pub(super) type Streams<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::Hash,
+		Blake2_128Concat, u32, Stream<T>, OptionQuery>;
+
+	/// Id of the next org treasury payment stream.
+	///
+	/// NextStreamId: map Hash => u32
+	#[pallet::storage]
+	pub(super) type NextStreamId<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, u32, ValueQuery>;
+
+	/// Treasury spending thresholds of a Hybrid org, by currency.
+	/// Spends above the threshold are decided by the members voting.
+	///
+	/// OrgSpendThresholds: map Hash => BoundedVec<(CurrencyId, Balance), MaxSpendThresholds>
+	#[pallet::storage]
+	pub(super) type OrgSpendThresholds<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash,
+		BoundedVec<(T::CurrencyId, T::Balance), MaxSpendThresholds>, ValueQuery>;
+
+	/// Min Sense reputation of the Company org members.
+	///
+	/// OrgMinReputation: map Hash => u64
+	#[pallet::storage]
+	pub(super) type OrgMinReputation<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, u64, ValueQuery>;
+
+	/// Pending prime handover: proposed prime and the last block to accept the handover.
+	///
+	/// PrimeHandovers: map Hash => (AccountId, BlockNumber)
+	#[pallet::storage]
+	pub(super) type PrimeHandovers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, (T::AccountId, T::BlockNumber), OptionQuery>;
+
+	/// Named org treasury vaults by org id and vault id.
+	///
+	/// OrgVaults: map Hash, VaultId => Vault
+	#[pallet::storage]
+	pub(super) type OrgVaults<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::Hash,
+		Blake2_128Concat, VaultId, Vault<T>, OptionQuery>;
+
+	/// Number of the org vaults, vault ids start from 1.
+	///
+	/// OrgVaultCount: map Hash => u32
+	#[pallet::storage]
+	pub(super) type OrgVaultCount<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, u32, ValueQuery>;
+
+	/// Amount spent from the vault in the current period, by currency.
+	///
+	/// VaultSpendings: map (Hash, VaultId), CurrencyId => (BlockNumber, Balance)
+	#[pallet::storage]
+	pub(super) type VaultSpendings<T: Config> = StorageDoubleMap<_, Blake2_128Concat, (T::Hash, VaultId),
+		Blake2_128Concat, T::CurrencyId, (T::BlockNumber, T::Balance), ValueQuery>;
+
+	/// Membership seats prepaid by the sponsor account for the org joiners.
+	///
+	/// SponsorPools: double map Hash, AccountId => SponsorPool
+	#[pallet::storage]
+	pub(super) type SponsorPools<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::Hash,
+		Blake2_128Concat, T::AccountId, SponsorPool<T>, OptionQuery>;
+
+	/// Membership fee reserved for the member at join and the account holding it,
+	/// the member itself or the sponsor. Exactly this amount is released when the member leaves.
+	///
+	/// MemberReservedFees: double map Hash, AccountId => (AccountId, Balance)
+	#[pallet::storage]
+	pub(super) type MemberReservedFees<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::Hash,
+		Blake2_128Concat, T::AccountId, (T::AccountId, T::Balance), OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn org_count)]
 	pub type OrgCount<T: Config> = StorageValue<_, u32, ValueQuery>;
@@ -190,8 +429,8 @@ pub mod pallet {
 						let org_id = <T as frame_system::Config>::Hashing::hash_of(&org);
 
 						Pallet::<T>::do_create_org(org_id, &org, treasury_id.clone(), *deposit).expect("Error creating organization in genesis.");
-						Pallet::<T>::do_add_member(org_id, creator.clone(), MemberState::Active).expect("Error adding member in genesis.");
-						Pallet::<T>::pay_membership_fee(creator, treasury_id, &org).expect("Error paying membership fee in genesis.");
+						Pallet::<T>::do_add_member(org_id, &org, creator.clone(), MemberState::Active).expect("Error adding member in genesis.");
+						Pallet::<T>::pay_membership_fee(&org_id, creator, creator, treasury_id, &org).expect("Error paying membership fee in genesis.");
 				});
 		}
 	}
@@ -230,6 +469,29 @@ pub mod pallet {
 			state: MemberState,
 			block_number: T::BlockNumber,
 		},
+		/// A member has been kicked from the Org.
+		MemberKicked {
+			org_id: T::Hash,
+			who: T::AccountId,
+			reason: String<T>,
+			fee_slashed: bool,
+			block_number: T::BlockNumber,
+		},
+		/// An account has been banned from the Org.
+		MemberBanned {
+			org_id: T::Hash,
+			who: T::AccountId,
+			reason: String<T>,
+			fee_slashed: bool,
+			block_number: T::BlockNumber,
+		},
+		/// A member has left the Org.
+		MemberLeft {
+			org_id: T::Hash,
+			who: T::AccountId,
+			reason: String<T>,
+			block_number: T::BlockNumber,
+		},
 		OrgUpdated {
 			org_id: T::Hash,
 			prime_id: Option<T::AccountId>,
@@ -247,6 +509,222 @@ pub mod pallet {
 			currency_id: T::CurrencyId,
 			block_number: T::BlockNumber,
 		},
+		/// A role has been granted to the org member.
+		RoleGranted {
+			org_id: T::Hash,
+			who: T::AccountId,
+			role: OrgRole,
+			block_number: T::BlockNumber,
+		},
+		/// A role has been revoked from the org member.
+		RoleRevoked {
+			org_id: T::Hash,
+			who: T::AccountId,
+			role: OrgRole,
+			block_number: T::BlockNumber,
+		},
+		/// Permissions of the org role have been updated.
+		RolePermissionsUpdated {
+			org_id: T::Hash,
+			role: OrgRole,
+			permissions: BoundedVec<OrgPermission, MaxPermissions>,
+			block_number: T::BlockNumber,
+		},
+		/// Org was frozen and its settlement has started.
+		OrgDissolutionStarted {
+			org_id: T::Hash,
+			block_number: T::BlockNumber,
+		},
+		/// Org was settled and removed.
+		OrgDissolved {
+			org_id: T::Hash,
+			block_number: T::BlockNumber,
+		},
+		/// Org was attached to the parent org.
+		SubOrgAdded {
+			parent_id: T::Hash,
+			org_id: T::Hash,
+			parent_members_only: bool,
+			block_number: T::BlockNumber,
+		},
+		/// Org was detached from the parent org.
+		SubOrgRemoved {
+			parent_id: T::Hash,
+			org_id: T::Hash,
+			block_number: T::BlockNumber,
+		},
+		/// Funds were transferred from the parent org treasury to the sub-org treasury.
+		SubOrgFunded {
+			parent_id: T::Hash,
+			org_id: T::Hash,
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+			block_number: T::BlockNumber,
+		},
+		/// Funds were transferred back from the sub-org treasury to the parent org treasury.
+		SubOrgFundsClawedBack {
+			parent_id: T::Hash,
+			org_id: T::Hash,
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+			block_number: T::BlockNumber,
+		},
+		/// Invitation was redeemed by the new org member.
+		InvitationRedeemed {
+			org_id: T::Hash,
+			who: T::AccountId,
+			inviter: T::AccountId,
+			nonce: u64,
+			block_number: T::BlockNumber,
+		},
+		/// Treasury payment stream was created.
+		StreamCreated {
+			org_id: T::Hash,
+			stream_id: u32,
+			beneficiary: T::AccountId,
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+			period: T::BlockNumber,
+			start: T::BlockNumber,
+			end: Option<T::BlockNumber>,
+			block_number: T::BlockNumber,
+		},
+		/// Accrued stream amount was claimed by the beneficiary.
+		StreamClaimed {
+			org_id: T::Hash,
+			stream_id: u32,
+			beneficiary: T::AccountId,
+			amount: T::Balance,
+			block_number: T::BlockNumber,
+		},
+		/// Treasury payment stream was cancelled, accrued amount was paid out.
+		StreamCancelled {
+			org_id: T::Hash,
+			stream_id: u32,
+			amount: T::Balance,
+			block_number: T::BlockNumber,
+		},
+		/// Subscription fee was paid by the org member.
+		SubscriptionPaid {
+			org_id: T::Hash,
+			who: T::AccountId,
+			amount: T::Balance,
+			paid_until: T::BlockNumber,
+			block_number: T::BlockNumber,
+		},
+		/// Org subscription period was set.
+		SubscriptionPeriodSet {
+			org_id: T::Hash,
+			period: T::BlockNumber,
+			block_number: T::BlockNumber,
+		},
+		/// Hybrid org spending threshold was set or removed.
+		SpendThresholdSet {
+			org_id: T::Hash,
+			currency_id: T::CurrencyId,
+			threshold: Option<T::Balance>,
+			block_number: T::BlockNumber,
+		},
+		/// Hybrid org spending above the threshold was submitted to the members voting.
+		SpendingProposed {
+			org_id: T::Hash,
+			proposer: T::AccountId,
+			beneficiary: T::AccountId,
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+			proposal_id: T::Hash,
+			block_number: T::BlockNumber,
+		},
+		/// Company org min member reputation was set.
+		MinReputationSet {
+			org_id: T::Hash,
+			min_reputation: u64,
+			block_number: T::BlockNumber,
+		},
+		/// Org prime proposed the handover to another member.
+		PrimeHandoverProposed {
+			org_id: T::Hash,
+			prime: T::AccountId,
+			new_prime: T::AccountId,
+			expires: T::BlockNumber,
+			block_number: T::BlockNumber,
+		},
+		/// Pending prime handover was cancelled.
+		PrimeHandoverCancelled {
+			org_id: T::Hash,
+			new_prime: T::AccountId,
+			block_number: T::BlockNumber,
+		},
+		/// Org members voting to recall the prime was created.
+		PrimeRecallProposed {
+			org_id: T::Hash,
+			proposer: T::AccountId,
+			new_prime: T::AccountId,
+			proposal_id: T::Hash,
+			block_number: T::BlockNumber,
+		},
+		/// Org prime was changed.
+		PrimeChanged {
+			org_id: T::Hash,
+			prime: T::AccountId,
+			new_prime: T::AccountId,
+			block_number: T::BlockNumber,
+		},
+		/// Named treasury vault was created.
+		VaultCreated {
+			org_id: T::Hash,
+			vault_id: VaultId,
+			name: String<T>,
+			account: T::AccountId,
+			block_number: T::BlockNumber,
+		},
+		/// Vault spending rules were updated.
+		VaultRulesSet {
+			org_id: T::Hash,
+			vault_id: VaultId,
+			block_number: T::BlockNumber,
+		},
+		/// Funds were spent from the vault.
+		VaultFundsSpent {
+			org_id: T::Hash,
+			vault_id: VaultId,
+			beneficiary: T::AccountId,
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+			block_number: T::BlockNumber,
+		},
+		/// Funds were transferred between the org vaults, `None` is the main org treasury.
+		VaultTransfer {
+			org_id: T::Hash,
+			from: Option<VaultId>,
+			to: Option<VaultId>,
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+			block_number: T::BlockNumber,
+		},
+		/// Membership seats were prepaid by the sponsor.
+		SeatsSponsored {
+			org_id: T::Hash,
+			sponsor: T::AccountId,
+			seats: u32,
+			amount: T::Balance,
+			block_number: T::BlockNumber,
+		},
+		/// Sponsored seat was used by the joining member.
+		SponsoredSeatUsed {
+			org_id: T::Hash,
+			sponsor: T::AccountId,
+			who: T::AccountId,
+			block_number: T::BlockNumber,
+		},
+		/// Unused sponsored seats were refunded to the sponsor.
+		SeatsRefunded {
+			org_id: T::Hash,
+			sponsor: T::AccountId,
+			seats: u32,
+			amount: T::Balance,
+			block_number: T::BlockNumber,
+		},
 	}
 
 	#[pallet::error]
@@ -264,6 +742,8 @@ pub mod pallet {
 		AlreadyMember,
 		/// Member Unknonw.
 		NotMember,
+		/// Account is banned from the Org.
+		MemberBanned,
 		NoChangesProvided,
 		/// Treasury account already exists.
 		TreasuryExists,
@@ -272,7 +752,106 @@ pub mod pallet {
 		/// Minimum deposit to Treasury too low.
 		MinimumDepositTooLow,
 		MissingParameter,
-		WrongOrganizationType
+		WrongOrganizationType,
+		/// Role is already granted to the member.
+		RoleAlreadyGranted,
+		/// Role is not granted to the member.
+		RoleNotGranted,
+		/// Caller can't grant or revoke the permissions it doesn't hold.
+		PermissionNotHeld,
+		/// Prime can't be removed or leave the org until the prime is handed over.
+		PrimeNotRemovable,
+		/// Member's roles outrank the caller's.
+		RoleOutranked,
+		/// Org is being dissolved.
+		OrganizationDissolving,
+		/// Org fee model is not a subscription.
+		NoSubscription,
+		/// Subscription period is zero.
+		InvalidSubscriptionPeriod,
+		/// Org has sub-orgs.
+		OrgHasSubOrgs,
+		/// Org already has a parent org.
+		ParentExists,
+		/// Org is not a sub-org of the given org.
+		NotSubOrg,
+		/// Org can't be a sub-org of itself.
+		WrongParent,
+		/// Max depth of the sub-orgs tree reached.
+		MaxOrgDepthReached,
+		/// Max number of the org sub-orgs reached.
+		MaxSubOrgsReached,
+		/// Amount exceeds the funds given to the sub-org by the ancestor org.
+		ClawBackLimitExceeded,
+		/// Account is not an active member of the parent org.
+		NotParentMember,
+		/// Invitation is expired.
+		InvitationExpired,
+		/// Invitation was already redeemed.
+		InvitationUsed,
+		/// Invitation signature is invalid.
+		InvalidSignature,
+		/// Invitation is issued for another account.
+		WrongInvitee,
+		/// Invitations are not available for the org access model.
+		InvitationsNotAllowed,
+		/// Treasury funds are committed to the payment streams.
+		FundsCommitted,
+		/// Stream amount, period or schedule is invalid.
+		InvalidStream,
+		/// Max number of the org streams reached.
+		TooManyStreams,
+		/// Stream Unknown.
+		StreamUnknown,
+		/// Nothing accrued since the last claim.
+		NothingAccrued,
+		/// Spending above the org threshold has to be decided by the members voting.
+		SpendingVoteRequired,
+		/// Max number of the spending thresholds reached.
+		TooManyThresholds,
+		/// Account has no verified identity (Sense entity).
+		NoIdentity,
+		/// Account reputation is below the org minimum.
+		ReputationTooLow,
+		/// Org has too many members to be validated in one call.
+		TooManyMembers,
+		/// No pending prime handover.
+		NoPrimeHandover,
+		/// Prime handover is expired.
+		PrimeHandoverExpired,
+		/// Account is already the org prime.
+		AlreadyPrime,
+		/// Vault Unknown.
+		VaultUnknown,
+		/// Org already has a vault with this name.
+		VaultExists,
+		/// Max number of the org vaults reached.
+		TooManyVaults,
+		/// Vault name is empty, period is not set for the limits or a currency is limited twice.
+		InvalidVaultRules,
+		/// Spending exceeds the vault limit for the current period.
+		VaultLimitExceeded,
+		/// Source and destination vaults are the same.
+		SameVault,
+		/// Org fee model or access model doesn't allow sponsored memberships.
+		SponsorshipNotAllowed,
+		/// Sponsor has no seat left for the joiner.
+		NoSponsoredSeat,
+		/// Membership fee differs from the price of the sponsored seats, the seats should be refunded first.
+		SeatPriceChanged,
+		/// Max number of the named sponsored seats reached or more named joiners than seats.
+		TooManyNamedSeats,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+
+		fn on_initialize(block_number: T::BlockNumber) -> Weight {
+			let max_members = T::MaxMembersProcessing::get();
+			let (members, orgs) = Self::process_dissolutions(max_members);
+			let dues = Self::process_subscriptions(block_number, max_members.saturating_sub(members));
+			T::WeightInfo::on_initialize(members, orgs).saturating_add(T::WeightInfo::collect_subscriptions(dues))
+		}
 	}
 
 	#[pallet::call]
@@ -294,11 +873,17 @@ pub mod pallet {
 		/// - `gov_asset`: control assets to empower actors.
 		/// - `pay_asset`: asset used for payments.
 		/// - `deposit`: initial deposit for the org treasury (in Protocol tokens).
+		/// - `parent_id`: parent org, the new org is attached to as a sub-org (see `add_sub_org`).
+		/// - `parent_members_only`: only active parent org members can join the org. Default: false.
+		///
+		/// Company org creator is required to have a verified identity (Sense entity).
 		///
 		/// Emits `OrgCreated` event when successful.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::create_org())]
+		#[pallet::weight(T::WeightInfo::create_org().saturating_add(
+			if parent_id.is_some() { T::WeightInfo::add_sub_org() } else { Weight::zero() }
+		))]
 		// Tests are not working without this deprecated transactional macro
 		#[transactional]
 		pub fn create_org(
@@ -314,8 +899,10 @@ pub mod pallet {
 			gov_currency: Option<T::CurrencyId>,
 			pay_currency: Option<T::CurrencyId>,
 			deposit: Option<T::Balance>,
+			parent_id: Option<T::Hash>,
+			parent_members_only: Option<bool>,
 		) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
+			let sender = ensure_signed(origin.clone())?;
 
 			// Provide default values for optional parameters:
 			let member_limit = member_limit.unwrap_or(T::MaxMembers::get());
@@ -323,10 +910,6 @@ pub mod pallet {
 			let pay_currency = pay_currency.unwrap_or(T::PaymentTokenId::get());
 			let deposit = deposit.unwrap_or(T::MinimumDeposit::get());
 
-			ensure!(
-				org_type == OrgType::Individual || org_type == OrgType::Dao,
-				Error::<T>::WrongOrganizationType
-			);
 			ensure!(deposit >= T::MinimumDeposit::get(), Error::<T>::MinimumDepositTooLow);
 			ensure!(fee_model == FeeModel::NoFees || membership_fee.is_some(), Error::<T>::MissingParameter);
 
@@ -344,21 +927,33 @@ pub mod pallet {
 			ensure!(!Orgs::<T>::contains_key(org_id), Error::<T>::OrganizationExists);
 
 			Self::do_create_org(org_id, &org, treasury_id.clone(), deposit)?;
-			Self::do_add_member(org_id, sender.clone(), MemberState::Active)?;
-			Self::pay_membership_fee(&sender, &treasury_id, &org)?;
+			Self::do_add_member(org_id, &org, sender.clone(), MemberState::Active)?;
+			Self::pay_membership_fee(&org_id, &sender, &sender, &treasury_id, &org)?;
 
+			if let Some(parent_id) = parent_id {
+				let parent = Orgs::<T>::get(parent_id).ok_or(Error::<T>::OrganizationUnknown)?;
+				Self::ensure_sub_org_allowed(&parent_id, &org_id)?;
+				Self::request_sub_org(origin, parent_id, &parent, org_id, parent_members_only.unwrap_or(false))?;
+			}
 			Ok(())
 		}
 
 		/// Update Org
 		///
-		/// Allowed origins: Root or prime if OrgType::Individual
+		/// Allowed origins: Root or member with UpdateOrg permission.
+		/// Prime and org type can be changed by Root or the current prime only.
+		/// New prime set by Root is applied at once,
+		/// the current prime proposes the handover, which has to be accepted by the new prime.
+		/// Org which is switched to Company type requires the prime and the members to have
+		/// a verified identity, the org can't have more than `MaxMembersProcessing` member records.
+		/// Spending thresholds are removed when switching from Hybrid type,
+		/// min member reputation is removed when switching from Company type.
 		///
 		/// Parameters:
 		/// - `org_id`: Org hash.
 		///
 		/// Optional parameters:
-		/// - `prime_id`: new prime id.
+		/// - `prime_id`: new prime id, active org member.
 		/// - `access_model`: new access model.
 		/// - `member_limit`: new member limit.
 		/// - `fee_model`: new fee model.
@@ -367,7 +962,10 @@ pub mod pallet {
 		/// Emits `OrgUpdated` event when successful.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::update_org())]
+		#[pallet::weight(T::WeightInfo::update_org().saturating_add(
+			// Members are validated when switching to Company type
+			T::DbWeight::get().reads(if org_type.is_some() { 2 * T::MaxMembersProcessing::get() as u64 } else { 0 })
+		))]
 		pub fn update_org(
 			origin: OriginFor<T>,
 			org_id: T::Hash,
@@ -381,8 +979,13 @@ pub mod pallet {
 			membership_fee: Option<T::Balance>,
 		) -> DispatchResult {
 			let mut org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
-			// Create entity like a council
-			Self::ensure_root_or_prime(origin, org.prime.clone(), org.org_type.clone())?;
+			Self::ensure_not_dissolving(&org_id)?;
+			if prime_id.is_some() || org_type.is_some() {
+				Self::ensure_root_or_prime(origin.clone(), org.prime.clone())?;
+			}
+			let is_root = ensure_root(origin.clone()).is_ok();
+			Self::ensure_permission(origin, &org_id, &org, OrgPermission::UpdateOrg)?;
+			let previous_org_type = org.org_type.clone();
 
 			let args = [ name.is_some(), cid.is_some(), prime_id.is_some(), fee_model.is_some(), membership_fee.is_some(),
 						access_model.is_some(), member_limit.is_some(), org_type.is_some()];
@@ -392,12 +995,21 @@ pub mod pallet {
 			if cid.is_some() { org.cid = cid.clone().unwrap(); };
 			if access_model.is_some() { org.access_model = access_model.clone().unwrap(); };
 			if org_type.is_some() { org.org_type = org_type.clone().unwrap(); };
-			if member_limit.is_some() { org.member_limit = member_limit.unwrap(); };
+			if member_limit.is_some() {
+				let member_limit = member_limit.unwrap();
+				ensure!(member_limit >= OrgMemberCount::<T>::get(org_id), Error::<T>::MembershipLimitReached);
+				org.member_limit = member_limit;
+			};
 			if membership_fee.is_some() { org.membership_fee = membership_fee; };
 			if prime_id.is_some() {
 				let prime_id = prime_id.clone().unwrap();
-				ensure!(MemberStates::<T>::contains_key(org_id, &prime_id), Error::<T>::NotMember);
-				org.prime = prime_id;
+				ensure!(Self::is_member_active(&org_id, &org, &prime_id), Error::<T>::NotMember);
+				if is_root {
+					Self::do_set_prime(org_id, &mut org, prime_id);
+				} else {
+					ensure!(prime_id != org.prime, Error::<T>::AlreadyPrime);
+					Self::do_propose_prime(org_id, &org, prime_id);
+				}
 			};
 			if fee_model.is_some() {
 				let fee_model = fee_model.clone().unwrap();
@@ -406,6 +1018,9 @@ pub mod pallet {
 				};
 				org.fee_model = fee_model;
 			};
+			if org.org_type != previous_org_type {
+				Self::switch_org_type(&org_id, &org, &previous_org_type)?;
+			}
 
 			Orgs::<T>::insert(org_id, org);
 
@@ -421,7 +1036,7 @@ pub mod pallet {
 		/// Enable Org
 		///
 		/// Enables an Org to be used and changes it's state to Active.
-		/// Allowed origins: Root or prime if OrgType::Individual
+		/// Allowed origins: Root or member with ManageState permission.
 		///
 		/// Parameters:
 		/// - `org_id`: Org hash.
@@ -432,7 +1047,8 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::enable_org())]
 		pub fn enable_org(origin: OriginFor<T>, org_id: T::Hash) -> DispatchResult {
 			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
-			Self::ensure_root_or_prime(origin, org.prime, org.org_type)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			Self::ensure_permission(origin, &org_id, &org, OrgPermission::ManageState)?;
 
 			OrgStates::<T>::insert(org_id, OrgState::Active);
 			Self::deposit_event(Event::OrgEnabled(org_id));
@@ -442,7 +1058,7 @@ pub mod pallet {
 		/// Disable Org
 		///
 		/// Disables an Org to be used and changes it's state to Inactive.
-		/// Allowed origins: Root or prime if OrgType::Individual
+		/// Allowed origins: Root or member with ManageState permission.
 		///
 		/// Parameters:
 		/// - `org_id`: Org hash.
@@ -453,7 +1069,8 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::disable_org())]
 		pub fn disable_org(origin: OriginFor<T>, org_id: T::Hash) -> DispatchResult {
 			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
-			Self::ensure_root_or_prime(origin, org.prime, org.org_type)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			Self::ensure_permission(origin, &org_id, &org, OrgPermission::ManageState)?;
 
 			OrgStates::<T>::insert(org_id, OrgState::Inactive);
 			Self::deposit_event(Event::OrgDisabled(org_id));
@@ -462,236 +1079,2271 @@ pub mod pallet {
 
 		/// Add Member to Org
 		///
+		/// Member count is limited by the org `member_limit`.
+		///
+		/// For the orgs with AccessModel::Voting an account applies for membership by itself:
+		/// membership fee is reserved and a members voting is created. Applicant remains
+		/// Pending until the voting is finalized, rejected applicant is removed and the fee is unreserved.
+		/// Root adds members without a voting.
+		///
+		/// Membership fee is paid by the account adding the member, unless a sponsor is selected:
+		/// the fee is then taken from the sponsor's prepaid seats. Members added by Root pay the fee themselves.
+		///
 		/// Parameters:
 		/// - `org_id`: Org id
 		/// - `who`: Account to be added
+		/// - `sponsor`: account paying the membership fee from its sponsored seats
 		///
 		/// Emits `MemberAdded` event when successful.
 		///
-		/// Weight: `O(log n)`
-		#[pallet::weight(T::WeightInfo::add_member(T::MaxMembers::get()))]
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::add_member())]
+		#[transactional]
 		pub fn add_member(
 			origin: OriginFor<T>,
 			org_id: T::Hash,
-			who: T::AccountId
-		) -> DispatchResultWithPostInfo {
+			who: T::AccountId,
+			sponsor: Option<T::AccountId>
+		) -> DispatchResult {
 			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			let mut is_application = false;
+			// Root adds members at their own cost, the signed sender pays for the others it adds
+			let mut fee_payer = who.clone();
 			if let Ok(sender) = ensure_signed(origin.clone()) {
 				ensure!(
-					sender == who || (org.access_model == AccessModel::Prime && sender == org.prime),
+					sender == who || (
+						org.access_model == AccessModel::Prime &&
+						Self::has_permission(&org_id, &org, &sender, OrgPermission::AddMember)
+					),
+					BadOrigin
+				);
+				is_application = org.access_model == AccessModel::Voting;
+				fee_payer = sender;
+			}
+			else {
+				ensure_root(origin)?;
+			}
+			Self::ensure_parent_member(&org_id, &who)?;
+			if is_application {
+				ensure!(sponsor.is_none(), Error::<T>::SponsorshipNotAllowed);
+				// Applicant's fee stays reserved until the members voting is finalized
+				Self::reserve_membership_fee(&org_id, &who, &org)?;
+				Self::do_add_member(org_id, &org, who.clone(), MemberState::Pending)?;
+				T::Governance::create_org_action_proposal(&org_id, &who, OrgAction::AddMember(who.clone()))?;
+			} else {
+				let member_state = match org.access_model {
+					AccessModel::Prime => MemberState::Pending,
+					_ => MemberState::Active,
+				};
+				let treasury_id = OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?;
+				match sponsor {
+					Some(sponsor) if sponsor != who => {
+						Self::use_sponsored_seat(&org_id, &org, &treasury_id, &sponsor, &who)?;
+					},
+					_ => Self::pay_membership_fee(&org_id, &who, &fee_payer, &treasury_id, &org)?,
+				}
+				Self::do_add_member(org_id, &org, who.clone(), member_state)?;
+			}
+
+			Ok(())
+		}
+
+		/// Update member state in the organization
+		///
+		/// Only Pending members are updated. Activated member pays the membership fee reserved at join,
+		/// the reserved fee is returned to its payer when the member is moved to a former member state.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `who`: Account to change state for
+		/// - `state`: new state value
+		///
+		/// Emits `MemberUpdated` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::update_member_state())]
+		pub fn update_member_state(
+			origin: OriginFor<T>,
+			org_id: T::Hash,
+			who: T::AccountId,
+			state: MemberState
+		) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			Self::ensure_membership_permissions(origin, &org_id, &org, who.clone(), OrgPermission::UpdateMember)?;
+
+			let current_member_state = MemberStates::<T>::get(org_id, who.clone());
+			if current_member_state != MemberState::Pending {
+				return Ok(())
+			}
+			match state {
+				MemberState::Active => Self::process_membership_application(org_id, &org, who, true)?,
+				MemberState::Kicked | MemberState::Banned | MemberState::Exited => {
+					Self::do_expel_member(org_id, &org, &who, state.clone(), false)?;
+					let block_number = frame_system::Pallet::<T>::block_number();
+					Self::deposit_event(Event::MemberUpdated { org_id, who, state, block_number });
+				},
+				_ => Self::do_update_member(org_id, who, state)?,
+			}
+			Ok(())
+		}
+
+		/// Remove member from Org
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `who`: Account to be removed
+		///
+		/// Emits `MemberRemoved` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::remove_member())]
+		pub fn remove_member(origin: OriginFor<T>, org_id: T::Hash, who: T::AccountId) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			let member_state = MemberStates::<T>::get(org_id, &who);
+			// Ban is lifted by the org only
+			if member_state == MemberState::Banned {
+				Self::ensure_permission(origin, &org_id, &org, OrgPermission::RemoveMember)?;
+			} else {
+				Self::ensure_membership_permissions(origin.clone(), &org_id, &org, who.clone(), OrgPermission::RemoveMember)?;
+				Self::ensure_can_expel(origin, &org_id, &org, &who)?;
+			}
+			Self::do_remove_member(org_id, &org, who)?;
+
+			Ok(())
+		}
+
+		/// Kick member from Org
+		///
+		/// Member state becomes Kicked, the account can join the org again.
+		/// Reserved membership fee is slashed to the org treasury or unreserved.
+		///
+		/// Allowed origins: Root or member with RemoveMember permission.
+		/// Prime and the members with higher roles than the caller's can't be kicked.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `who`: Account to be kicked
+		/// - `reason`: CID of the kick reason
+		/// - `slash_fee`: slash the reserved membership fee
+		///
+		/// Emits `MemberKicked` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::kick_member())]
+		#[transactional]
+		pub fn kick_member(
+			origin: OriginFor<T>,
+			org_id: T::Hash,
+			who: T::AccountId,
+			reason: String<T>,
+			slash_fee: bool
+		) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			Self::ensure_permission(origin.clone(), &org_id, &org, OrgPermission::RemoveMember)?;
+			Self::ensure_can_expel(origin, &org_id, &org, &who)?;
+			ensure!(
+				MemberStates::<T>::contains_key(org_id, &who) &&
+				!Self::is_former_member(&MemberStates::<T>::get(org_id, &who)),
+				Error::<T>::NotMember
+			);
+			let fee_slashed = Self::do_expel_member(org_id, &org, &who, MemberState::Kicked, slash_fee)?;
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::MemberKicked { org_id, who, reason, fee_slashed, block_number });
+			Ok(())
+		}
+
+		/// Ban account from Org
+		///
+		/// Banned account can't join the org until the ban is lifted by `remove_member`.
+		/// Member's reserved membership fee is slashed to the org treasury or unreserved.
+		///
+		/// Allowed origins: Root or member with RemoveMember permission.
+		/// Prime and the members with higher roles than the caller's can't be banned.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `who`: Account to be banned, not necessarily a member
+		/// - `reason`: CID of the ban reason
+		/// - `slash_fee`: slash the reserved membership fee
+		///
+		/// Emits `MemberBanned` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::ban_member())]
+		#[transactional]
+		pub fn ban_member(
+			origin: OriginFor<T>,
+			org_id: T::Hash,
+			who: T::AccountId,
+			reason: String<T>,
+			slash_fee: bool
+		) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			Self::ensure_permission(origin.clone(), &org_id, &org, OrgPermission::RemoveMember)?;
+			Self::ensure_can_expel(origin, &org_id, &org, &who)?;
+			ensure!(MemberStates::<T>::get(org_id, &who) != MemberState::Banned, Error::<T>::MemberBanned);
+			let fee_slashed = Self::do_expel_member(org_id, &org, &who, MemberState::Banned, slash_fee)?;
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::MemberBanned { org_id, who, reason, fee_slashed, block_number });
+			Ok(())
+		}
+
+		/// Leave Org
+		///
+		/// Member state becomes Exited, reserved membership fee is unreserved.
+		/// Prime has to hand the org over before leaving.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `reason`: CID of the leave reason
+		///
+		/// Emits `MemberLeft` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::leave_org())]
+		#[transactional]
+		pub fn leave_org(origin: OriginFor<T>, org_id: T::Hash, reason: String<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			ensure!(who != org.prime, Error::<T>::PrimeNotRemovable);
+			ensure!(
+				MemberStates::<T>::contains_key(org_id, &who) &&
+				!Self::is_former_member(&MemberStates::<T>::get(org_id, &who)),
+				Error::<T>::NotMember
+			);
+			Self::do_expel_member(org_id, &org, &who, MemberState::Exited, false)?;
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::MemberLeft { org_id, who, reason, block_number });
+			Ok(())
+		}
+
+		/// Make spending from the org treasury
+		///
+		/// Allowed origins: Root or member with SpendFunds permission.
+		/// Hybrid org spends above the threshold are made by Root or the members voting only,
+		/// a signed call creates the spending voting, the amount is reserved until it ends.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `currency_id`: currency to be spent
+		/// - `beneficiary`: receiver account
+		/// - `amount`: amount to be spent
+		///
+		/// Emits `FundsSpended` or `SpendingProposed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::spend_funds())]
+		#[transactional]
+		pub fn spend_funds(
+			origin: OriginFor<T>,
+			org_id: T::Hash,
+			currency_id: T::CurrencyId,
+			beneficiary: T::AccountId,
+			amount: T::Balance
+		) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			let treasury_id = OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?;
+			Self::ensure_permission(origin.clone(), &org_id, &org, OrgPermission::SpendFunds)?;
+			Self::ensure_uncommitted_funds(&org_id, &treasury_id, currency_id, amount)?;
+			if Self::ensure_spend_threshold(origin.clone(), &org_id, &org, currency_id, Some(amount)).is_err() {
+				let proposer = ensure_signed(origin)?;
+				let proposal_id = T::Governance::create_spending_proposal(
+					&org_id, &proposer, currency_id, &beneficiary, amount
+				)?;
+				let block_number = frame_system::Pallet::<T>::block_number();
+				Self::deposit_event(Event::SpendingProposed {
+					org_id, proposer, beneficiary, currency_id, amount, proposal_id, block_number
+				});
+				return Ok(())
+			}
+
+			T::Currency::transfer(currency_id, &treasury_id, &beneficiary, amount
+				).map_err(|_| Error::<T>::BalanceLow)?;
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::FundsSpended { org_id, beneficiary, amount, currency_id, block_number });
+
+			Ok(())
+		}
+
+		/// Create treasury payment stream
+		///
+		/// Beneficiary claims `amount` for every `period` passed since `start`, until the optional `end`.
+		/// Treasury has to cover the whole amount of the stream with the end, accrued amounts
+		/// of the active streams can't be spent.
+		///
+		/// Allowed origins: Root or member with SpendFunds permission.
+		/// Hybrid org streams above the spending threshold and endless streams are created by Root only.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `beneficiary`: receiver account
+		/// - `currency_id`: currency to be paid
+		/// - `amount`: amount paid per period
+		/// - `period`: period length in blocks
+		/// - `start`: first block of the stream
+		/// - `end`: last block of the stream, endless if not set
+		///
+		/// Emits `StreamCreated` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::create_stream())]
+		pub fn create_stream(
+			origin: OriginFor<T>,
+			org_id: T::Hash,
+			beneficiary: T::AccountId,
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+			period: T::BlockNumber,
+			start: T::BlockNumber,
+			end: Option<T::BlockNumber>
+		) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			let treasury_id = OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?;
+			Self::ensure_permission(origin.clone(), &org_id, &org, OrgPermission::SpendFunds)?;
+			let block_number = frame_system::Pallet::<T>::block_number();
+			ensure!(!amount.is_zero() && !period.is_zero() && start >= block_number, Error::<T>::InvalidStream);
+			ensure!(
+				(Streams::<T>::iter_key_prefix(org_id).count() as u32) < T::MaxStreams::get(),
+				Error::<T>::TooManyStreams
+			);
+
+			let stream = types::Stream {
+				beneficiary: beneficiary.clone(), currency_id, amount, period, start, end, claimed_until: start
+			};
+			if let Some(end) = end {
+				ensure!(end >= start.saturating_add(period), Error::<T>::InvalidStream);
+			}
+			let committed = end.map(|end| Self::stream_committed(&stream, end));
+			Self::ensure_spend_threshold(origin, &org_id, &org, currency_id, committed)?;
+			if let Some(committed) = committed {
+				Self::ensure_uncommitted_funds(&org_id, &treasury_id, currency_id, committed)?;
+			}
+			let stream_id = NextStreamId::<T>::get(org_id);
+			NextStreamId::<T>::insert(org_id, stream_id.checked_add(1).ok_or(Overflow)?);
+			Streams::<T>::insert(org_id, stream_id, stream);
+
+			Self::deposit_event(Event::StreamCreated {
+				org_id, stream_id, beneficiary, currency_id, amount, period, start, end, block_number
+			});
+			Ok(())
+		}
+
+		/// Claim treasury payment stream
+		///
+		/// Pays out the amount accrued since the last claim. Finished stream is removed.
+		///
+		/// Allowed origins: Stream beneficiary.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `stream_id`: Stream id
+		///
+		/// Emits `StreamClaimed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::claim_stream())]
+		pub fn claim_stream(origin: OriginFor<T>, org_id: T::Hash, stream_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut stream = Streams::<T>::get(org_id, stream_id).ok_or(Error::<T>::StreamUnknown)?;
+			ensure!(stream.beneficiary == who, BadOrigin);
+			let treasury_id = OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?;
+			let block_number = frame_system::Pallet::<T>::block_number();
+			let (amount, claimed_until) = Self::stream_accrued(&stream, block_number);
+			ensure!(!amount.is_zero(), Error::<T>::NothingAccrued);
+
+			T::Currency::transfer(stream.currency_id, &treasury_id, &who, amount).map_err(|_| Error::<T>::BalanceLow)?;
+			stream.claimed_until = claimed_until;
+			match stream.end {
+				Some(end) if claimed_until.saturating_add(stream.period) > end => Streams::<T>::remove(org_id, stream_id),
+				_ => Streams::<T>::insert(org_id, stream_id, stream),
+			}
+
+			Self::deposit_event(Event::StreamClaimed { org_id, stream_id, beneficiary: who, amount, block_number });
+			Ok(())
+		}
+
+		/// Cancel treasury payment stream
+		///
+		/// Pays out the accrued amount to the beneficiary and removes the stream.
+		///
+		/// Allowed origins: Root or member with SpendFunds permission.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `stream_id`: Stream id
+		///
+		/// Emits `StreamCancelled` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_stream())]
+		#[transactional]
+		pub fn cancel_stream(origin: OriginFor<T>, org_id: T::Hash, stream_id: u32) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_permission(origin, &org_id, &org, OrgPermission::SpendFunds)?;
+			let stream = Streams::<T>::take(org_id, stream_id).ok_or(Error::<T>::StreamUnknown)?;
+			let treasury_id = OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?;
+			let amount = Self::settle_stream(&treasury_id, &stream)?;
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::StreamCancelled { org_id, stream_id, amount, block_number });
+			Ok(())
+		}
+
+		/// Grant a role to the org member
+		///
+		/// Allowed origins: Root, org prime or member with ManageRoles permission holding
+		/// every permission of the role.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `who`: Active org member
+		/// - `role`: Admin | Treasurer | Moderator | Recruiter
+		///
+		/// Emits `RoleGranted` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::grant_role())]
+		pub fn grant_role(
+			origin: OriginFor<T>,
+			org_id: T::Hash,
+			who: T::AccountId,
+			role: OrgRole
+		) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			Self::ensure_permission(origin.clone(), &org_id, &org, OrgPermission::ManageRoles)?;
+			Self::ensure_permissions_held(origin, &org_id, &org, &Self::role_permissions(&org_id, &role))?;
+			ensure!(MemberStates::<T>::get(org_id, &who) == MemberState::Active, Error::<T>::NotMember);
+
+			MemberRoles::<T>::try_mutate(org_id, &who, |roles| -> DispatchResult {
+				let location = roles.binary_search(&role).err().ok_or(Error::<T>::RoleAlreadyGranted)?;
+				roles.try_insert(location, role).map_err(|_| Error::<T>::RoleAlreadyGranted)?;
+				Ok(())
+			})?;
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::RoleGranted { org_id, who, role, block_number });
+			Ok(())
+		}
+
+		/// Revoke a role from the org member
+		///
+		/// Allowed origins: Root, org prime or member with ManageRoles permission holding
+		/// every permission of the role.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `who`: Org member
+		/// - `role`: Admin | Treasurer | Moderator | Recruiter
+		///
+		/// Emits `RoleRevoked` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::revoke_role())]
+		pub fn revoke_role(
+			origin: OriginFor<T>,
+			org_id: T::Hash,
+			who: T::AccountId,
+			role: OrgRole
+		) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			Self::ensure_permission(origin.clone(), &org_id, &org, OrgPermission::ManageRoles)?;
+			Self::ensure_permissions_held(origin, &org_id, &org, &Self::role_permissions(&org_id, &role))?;
+
+			MemberRoles::<T>::try_mutate_exists(org_id, &who, |maybe_roles| -> DispatchResult {
+				let roles = maybe_roles.as_mut().ok_or(Error::<T>::RoleNotGranted)?;
+				let location = roles.binary_search(&role).map_err(|_| Error::<T>::RoleNotGranted)?;
+				roles.remove(location);
+				if roles.is_empty() {
+					*maybe_roles = None;
+				}
+				Ok(())
+			})?;
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::RoleRevoked { org_id, who, role, block_number });
+			Ok(())
+		}
+
+		/// Set permissions of the org role, replacing the role defaults
+		///
+		/// Allowed origins: Root, org prime or member with ManageRoles permission holding
+		/// every permission of the role, before and after the change.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `role`: Admin | Treasurer | Moderator | Recruiter
+		/// - `permissions`: list of permissions for the role
+		///
+		/// Emits `RolePermissionsUpdated` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_role_permissions())]
+		pub fn set_role_permissions(
+			origin: OriginFor<T>,
+			org_id: T::Hash,
+			role: OrgRole,
+			permissions: BoundedVec<OrgPermission, MaxPermissions>
+		) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			Self::ensure_permission(origin.clone(), &org_id, &org, OrgPermission::ManageRoles)?;
+			let mut permissions = permissions.into_inner();
+			let mut changed = Self::role_permissions(&org_id, &role);
+			changed.extend(permissions.iter().cloned());
+			Self::ensure_permissions_held(origin, &org_id, &org, &changed)?;
+
+			permissions.sort();
+			permissions.dedup();
+			let permissions = BoundedVec::truncate_from(permissions);
+			RolePermissions::<T>::insert(org_id, role, &permissions);
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::RolePermissionsUpdated { org_id, role, permissions, block_number });
+			Ok(())
+		}
+
+		/// Dissolve Org
+		///
+		/// Freezes the org and returns the creation deposit to the creator. Settlement is processed
+		/// in batches of `MaxMembersProcessing` members per block: reserved membership fees are
+		/// unreserved, treasury balances in the org and `TreasuryCurrencies` currencies are distributed
+		/// pro rata to the active members, then the org is removed. Dissolving orgs are processed in turns.
+		/// For Dao orgs a signed call creates a members voting, dissolution starts when it passes.
+		///
+		/// Allowed origins: Root or member with DissolveOrg permission.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		///
+		/// Emits `OrgDissolutionStarted` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::dissolve_org())]
+		#[transactional]
+		pub fn dissolve_org(origin: OriginFor<T>, org_id: T::Hash) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			Self::ensure_permission(origin.clone(), &org_id, &org, OrgPermission::DissolveOrg)?;
+			ensure!(!Self::has_sub_orgs(&org_id), Error::<T>::OrgHasSubOrgs);
+
+			match ensure_signed(origin) {
+				Ok(sender) if org.org_type == OrgType::Dao || org.org_type == OrgType::Hybrid => {
+					T::Governance::create_org_action_proposal(&org_id, &sender, OrgAction::Dissolve)?;
+				},
+				_ => Self::do_dissolve_org(org_id, &org)?,
+			}
+			Ok(())
+		}
+
+		/// Add sub-org
+		///
+		/// Attaches the org as a sub-org (guild) of the parent org. Trees are built from the top,
+		/// so the org should not have sub-orgs itself. Org is attached at once if the caller
+		/// has ManageSubOrgs permission in the parent org. Otherwise for the Dao parent org
+		/// a parent members voting is created.
+		///
+		/// Allowed origins: Root or the org prime.
+		///
+		/// Parameters:
+		/// - `parent_id`: Parent org id
+		/// - `org_id`: Org id
+		/// - `parent_members_only`: only active parent org members can join the org
+		///
+		/// Emits `SubOrgAdded` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::add_sub_org())]
+		pub fn add_sub_org(
+			origin: OriginFor<T>,
+			parent_id: T::Hash,
+			org_id: T::Hash,
+			parent_members_only: bool
+		) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			let parent = Orgs::<T>::get(parent_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_root_or_prime(origin.clone(), org.prime.clone())?;
+			Self::ensure_sub_org_allowed(&parent_id, &org_id)?;
+			Self::request_sub_org(origin, parent_id, &parent, org_id, parent_members_only)
+		}
+
+		/// Remove sub-org
+		///
+		/// Detaches the org from its parent org, the org becomes a top level org.
+		/// Its own sub-orgs stay attached to it, the parent members only restriction is lifted.
+		///
+		/// Allowed origins: Root or parent org member with ManageSubOrgs permission.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		///
+		/// Emits `SubOrgRemoved` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::remove_sub_org())]
+		pub fn remove_sub_org(origin: OriginFor<T>, org_id: T::Hash) -> DispatchResult {
+			let parent_id = OrgParent::<T>::get(org_id).ok_or(Error::<T>::NotSubOrg)?;
+			let parent = Orgs::<T>::get(parent_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_permission(origin, &parent_id, &parent, OrgPermission::ManageSubOrgs)?;
+
+			OrgParent::<T>::remove(org_id);
+			OrgChildren::<T>::remove(parent_id, org_id);
+			OrgParentMembersOnly::<T>::remove(org_id);
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::SubOrgRemoved { parent_id, org_id, block_number });
+			Ok(())
+		}
+
+		/// Fund sub-org
+		///
+		/// Transfers funds from the parent org treasury to the treasury of its sub-org on any level.
+		///
+		/// Allowed origins: Root or parent org member with SpendFunds permission.
+		///
+		/// Parameters:
+		/// - `parent_id`: Parent org id
+		/// - `org_id`: Sub-org id
+		/// - `currency_id`: currency to be transferred
+		/// - `amount`: amount to be transferred
+		///
+		/// Emits `SubOrgFunded` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::fund_sub_org())]
+		#[transactional]
+		pub fn fund_sub_org(
+			origin: OriginFor<T>,
+			parent_id: T::Hash,
+			org_id: T::Hash,
+			currency_id: T::CurrencyId,
+			amount: T::Balance
+		) -> DispatchResult {
+			let (parent_treasury_id, treasury_id) = Self::ensure_sub_org_spending(origin.clone(), &parent_id, &org_id)?;
+			let parent = Orgs::<T>::get(parent_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_spend_threshold(origin, &parent_id, &parent, currency_id, Some(amount))?;
+			Self::ensure_uncommitted_funds(&parent_id, &parent_treasury_id, currency_id, amount)?;
+			T::Currency::transfer(currency_id, &parent_treasury_id, &treasury_id, amount
+				).map_err(|_| Error::<T>::BalanceLow)?;
+			SubOrgFunding::<T>::mutate(org_id, (parent_id, currency_id), |funding| *funding = funding.saturating_add(amount));
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::SubOrgFunded { parent_id, org_id, currency_id, amount, block_number });
+			Ok(())
+		}
+
+		/// Claw back sub-org funds
+		///
+		/// Transfers funds from the treasury of the sub-org on any level back to the parent org treasury.
+		/// Only the funds given by the parent org with `fund_sub_org` can be clawed back,
+		/// funds raised by the sub-org itself and the committed funds stay with the sub-org.
+		///
+		/// Allowed origins: Root or parent org member with SpendFunds permission.
+		///
+		/// Parameters:
+		/// - `parent_id`: Parent org id
+		/// - `org_id`: Sub-org id
+		/// - `currency_id`: currency to be transferred
+		/// - `amount`: amount to be transferred
+		///
+		/// Emits `SubOrgFundsClawedBack` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::claw_back_funds())]
+		#[transactional]
+		pub fn claw_back_funds(
+			origin: OriginFor<T>,
+			parent_id: T::Hash,
+			org_id: T::Hash,
+			currency_id: T::CurrencyId,
+			amount: T::Balance
+		) -> DispatchResult {
+			let (parent_treasury_id, treasury_id) = Self::ensure_sub_org_spending(origin, &parent_id, &org_id)?;
+			let funding = SubOrgFunding::<T>::get(org_id, (parent_id, currency_id));
+			ensure!(amount <= funding, Error::<T>::ClawBackLimitExceeded);
+			Self::ensure_uncommitted_funds(&org_id, &treasury_id, currency_id, amount)?;
+			T::Currency::transfer(currency_id, &treasury_id, &parent_treasury_id, amount
+				).map_err(|_| Error::<T>::BalanceLow)?;
+			SubOrgFunding::<T>::insert(org_id, (parent_id, currency_id), funding - amount);
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::SubOrgFundsClawedBack { parent_id, org_id, currency_id, amount, block_number });
+			Ok(())
+		}
+
+		/// Redeem invitation
+		///
+		/// Adds the caller to the org as an active member, using the invitation signed off-chain
+		/// by the org prime or a member with AddMember permission. Membership fee is paid
+		/// by the invitee, unless it was waived by the invitation.
+		/// Not available for the Voting access model.
+		///
+		/// Parameters:
+		/// - `invitation`: Signed invitation
+		/// - `signature`: Inviter's signature of the invitation payload, see `invitation_payload`
+		/// - `inviter`: Invitation signer
+		///
+		/// Emits `MemberAdded` and `InvitationRedeemed` events when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::redeem_invitation())]
+		#[transactional]
+		pub fn redeem_invitation(
+			origin: OriginFor<T>,
+			invitation: Invitation<T>,
+			signature: T::OffchainSignature,
+			inviter: T::AccountId
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let org_id = invitation.org_id;
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			ensure!(org.access_model != AccessModel::Voting, Error::<T>::InvitationsNotAllowed);
+			if let Some(invitee) = &invitation.invitee {
+				ensure!(*invitee == who, Error::<T>::WrongInvitee);
+			}
+			let block_number = frame_system::Pallet::<T>::block_number();
+			ensure!(block_number <= invitation.expires, Error::<T>::InvitationExpired);
+			let nonce = invitation.nonce;
+			ensure!(
+				!UsedInvitations::<T>::contains_key(org_id, (&inviter, nonce)),
+				Error::<T>::InvitationUsed
+			);
+			ensure!(signature.verify(&Self::invitation_payload(&invitation)[..], &inviter), Error::<T>::InvalidSignature);
+			ensure!(Self::has_permission(&org_id, &org, &inviter, OrgPermission::AddMember), BadOrigin);
+			Self::ensure_parent_member(&org_id, &who)?;
+
+			UsedInvitations::<T>::insert(org_id, (&inviter, nonce), ());
+			if !invitation.fee_waived {
+				let treasury_id = OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?;
+				Self::pay_membership_fee(&org_id, &who, &who, &treasury_id, &org)?;
+			}
+			Self::do_add_member(org_id, &org, who.clone(), MemberState::Active)?;
+
+			Self::deposit_event(Event::InvitationRedeemed { org_id, who, inviter, nonce, block_number });
+			Ok(())
+		}
+
+		/// Pay the org subscription fee
+		///
+		/// Extends the subscription by one org subscription period. The first period is paid
+		/// when the member joins, members who joined before the org switched to the subscription
+		/// fee model pay it with this call. Lapsed subscription is renewed from the current block
+		/// and the Inactive member becomes Active again.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		///
+		/// Emits `SubscriptionPaid` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::pay_subscription())]
+		#[transactional]
+		pub fn pay_subscription(origin: OriginFor<T>, org_id: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			ensure!(org.fee_model == FeeModel::Subscription, Error::<T>::NoSubscription);
+			let member_state = MemberStates::<T>::try_get(org_id, &who).map_err(|_| Error::<T>::NotMember)?;
+			ensure!(
+				member_state == MemberState::Active || member_state == MemberState::Inactive,
+				Error::<T>::NotMember
+			);
+
+			Self::do_pay_subscription(org_id, &org, who.clone(), &who)?;
+			if member_state == MemberState::Inactive {
+				Self::do_update_member(org_id, who, MemberState::Active)?;
+			}
+			Ok(())
+		}
+
+		/// Set the org subscription period
+		///
+		/// New period applies from the next subscription payment.
+		///
+		/// Allowed origins: Root or member with UpdateOrg permission.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `period`: blocks covered by one subscription fee
+		///
+		/// Emits `SubscriptionPeriodSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_subscription_period())]
+		pub fn set_subscription_period(origin: OriginFor<T>, org_id: T::Hash, period: T::BlockNumber) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			Self::ensure_permission(origin, &org_id, &org, OrgPermission::UpdateOrg)?;
+			ensure!(org.fee_model == FeeModel::Subscription, Error::<T>::NoSubscription);
+			ensure!(!period.is_zero(), Error::<T>::InvalidSubscriptionPeriod);
+
+			OrgSubscriptionPeriod::<T>::insert(org_id, period);
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::SubscriptionPeriodSet { org_id, period, block_number });
+			Ok(())
+		}
+
+		/// Set Hybrid org spending threshold
+		///
+		/// Spends above the threshold are decided by the members voting. Any spend
+		/// in the currency without threshold requires a voting.
+		///
+		/// Allowed origins: Root or the org prime.
+		/// Prime can set a threshold for the new currency and lower or remove the existing one only.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `currency_id`: currency of the threshold
+		/// - `threshold`: max amount spent without voting, removed if not set
+		///
+		/// Emits `SpendThresholdSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_spend_threshold())]
+		pub fn set_spend_threshold(
+			origin: OriginFor<T>,
+			org_id: T::Hash,
+			currency_id: T::CurrencyId,
+			threshold: Option<T::Balance>
+		) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			Self::ensure_root_or_prime(origin.clone(), org.prime.clone())?;
+			ensure!(org.org_type == OrgType::Hybrid, Error::<T>::WrongOrganizationType);
+
+			let mut thresholds = OrgSpendThresholds::<T>::get(org_id);
+			if let Some(index) = thresholds.iter().position(|(currency, _)| *currency == currency_id) {
+				if let Some(threshold) = threshold {
+					ensure!(ensure_root(origin).is_ok() || threshold <= thresholds[index].1, BadOrigin);
+				}
+				thresholds.remove(index);
+			}
+			if let Some(threshold) = threshold {
+				thresholds.try_push((currency_id, threshold)).map_err(|_| Error::<T>::TooManyThresholds)?;
+			}
+			OrgSpendThresholds::<T>::insert(org_id, thresholds);
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::SpendThresholdSet { org_id, currency_id, threshold, block_number });
+			Ok(())
+		}
+
+		/// Set Company org min member reputation
+		///
+		/// New members are required to have a verified identity and the Sense reputation
+		/// not lower than the min reputation.
+		///
+		/// Allowed origins: Root or member with UpdateOrg permission.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `min_reputation`: min Sense reputation of the new members
+		///
+		/// Emits `MinReputationSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_min_reputation())]
+		pub fn set_min_reputation(origin: OriginFor<T>, org_id: T::Hash, min_reputation: u64) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			Self::ensure_permission(origin, &org_id, &org, OrgPermission::UpdateOrg)?;
+			ensure!(org.org_type == OrgType::Company, Error::<T>::WrongOrganizationType);
+
+			OrgMinReputation::<T>::insert(org_id, min_reputation);
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::MinReputationSet { org_id, min_reputation, block_number });
+			Ok(())
+		}
+
+		/// Accept prime handover
+		///
+		/// Proposed prime takes over the org before the handover expires.
+		///
+		/// Allowed origins: Proposed prime, active org member.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		///
+		/// Emits `PrimeChanged` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::accept_prime_handover())]
+		pub fn accept_prime_handover(origin: OriginFor<T>, org_id: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			let (new_prime, expires) = PrimeHandovers::<T>::get(org_id).ok_or(Error::<T>::NoPrimeHandover)?;
+			ensure!(new_prime == who, BadOrigin);
+			ensure!(frame_system::Pallet::<T>::block_number() <= expires, Error::<T>::PrimeHandoverExpired);
+			ensure!(Self::is_member_active(&org_id, &org, &who), Error::<T>::NotMember);
+
+			Self::do_set_prime(org_id, &mut org, who);
+			Orgs::<T>::insert(org_id, org);
+			Ok(())
+		}
+
+		/// Cancel prime handover
+		///
+		/// Removes the pending prime handover, expired or not.
+		///
+		/// Allowed origins: Root, the org prime or the proposed prime.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		///
+		/// Emits `PrimeHandoverCancelled` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_prime_handover())]
+		pub fn cancel_prime_handover(origin: OriginFor<T>, org_id: T::Hash) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			let (new_prime, _) = PrimeHandovers::<T>::get(org_id).ok_or(Error::<T>::NoPrimeHandover)?;
+			if Self::ensure_root_or_self(origin.clone(), new_prime.clone()).is_err() {
+				Self::ensure_root_or_prime(origin, org.prime.clone())?;
+			}
+
+			PrimeHandovers::<T>::remove(org_id);
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::PrimeHandoverCancelled { org_id, new_prime, block_number });
+			Ok(())
+		}
+
+		/// Recall prime
+		///
+		/// Creates the org members voting to replace the Dao org prime with another active member.
+		///
+		/// Allowed origins: Active org member.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `new_prime`: active org member to become the prime
+		///
+		/// Emits `PrimeRecallProposed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::recall_prime())]
+		pub fn recall_prime(origin: OriginFor<T>, org_id: T::Hash, new_prime: T::AccountId) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			ensure!(org.org_type == OrgType::Dao, Error::<T>::WrongOrganizationType);
+			ensure!(Self::is_member_active(&org_id, &org, &proposer), BadOrigin);
+			ensure!(Self::is_member_active(&org_id, &org, &new_prime), Error::<T>::NotMember);
+			ensure!(new_prime != org.prime, Error::<T>::AlreadyPrime);
+
+			let proposal_id = T::Governance::create_org_action_proposal(
+				&org_id, &proposer, OrgAction::ReplacePrime(new_prime.clone())
+			)?;
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::PrimeRecallProposed { org_id, proposer, new_prime, proposal_id, block_number });
+			Ok(())
+		}
+
+		/// Create treasury vault
+		///
+		/// Creates the named org treasury vault with its own account and spending rules.
+		///
+		/// Allowed origins: Root or org prime.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `name`: vault name, unique within the org
+		/// - `rules`: roles allowed to spend from the vault and spending limits per period
+		///
+		/// Emits `VaultCreated` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::create_vault())]
+		pub fn create_vault(
+			origin: OriginFor<T>,
+			org_id: T::Hash,
+			name: String<T>,
+			rules: VaultRules<T>
+		) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			Self::ensure_root_or_prime(origin, org.prime.clone())?;
+			ensure!(!name.is_empty(), Error::<T>::InvalidVaultRules);
+			Self::ensure_vault_rules(&rules)?;
+			ensure!(!OrgVaults::<T>::iter_prefix_values(org_id).any(|vault| vault.name == name), Error::<T>::VaultExists);
+			let count = OrgVaultCount::<T>::get(org_id);
+			ensure!(count < MaxVaults::get(), Error::<T>::TooManyVaults);
+
+			let vault_id: VaultId = count + 1;
+			let account: T::AccountId = T::PalletId::get().into_sub_account_truncating((org.index, vault_id));
+			OrgVaults::<T>::insert(org_id, vault_id, types::Vault { name: name.clone(), account: account.clone(), rules });
+			OrgVaultCount::<T>::insert(org_id, vault_id);
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::VaultCreated { org_id, vault_id, name, account, block_number });
+			Ok(())
+		}
+
+		/// Set vault rules
+		///
+		/// Spendings made in the current period are counted against the new limits.
+		///
+		/// Allowed origins: Root or org prime.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `vault_id`: Vault id
+		/// - `rules`: roles allowed to spend from the vault and spending limits per period
+		///
+		/// Emits `VaultRulesSet` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_vault_rules())]
+		pub fn set_vault_rules(
+			origin: OriginFor<T>,
+			org_id: T::Hash,
+			vault_id: VaultId,
+			rules: VaultRules<T>
+		) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			Self::ensure_root_or_prime(origin, org.prime.clone())?;
+			Self::ensure_vault_rules(&rules)?;
+
+			OrgVaults::<T>::try_mutate(org_id, vault_id, |maybe_vault| -> DispatchResult {
+				let vault = maybe_vault.as_mut().ok_or(Error::<T>::VaultUnknown)?;
+				vault.rules = rules;
+				Ok(())
+			})?;
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::VaultRulesSet { org_id, vault_id, block_number });
+			Ok(())
+		}
+
+		/// Spend from vault
+		///
+		/// Spendings of the members and the prime are limited by the vault rules.
+		///
+		/// Allowed origins: Root, org prime or active member with a vault spending role.
+		/// Hybrid org spends above the threshold are made by Root or the members voting only.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `vault_id`: Vault id
+		/// - `currency_id`: currency to be spent
+		/// - `beneficiary`: receiver account
+		/// - `amount`: amount to be spent
+		///
+		/// Emits `VaultFundsSpent` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::spend_from_vault())]
+		#[transactional]
+		pub fn spend_from_vault(
+			origin: OriginFor<T>,
+			org_id: T::Hash,
+			vault_id: VaultId,
+			currency_id: T::CurrencyId,
+			beneficiary: T::AccountId,
+			amount: T::Balance
+		) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			let vault = OrgVaults::<T>::get(org_id, vault_id).ok_or(Error::<T>::VaultUnknown)?;
+			Self::ensure_vault_spender(origin.clone(), &org_id, &org, &vault)?;
+			Self::ensure_spend_threshold(origin.clone(), &org_id, &org, currency_id, Some(amount))?;
+			if ensure_root(origin).is_err() {
+				Self::track_vault_spending(&org_id, vault_id, &vault.rules, currency_id, amount)?;
+			}
+
+			T::Currency::transfer(currency_id, &vault.account, &beneficiary, amount
+				).map_err(|_| Error::<T>::BalanceLow)?;
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::VaultFundsSpent { org_id, vault_id, beneficiary, currency_id, amount, block_number });
+			Ok(())
+		}
+
+		/// Transfer between vaults
+		///
+		/// Moves funds between the org vaults, `None` is the main org treasury.
+		/// Funds committed to the payment streams can't leave the main org treasury.
+		/// Transfers from a vault follow its rules, like the vault spendings.
+		///
+		/// Allowed origins: Root or member with SpendFunds permission for the main org treasury,
+		/// Root, org prime or active member with a vault spending role for a vault.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `from`: source vault
+		/// - `to`: destination vault
+		/// - `currency_id`: currency to be transferred
+		/// - `amount`: amount to be transferred
+		///
+		/// Emits `VaultTransfer` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::transfer_between_vaults())]
+		#[transactional]
+		pub fn transfer_between_vaults(
+			origin: OriginFor<T>,
+			org_id: T::Hash,
+			from: Option<VaultId>,
+			to: Option<VaultId>,
+			currency_id: T::CurrencyId,
+			amount: T::Balance
+		) -> DispatchResult {
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			ensure!(from != to, Error::<T>::SameVault);
+			let to_account = Self::vault_account(&org_id, to)?;
+			let from_account = match from {
+				Some(vault_id) => {
+					let vault = OrgVaults::<T>::get(org_id, vault_id).ok_or(Error::<T>::VaultUnknown)?;
+					Self::ensure_vault_spender(origin.clone(), &org_id, &org, &vault)?;
+					if ensure_root(origin).is_err() {
+						Self::track_vault_spending(&org_id, vault_id, &vault.rules, currency_id, amount)?;
+					}
+					vault.account
+				},
+				None => {
+					Self::ensure_permission(origin, &org_id, &org, OrgPermission::SpendFunds)?;
+					OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?
+				},
+			};
+			Self::ensure_uncommitted_funds(&org_id, &from_account, currency_id, amount)?;
+
+			T::Currency::transfer(currency_id, &from_account, &to_account, amount
+				).map_err(|_| Error::<T>::BalanceLow)?;
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::VaultTransfer { org_id, from, to, currency_id, amount, block_number });
+			Ok(())
+		}
+
+		/// Sponsor membership seats
+		///
+		/// Prepays the membership fees of `seats` joiners, the fees are reserved on the sponsor
+		/// until the seats are used or refunded. Named joiners have a seat reserved for them,
+		/// the remaining seats may be used by anyone selecting the sponsor as the fee payer.
+		/// Seats are added to the sponsor's existing seats, if the membership fee is unchanged.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		/// - `seats`: number of seats to be prepaid
+		/// - `named`: joiners with a seat reserved for them, up to `seats` accounts
+		///
+		/// Emits `SeatsSponsored` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::sponsor_seats())]
+		#[transactional]
+		pub fn sponsor_seats(
+			origin: OriginFor<T>,
+			org_id: T::Hash,
+			seats: u32,
+			named: BoundedVec<T::AccountId, MaxNamedSeats>
+		) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
+			Self::ensure_not_dissolving(&org_id)?;
+			ensure!(
+				matches!(org.fee_model, FeeModel::Reserve | FeeModel::Transfer) && org.access_model != AccessModel::Voting,
+				Error::<T>::SponsorshipNotAllowed
+			);
+			let fee = org.membership_fee.ok_or(Error::<T>::SponsorshipNotAllowed)?;
+			ensure!(seats > 0, Error::<T>::NoSponsoredSeat);
+			ensure!(named.len() as u32 <= seats, Error::<T>::TooManyNamedSeats);
+
+			let mut pool = SponsorPools::<T>::get(org_id, &sponsor).unwrap_or(SponsorPool::<T> {
+				currency_id: org.gov_currency, seats: 0, price: fee, reserved: Zero::zero(), named: BoundedVec::default()
+			});
+			ensure!(pool.price == fee, Error::<T>::SeatPriceChanged);
+			for joiner in named {
+				if !pool.named.contains(&joiner) {
+					pool.named.try_push(joiner).map_err(|_| Error::<T>::TooManyNamedSeats)?;
+				}
+			}
+			let amount = fee.saturating_mul(seats.into());
+			T::Currency::reserve(org.gov_currency, &sponsor, amount).map_err(|_| Error::<T>::BalanceLow)?;
+			pool.seats = pool.seats.saturating_add(seats);
+			pool.reserved = pool.reserved.saturating_add(amount);
+			SponsorPools::<T>::insert(org_id, &sponsor, pool);
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::SeatsSponsored { org_id, sponsor, seats, amount, block_number });
+			Ok(())
+		}
+
+		/// Refund sponsored seats
+		///
+		/// Unused seats are removed and their reserved fees are returned to the sponsor.
+		/// Seats are refundable after the org is dissolved as well.
+		///
+		/// Parameters:
+		/// - `org_id`: Org id
+		///
+		/// Emits `SeatsRefunded` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::refund_seats())]
+		pub fn refund_seats(origin: OriginFor<T>, org_id: T::Hash) -> DispatchResult {
+			let sponsor = ensure_signed(origin)?;
+			let pool = SponsorPools::<T>::take(org_id, &sponsor).ok_or(Error::<T>::NoSponsoredSeat)?;
+			T::Currency::unreserve(pool.currency_id, &sponsor, pool.reserved);
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			Self::deposit_event(Event::SeatsRefunded {
+				org_id, sponsor, seats: pool.seats, amount: pool.reserved, block_number
+			});
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+
+	/// Message signed by the inviter: the encoded invitation prefixed with the pallet tag and
+	/// the chain genesis hash, the signature is not valid for other messages or chains.
+	pub fn invitation_payload(invitation: &Invitation<T>) -> Vec<u8> {
+		(INVITATION_TAG, frame_system::Pallet::<T>::block_hash(T::BlockNumber::zero()), invitation).encode()
+	}
+
+	/// Org members with their states, up to `limit` entries.
+	///
+	/// Listing starts after the `start_after` account, which is the last account of the previous page.
+	/// The order of accounts is stable, but not sorted.
+	pub fn org_members(
+		org_id: &T::Hash,
+		start_after: Option<T::AccountId>,
+		limit: u32
+	) -> Vec<(T::AccountId, MemberState)> {
+		let members = match start_after {
+			Some(account_id) => MemberStates::<T>::iter_prefix_from(
+				org_id, MemberStates::<T>::hashed_key_for(org_id, account_id)
+			),
+			None => MemberStates::<T>::iter_prefix(org_id),
+		};
+		members.take(limit as usize).collect()
+	}
+
+	fn do_create_org(org_id: T::Hash, org: &Org<T>, treasury_id: T::AccountId, deposit: T::Balance,
+	) -> Result<(), DispatchError> {
+		let creator = org.creator.clone();
+		let created_at = org.created;
+
+		OrgTreasury::<T>::insert(org_id, &treasury_id);
+		OrgStates::<T>::insert(org_id, OrgState::Active);
+		OrgDeposits::<T>::insert(org_id, deposit);
+		OrgCount::<T>::set(org.index.checked_add(1).ok_or(Overflow)?);
+
+		T::Currency::transfer(
+			org.gov_currency, &creator, &treasury_id, deposit,
+		).map_err(|_| Error::<T>::BalanceLow)?;
+
+		Orgs::<T>::insert(org_id, org);
+
+		Self::deposit_event(Event::OrgCreated { org_id, creator, treasury_id, created_at, realm_index: 0 });
+		Ok(())
+	}
+
+	fn do_add_member(org_id: T::Hash, org: &Org<T>, who: T::AccountId, member_state: MemberState
+	) -> Result<u32, DispatchError> {
+		// Kicked and exited accounts can join again
+		if MemberStates::<T>::contains_key(org_id, &who) {
+			let member_state = MemberStates::<T>::get(org_id, &who);
+			ensure!(member_state != MemberState::Banned, Error::<T>::MemberBanned);
+			ensure!(Self::is_former_member(&member_state), Error::<T>::AlreadyMember);
+		}
+		if org.org_type == OrgType::Company {
+			Self::ensure_min_reputation(&org_id, &who)?;
+		}
+		let members_count = OrgMemberCount::<T>::get(org_id);
+		ensure!(members_count < org.member_limit, Error::<T>::MembershipLimitReached);
+		let members_count = members_count.checked_add(1).ok_or(Overflow)?;
+
+		OrgMemberCount::<T>::insert(org_id, members_count);
+		MemberStates::<T>::insert(org_id, &who, member_state.clone());
+		if member_state == MemberState::Active {
+			Self::start_subscription(org_id, org, &who);
+		}
+
+		let block_number = frame_system::Pallet::<T>::block_number();
+		Self::deposit_event(Event::MemberAdded { org_id, who, block_number });
+
+		Ok(members_count)
+	}
+
+	fn do_update_member(
+		org_id: T::Hash,
+		who: T::AccountId,
+		state: MemberState
+	) -> Result<(), DispatchError> {
+		Self::set_member_state(org_id, &who, state.clone());
+		let block_number = frame_system::Pallet::<T>::block_number();
+		Self::deposit_event(Event::MemberUpdated { org_id, who, state, block_number });
+		Ok(())
+	}
+
+	fn set_member_state(org_id: T::Hash, who: &T::AccountId, state: MemberState) {
+		let current_state = MemberStates::<T>::get(org_id, who);
+		if Self::is_former_member(&state) {
+			if MemberStates::<T>::contains_key(org_id, who) && !Self::is_former_member(&current_state) {
+				OrgMemberCount::<T>::mutate(org_id, |count| *count = count.saturating_sub(1));
+			}
+			MemberRoles::<T>::remove(org_id, who);
+			MemberSubscriptions::<T>::remove(org_id, who);
+		}
+		MemberStates::<T>::set(org_id, who, state.clone());
+		if state == MemberState::Active {
+			if let Some(org) = Orgs::<T>::get(org_id) {
+				Self::start_subscription(org_id, &org, who);
+			}
+		}
+	}
+
+	/// Kicked, banned and exited accounts are kept in the org, but are not counted as members.
+	fn is_former_member(member_state: &MemberState) -> bool {
+		matches!(member_state, MemberState::Kicked | MemberState::Banned | MemberState::Exited)
+	}
+
+	/// Settles the reserved membership fee and moves the member to the former member state.
+	fn do_expel_member(
+		org_id: T::Hash,
+		org: &Org<T>,
+		who: &T::AccountId,
+		state: MemberState,
+		slash_fee: bool
+	) -> Result<bool, DispatchError> {
+		let member_state = MemberStates::<T>::get(org_id, who);
+		let is_member = MemberStates::<T>::contains_key(org_id, who) && !Self::is_former_member(&member_state);
+		let mut fee_slashed = false;
+		if is_member {
+			if let Some((fee_payer, fee)) = MemberReservedFees::<T>::take(org_id, who) {
+				if slash_fee {
+					let treasury_id = OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?;
+					T::Currency::repatriate_reserved(org.gov_currency, &fee_payer, &treasury_id, fee, BalanceStatus::Free)?;
+					fee_slashed = true;
+				} else {
+					T::Currency::unreserve(org.gov_currency, &fee_payer, fee);
+				}
+			}
+		}
+		Self::set_member_state(org_id, who, state);
+		Ok(fee_slashed)
+	}
+
+	fn do_remove_member(org_id: T::Hash, org: &Org<T>, who: T::AccountId) -> Result<u32, DispatchError> {
+		ensure!(MemberStates::<T>::contains_key(org_id, &who), Error::<T>::NotMember);
+		let mut members_count = OrgMemberCount::<T>::get(org_id);
+		if !Self::is_former_member(&MemberStates::<T>::get(org_id, &who)) {
+			members_count = members_count.saturating_sub(1);
+		}
+
+		OrgMemberCount::<T>::insert(org_id, members_count);
+		MemberStates::<T>::remove(org_id, &who);
+		MemberRoles::<T>::remove(org_id, &who);
+		MemberSubscriptions::<T>::remove(org_id, &who);
+		Self::unreserve_membership_fee(&org_id, org, &who);
+
+		let block_number = frame_system::Pallet::<T>::block_number();
+		Self::deposit_event(Event::MemberRemoved { org_id, who, block_number });
+
+		Ok(members_count)
+	}
+
+	/// Charges the member's fee to the payer, the member itself or the account adding it.
+	fn pay_membership_fee(
+		org_id: &T::Hash,
+		who: &T::AccountId,
+		payer: &T::AccountId,
+		treasury_id: &T::AccountId,
+		org: &Org<T>
+	) -> Result<(), DispatchError> {
+		match org.fee_model {
+			FeeModel::NoFees => {},
+			FeeModel::Reserve => {
+				let fee = org.membership_fee.ok_or(Error::<T>::MissingParameter)?;
+				T::Currency::reserve(org.gov_currency, payer, fee).map_err(|_| Error::<T>::BalanceLow)?;
+				MemberReservedFees::<T>::insert(org_id, who, (payer, fee));
+			},
+			FeeModel::Transfer => {
+				T::Currency::transfer(
+					org.gov_currency, payer, treasury_id, org.membership_fee.unwrap()
+				).map_err(|_| Error::<T>::BalanceLow)?;
+			},
+			// First period is paid at join, the next ones are collected by schedule
+			FeeModel::Subscription => Self::do_pay_subscription(*org_id, org, who.clone(), payer)?,
+		};
+		Ok(())
+	}
+
+	/// Pays the joiner's membership fee from the sponsor's seats, a seat named for the joiner is used first.
+	fn use_sponsored_seat(
+		org_id: &T::Hash,
+		org: &Org<T>,
+		treasury_id: &T::AccountId,
+		sponsor: &T::AccountId,
+		who: &T::AccountId
+	) -> DispatchResult {
+		let mut pool = SponsorPools::<T>::get(org_id, sponsor).ok_or(Error::<T>::NoSponsoredSeat)?;
+		if let Some(index) = pool.named.iter().position(|joiner| joiner == who) {
+			pool.named.remove(index);
+		} else {
+			ensure!(pool.seats > pool.named.len() as u32, Error::<T>::NoSponsoredSeat);
+		}
+		// Seat covers the current fee, the overpaid part is returned to the sponsor
+		let fee = org.membership_fee.ok_or(Error::<T>::SponsorshipNotAllowed)?;
+		ensure!(pool.currency_id == org.gov_currency && pool.price >= fee, Error::<T>::SeatPriceChanged);
+		ensure!(pool.reserved >= pool.price, Error::<T>::NoSponsoredSeat);
+		pool.seats -= 1;
+		pool.reserved -= pool.price;
+		T::Currency::unreserve(pool.currency_id, sponsor, pool.price - fee);
+		match org.fee_model {
+			FeeModel::Reserve => {
+				MemberReservedFees::<T>::insert(org_id, who, (sponsor, fee));
+			},
+			FeeModel::Transfer => {
+				T::Currency::repatriate_reserved(org.gov_currency, sponsor, treasury_id, fee, BalanceStatus::Free)?;
+			},
+			_ => return Err(Error::<T>::SponsorshipNotAllowed.into()),
+		}
+		if pool.seats == 0 {
+			T::Currency::unreserve(pool.currency_id, sponsor, pool.reserved);
+			SponsorPools::<T>::remove(org_id, sponsor);
+		} else {
+			SponsorPools::<T>::insert(org_id, sponsor, pool);
+		}
+
+		let block_number = frame_system::Pallet::<T>::block_number();
+		Self::deposit_event(Event::SponsoredSeatUsed {
+			org_id: *org_id, sponsor: sponsor.clone(), who: who.clone(), block_number
+		});
+		Ok(())
+	}
+
+	/// Reserves the applicant's fee until the members voting is finalized.
+	fn reserve_membership_fee(org_id: &T::Hash, who: &T::AccountId, org: &Org<T>) -> Result<(), DispatchError> {
+		if matches!(org.fee_model, FeeModel::Reserve | FeeModel::Transfer) {
+			let fee = org.membership_fee.ok_or(Error::<T>::MissingParameter)?;
+			T::Currency::reserve(org.gov_currency, who, fee).map_err(|_| Error::<T>::BalanceLow)?;
+			MemberReservedFees::<T>::insert(org_id, who, (who, fee));
+		}
+		Ok(())
+	}
+
+	/// Returns the membership fee reserved for the member at join to the account holding it.
+	fn unreserve_membership_fee(org_id: &T::Hash, org: &Org<T>, who: &T::AccountId) {
+		if let Some((fee_payer, fee)) = MemberReservedFees::<T>::take(org_id, who) {
+			T::Currency::unreserve(org.gov_currency, &fee_payer, fee);
+		}
+	}
+
+	fn process_membership_application(
+		org_id: T::Hash,
+		org: &Org<T>,
+		who: T::AccountId,
+		approved: bool
+	) -> Result<(), DispatchError> {
+		// Application could be already processed or removed by root
+		if MemberStates::<T>::get(org_id, &who) != MemberState::Pending {
+			return Ok(())
+		}
+		if !approved {
+			return Self::do_remove_member(org_id, org, who).map(|_| ())
+		}
+		// Reserved fee is kept for the Reserve fee model only
+		if org.fee_model != FeeModel::Reserve {
+			if let Some((fee_payer, fee)) = MemberReservedFees::<T>::take(org_id, &who) {
+				let treasury_id = OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?;
+				T::Currency::repatriate_reserved(org.gov_currency, &fee_payer, &treasury_id, fee, BalanceStatus::Free)?;
+			}
+		}
+		Self::do_update_member(org_id, who, MemberState::Active)
+	}
+
+	fn do_apply_org_action(
+		org_id: &T::Hash,
+		mut org: Org<T>,
+		action: OrgAction<T::AccountId, T::Hash>,
+		approved: bool
+	) -> DispatchResult {
+		match action {
+			OrgAction::AddMember(who) => Self::process_membership_application(*org_id, &org, who, approved),
+			OrgAction::Dissolve => {
+				if !approved {
+					return Ok(())
+				}
+				Self::do_dissolve_org(*org_id, &org)
+			},
+			OrgAction::AddSubOrg { org_id: sub_org_id, parent_members_only } => {
+				if !approved {
+					return Ok(())
+				}
+				Self::do_add_sub_org(*org_id, sub_org_id, parent_members_only)
+			},
+			OrgAction::ReplacePrime(new_prime) => {
+				// New prime could leave the org while the voting was in progress
+				if !approved || !Self::is_member_active(org_id, &org, &new_prime) {
+					return Ok(())
+				}
+				Self::do_set_prime(*org_id, &mut org, new_prime);
+				Orgs::<T>::insert(org_id, org);
+				Ok(())
+			},
+		}
+	}
+
+	fn do_dissolve_org(org_id: T::Hash, org: &Org<T>) -> DispatchResult {
+		Self::ensure_not_dissolving(&org_id)?;
+		ensure!(!Self::has_sub_orgs(&org_id), Error::<T>::OrgHasSubOrgs);
+		let treasury_id = OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?;
+		OrgStates::<T>::insert(org_id, OrgState::Dissolving);
+
+		// Streams are settled before the treasury is shared
+		for (stream_id, stream) in Streams::<T>::drain_prefix(org_id) {
+			if Self::settle_stream(&treasury_id, &stream).is_err() {
+				log::error!(target: "runtime::gamedao_control", "Stream {} settlement failed for: '{:?}'", stream_id, &org_id);
+			}
+		}
+		NextStreamId::<T>::remove(org_id);
+
+		// Vault funds are shared together with the main treasury
+		for vault in OrgVaults::<T>::iter_prefix_values(org_id) {
+			for currency_id in Self::treasury_currencies(org) {
+				let balance = T::Currency::free_balance(currency_id, &vault.account);
+				if !balance.is_zero() && T::Currency::transfer(currency_id, &vault.account, &treasury_id, balance).is_err() {
+					log::error!(target: "runtime::gamedao_control", "Vault funds transfer failed for: '{:?}'", &org_id);
+				}
+			}
+		}
+
+		// Creation deposit is returned before the treasury is shared
+		let deposit = OrgDeposits::<T>::take(org_id).min(T::Currency::free_balance(org.gov_currency, &treasury_id));
+		if !deposit.is_zero() {
+			T::Currency::transfer(org.gov_currency, &treasury_id, &org.creator, deposit)?;
+		}
+		OrgDissolutions::<T>::insert(org_id, types::Dissolution {
+			phase: DissolutionPhase::Counting, last_counted: None, active_members: 0, shares: BoundedVec::default()
+		});
+
+		let block_number = frame_system::Pallet::<T>::block_number();
+		Self::deposit_event(Event::OrgDissolutionStarted { org_id, block_number });
+		Ok(())
+	}
+
+	/// Processes the dissolving orgs until `max_members` members are processed.
+	/// Returns the number of processed members and orgs.
+	fn process_dissolutions(max_members: u32) -> (u32, u32) {
+		let mut processed: u32 = 0;
+		let mut orgs: u32 = 0;
+		let mut cursor = None;
+		// Every processed org takes at least one member or is removed
+		for (org_id, mut dissolution) in Self::dissolution_queue(max_members.max(1) as usize) {
+			if processed >= max_members {
+				break
+			}
+			cursor = Some(org_id);
+			let (org, treasury_id) = match (Orgs::<T>::get(org_id), OrgTreasury::<T>::get(org_id)) {
+				(Some(org), Some(treasury_id)) => (org, treasury_id),
+				_ => {
+					log::error!(target: "runtime::gamedao_control", "Dissolving org unknown: '{:?}'", &org_id);
+					OrgDissolutions::<T>::remove(org_id);
+					continue
+				}
+			};
+			orgs += 1;
+			let batch_size = max_members - processed;
+			match dissolution.phase {
+				DissolutionPhase::Counting => {
+					let members = Self::org_members(&org_id, dissolution.last_counted.clone(), batch_size);
+					let active = members.iter().filter(|(_, state)| *state == MemberState::Active).count() as u32;
+					dissolution.active_members = dissolution.active_members.saturating_add(active);
+					if (members.len() as u32) < batch_size {
+						dissolution.shares = Self::treasury_shares(&treasury_id, &org, dissolution.active_members);
+						dissolution.phase = DissolutionPhase::Settling;
+						dissolution.last_counted = None;
+					} else {
+						dissolution.last_counted = members.last().map(|(who, _)| who.clone());
+					}
+					processed += members.len() as u32;
+					OrgDissolutions::<T>::insert(org_id, dissolution);
+				},
+				DissolutionPhase::Settling => {
+					// Settled members are removed, so the next batch starts from the beginning
+					let members = Self::org_members(&org_id, None, batch_size);
+					processed += members.len() as u32;
+					let finished = (members.len() as u32) < batch_size;
+					for (who, member_state) in members {
+						Self::settle_member(&org_id, &org, &treasury_id, &dissolution.shares, who, member_state);
+					}
+					if finished {
+						Self::finish_dissolution(org_id, &org, &treasury_id);
+					}
+				},
+			}
+		}
+		match cursor {
+			Some(org_id) => DissolutionCursor::<T>::put(org_id),
+			None => DissolutionCursor::<T>::kill(),
+		}
+		(processed, orgs)
+	}
+
+	/// Up to `max_orgs` dissolving orgs following the cursor, wrapping around to the first ones.
+	fn dissolution_queue(max_orgs: usize) -> Vec<(T::Hash, Dissolution<T>)> {
+		let mut queue = match DissolutionCursor::<T>::get() {
+			Some(cursor) => OrgDissolutions::<T>::iter_from(OrgDissolutions::<T>::hashed_key_for(cursor))
+				.take(max_orgs)
+				.collect::<Vec<_>>(),
+			None => Vec::new(),
+		};
+		let first = queue.first().map(|(org_id, _)| *org_id);
+		let wrapped = OrgDissolutions::<T>::iter()
+			.take_while(|(org_id, _)| Some(*org_id) != first)
+			.take(max_orgs - queue.len())
+			.collect::<Vec<_>>();
+		queue.extend(wrapped);
+		queue
+	}
+
+	/// Currencies of the org treasury which are distributed on dissolution.
+	fn treasury_currencies(org: &Org<T>) -> Vec<T::CurrencyId> {
+		let mut currencies = Vec::new();
+		for currency_id in vec![org.gov_currency, org.pay_currency].into_iter().chain(T::TreasuryCurrencies::get()) {
+			if !currencies.contains(&currency_id) {
+				currencies.push(currency_id);
+			}
+		}
+		currencies
+	}
+
+	/// Treasury free balance share of every active member, per currency.
+	fn treasury_shares(
+		treasury_id: &T::AccountId,
+		org: &Org<T>,
+		active_members: u32
+	) -> BoundedVec<(T::CurrencyId, T::Balance), MaxDissolutionCurrencies> {
+		if active_members == 0 {
+			return BoundedVec::default()
+		}
+		let shares = Self::treasury_currencies(org)
+			.into_iter()
+			.map(|currency_id| (
+				currency_id, T::Currency::free_balance(currency_id, treasury_id) / T::Balance::from(active_members)
+			))
+			.filter(|(_, share)| !share.is_zero())
+			.collect::<Vec<_>>();
+		BoundedVec::truncate_from(shares)
+	}
+
+	fn settle_member(
+		org_id: &T::Hash,
+		org: &Org<T>,
+		treasury_id: &T::AccountId,
+		shares: &BoundedVec<(T::CurrencyId, T::Balance), MaxDissolutionCurrencies>,
+		who: T::AccountId,
+		member_state: MemberState
+	) {
+		Self::unreserve_membership_fee(org_id, org, &who);
+		if member_state == MemberState::Active {
+			for (currency_id, share) in shares {
+				if T::Currency::transfer(*currency_id, treasury_id, &who, *share).is_err() {
+					log::error!(target: "runtime::gamedao_control", "Dissolution share transfer failed for: '{:?}'", &who);
+				}
+			}
+		}
+		MemberStates::<T>::remove(org_id, &who);
+		MemberRoles::<T>::remove(org_id, &who);
+		MemberSubscriptions::<T>::remove(org_id, &who);
+	}
+
+	fn finish_dissolution(org_id: T::Hash, org: &Org<T>, treasury_id: &T::AccountId) {
+		// Rounding leftovers and failed shares go to the org creator
+		for currency_id in Self::treasury_currencies(org) {
+			let balance = T::Currency::free_balance(currency_id, treasury_id);
+			if !balance.is_zero() {
+				let _ = T::Currency::transfer(currency_id, treasury_id, &org.creator, balance);
+			}
+		}
+		Orgs::<T>::remove(org_id);
+		OrgStates::<T>::remove(org_id);
+		OrgTreasury::<T>::remove(org_id);
+		OrgMemberCount::<T>::remove(org_id);
+		OrgDeposits::<T>::remove(org_id);
+		OrgDissolutions::<T>::remove(org_id);
+		let _ = RolePermissions::<T>::clear_prefix(org_id, MaxRoles::get(), None);
+		if let Some(parent_id) = OrgParent::<T>::take(org_id) {
+			OrgChildren::<T>::remove(parent_id, org_id);
+		}
+		OrgParentMembersOnly::<T>::remove(org_id);
+		let _ = SubOrgFunding::<T>::clear_prefix(org_id, MaxOrgDepth::get() * MaxDissolutionCurrencies::get(), None);
+		OrgSpendThresholds::<T>::remove(org_id);
+		OrgMinReputation::<T>::remove(org_id);
+		OrgSubscriptionPeriod::<T>::remove(org_id);
+		PrimeHandovers::<T>::remove(org_id);
+		for vault_id in 1..=OrgVaultCount::<T>::take(org_id) {
+			let _ = VaultSpendings::<T>::clear_prefix((org_id, vault_id), MaxVaultLimits::get(), None);
+		}
+		let _ = OrgVaults::<T>::clear_prefix(org_id, MaxVaults::get(), None);
+
+		let block_number = frame_system::Pallet::<T>::block_number();
+		Self::deposit_event(Event::OrgDissolved { org_id, block_number });
+	}
+
+	fn has_sub_orgs(org_id: &T::Hash) -> bool {
+		OrgChildren::<T>::iter_key_prefix(org_id).next().is_some()
+	}
+
+	/// Parent, parent of the parent and so on, up to the top level org.
+	fn org_ancestors(org_id: &T::Hash) -> Vec<T::Hash> {
+		let mut ancestors = Vec::new();
+		let mut current = *org_id;
+		while let Some(parent_id) = OrgParent::<T>::get(current) {
+			if ancestors.len() >= MaxOrgDepth::get() as usize {
+				break
+			}
+			ancestors.push(parent_id);
+			current = parent_id;
+		}
+		ancestors
+	}
+
+	fn ensure_sub_org_allowed(parent_id: &T::Hash, org_id: &T::Hash) -> DispatchResult {
+		ensure!(Orgs::<T>::contains_key(parent_id), Error::<T>::OrganizationUnknown);
+		ensure!(Orgs::<T>::contains_key(org_id), Error::<T>::OrganizationUnknown);
+		Self::ensure_not_dissolving(parent_id)?;
+		Self::ensure_not_dissolving(org_id)?;
+		ensure!(parent_id != org_id, Error::<T>::WrongParent);
+		ensure!(!OrgParent::<T>::contains_key(org_id), Error::<T>::ParentExists);
+		// Org without sub-orgs can't be an ancestor of the parent
+		ensure!(!Self::has_sub_orgs(org_id), Error::<T>::OrgHasSubOrgs);
+		ensure!(Self::org_ancestors(parent_id).len() < MaxOrgDepth::get() as usize, Error::<T>::MaxOrgDepthReached);
+		let max_sub_orgs = MaxSubOrgs::get() as usize;
+		ensure!(
+			OrgChildren::<T>::iter_key_prefix(parent_id).take(max_sub_orgs).count() < max_sub_orgs,
+			Error::<T>::MaxSubOrgsReached
+		);
+		Ok(())
+	}
+
+	/// Attaches the org at once if the caller has ManageSubOrgs permission in the parent org.
+	/// Otherwise creates a parent members voting for the Dao parent org.
+	fn request_sub_org(
+		origin: T::RuntimeOrigin,
+		parent_id: T::Hash,
+		parent: &Org<T>,
+		org_id: T::Hash,
+		parent_members_only: bool
+	) -> DispatchResult {
+		match ensure_signed(origin) {
+			Ok(sender) if !Self::has_permission(&parent_id, parent, &sender, OrgPermission::ManageSubOrgs) => {
+				ensure!(
+					parent.org_type == OrgType::Dao && Self::is_member_active(&parent_id, parent, &sender),
 					BadOrigin
 				);
-			}
-			else {
-				ensure_root(origin)?;
-			}
-			let member_state = match org.access_model {
-				AccessModel::Open => MemberState::Active,
-				_ => MemberState::Pending,
-			};
-			let treasury_id = OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?;
-			Self::pay_membership_fee(&org.prime, &treasury_id, &org)?;
-			let members_count = Self::do_add_member(org_id, who.clone(), member_state)?;
+				T::Governance::create_org_action_proposal(
+					&parent_id, &sender, OrgAction::AddSubOrg { org_id, parent_members_only }
+				)?;
+			},
+			_ => Self::do_add_sub_org(parent_id, org_id, parent_members_only)?,
+		}
+		Ok(())
+	}
 
-			Ok(Some(T::WeightInfo::add_member(members_count)).into())
+	fn do_add_sub_org(parent_id: T::Hash, org_id: T::Hash, parent_members_only: bool) -> DispatchResult {
+		Self::ensure_sub_org_allowed(&parent_id, &org_id)?;
+		OrgParent::<T>::insert(org_id, parent_id);
+		OrgChildren::<T>::insert(parent_id, org_id, ());
+		if parent_members_only {
+			OrgParentMembersOnly::<T>::insert(org_id, true);
 		}
 
-		/// Update member state in the organization
-		///
-		/// Parameters:
-		/// - `org_id`: Org id
-		/// - `who`: Account to change state for
-		/// - `state`: new state value
-		///
-		/// Emits `MemberUpdated` event when successful.
-		///
-		/// Weight: `O(log n)`
-		#[pallet::weight(T::WeightInfo::update_member_state())]
-		pub fn update_member_state(
-			origin: OriginFor<T>,
-			org_id: T::Hash,
-			who: T::AccountId,
-			state: MemberState
-		) -> DispatchResult {
-			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
-			Self::ensure_membership_permissions(origin, who.clone(), org.prime.clone(), org.org_type.clone(), org.access_model)?;
+		let block_number = frame_system::Pallet::<T>::block_number();
+		Self::deposit_event(Event::SubOrgAdded { parent_id, org_id, parent_members_only, block_number });
+		Ok(())
+	}
 
-			let current_member_state = MemberStates::<T>::get(org_id, who.clone());
-			if current_member_state == MemberState::Pending {
-				Self::do_update_member(org_id, who.clone(), state)?;
-			}
-			Ok(())
+	/// Checks the spending permission in the parent org. Returns parent and sub-org treasuries.
+	fn ensure_sub_org_spending(
+		origin: T::RuntimeOrigin,
+		parent_id: &T::Hash,
+		org_id: &T::Hash
+	) -> Result<(T::AccountId, T::AccountId), DispatchError> {
+		let parent = Orgs::<T>::get(parent_id).ok_or(Error::<T>::OrganizationUnknown)?;
+		Self::ensure_not_dissolving(parent_id)?;
+		Self::ensure_not_dissolving(org_id)?;
+		Self::ensure_permission(origin, parent_id, &parent, OrgPermission::SpendFunds)?;
+		ensure!(Self::org_ancestors(org_id).contains(parent_id), Error::<T>::NotSubOrg);
+
+		let parent_treasury_id = OrgTreasury::<T>::get(parent_id).ok_or(Error::<T>::TreasuryUnknown)?;
+		let treasury_id = OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?;
+		Ok((parent_treasury_id, treasury_id))
+	}
+
+	fn ensure_parent_member(org_id: &T::Hash, who: &T::AccountId) -> DispatchResult {
+		if !OrgParentMembersOnly::<T>::get(org_id) {
+			return Ok(())
 		}
+		let parent_id = OrgParent::<T>::get(org_id).ok_or(Error::<T>::NotSubOrg)?;
+		let parent = Orgs::<T>::get(parent_id).ok_or(Error::<T>::OrganizationUnknown)?;
+		ensure!(Self::is_member_active(&parent_id, &parent, who), Error::<T>::NotParentMember);
+		Ok(())
+	}
 
-		/// Remove member from Org
-		///
-		/// Parameters:
-		/// - `org_id`: Org id
-		/// - `who`: Account to be removed
-		///
-		/// Emits `MemberRemoved` event when successful.
-		///
-		/// Weight: `O(log n)`
-		#[pallet::weight(T::WeightInfo::remove_member(T::MaxMembers::get()))]
-		pub fn remove_member(origin: OriginFor<T>, org_id: T::Hash, who: T::AccountId) -> DispatchResultWithPostInfo {
-			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
-			Self::ensure_membership_permissions(origin, who.clone(), org.prime.clone(), org.org_type.clone(), org.access_model.clone())?;
-			let member_count = Self::do_remove_member(org_id, who.clone())?;
-			if org.fee_model == FeeModel::Reserve {
-				T::Currency::unreserve(org.gov_currency, &who, org.membership_fee.ok_or(Error::<T>::MissingParameter)?);
-			}
+	fn ensure_min_reputation(org_id: &T::Hash, who: &T::AccountId) -> DispatchResult {
+		ensure!(T::Sense::has_entity(who), Error::<T>::NoIdentity);
+		ensure!(T::Sense::reputation(who) >= OrgMinReputation::<T>::get(org_id), Error::<T>::ReputationTooLow);
+		Ok(())
+	}
 
-			Ok(Some(T::WeightInfo::remove_member(member_count)).into())
+	/// Check the Hybrid org spending threshold for the signed origin.
+	/// Amount is not set for the endless spending, which is always above the threshold.
+	fn ensure_spend_threshold(
+		origin: T::RuntimeOrigin,
+		org_id: &T::Hash,
+		org: &Org<T>,
+		currency_id: T::CurrencyId,
+		amount: Option<T::Balance>
+	) -> DispatchResult {
+		if org.org_type != OrgType::Hybrid || ensure_root(origin).is_ok() {
+			return Ok(())
+		}
+		let threshold = OrgSpendThresholds::<T>::get(org_id)
+			.into_iter()
+			.find(|(currency, _)| *currency == currency_id)
+			.map(|(_, threshold)| threshold);
+		match (amount, threshold) {
+			(Some(amount), Some(threshold)) if amount <= threshold => Ok(()),
+			_ => Err(Error::<T>::SpendingVoteRequired.into()),
 		}
+	}
 
-		/// Make spending from the org treasury
-		///
-		/// Allowed origins: Root or prime if OrgType::Individual
-		///
-		/// Parameters:
-		/// - `org_id`: Org id
-		/// - `currency_id`: currency to be spent
-		/// - `beneficiary`: receiver account
-		/// - `amount`: amount to be spent
-		///
-		/// Emits `FundsSpended` event when successful.
-		///
-		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::spend_funds())]
-		#[transactional]
-		pub fn spend_funds(
-			origin: OriginFor<T>,
-			org_id: T::Hash,
-			currency_id: T::CurrencyId,
-			beneficiary: T::AccountId,
-			amount: T::Balance
-		) -> DispatchResult {
-			let org = Orgs::<T>::get(org_id).ok_or(Error::<T>::OrganizationUnknown)?;
-			let treasury_id = OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?;
-			Self::ensure_root_or_prime(origin, org.prime, org.org_type)?;
+	/// Root, org prime or active member with any of the vault spending roles.
+	fn ensure_vault_spender(
+		origin: T::RuntimeOrigin,
+		org_id: &T::Hash,
+		org: &Org<T>,
+		vault: &Vault<T>
+	) -> Result<(), BadOrigin> {
+		if Self::ensure_root_or_prime(origin.clone(), org.prime.clone()).is_ok() {
+			return Ok(())
+		}
+		let who = ensure_signed(origin)?;
+		ensure!(Self::is_member_active(org_id, org, &who), BadOrigin);
+		ensure!(MemberRoles::<T>::get(org_id, &who).iter().any(|role| vault.rules.roles.contains(role)), BadOrigin);
+		Ok(())
+	}
 
-			T::Currency::transfer(currency_id, &treasury_id, &beneficiary, amount
-				).map_err(|_| Error::<T>::BalanceLow)?;
+	fn ensure_vault_rules(rules: &VaultRules<T>) -> DispatchResult {
+		let limits = &rules.limits;
+		if !limits.is_empty() {
+			ensure!(!rules.period.is_zero(), Error::<T>::InvalidVaultRules);
+		}
+		let unique = limits.iter().enumerate()
+			.all(|(index, (currency_id, _))| !limits[..index].iter().any(|(other, _)| other == currency_id));
+		ensure!(unique, Error::<T>::InvalidVaultRules);
+		Ok(())
+	}
 
-			let block_number = frame_system::Pallet::<T>::block_number();
-			Self::deposit_event(Event::FundsSpended { org_id, beneficiary, amount, currency_id, block_number });
+	/// Adds the amount to the vault spendings of the current period and checks the limit.
+	/// Periods are aligned to the multiples of the period length.
+	fn track_vault_spending(
+		org_id: &T::Hash,
+		vault_id: VaultId,
+		rules: &VaultRules<T>,
+		currency_id: T::CurrencyId,
+		amount: T::Balance
+	) -> DispatchResult {
+		let limit = match rules.limits.iter().find(|(currency, _)| *currency == currency_id) {
+			Some((_, limit)) => *limit,
+			None => return Ok(()),
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+		let period_start = now - now % rules.period;
+		let (spent_since, spent) = VaultSpendings::<T>::get((*org_id, vault_id), currency_id);
+		let spent = if spent_since == period_start { spent } else { Zero::zero() };
+		let spent = spent.saturating_add(amount);
+		ensure!(spent <= limit, Error::<T>::VaultLimitExceeded);
+		VaultSpendings::<T>::insert((*org_id, vault_id), currency_id, (period_start, spent));
+		Ok(())
+	}
 
-			Ok(())
+	/// Account of the org vault, the main org treasury if the vault is not set.
+	fn vault_account(org_id: &T::Hash, vault_id: Option<VaultId>) -> Result<T::AccountId, DispatchError> {
+		match vault_id {
+			Some(vault_id) => Ok(OrgVaults::<T>::get(org_id, vault_id).ok_or(Error::<T>::VaultUnknown)?.account),
+			None => Ok(OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?),
 		}
 	}
-}
 
-impl<T: Config> Pallet<T> {
+	/// Validate the org type switch and clean up the state of the previous type.
+	fn switch_org_type(org_id: &T::Hash, org: &Org<T>, previous_org_type: &OrgType) -> DispatchResult {
+		if org.org_type == OrgType::Company {
+			ensure!(T::Sense::has_entity(&org.prime), Error::<T>::NoIdentity);
+			let max_members = T::MaxMembersProcessing::get();
+			let members = Self::org_members(org_id, None, max_members.saturating_add(1));
+			ensure!(members.len() as u32 <= max_members, Error::<T>::TooManyMembers);
+			for (who, member_state) in members {
+				if !Self::is_former_member(&member_state) {
+					Self::ensure_min_reputation(org_id, &who)?;
+				}
+			}
+		}
+		match previous_org_type {
+			OrgType::Hybrid => OrgSpendThresholds::<T>::remove(org_id),
+			OrgType::Company => OrgMinReputation::<T>::remove(org_id),
+			_ => {},
+		}
+		Ok(())
+	}
 
-	fn do_create_org(org_id: T::Hash, org: &Org<T>, treasury_id: T::AccountId, deposit: T::Balance,
-	) -> Result<(), DispatchError> {
-		let creator = org.creator.clone();
-		let created_at = org.created;
+	fn do_propose_prime(org_id: T::Hash, org: &Org<T>, new_prime: T::AccountId) {
+		let block_number = frame_system::Pallet::<T>::block_number();
+		let expires = block_number.saturating_add(T::PrimeHandoverPeriod::get());
+		PrimeHandovers::<T>::insert(org_id, (new_prime.clone(), expires));
+		Self::deposit_event(Event::PrimeHandoverProposed {
+			org_id, prime: org.prime.clone(), new_prime, expires, block_number
+		});
+	}
 
-		OrgTreasury::<T>::insert(org_id, &treasury_id);
-		OrgStates::<T>::insert(org_id, OrgState::Active);
-		OrgCount::<T>::set(org.index.checked_add(1).ok_or(Overflow)?);
+	/// Sets the new prime and drops the pending handover, org has to be saved by the caller.
+	fn do_set_prime(org_id: T::Hash, org: &mut Org<T>, new_prime: T::AccountId) {
+		PrimeHandovers::<T>::remove(org_id);
+		let prime = sp_std::mem::replace(&mut org.prime, new_prime.clone());
+		let block_number = frame_system::Pallet::<T>::block_number();
+		Self::deposit_event(Event::PrimeChanged { org_id, prime, new_prime, block_number });
+	}
 
-		T::Currency::transfer(
-			org.gov_currency, &creator, &treasury_id, deposit,
-		).map_err(|_| Error::<T>::BalanceLow)?;
+	/// Charges the first subscription period of the new active member who hasn't paid it at join,
+	/// the fee is collected by schedule if the payment fails.
+	fn start_subscription(org_id: T::Hash, org: &Org<T>, who: &T::AccountId) {
+		if org.fee_model != FeeModel::Subscription || MemberSubscriptions::<T>::contains_key(org_id, who) {
+			return
+		}
+		if Self::do_pay_subscription(org_id, org, who.clone(), who).is_ok() {
+			return
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		MemberSubscriptions::<T>::insert(org_id, who, now);
+		Self::schedule_subscription(now, org_id, who.clone());
+	}
 
-		Orgs::<T>::insert(org_id, org);
+	fn subscription_period(org_id: &T::Hash) -> T::BlockNumber {
+		OrgSubscriptionPeriod::<T>::get(org_id).unwrap_or_else(T::SubscriptionPeriod::get)
+	}
 
-		Self::deposit_event(Event::OrgCreated { org_id, creator, treasury_id, created_at, realm_index: 0 });
-		Ok(())
+	fn schedule_subscription(block_number: T::BlockNumber, org_id: T::Hash, who: T::AccountId) {
+		// Current block dues could be already processed
+		let now = frame_system::Pallet::<T>::block_number();
+		let block_number = block_number.max(now + One::one());
+		SubscriptionDues::<T>::insert(block_number, (org_id, who), ());
+		let cursor = SubscriptionDuesCursor::<T>::get().map_or(block_number, |cursor| cursor.min(block_number));
+		SubscriptionDuesCursor::<T>::put(cursor);
 	}
 
-	fn do_add_member(org_id: T::Hash, who: T::AccountId, member_state: MemberState
-	) -> Result<u32, DispatchError> {
-		let mut members = Members::<T>::get(org_id);
-		let location = members.binary_search(&who).err().ok_or(Error::<T>::AlreadyMember)?;
-		members
-			.try_insert(location, who.clone())
-			.map_err(|_| Error::<T>::MembershipLimitReached)?;
-		let members_count = members.len() as u32;
-
-		// TODO: flatten Members and MemberStates
-		Members::<T>::insert(org_id, &members);
-		OrgMemberCount::<T>::insert(org_id, members_count);
-		MemberStates::<T>::insert(org_id, &who, member_state);
+	fn is_subscription_lapsed(paid_until: T::BlockNumber, now: T::BlockNumber) -> bool {
+		paid_until.saturating_add(T::SubscriptionGracePeriod::get()) <= now
+	}
 
-		let block_number = frame_system::Pallet::<T>::block_number();
-		Self::deposit_event(Event::MemberAdded { org_id, who, block_number });
+	/// Member is active and the subscription, if any, is not lapsed.
+	fn is_member_active(org_id: &T::Hash, org: &Org<T>, who: &T::AccountId) -> bool {
+		if MemberStates::<T>::get(org_id, who) != MemberState::Active {
+			return false
+		}
+		if org.fee_model != FeeModel::Subscription {
+			return true
+		}
+		match MemberSubscriptions::<T>::get(org_id, who) {
+			Some(paid_until) => {
+				let now = frame_system::Pallet::<T>::block_number();
+				!Self::is_subscription_lapsed(paid_until, now)
+			},
+			// Members who joined before the org switched to subscriptions pay the first period themselves
+			None => false,
+		}
+	}
 
-		Ok(members_count)
+	/// Amount accrued by the stream since the last claim and the block it becomes claimed until.
+	fn stream_accrued(stream: &Stream<T>, now: T::BlockNumber) -> (T::Balance, T::BlockNumber) {
+		let until = match stream.end {
+			Some(end) => end.min(now),
+			None => now,
+		};
+		if until <= stream.claimed_until {
+			return (Zero::zero(), stream.claimed_until)
+		}
+		let periods = (until - stream.claimed_until) / stream.period;
+		let amount = stream.amount.saturating_mul(periods.saturated_into::<u128>().saturated_into());
+		(amount, stream.claimed_until + periods * stream.period)
 	}
 
-	fn do_update_member(
-		org_id: T::Hash,
-		who: T::AccountId,
-		state: MemberState
-	) -> Result<(), DispatchError> {
-		MemberStates::<T>::set(org_id, &who, state.clone());
-		let block_number = frame_system::Pallet::<T>::block_number();
-		Self::deposit_event(Event::MemberUpdated { org_id, who, state, block_number });
+	/// Treasury funds owed to the stream: the accrued amount, or the whole remaining amount
+	/// for the stream with the end.
+	fn stream_committed(stream: &Stream<T>, now: T::BlockNumber) -> T::Balance {
+		Self::stream_accrued(stream, stream.end.unwrap_or(now)).0
+	}
+
+	fn ensure_uncommitted_funds(
+		org_id: &T::Hash,
+		account: &T::AccountId,
+		currency_id: T::CurrencyId,
+		amount: T::Balance
+	) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		// Streams are paid from the main org treasury only
+		let committed = if OrgTreasury::<T>::get(org_id).as_ref() == Some(account) {
+			Streams::<T>::iter_prefix_values(org_id)
+				.filter(|stream| stream.currency_id == currency_id)
+				.fold(T::Balance::zero(), |acc, stream| acc.saturating_add(Self::stream_committed(&stream, now)))
+		} else {
+			Zero::zero()
+		};
+		let balance = T::Currency::free_balance(currency_id, account);
+		ensure!(balance >= amount, Error::<T>::BalanceLow);
+		ensure!(balance - amount >= committed, Error::<T>::FundsCommitted);
 		Ok(())
 	}
 
-	fn do_remove_member(org_id: T::Hash, who: T::AccountId) -> Result<u32, DispatchError> {
-		let mut members = Members::<T>::get(org_id);
-		let location = members.binary_search(&who).ok().ok_or(Error::<T>::NotMember)?;
-		members.remove(location);
-		let members_count = members.len() as u32;
+	/// Pays out the accrued amount of the removed stream.
+	fn settle_stream(treasury_id: &T::AccountId, stream: &Stream<T>) -> Result<T::Balance, DispatchError> {
+		let now = frame_system::Pallet::<T>::block_number();
+		let (amount, _) = Self::stream_accrued(stream, now);
+		if !amount.is_zero() {
+			T::Currency::transfer(stream.currency_id, treasury_id, &stream.beneficiary, amount
+				).map_err(|_| Error::<T>::BalanceLow)?;
+		}
+		Ok(amount)
+	}
+
+	fn do_pay_subscription(org_id: T::Hash, org: &Org<T>, who: T::AccountId, payer: &T::AccountId) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		let treasury_id = OrgTreasury::<T>::get(org_id).ok_or(Error::<T>::TreasuryUnknown)?;
+		let amount = org.membership_fee.ok_or(Error::<T>::MissingParameter)?;
+		T::Currency::transfer(org.pay_currency, payer, &treasury_id, amount).map_err(|_| Error::<T>::BalanceLow)?;
 
-		Members::<T>::insert(org_id, &members);
-		OrgMemberCount::<T>::insert(org_id, members_count);
-		MemberStates::<T>::remove(org_id, &who);
+		let paid_until = match MemberSubscriptions::<T>::get(org_id, &who) {
+			Some(paid_until) if !Self::is_subscription_lapsed(paid_until, now) => paid_until,
+			_ => now,
+		};
+		let paid_until = paid_until.saturating_add(Self::subscription_period(&org_id));
+		MemberSubscriptions::<T>::insert(org_id, &who, paid_until);
+		Self::schedule_subscription(paid_until, org_id, who.clone());
 
-		let block_number = frame_system::Pallet::<T>::block_number();
-		Self::deposit_event(Event::MemberRemoved { org_id, who, block_number });
+		Self::deposit_event(Event::SubscriptionPaid { org_id, who, amount, paid_until, block_number: now });
+		Ok(())
+	}
 
-		Ok(members_count)
+	/// Collects the subscription fees due until the current block, up to `max_members` fees.
+	/// Returns the number of processed fees.
+	fn process_subscriptions(now: T::BlockNumber, max_members: u32) -> u32 {
+		let mut block_number = match SubscriptionDuesCursor::<T>::get() {
+			Some(block_number) => block_number,
+			None => return 0,
+		};
+		let mut processed: u32 = 0;
+		while block_number <= now && processed < max_members {
+			let batch_size = max_members - processed;
+			let dues = SubscriptionDues::<T>::iter_key_prefix(block_number)
+				.take(batch_size as usize)
+				.collect::<Vec<_>>();
+			let finished = (dues.len() as u32) < batch_size;
+			// Empty block counts as a processed item to bound the catch up
+			processed += (dues.len() as u32).max(1);
+			for (org_id, who) in dues {
+				SubscriptionDues::<T>::remove(block_number, (org_id, who.clone()));
+				Self::collect_subscription(org_id, who, now);
+			}
+			if finished {
+				block_number += One::one();
+			}
+		}
+		SubscriptionDuesCursor::<T>::put(block_number);
+		processed
 	}
 
-	fn pay_membership_fee(
-		who: &T::AccountId,
-		treasury_id: &T::AccountId,
-		org: &Org<T>
-	) -> Result<(), DispatchError> {
-		match org.fee_model {
-			FeeModel::NoFees => {},
-			FeeModel::Reserve => {
-				T::Currency::reserve(org.gov_currency, who, org.membership_fee.unwrap()
-					).map_err(|_| Error::<T>::BalanceLow)?;
-			},
-			FeeModel::Transfer => {
-				T::Currency::transfer(
-					org.gov_currency, who, treasury_id, org.membership_fee.unwrap()
-				).map_err(|_| Error::<T>::BalanceLow)?;
+	fn collect_subscription(org_id: T::Hash, who: T::AccountId, now: T::BlockNumber) {
+		let paid_until = match MemberSubscriptions::<T>::get(org_id, &who) {
+			Some(paid_until) => paid_until,
+			None => return,
+		};
+		// Already paid in advance, the next collection is scheduled
+		if paid_until > now {
+			return
+		}
+		let org = match Orgs::<T>::get(org_id) {
+			Some(org) if org.fee_model == FeeModel::Subscription => org,
+			_ => {
+				MemberSubscriptions::<T>::remove(org_id, &who);
+				return
 			}
 		};
+		// Dissolution removes the subscriptions
+		if OrgStates::<T>::get(org_id) == OrgState::Dissolving {
+			return
+		}
+		let member_state = MemberStates::<T>::get(org_id, &who);
+		if member_state != MemberState::Active {
+			return
+		}
+		if Self::do_pay_subscription(org_id, &org, who.clone(), &who).is_ok() {
+			return
+		}
+		if Self::is_subscription_lapsed(paid_until, now) {
+			// Member becomes active again after paying the subscription
+			let _ = Self::do_update_member(org_id, who, MemberState::Inactive);
+		} else {
+			Self::schedule_subscription(paid_until.saturating_add(T::SubscriptionGracePeriod::get()), org_id, who);
+		}
+	}
+
+	/// Permissions assigned to the role, unless they were changed by the org.
+	fn default_role_permissions(role: &OrgRole) -> Vec<OrgPermission> {
+		match role {
+			// Admin manages the org, but not the treasury
+			OrgRole::Admin => vec![
+				OrgPermission::UpdateOrg, OrgPermission::ManageState, OrgPermission::AddMember,
+				OrgPermission::UpdateMember, OrgPermission::RemoveMember, OrgPermission::ManageRoles,
+				OrgPermission::CreateCampaign, OrgPermission::ManageBattlepass,
+			],
+			OrgRole::Treasurer => vec![OrgPermission::SpendFunds, OrgPermission::CreateCampaign],
+			OrgRole::Moderator => vec![OrgPermission::UpdateMember, OrgPermission::RemoveMember],
+			OrgRole::Recruiter => vec![OrgPermission::AddMember],
+		}
+	}
+
+	fn role_permissions(org_id: &T::Hash, role: &OrgRole) -> Vec<OrgPermission> {
+		match RolePermissions::<T>::get(org_id, role) {
+			Some(permissions) => permissions.into_inner(),
+			None => Self::default_role_permissions(role),
+		}
+	}
+
+	fn role_has_permission(org_id: &T::Hash, role: &OrgRole, permission: &OrgPermission) -> bool {
+		Self::role_permissions(org_id, role).contains(permission)
+	}
+
+	/// Roles and permissions are delegated by Root and the prime, or by the members holding them,
+	/// so a member can't escalate its own permissions.
+	fn ensure_permissions_held(
+		origin: T::RuntimeOrigin,
+		org_id: &T::Hash,
+		org: &Org<T>,
+		permissions: &[OrgPermission]
+	) -> DispatchResult {
+		let who = match origin.into() {
+			Ok(RawOrigin::Root) => return Ok(()),
+			Ok(RawOrigin::Signed(who)) => who,
+			_ => return Err(BadOrigin.into()),
+		};
+		if who == org.prime {
+			return Ok(())
+		}
+		let held = MemberRoles::<T>::get(org_id, &who)
+			.iter()
+			.flat_map(|role| Self::role_permissions(org_id, role))
+			.collect::<Vec<_>>();
+		ensure!(permissions.iter().all(|permission| held.contains(permission)), Error::<T>::PermissionNotHeld);
+		Ok(())
+	}
+
+	/// Prime is never expelled, other members are expelled by Root, the prime
+	/// or the members whose roles aren't outranked by the member's roles.
+	fn ensure_can_expel(
+		origin: T::RuntimeOrigin,
+		org_id: &T::Hash,
+		org: &Org<T>,
+		who: &T::AccountId
+	) -> DispatchResult {
+		ensure!(*who != org.prime, Error::<T>::PrimeNotRemovable);
+		let caller = match origin.into() {
+			Ok(RawOrigin::Root) => return Ok(()),
+			Ok(RawOrigin::Signed(caller)) => caller,
+			_ => return Err(BadOrigin.into()),
+		};
+		if caller == org.prime {
+			return Ok(())
+		}
+		// Roles are kept sorted, the first one is the highest
+		if let Some(target_rank) = MemberRoles::<T>::get(org_id, who).first() {
+			let caller_roles = MemberRoles::<T>::get(org_id, &caller);
+			ensure!(caller_roles.first().map_or(false, |rank| rank <= target_rank), Error::<T>::RoleOutranked);
+		}
+		Ok(())
+	}
+
+	fn has_permission(org_id: &T::Hash, org: &Org<T>, who: &T::AccountId, permission: OrgPermission) -> bool {
+		if *who == org.prime {
+			return true;
+		}
+		if !Self::is_member_active(org_id, org, who) {
+			return false;
+		}
+		MemberRoles::<T>::get(org_id, who)
+			.iter()
+			.any(|role| Self::role_has_permission(org_id, role, &permission))
+	}
+
+	fn ensure_not_dissolving(org_id: &T::Hash) -> DispatchResult {
+		ensure!(OrgStates::<T>::get(org_id) != OrgState::Dissolving, Error::<T>::OrganizationDissolving);
 		Ok(())
 	}
 
+	fn ensure_permission(
+		origin: T::RuntimeOrigin,
+		org_id: &T::Hash,
+		org: &Org<T>,
+		permission: OrgPermission
+	) -> Result<(), BadOrigin> {
+		match origin.into() {
+			Ok(RawOrigin::Root) => Ok(()),
+			Ok(RawOrigin::Signed(t)) => {
+				if Self::has_permission(org_id, org, &t, permission) {
+					return Ok(());
+				}
+				Err(BadOrigin)
+			},
+			_ => Err(BadOrigin),
+		}
+	}
+
 	fn ensure_membership_permissions(
 		origin: T::RuntimeOrigin,
+		org_id: &T::Hash,
+		org: &Org<T>,
 		who: T::AccountId,
-		prime: T::AccountId,
-		org_type: OrgType,
-		access_model: AccessModel
+		permission: OrgPermission
 	) -> Result<(), BadOrigin> {
-		match access_model {
+		match org.access_model {
 			AccessModel::Open => {
-				Self::ensure_root_or_self(origin, who)
+				if Self::ensure_root_or_self(origin.clone(), who).is_ok() {
+					return Ok(());
+				}
+				Self::ensure_permission(origin, org_id, org, permission)
 			},
 			AccessModel::Prime => {
-				Self::ensure_root_or_prime(origin, prime, org_type)
-			},
-			AccessModel::Voting => {
-				Self::ensure_root_or_prime(origin, prime, org_type)
-				// TODO: add voting when membership voting is available
-				// return Ok(Self::ensure_root_or_governance(origin)?);
+				Self::ensure_permission(origin, org_id, org, permission)
 			},
+			// Members votings report back with `apply_org_action`, manual changes are left to Root
+			AccessModel::Voting => ensure_root(origin),
 		}
 	}
 
-	fn ensure_root_or_prime(origin: T::RuntimeOrigin, prime: T::AccountId, _org_type: OrgType) -> Result<(), BadOrigin> {
+	fn ensure_root_or_prime(origin: T::RuntimeOrigin, prime: T::AccountId) -> Result<(), BadOrigin> {
 		match origin.into() {
 			Ok(RawOrigin::Root) => Ok(()),
 			Ok(RawOrigin::Signed(t)) => {
@@ -704,14 +3356,6 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	// fn ensure_root_or_governance(origin: T::RuntimeOrigin) -> Result<(), BadOrigin> {
-	// 	match origin.into() {
-	// 		Ok(RawOrigin::Root) => Ok(()),
-	// 		// TODO: implement governance origin type
-	// 		_ => Err(BadOrigin),
-	// 	}
-	// }
-
 	fn ensure_root_or_self(origin: T::RuntimeOrigin, who: T::AccountId) -> Result<(), BadOrigin> {
 		match origin.into() {
 			Ok(RawOrigin::Root) => Ok(()),
@@ -740,6 +3384,9 @@ impl<T: Config> ControlTrait<T::AccountId, T::Hash> for Pallet<T> {
 	fn org_treasury_account(org_id: &T::Hash) -> Option<T::AccountId> {
 		OrgTreasury::<T>::get(org_id)
 	}
+	fn org_vault_account(org_id: &T::Hash, vault_id: Option<VaultId>) -> Option<T::AccountId> {
+		Self::vault_account(org_id, vault_id).ok()
+	}
 	fn org_member_count(org_id: &T::Hash) -> u32 {
 		OrgMemberCount::<T>::get(org_id)
 	}
@@ -747,7 +3394,64 @@ impl<T: Config> ControlTrait<T::AccountId, T::Hash> for Pallet<T> {
 		OrgStates::<T>::get(org_id) == OrgState::Active
 	}
 	fn is_org_member_active(org_id: &T::Hash, account_id: &T::AccountId) -> bool {
-		MemberStates::<T>::get(org_id, account_id) == MemberState::Active
+		match Orgs::<T>::get(org_id) {
+			Some(org) => Self::is_member_active(org_id, &org, account_id),
+			None => false,
+		}
+	}
+	fn has_org_role(org_id: &T::Hash, account_id: &T::AccountId, role: OrgRole) -> bool {
+		MemberRoles::<T>::get(org_id, account_id).contains(&role)
+	}
+	fn has_org_permission(org_id: &T::Hash, account_id: &T::AccountId, permission: OrgPermission) -> bool {
+		match Orgs::<T>::get(org_id) {
+			Some(org) => Self::has_permission(org_id, &org, account_id, permission),
+			None => false,
+		}
+	}
+	fn apply_org_action(org_id: &T::Hash, action: OrgAction<T::AccountId, T::Hash>, approved: bool) -> DispatchResult {
+		// Votings of the dissolved org are closed without the action
+		let org = match Orgs::<T>::get(org_id) {
+			Some(org) => org,
+			None => return Ok(()),
+		};
+		// Pending applications of the dissolving org are settled by the dissolution
+		if OrgStates::<T>::get(org_id) == OrgState::Dissolving {
+			return Ok(())
+		}
+		// Action could become stale while the voting was in progress, e.g. the sub-org got another parent.
+		// It is skipped then, so the voting is still finalized.
+		let result = frame_support::storage::with_storage_layer(|| Self::do_apply_org_action(org_id, org, action, approved));
+		if let Err(error) = result {
+			log::warn!(target: "runtime::gamedao_control", "Org action skipped for: '{:?}', error: {:?}", org_id, error);
+		}
+		Ok(())
+	}
+	fn org_parent(org_id: &T::Hash) -> Option<T::Hash> {
+		OrgParent::<T>::get(org_id)
+	}
+	fn org_children(org_id: &T::Hash) -> Vec<T::Hash> {
+		OrgChildren::<T>::iter_key_prefix(org_id).collect()
+	}
+	fn org_subtree(org_id: &T::Hash) -> Vec<T::Hash> {
+		let max_orgs = MaxSubtreeOrgs::get() as usize;
+		let mut subtree: Vec<T::Hash> = OrgChildren::<T>::iter_key_prefix(org_id).take(max_orgs).collect();
+		let mut i = 0;
+		while i < subtree.len() && subtree.len() < max_orgs {
+			let children = OrgChildren::<T>::iter_key_prefix(subtree[i]).take(max_orgs - subtree.len());
+			subtree.extend(children.collect::<Vec<_>>());
+			i += 1;
+		}
+		subtree
+	}
+	fn is_org_ancestor(ancestor_id: &T::Hash, org_id: &T::Hash) -> bool {
+		Self::org_ancestors(org_id).contains(ancestor_id)
+	}
+}
+
+impl<T: Config> TreasuryTrait<T::AccountId, T::CurrencyId, T::Hash> for Pallet<T> {
+
+	fn ensure_committed_funds(org_id: &T::Hash, account: &T::AccountId, currency_id: T::CurrencyId) -> DispatchResult {
+		Self::ensure_uncommitted_funds(org_id, account, currency_id, Zero::zero())
 	}
 }
 
@@ -768,7 +3472,7 @@ impl<T: Config> ControlBenchmarkingTrait<T::AccountId, T::Hash> for Pallet<T> {
 		Pallet::<T>::create_org(
 			frame_system::RawOrigin::Signed(caller).into(),
 			org.name, org.cid, org.org_type, org.access_model, org.fee_model, None,
-			None, Some(org.gov_currency), Some(org.pay_currency), None
+			None, Some(org.gov_currency), Some(org.pay_currency), None, None, None
 		)?;
 		Ok(org_id)
 	}
@@ -778,7 +3482,7 @@ impl<T: Config> ControlBenchmarkingTrait<T::AccountId, T::Hash> for Pallet<T> {
 			Pallet::<T>::add_member(
 				frame_system::RawOrigin::Root.into(),
 				org_id.clone(),
-				acc.clone()
+				acc.clone(), None
 			).unwrap();
 			Pallet::<T>::update_member_state(
 				frame_system::RawOrigin::Root.into(),
@@ -0,0 +1,128 @@
+//! Storage migrations for the control pallet.
+
+use super::*;
+use frame_support::{
+	log, storage_alias,
+	traits::{GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+	Blake2_128Concat,
+};
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+
+pub mod v1 {
+	use super::*;
+
+	/// Org members list by org id, removed in v1.
+	#[storage_alias]
+	pub(crate) type Members<T: Config> = StorageMap<
+		Pallet<T>, Blake2_128Concat, <T as frame_system::Config>::Hash,
+		BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxMembers>, ValueQuery
+	>;
+
+	/// Removes `Members` lists, membership is stored in `MemberStates` only.
+	/// Member counters are recalculated from the removed lists.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version != 0 {
+				log::info!(target: "runtime::gamedao_control", "MigrateToV1 skipped, on-chain version: {:?}", onchain_version);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut orgs: u64 = 0;
+			for (org_id, members) in Members::<T>::drain() {
+				OrgMemberCount::<T>::insert(org_id, members.len() as u32);
+				orgs += 1;
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::gamedao_control", "MigrateToV1 migrated members of {} orgs", orgs);
+			T::DbWeight::get().reads_writes(orgs + 1, 2 * orgs + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let members: u32 = Members::<T>::iter_values().map(|members| members.len() as u32).sum();
+			Ok(members.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let members: u32 = Decode::decode(&mut &state[..]).map_err(|_| "Failed to decode the pre upgrade state")?;
+			ensure!(Members::<T>::iter().next().is_none(), "Members lists were not removed");
+			ensure!(OrgMemberCount::<T>::iter_values().sum::<u32>() == members, "Member counters mismatch");
+			ensure!(MemberStates::<T>::iter().count() as u32 == members, "Member states mismatch");
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "Storage version was not updated");
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Records the membership fees reserved before v2 in `MemberReservedFees`. Until v2 the fee
+	/// of every Reserve org member was reserved on the org prime, so the prime is recorded as the fee payer
+	/// of the current members. The recorded fees are capped by the balance reserved by the prime.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version != 1 {
+				log::info!(target: "runtime::gamedao_control", "MigrateToV2 skipped, on-chain version: {:?}", onchain_version);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let (mut reads, mut writes) = (1_u64, 1_u64);
+			// Reserved balances accounted by the prime and the currency
+			let mut accounted: Vec<(T::AccountId, T::CurrencyId, T::Balance)> = Vec::new();
+			let mut recorded: u64 = 0;
+			for (org_id, who, member_state) in MemberStates::<T>::iter() {
+				reads += 1;
+				if Pallet::<T>::is_former_member(&member_state) {
+					continue
+				}
+				let org = match Orgs::<T>::get(org_id) {
+					Some(org) if org.fee_model == FeeModel::Reserve => org,
+					_ => continue,
+				};
+				reads += 1;
+				let index = match accounted.iter().position(|(a, c, _)| *a == org.prime && *c == org.gov_currency) {
+					Some(index) => index,
+					None => {
+						accounted.push((org.prime.clone(), org.gov_currency, Zero::zero()));
+						reads += 1;
+						accounted.len() - 1
+					},
+				};
+				let available = T::Currency::reserved_balance(org.gov_currency, &org.prime).saturating_sub(accounted[index].2);
+				let fee = org.membership_fee.unwrap_or_default().min(available);
+				if !fee.is_zero() {
+					accounted[index].2 = accounted[index].2.saturating_add(fee);
+					MemberReservedFees::<T>::insert(org_id, &who, (org.prime, fee));
+					recorded += 1;
+					writes += 1;
+				}
+			}
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!(target: "runtime::gamedao_control", "MigrateToV2 recorded {} reserved membership fees", recorded);
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+			for (org_id, _, (fee_payer, _)) in MemberReservedFees::<T>::iter() {
+				let org = Orgs::<T>::get(org_id).ok_or("Fee recorded for unknown org")?;
+				ensure!(fee_payer == org.prime, "Fee payer is not the org prime");
+			}
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "Storage version was not updated");
+			Ok(())
+		}
+	}
+}
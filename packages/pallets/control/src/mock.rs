@@ -4,12 +4,15 @@ use crate as pallet_control;
 use frame_support::{PalletId, {traits::GenesisBuild}, pallet_prelude::*, traits::Nothing};
 use frame_system;
 use codec::MaxEncodedLen;
+use gamedao_traits::{GovernanceTrait, OrgAction, SenseTrait};
 use sp_core::H256;
 use sp_std::convert::{TryInto, TryFrom};
-use sp_runtime::{testing::Header, traits::{ConstU32, IdentityLookup, BlakeTwo256}};
+use sp_runtime::{testing::{Header, TestSignature}, traits::{ConstU32, IdentityLookup, BlakeTwo256, Verify, IdentifyAccount}};
 
 // Types:
-pub type AccountId = u32;
+pub type Signature = TestSignature;
+pub type AccountPublic = <Signature as Verify>::Signer;
+pub type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
 pub type BlockNumber = u64;
 pub type Hash = H256;
 pub type Balance = u128;
@@ -36,6 +39,7 @@ pub const CENTS: Balance = 1_000 * MILLICENTS;
 pub const DOLLARS: Balance = 100 * CENTS;
 pub const PROTOCOL_TOKEN_ID: CurrencyId = 1;
 pub const PAYMENT_TOKEN_ID: CurrencyId = 2;
+pub const ASSET_TOKEN_ID: CurrencyId = 3;
 
 // Accounts:
 pub const TREASURY: AccountId = 1;
@@ -138,21 +142,77 @@ impl orml_currencies::Config for Test {
 frame_support::parameter_types! {
 	pub const ProtocolTokenId: u32 = PROTOCOL_TOKEN_ID;
 	pub const PaymentTokenId: CurrencyId = PAYMENT_TOKEN_ID;
+	pub TreasuryCurrencies: Vec<CurrencyId> = vec![PROTOCOL_TOKEN_ID, PAYMENT_TOKEN_ID, ASSET_TOKEN_ID];
 	pub const MinimumDeposit: Balance = 5 * DOLLARS;
+	pub const SubscriptionPeriod: BlockNumber = 10;
+	pub const SubscriptionGracePeriod: BlockNumber = 5;
+	pub const PrimeHandoverPeriod: BlockNumber = 10;
 	pub const ControlPalletId: PalletId = PalletId(*b"gd/cntrl");
 	pub const MaxMembers: u32 = 10000;
+	pub const MaxMembersProcessing: u32 = 5;
+	pub const MaxStreams: u32 = 3;
 	pub const StringLimit: u32 = 64;
 }
+pub struct MockGovernance;
+impl GovernanceTrait<AccountId, Balance, CurrencyId, Hash> for MockGovernance {
+	fn create_org_action_proposal(
+		_org_id: &Hash, _proposer: &AccountId, _action: OrgAction<AccountId, Hash>
+	) -> Result<Hash, DispatchError> {
+		Ok(Hash::default())
+	}
+	fn create_spending_proposal(
+		_org_id: &Hash, _proposer: &AccountId, _currency_id: CurrencyId, _beneficiary: &AccountId, _amount: Balance
+	) -> Result<Hash, DispatchError> {
+		Ok(Hash::default())
+	}
+}
+
+/// ALICE and BOB have Sense entities with 100 and 10 reputation.
+pub struct MockSense;
+impl SenseTrait<AccountId> for MockSense {
+	fn has_entity(account_id: &AccountId) -> bool {
+		*account_id == ALICE || *account_id == BOB
+	}
+	fn reputation(account_id: &AccountId) -> u64 {
+		match *account_id {
+			ALICE => 100,
+			BOB => 10,
+			_ => 0,
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct InvitationSigner;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_control::InvitationSigner<AccountId, Signature> for InvitationSigner {
+	fn sign(message: &[u8]) -> (AccountId, Signature) {
+		(ALICE, TestSignature(ALICE, message.to_vec()))
+	}
+}
+
 impl pallet_control::Config for Test {
 	type Balance = Balance;
 	type CurrencyId = CurrencyId;
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Currencies;
+	type Governance = MockGovernance;
+	type Sense = MockSense;
+	type OffchainSignature = Signature;
+	type OffchainPublic = AccountPublic;
+	#[cfg(feature = "runtime-benchmarks")]
+	type InvitationSigner = InvitationSigner;
 	type MaxMembers = MaxMembers;
+	type MaxMembersProcessing = MaxMembersProcessing;
+	type MaxStreams = MaxStreams;
 	type ProtocolTokenId = ProtocolTokenId;
 	type PaymentTokenId = PaymentTokenId;
+	type TreasuryCurrencies = TreasuryCurrencies;
 	type MinimumDeposit = MinimumDeposit;
+	type SubscriptionPeriod = SubscriptionPeriod;
+	type SubscriptionGracePeriod = SubscriptionGracePeriod;
+	type PrimeHandoverPeriod = PrimeHandoverPeriod;
 	type PalletId = ControlPalletId;
 	type StringLimit = ConstU32<256>;
 }
@@ -1,11 +1,13 @@
 #![cfg(test)]
 
-use frame_support::{assert_noop, assert_ok};
-use sp_runtime::traits::BadOrigin;
+use frame_support::{assert_noop, assert_ok, traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade}};
+use codec::Encode;
+use sp_runtime::{testing::TestSignature, traits::BadOrigin};
 use sp_core::{H256, ConstU32};
 use super::*;
+use gamedao_traits::testing::snapshot_ext;
 use mock::{new_test_ext, System, Test, RuntimeEvent as Event, Control, RuntimeOrigin as Origin, Tokens, CurrencyId, Balance, AccountId,
-	ALICE, BOB, CHARLIE, PAYMENT_TOKEN_ID, PROTOCOL_TOKEN_ID, DOLLARS};
+	BlockNumber, ALICE, BOB, CHARLIE, PAYMENT_TOKEN_ID, PROTOCOL_TOKEN_ID, ASSET_TOKEN_ID, DOLLARS};
 
 
 fn create_org(access_model: AccessModel) -> H256 {
@@ -23,7 +25,7 @@ fn create_org(access_model: AccessModel) -> H256 {
 	assert_ok!(
 		Control::create_org(
 			Origin::signed(ALICE), org.name, org.cid, org.org_type, org.access_model,
-			org.fee_model, None, org.membership_fee, None, None, None
+			org.fee_model, None, org.membership_fee, None, None, None, None, None
 	));
 	org_id
 }
@@ -36,32 +38,18 @@ fn control_create_org() {
 		System::set_block_number(current_block);
 		let bounded_str = BoundedVec::truncate_from(vec![1,2]);
 
-		// Create org with org type Company
-		// Error: WrongOrganizationType
-		assert_noop!(Control::create_org(
-			Origin::signed(ALICE), bounded_str.clone(), bounded_str.clone(), OrgType::Company,
-			AccessModel::Prime, FeeModel::NoFees, None, None, None, None, None),
-			Error::<Test>::WrongOrganizationType);
-
-		// Create org with org type Hybrid
-		// Error: WrongOrganizationType
-		assert_noop!(Control::create_org(
-			Origin::signed(ALICE), bounded_str.clone(), bounded_str.clone(), OrgType::Hybrid,
-			AccessModel::Prime, FeeModel::NoFees, None, None, None, None, None),
-			Error::<Test>::WrongOrganizationType);
-
 		// Deposit into Org treasury less than MinDeposit
 		// Error: MinimumDepositTooLow
 		assert_noop!(Control::create_org(
 			Origin::signed(ALICE), bounded_str.clone(), bounded_str.clone(), OrgType::Individual,
-			AccessModel::Prime, FeeModel::NoFees, None, None, None, None, Some(1 * DOLLARS)),
+			AccessModel::Prime, FeeModel::NoFees, None, None, None, None, Some(1 * DOLLARS), None, None),
 			Error::<Test>::MinimumDepositTooLow);
 
 		// No membership_fee set for FeeModel::Transfer
 		// Error: MissingParameter
 		assert_noop!(Control::create_org(
 			Origin::signed(ALICE), bounded_str.clone(), bounded_str.clone(), OrgType::Individual,
-			AccessModel::Prime, FeeModel::Transfer, None, None, None, None, None),
+			AccessModel::Prime, FeeModel::Transfer, None, None, None, None, None, None, None),
 			Error::<Test>::MissingParameter);
 
 		// Check if creator (sender) has enough protocol token free balance
@@ -69,7 +57,7 @@ fn control_create_org() {
 		// Error: BalanceLow
 		assert_noop!(Control::create_org(
 			Origin::signed(ALICE), bounded_str.clone(), bounded_str.clone(), OrgType::Individual,
-			AccessModel::Prime, FeeModel::NoFees, None, None, None, None, Some(1000 * DOLLARS)),
+			AccessModel::Prime, FeeModel::NoFees, None, None, None, None, Some(1000 * DOLLARS), None, None),
 			Error::<Test>::BalanceLow);
 
 		// Create org Success, check event
@@ -86,6 +74,15 @@ fn control_create_org() {
 			)
 		);
 
+		// Create org with org type Company and Hybrid
+		assert_ok!(Control::create_org(
+			Origin::signed(ALICE), bounded_str.clone(), bounded_str.clone(), OrgType::Company,
+			AccessModel::Prime, FeeModel::NoFees, None, None, None, None, None, None, None));
+		assert_ok!(Control::create_org(
+			Origin::signed(ALICE), bounded_str.clone(), bounded_str.clone(), OrgType::Hybrid,
+			AccessModel::Prime, FeeModel::NoFees, None, None, None, None, None, None, None));
+		assert_eq!(OrgCount::<Test>::get(), 3);
+
 	})
 }
 
@@ -119,7 +116,7 @@ fn control_update_org() {
 			Origin::signed(ALICE), org_id, None, None, Some(BOB), None, None, None, None, None),
 			Error::<Test>::NotMember);
 
-		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, BOB));
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, BOB, None));
 
 		// Check if only prime can perform update_org
 		// Error: BadOrigin
@@ -144,6 +141,10 @@ fn control_update_org() {
 			Origin::signed(ALICE), org_id, name.clone(), cid.clone(), prime_id, org_type.clone(), access_model.clone(), member_limit,
 			fee_model.clone(), membership_fee));
 
+		// Prime handover has to be accepted by the new prime
+		assert_eq!(Orgs::<Test>::get(org_id).unwrap().prime, ALICE);
+		assert_ok!(Control::accept_prime_handover(Origin::signed(BOB), org_id));
+
 		let org = Orgs::<Test>::get(org_id).unwrap();
 		assert_eq!(org.prime, prime_id.clone().unwrap());
 		assert_eq!(org.name, name.clone().unwrap());
@@ -197,10 +198,10 @@ fn control_add_remove_member_access_prime() {
 		System::set_block_number(current_block);
 		let org_id = create_org(AccessModel::Prime);
 		// Add member prime
-		assert_noop!(Control::add_member(Origin::signed(BOB), org_id, CHARLIE), BadOrigin);
-		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, CHARLIE));
-		assert!(Members::<Test>::get(org_id).contains(&CHARLIE));
-		assert_noop!(Control::add_member(Origin::signed(ALICE), org_id, CHARLIE), Error::<Test>::AlreadyMember);
+		assert_noop!(Control::add_member(Origin::signed(BOB), org_id, CHARLIE, None), BadOrigin);
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, CHARLIE, None));
+		assert!(MemberStates::<Test>::contains_key(org_id, CHARLIE));
+		assert_noop!(Control::add_member(Origin::signed(ALICE), org_id, CHARLIE, None), Error::<Test>::AlreadyMember);
 		System::assert_has_event(
 			Event::Control(crate::Event::MemberAdded{
 				org_id, who: CHARLIE, block_number: current_block
@@ -209,7 +210,7 @@ fn control_add_remove_member_access_prime() {
 		// Remove member prime
 		assert_noop!(Control::remove_member(Origin::signed(BOB), org_id, CHARLIE), BadOrigin);
 		assert_ok!(Control::remove_member(Origin::signed(ALICE), org_id, CHARLIE));
-		assert!(!Members::<Test>::get(org_id).contains(&CHARLIE));
+		assert!(!MemberStates::<Test>::contains_key(org_id, CHARLIE));
 		assert_noop!(Control::remove_member(Origin::signed(ALICE), org_id, CHARLIE), Error::<Test>::NotMember);
 		System::assert_has_event(
 			Event::Control(crate::Event::MemberRemoved{
@@ -218,15 +219,15 @@ fn control_add_remove_member_access_prime() {
 		);
 
 		// Add member root
-		assert_ok!(Control::add_member(Origin::root(), org_id, CHARLIE));
-		assert!(Members::<Test>::get(org_id).contains(&CHARLIE));
+		assert_ok!(Control::add_member(Origin::root(), org_id, CHARLIE, None));
+		assert!(MemberStates::<Test>::contains_key(org_id, CHARLIE));
 		// Remove member root
 		assert_ok!(Control::remove_member(Origin::root(), org_id, CHARLIE));
-		assert!(!Members::<Test>::get(org_id).contains(&CHARLIE));
+		assert!(!MemberStates::<Test>::contains_key(org_id, CHARLIE));
 
 		// Add member signed
-		assert_ok!(Control::add_member(Origin::signed(CHARLIE), org_id, CHARLIE));
-		assert!(Members::<Test>::get(org_id).contains(&CHARLIE));
+		assert_ok!(Control::add_member(Origin::signed(CHARLIE), org_id, CHARLIE, None));
+		assert!(MemberStates::<Test>::contains_key(org_id, CHARLIE));
 		assert_eq!(MemberStates::<Test>::get(org_id, CHARLIE), MemberState::Pending);
 
 		// TODO: since membership_fee logic is unclear for the moment, there is no tests for it yet
@@ -240,22 +241,22 @@ fn control_add_remove_member_access_open() {
 		System::set_block_number(current_block);
 		let org_id = create_org(AccessModel::Open);
 		// Add member
-		assert_noop!(Control::add_member(Origin::signed(BOB), org_id, CHARLIE), BadOrigin);
-		assert_ok!(Control::add_member(Origin::signed(BOB), org_id, BOB));
-		assert!(Members::<Test>::get(org_id).contains(&BOB));
-		assert_noop!(Control::add_member(Origin::signed(BOB), org_id, BOB), Error::<Test>::AlreadyMember);
+		assert_noop!(Control::add_member(Origin::signed(BOB), org_id, CHARLIE, None), BadOrigin);
+		assert_ok!(Control::add_member(Origin::signed(BOB), org_id, BOB, None));
+		assert!(MemberStates::<Test>::contains_key(org_id, BOB));
+		assert_noop!(Control::add_member(Origin::signed(BOB), org_id, BOB, None), Error::<Test>::AlreadyMember);
 		// Remove member prime
 		assert_noop!(Control::remove_member(Origin::signed(BOB), org_id, CHARLIE), BadOrigin);
 		assert_ok!(Control::remove_member(Origin::signed(BOB), org_id, BOB));
-		assert!(!Members::<Test>::get(org_id).contains(&BOB));
+		assert!(!MemberStates::<Test>::contains_key(org_id, BOB));
 		assert_noop!(Control::remove_member(Origin::signed(BOB), org_id, BOB), Error::<Test>::NotMember);
 
 		// Add member root
-		assert_ok!(Control::add_member(Origin::root(), org_id, BOB));
-		assert!(Members::<Test>::get(org_id).contains(&BOB));
+		assert_ok!(Control::add_member(Origin::root(), org_id, BOB, None));
+		assert!(MemberStates::<Test>::contains_key(org_id, BOB));
 		// Remove member root
 		assert_ok!(Control::remove_member(Origin::root(), org_id, BOB));
-		assert!(!Members::<Test>::get(org_id).contains(&BOB));
+		assert!(!MemberStates::<Test>::contains_key(org_id, BOB));
 	})
 }
 
@@ -266,19 +267,417 @@ fn control_add_remove_member_access_voting() {
 		System::set_block_number(current_block);
 		let org_id = create_org(AccessModel::Voting);
 		// Add member prime / not member
-		assert_noop!(Control::add_member(Origin::signed(ALICE), org_id, CHARLIE), BadOrigin);
-		assert_noop!(Control::add_member(Origin::signed(BOB), org_id, CHARLIE), BadOrigin);
-		// TODO: Not implemented yet
+		assert_noop!(Control::add_member(Origin::signed(ALICE), org_id, CHARLIE, None), BadOrigin);
+		assert_noop!(Control::add_member(Origin::signed(BOB), org_id, CHARLIE, None), BadOrigin);
 
-		// Remove member prime
-		// TODO: Not implemented yet
+		// Apply for membership, voting is created
+		assert_ok!(Control::add_member(Origin::signed(CHARLIE), org_id, CHARLIE, None));
+		assert_eq!(MemberStates::<Test>::get(org_id, CHARLIE), MemberState::Pending);
+
+		// Update member state / remove member prime
+		assert_noop!(
+			Control::update_member_state(Origin::signed(ALICE), org_id, CHARLIE, MemberState::Active),
+			BadOrigin);
+		assert_noop!(Control::remove_member(Origin::signed(ALICE), org_id, CHARLIE), BadOrigin);
+		assert_ok!(Control::remove_member(Origin::root(), org_id, CHARLIE));
 
 		// Add member root
-		assert_ok!(Control::add_member(Origin::root(), org_id, CHARLIE));
-		assert!(Members::<Test>::get(org_id).contains(&CHARLIE));
+		assert_ok!(Control::add_member(Origin::root(), org_id, CHARLIE, None));
+		assert!(MemberStates::<Test>::contains_key(org_id, CHARLIE));
 		// Remove member root
 		assert_ok!(Control::remove_member(Origin::root(), org_id, CHARLIE));
-		assert!(!Members::<Test>::get(org_id).contains(&CHARLIE));
+		assert!(!MemberStates::<Test>::contains_key(org_id, CHARLIE));
+	})
+}
+
+#[test]
+fn control_membership_voting() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let bounded_str = BoundedVec::truncate_from(vec![1,2]);
+		let fee = 1 * DOLLARS;
+		let index = OrgCount::<Test>::get();
+		let org = types::Org {
+			index, creator: ALICE, prime: ALICE, name: bounded_str.clone(), cid: bounded_str.clone(),
+			org_type: OrgType::Dao, fee_model: FeeModel::Transfer, membership_fee: Some(fee),
+			gov_currency: PROTOCOL_TOKEN_ID, pay_currency: PAYMENT_TOKEN_ID, access_model: AccessModel::Voting,
+			member_limit: <Test as Config>::MaxMembers::get(), created: current_block, mutated: current_block
+		};
+		let org_id = <Test as frame_system::Config>::Hashing::hash_of(&org);
+		assert_ok!(Control::create_org(
+			Origin::signed(ALICE), org.name, org.cid, org.org_type, org.access_model,
+			org.fee_model, None, org.membership_fee, None, None, None, None, None));
+		let treasury_id = OrgTreasury::<Test>::get(org_id).unwrap();
+		let treasury_balance = Tokens::accounts(treasury_id, PROTOCOL_TOKEN_ID).free;
+
+		// Application rejected: fee is unreserved, applicant removed
+		assert_ok!(Control::add_member(Origin::signed(CHARLIE), org_id, CHARLIE, None));
+		assert_eq!(Tokens::accounts(CHARLIE, PROTOCOL_TOKEN_ID).reserved, fee);
+		assert_eq!(OrgMemberCount::<Test>::get(org_id), 2);
+		assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+			&org_id, OrgAction::AddMember(CHARLIE), false));
+		assert!(!MemberStates::<Test>::contains_key(org_id, CHARLIE));
+		assert_eq!(OrgMemberCount::<Test>::get(org_id), 1);
+		System::assert_has_event(Event::Control(crate::Event::MemberRemoved {
+			org_id, who: CHARLIE, block_number: current_block
+		}));
+		assert_eq!(Tokens::accounts(CHARLIE, PROTOCOL_TOKEN_ID).reserved, 0);
+		assert_eq!(Tokens::accounts(CHARLIE, PROTOCOL_TOKEN_ID).free, 2 * DOLLARS);
+
+		// Application approved: fee goes to the org treasury, applicant is active
+		assert_ok!(Control::add_member(Origin::signed(BOB), org_id, BOB, None));
+		assert_eq!(MemberStates::<Test>::get(org_id, BOB), MemberState::Pending);
+		assert!(!<Control as ControlTrait<AccountId, H256>>::is_org_member_active(&org_id, &BOB));
+		assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+			&org_id, OrgAction::AddMember(BOB), true));
+		assert_eq!(MemberStates::<Test>::get(org_id, BOB), MemberState::Active);
+		assert_eq!(Tokens::accounts(BOB, PROTOCOL_TOKEN_ID).reserved, 0);
+		assert_eq!(Tokens::accounts(BOB, PROTOCOL_TOKEN_ID).free, 1 * DOLLARS);
+		assert_eq!(Tokens::accounts(treasury_id, PROTOCOL_TOKEN_ID).free, treasury_balance + fee);
+		System::assert_has_event(Event::Control(crate::Event::MemberUpdated {
+			org_id, who: BOB, state: MemberState::Active, block_number: current_block
+		}));
+
+		// Voting result for a member which is not pending anymore is ignored
+		assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+			&org_id, OrgAction::AddMember(BOB), false));
+		assert_eq!(MemberStates::<Test>::get(org_id, BOB), MemberState::Active);
+	})
+}
+
+#[test]
+fn control_roles() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let org_id = create_org(AccessModel::Prime);
+		let treasury_id = OrgTreasury::<Test>::get(org_id).unwrap();
+		set_balance(treasury_id, PROTOCOL_TOKEN_ID, 100 * DOLLARS);
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, BOB, None));
+		assert_ok!(Control::update_member_state(Origin::signed(ALICE), org_id, BOB, MemberState::Active));
+
+		// Member without a role
+		assert_noop!(Control::spend_funds(Origin::signed(BOB), org_id, PROTOCOL_TOKEN_ID, BOB, 1 * DOLLARS), BadOrigin);
+		assert_noop!(Control::grant_role(Origin::signed(BOB), org_id, BOB, OrgRole::Treasurer), BadOrigin);
+		// Error: NotMember
+		assert_noop!(
+			Control::grant_role(Origin::signed(ALICE), org_id, CHARLIE, OrgRole::Treasurer),
+			Error::<Test>::NotMember);
+
+		// Treasurer with default permissions
+		assert_ok!(Control::grant_role(Origin::signed(ALICE), org_id, BOB, OrgRole::Treasurer));
+		System::assert_has_event(Event::Control(crate::Event::RoleGranted {
+			org_id, who: BOB, role: OrgRole::Treasurer, block_number: current_block
+		}));
+		assert_noop!(
+			Control::grant_role(Origin::signed(ALICE), org_id, BOB, OrgRole::Treasurer),
+			Error::<Test>::RoleAlreadyGranted);
+		assert!(<Control as ControlTrait<AccountId, H256>>::has_org_role(&org_id, &BOB, OrgRole::Treasurer));
+		assert!(<Control as ControlTrait<AccountId, H256>>::has_org_permission(&org_id, &BOB, OrgPermission::SpendFunds));
+		assert_ok!(Control::spend_funds(Origin::signed(BOB), org_id, PROTOCOL_TOKEN_ID, BOB, 1 * DOLLARS));
+		assert_noop!(Control::disable_org(Origin::signed(BOB), org_id), BadOrigin);
+
+		// Treasurer with custom permissions
+		let permissions = BoundedVec::truncate_from(vec![OrgPermission::ManageState]);
+		assert_ok!(Control::set_role_permissions(Origin::signed(ALICE), org_id, OrgRole::Treasurer, permissions.clone()));
+		System::assert_has_event(Event::Control(crate::Event::RolePermissionsUpdated {
+			org_id, role: OrgRole::Treasurer, permissions, block_number: current_block
+		}));
+		assert_ok!(Control::disable_org(Origin::signed(BOB), org_id));
+		assert_noop!(Control::spend_funds(Origin::signed(BOB), org_id, PROTOCOL_TOKEN_ID, BOB, 1 * DOLLARS), BadOrigin);
+
+		// Revoke role
+		assert_ok!(Control::revoke_role(Origin::signed(ALICE), org_id, BOB, OrgRole::Treasurer));
+		System::assert_has_event(Event::Control(crate::Event::RoleRevoked {
+			org_id, who: BOB, role: OrgRole::Treasurer, block_number: current_block
+		}));
+		assert_noop!(
+			Control::revoke_role(Origin::signed(ALICE), org_id, BOB, OrgRole::Treasurer),
+			Error::<Test>::RoleNotGranted);
+		assert_noop!(Control::enable_org(Origin::signed(BOB), org_id), BadOrigin);
+
+		// Admin can update the org, but can't change the prime
+		assert_ok!(Control::grant_role(Origin::root(), org_id, BOB, OrgRole::Admin));
+		assert_ok!(Control::update_org(
+			Origin::signed(BOB), org_id, None, None, None, None, None, Some(100), None, None));
+		assert_noop!(Control::update_org(
+			Origin::signed(BOB), org_id, None, None, Some(BOB), None, None, None, None, None),
+			BadOrigin);
+		// Admin manages roles, but not the treasury
+		assert_ok!(Control::grant_role(Origin::signed(BOB), org_id, BOB, OrgRole::Recruiter));
+		assert_ok!(Control::add_member(Origin::signed(BOB), org_id, CHARLIE, None));
+		assert_noop!(Control::spend_funds(Origin::signed(BOB), org_id, PROTOCOL_TOKEN_ID, BOB, 1 * DOLLARS), BadOrigin);
+
+		// Admin can't delegate or change the permissions it doesn't hold
+		let treasurer_permissions = BoundedVec::truncate_from(vec![OrgPermission::SpendFunds]);
+		assert_ok!(Control::set_role_permissions(Origin::signed(ALICE), org_id, OrgRole::Treasurer, treasurer_permissions));
+		assert_noop!(
+			Control::grant_role(Origin::signed(BOB), org_id, BOB, OrgRole::Treasurer),
+			Error::<Test>::PermissionNotHeld);
+		assert_noop!(
+			Control::set_role_permissions(Origin::signed(BOB), org_id, OrgRole::Admin,
+				BoundedVec::truncate_from(vec![OrgPermission::ManageRoles, OrgPermission::SpendFunds])),
+			Error::<Test>::PermissionNotHeld);
+		assert_noop!(
+			Control::set_role_permissions(Origin::signed(BOB), org_id, OrgRole::Treasurer, BoundedVec::truncate_from(vec![])),
+			Error::<Test>::PermissionNotHeld);
+		assert_ok!(Control::update_member_state(Origin::signed(ALICE), org_id, CHARLIE, MemberState::Active));
+		assert_ok!(Control::grant_role(Origin::signed(ALICE), org_id, CHARLIE, OrgRole::Treasurer));
+		assert_noop!(
+			Control::revoke_role(Origin::signed(BOB), org_id, CHARLIE, OrgRole::Treasurer),
+			Error::<Test>::PermissionNotHeld);
+
+		// Roles are removed together with the member
+		assert_ok!(Control::remove_member(Origin::signed(ALICE), org_id, BOB));
+		assert!(!<Control as ControlTrait<AccountId, H256>>::has_org_role(&org_id, &BOB, OrgRole::Admin));
+	})
+}
+
+#[test]
+fn control_org_members_pagination() {
+	new_test_ext().execute_with(|| {
+		let org_id = create_org(AccessModel::Open);
+		for who in 100..120 {
+			assert_ok!(Control::add_member(Origin::root(), org_id, who, None));
+		}
+		assert_eq!(OrgMemberCount::<Test>::get(org_id), 21);
+
+		// Walk all the pages, every member is listed exactly once
+		let mut listed: Vec<AccountId> = vec![];
+		let mut cursor = None;
+		loop {
+			let page = Control::org_members(&org_id, cursor, 6);
+			if page.is_empty() {
+				break
+			}
+			assert!(page.len() <= 6);
+			assert!(page.iter().all(|(_, state)| *state == MemberState::Active));
+			cursor = page.last().map(|(who, _)| *who);
+			listed.extend(page.into_iter().map(|(who, _)| who));
+		}
+		assert_eq!(listed.len(), 21);
+		listed.sort();
+		listed.dedup();
+		assert_eq!(listed.len(), 21);
+		assert!(listed.contains(&ALICE));
+	})
+}
+
+#[test]
+fn control_member_limit() {
+	new_test_ext().execute_with(|| {
+		let org_id = create_org(AccessModel::Open);
+		assert_ok!(Control::add_member(Origin::root(), org_id, BOB, None));
+
+		// Limit can't be set below the current members count
+		assert_noop!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, None, None, Some(1), None, None),
+			Error::<Test>::MembershipLimitReached);
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, None, None, Some(2), None, None));
+		assert_noop!(
+			Control::add_member(Origin::root(), org_id, CHARLIE, None),
+			Error::<Test>::MembershipLimitReached);
+
+		// Removing a member frees the slot
+		assert_ok!(Control::remove_member(Origin::root(), org_id, BOB));
+		assert_ok!(Control::add_member(Origin::root(), org_id, CHARLIE, None));
+		assert_eq!(OrgMemberCount::<Test>::get(org_id), 2);
+	})
+}
+
+#[test]
+fn control_migrate_to_v1() {
+	new_test_ext().execute_with(|| {
+		let org_id = create_org(AccessModel::Open);
+		assert_ok!(Control::add_member(Origin::root(), org_id, BOB, None));
+		assert_ok!(Control::add_member(Origin::root(), org_id, CHARLIE, None));
+
+		// Emulate v0 storage with a stale counter
+		StorageVersion::new(0).put::<Control>();
+		migrations::v1::Members::<Test>::insert(org_id, BoundedVec::truncate_from(vec![ALICE, BOB, CHARLIE]));
+		OrgMemberCount::<Test>::insert(org_id, 0);
+
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert!(!migrations::v1::Members::<Test>::contains_key(org_id));
+		assert_eq!(OrgMemberCount::<Test>::get(org_id), 3);
+		assert_eq!(Control::on_chain_storage_version(), 1);
+
+		// Second run is a no-op
+		OrgMemberCount::<Test>::insert(org_id, 0);
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(OrgMemberCount::<Test>::get(org_id), 0);
+	})
+}
+
+#[test]
+fn control_migrate_to_v2() {
+	new_test_ext().execute_with(|| {
+		let org_id = create_org(AccessModel::Prime);
+		let fee = 1 * DOLLARS;
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, None, None, None, Some(FeeModel::Reserve), Some(fee)));
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, BOB, None));
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, CHARLIE, None));
+		let reason: BoundedVec<u8, _> = BoundedVec::truncate_from(vec![1, 2]);
+		assert_ok!(Control::kick_member(Origin::signed(ALICE), org_id, CHARLIE, reason, false));
+
+		// Emulate v1 storage: fees of all the members, the prime included, were reserved on the prime
+		// and not recorded, members hold unrelated reserves
+		StorageVersion::new(1).put::<Control>();
+		let _ = MemberReservedFees::<Test>::clear(u32::MAX, None);
+		assert_ok!(Tokens::reserve(PROTOCOL_TOKEN_ID, &ALICE, fee));
+		assert_ok!(Tokens::reserve(PROTOCOL_TOKEN_ID, &BOB, fee));
+
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Control::on_chain_storage_version(), 2);
+		assert_eq!(MemberReservedFees::<Test>::get(org_id, BOB), Some((ALICE, fee)));
+		assert_eq!(MemberReservedFees::<Test>::get(org_id, ALICE), Some((ALICE, fee)));
+		// Former member holds no fee
+		assert!(!MemberReservedFees::<Test>::contains_key(org_id, CHARLIE));
+	})
+}
+
+#[test]
+fn control_sponsored_seats() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let fee = 1 * DOLLARS;
+		let org_id = create_org(AccessModel::Prime);
+		let named = |accounts: Vec<AccountId>| -> BoundedVec<AccountId, types::MaxNamedSeats> {
+			BoundedVec::truncate_from(accounts)
+		};
+
+		// Seats are prepaid for the orgs charging a membership fee only
+		assert_noop!(
+			Control::sponsor_seats(Origin::signed(ALICE), org_id, 3, named(vec![BOB])),
+			Error::<Test>::SponsorshipNotAllowed);
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, None, None, None, Some(FeeModel::Transfer), Some(fee)));
+		let treasury_id = OrgTreasury::<Test>::get(org_id).unwrap();
+		assert_noop!(
+			Control::sponsor_seats(Origin::signed(ALICE), org_id, 0, named(vec![])),
+			Error::<Test>::NoSponsoredSeat);
+		assert_noop!(
+			Control::sponsor_seats(Origin::signed(ALICE), org_id, 1, named(vec![BOB, CHARLIE])),
+			Error::<Test>::TooManyNamedSeats);
+
+		// Prime adding a member pays the fee, the added account is not charged
+		let alice_balance = Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).free;
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, CHARLIE, None));
+		assert_eq!(Tokens::accounts(CHARLIE, PROTOCOL_TOKEN_ID).free, 2 * DOLLARS);
+		assert_eq!(Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).free, alice_balance - fee);
+		// Joining member pays the fee by itself
+		set_balance(102, PROTOCOL_TOKEN_ID, 2 * DOLLARS);
+		assert_ok!(Control::add_member(Origin::signed(102), org_id, 102, None));
+		assert_eq!(Tokens::accounts(102, PROTOCOL_TOKEN_ID).free, 2 * DOLLARS - fee);
+		assert_ok!(Control::remove_member(Origin::signed(ALICE), org_id, 102));
+		assert_noop!(
+			Control::add_member(Origin::signed(ALICE), org_id, 100, Some(CHARLIE)),
+			Error::<Test>::NoSponsoredSeat);
+
+		// Sponsor prepays 3 seats, one of them is named
+		assert_ok!(Control::sponsor_seats(Origin::signed(ALICE), org_id, 3, named(vec![BOB])));
+		assert_eq!(Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).reserved, 3 * fee);
+		System::assert_has_event(Event::Control(crate::Event::SeatsSponsored {
+			org_id, sponsor: ALICE, seats: 3, amount: 3 * fee, block_number: current_block
+		}));
+
+		// Unnamed seats are used by anyone, the named seat is kept for its joiner
+		let treasury_balance = Tokens::accounts(treasury_id, PROTOCOL_TOKEN_ID).free;
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, 100, Some(ALICE)));
+		assert_ok!(Control::add_member(Origin::signed(101), org_id, 101, Some(ALICE)));
+		System::assert_has_event(Event::Control(crate::Event::SponsoredSeatUsed {
+			org_id, sponsor: ALICE, who: 101, block_number: current_block
+		}));
+		assert_eq!(Tokens::accounts(treasury_id, PROTOCOL_TOKEN_ID).free, treasury_balance + 2 * fee);
+		assert_eq!(Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).reserved, fee);
+		assert_noop!(
+			Control::add_member(Origin::signed(102), org_id, 102, Some(ALICE)),
+			Error::<Test>::NoSponsoredSeat);
+		assert_ok!(Control::add_member(Origin::signed(BOB), org_id, BOB, Some(ALICE)));
+		assert_eq!(Tokens::accounts(BOB, PROTOCOL_TOKEN_ID).free, 2 * DOLLARS);
+		assert_eq!(Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).reserved, 0);
+		assert!(!SponsorPools::<Test>::contains_key(org_id, ALICE));
+
+		// Unused seats are refunded
+		assert_noop!(Control::refund_seats(Origin::signed(ALICE), org_id), Error::<Test>::NoSponsoredSeat);
+		assert_ok!(Control::sponsor_seats(Origin::signed(ALICE), org_id, 2, named(vec![])));
+		assert_ok!(Control::refund_seats(Origin::signed(ALICE), org_id));
+		assert_eq!(Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).reserved, 0);
+		System::assert_has_event(Event::Control(crate::Event::SeatsRefunded {
+			org_id, sponsor: ALICE, seats: 2, amount: 2 * fee, block_number: current_block
+		}));
+
+		// Seats are priced at the fee they were prepaid with
+		assert_ok!(Control::sponsor_seats(Origin::signed(ALICE), org_id, 2, named(vec![])));
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, None, None, None, None, Some(fee / 2)));
+		assert_noop!(
+			Control::sponsor_seats(Origin::signed(ALICE), org_id, 1, named(vec![])),
+			Error::<Test>::SeatPriceChanged);
+		let alice_balance = Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).free;
+		let treasury_balance = Tokens::accounts(treasury_id, PROTOCOL_TOKEN_ID).free;
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, 104, Some(ALICE)));
+		assert_eq!(Tokens::accounts(treasury_id, PROTOCOL_TOKEN_ID).free, treasury_balance + fee / 2);
+		assert_eq!(Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).free, alice_balance + fee / 2);
+		assert_eq!(Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).reserved, fee);
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, None, None, None, None, Some(2 * fee)));
+		assert_noop!(
+			Control::add_member(Origin::signed(ALICE), org_id, 105, Some(ALICE)),
+			Error::<Test>::SeatPriceChanged);
+		assert_ok!(Control::refund_seats(Origin::signed(ALICE), org_id));
+		assert_eq!(Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).reserved, 0);
+
+		// Reserved fee of the sponsored member stays on the sponsor
+		let org_id = create_org(AccessModel::Prime);
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, None, None, None, Some(FeeModel::Reserve), Some(fee)));
+		let treasury_id = OrgTreasury::<Test>::get(org_id).unwrap();
+		assert_ok!(Control::sponsor_seats(Origin::signed(ALICE), org_id, 1, named(vec![])));
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, 103, Some(ALICE)));
+		assert_eq!(MemberReservedFees::<Test>::get(org_id, 103), Some((ALICE, fee)));
+		assert_eq!(Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).reserved, fee);
+		let treasury_balance = Tokens::accounts(treasury_id, PROTOCOL_TOKEN_ID).free;
+		let reason: BoundedVec<u8, _> = BoundedVec::truncate_from(vec![1, 2]);
+		assert_ok!(Control::kick_member(Origin::signed(ALICE), org_id, 103, reason, true));
+		assert_eq!(Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).reserved, 0);
+		assert_eq!(Tokens::accounts(treasury_id, PROTOCOL_TOKEN_ID).free, treasury_balance + fee);
+		assert!(!MemberReservedFees::<Test>::contains_key(org_id, 103));
+
+		// Reserved fee of the pending member moved to a former member state is returned
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, 104, None));
+		assert_eq!(Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).reserved, fee);
+		assert_ok!(Control::update_member_state(Origin::signed(ALICE), org_id, 104, MemberState::Kicked));
+		assert_eq!(MemberStates::<Test>::get(org_id, 104), MemberState::Kicked);
+		assert_eq!(Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).reserved, 0);
+		assert!(!MemberReservedFees::<Test>::contains_key(org_id, 104));
+	})
+}
+
+#[test]
+fn control_migrate_to_v1_snapshot() {
+	snapshot_ext(new_test_ext(), include_str!("../fixtures/v0.snapshot")).execute_with(|| {
+		let org_id = H256::repeat_byte(0x11);
+		assert_eq!(Control::on_chain_storage_version(), 0);
+		#[cfg(feature = "try-runtime")]
+		let state = migrations::v1::MigrateToV1::<Test>::pre_upgrade().unwrap();
+
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(migrations::v1::MigrateToV1::<Test>::post_upgrade(state));
+		assert_eq!(Control::on_chain_storage_version(), 1);
+		assert!(!migrations::v1::Members::<Test>::contains_key(org_id));
+		assert_eq!(OrgMemberCount::<Test>::get(org_id), 3);
+		assert_eq!(MemberStates::<Test>::get(org_id, BOB), MemberState::Active);
+		let org = Orgs::<Test>::get(org_id).unwrap();
+		assert_eq!((org.prime, org.access_model, org.membership_fee), (ALICE, AccessModel::Open, Some(1000)));
 	})
 }
 
@@ -286,6 +685,14 @@ fn set_balance(account_id: AccountId, currency_id: CurrencyId, balance: Balance)
 	let _ = Tokens::set_balance(RawOrigin::Root.into(), account_id, currency_id, balance, 0);
 }
 
+fn run_to_block(block_number: u64) {
+	while System::block_number() < block_number {
+		let next_block = System::block_number() + 1;
+		System::set_block_number(next_block);
+		Control::on_initialize(next_block);
+	}
+}
+
 #[test]
 fn control_spend_funds() {
 	new_test_ext().execute_with(|| {
@@ -312,3 +719,1067 @@ fn control_spend_funds() {
 		);
 	})
 }
+
+#[test]
+fn control_dissolve_org() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let org_id = create_org(AccessModel::Open);
+		let treasury_id = OrgTreasury::<Test>::get(org_id).unwrap();
+		assert_ok!(Control::add_member(Origin::signed(BOB), org_id, BOB, None));
+		assert_ok!(Control::add_member(Origin::signed(CHARLIE), org_id, CHARLIE, None));
+		for who in 100..107 {
+			assert_ok!(Control::add_member(Origin::root(), org_id, who, None));
+		}
+		assert_ok!(Control::grant_role(Origin::root(), org_id, BOB, OrgRole::Admin));
+		set_balance(treasury_id, PAYMENT_TOKEN_ID, 100 * DOLLARS + 5);
+		set_balance(treasury_id, ASSET_TOKEN_ID, 10 * DOLLARS);
+		let alice_protocol = Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).free;
+		let alice_payment = Tokens::accounts(ALICE, PAYMENT_TOKEN_ID).free;
+
+		// Admin has no DissolveOrg permission by default
+		assert_noop!(Control::dissolve_org(Origin::signed(BOB), org_id), BadOrigin);
+
+		// Org is frozen, deposit is returned to the creator
+		assert_ok!(Control::dissolve_org(Origin::signed(ALICE), org_id));
+		assert_eq!(OrgStates::<Test>::get(org_id), OrgState::Dissolving);
+		assert!(!<Control as ControlTrait<AccountId, H256>>::is_org_active(&org_id));
+		assert_eq!(Tokens::accounts(ALICE, PROTOCOL_TOKEN_ID).free, alice_protocol + <Test as Config>::MinimumDeposit::get());
+		System::assert_has_event(Event::Control(crate::Event::OrgDissolutionStarted {
+			org_id, block_number: current_block
+		}));
+		assert_noop!(Control::dissolve_org(Origin::signed(ALICE), org_id), Error::<Test>::OrganizationDissolving);
+		assert_noop!(Control::add_member(Origin::root(), org_id, 200, None), Error::<Test>::OrganizationDissolving);
+		assert_noop!(
+			Control::spend_funds(Origin::signed(ALICE), org_id, PAYMENT_TOKEN_ID, BOB, 1 * DOLLARS),
+			Error::<Test>::OrganizationDissolving);
+		assert_noop!(Control::enable_org(Origin::root(), org_id), Error::<Test>::OrganizationDissolving);
+
+		// 10 members are counted and settled in batches of 5
+		Control::on_initialize(4);
+		assert_eq!(OrgDissolutions::<Test>::get(org_id).unwrap().phase, DissolutionPhase::Counting);
+		assert_eq!(DissolutionCursor::<Test>::get(), Some(org_id));
+		Control::on_initialize(5);
+		Control::on_initialize(6);
+		let dissolution = OrgDissolutions::<Test>::get(org_id).unwrap();
+		assert_eq!(dissolution.phase, DissolutionPhase::Settling);
+		assert_eq!(dissolution.active_members, 10);
+		// Runtime treasury currencies are shared besides the org currencies
+		assert_eq!(
+			dissolution.shares.into_inner(),
+			vec![(PAYMENT_TOKEN_ID, 10 * DOLLARS), (ASSET_TOKEN_ID, 1 * DOLLARS)]
+		);
+		Control::on_initialize(7);
+		assert_eq!(MemberStates::<Test>::iter_prefix(org_id).count(), 5);
+		assert!(Orgs::<Test>::contains_key(org_id));
+		Control::on_initialize(8);
+		Control::on_initialize(9);
+
+		// Shares are paid out, leftovers go to the creator, org is removed
+		assert_eq!(Tokens::accounts(BOB, PAYMENT_TOKEN_ID).free, 12 * DOLLARS);
+		assert_eq!(Tokens::accounts(100, PAYMENT_TOKEN_ID).free, 10 * DOLLARS);
+		assert_eq!(Tokens::accounts(ALICE, PAYMENT_TOKEN_ID).free, alice_payment + 10 * DOLLARS + 5);
+		assert_eq!(Tokens::accounts(treasury_id, PAYMENT_TOKEN_ID).free, 0);
+		assert_eq!(Tokens::accounts(100, ASSET_TOKEN_ID).free, 1 * DOLLARS);
+		assert_eq!(Tokens::accounts(treasury_id, ASSET_TOKEN_ID).free, 0);
+		assert!(!Orgs::<Test>::contains_key(org_id));
+		assert!(!OrgTreasury::<Test>::contains_key(org_id));
+		assert!(!OrgDissolutions::<Test>::contains_key(org_id));
+		assert_eq!(OrgMemberCount::<Test>::get(org_id), 0);
+		assert_eq!(MemberStates::<Test>::iter_prefix(org_id).count(), 0);
+		assert!(!MemberRoles::<Test>::contains_key(org_id, BOB));
+		System::assert_has_event(Event::Control(crate::Event::OrgDissolved {
+			org_id, block_number: current_block
+		}));
+
+		// Votings still open for the removed org are closed without the action
+		assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+			&org_id, OrgAction::AddMember(200), true));
+		assert!(!MemberStates::<Test>::contains_key(org_id, 200));
+	})
+}
+
+#[test]
+fn control_dissolve_orgs_in_turns() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		let first_id = create_org(AccessModel::Open);
+		for who in 100..110 {
+			assert_ok!(Control::add_member(Origin::root(), first_id, who, None));
+		}
+		assert_ok!(Control::create_org(
+			Origin::signed(ALICE), BoundedVec::truncate_from(vec![3, 4]), BoundedVec::truncate_from(vec![3, 4]),
+			OrgType::Individual, AccessModel::Open, FeeModel::NoFees, None, None, None, None, None, None, None));
+		let second_id = Orgs::<Test>::iter_keys().find(|org_id| *org_id != first_id).unwrap();
+		assert_ok!(Control::dissolve_org(Origin::root(), first_id));
+		assert_ok!(Control::dissolve_org(Origin::root(), second_id));
+
+		// Orgs take turns, so the large org doesn't hold back the small one
+		let mut block_number = 4;
+		while Orgs::<Test>::contains_key(second_id) {
+			assert!(block_number < 8);
+			Control::on_initialize(block_number);
+			block_number += 1;
+		}
+		assert!(Orgs::<Test>::contains_key(first_id));
+		while Orgs::<Test>::contains_key(first_id) {
+			Control::on_initialize(block_number);
+			block_number += 1;
+		}
+		assert_eq!(DissolutionCursor::<Test>::get(), Some(first_id));
+		Control::on_initialize(block_number);
+		assert_eq!(DissolutionCursor::<Test>::get(), None);
+	})
+}
+
+#[test]
+fn control_dissolve_dao_org() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let bounded_str = BoundedVec::truncate_from(vec![1,2]);
+		let fee = 1 * DOLLARS;
+		let index = OrgCount::<Test>::get();
+		let org = types::Org {
+			index, creator: ALICE, prime: ALICE, name: bounded_str.clone(), cid: bounded_str.clone(),
+			org_type: OrgType::Dao, fee_model: FeeModel::Transfer, membership_fee: Some(fee),
+			gov_currency: PROTOCOL_TOKEN_ID, pay_currency: PAYMENT_TOKEN_ID, access_model: AccessModel::Voting,
+			member_limit: <Test as Config>::MaxMembers::get(), created: current_block, mutated: current_block
+		};
+		let org_id = <Test as frame_system::Config>::Hashing::hash_of(&org);
+		assert_ok!(Control::create_org(
+			Origin::signed(ALICE), org.name, org.cid, org.org_type, org.access_model,
+			org.fee_model, None, org.membership_fee, None, None, None, None, None));
+		assert_ok!(Control::add_member(Origin::signed(BOB), org_id, BOB, None));
+		assert_eq!(Tokens::accounts(BOB, PROTOCOL_TOKEN_ID).reserved, fee);
+
+		// Signed call creates a voting, org stays active until it passes
+		assert_ok!(Control::dissolve_org(Origin::signed(ALICE), org_id));
+		assert_eq!(OrgStates::<Test>::get(org_id), OrgState::Active);
+		assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+			&org_id, OrgAction::Dissolve, false));
+		assert_eq!(OrgStates::<Test>::get(org_id), OrgState::Active);
+		assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+			&org_id, OrgAction::Dissolve, true));
+		assert_eq!(OrgStates::<Test>::get(org_id), OrgState::Dissolving);
+
+		// Pending application is settled by the dissolution
+		assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+			&org_id, OrgAction::AddMember(BOB), true));
+		assert_eq!(MemberStates::<Test>::get(org_id, BOB), MemberState::Pending);
+		Control::on_initialize(4);
+		Control::on_initialize(5);
+		assert!(!Orgs::<Test>::contains_key(org_id));
+		assert_eq!(Tokens::accounts(BOB, PROTOCOL_TOKEN_ID).reserved, 0);
+		assert_eq!(Tokens::accounts(BOB, PROTOCOL_TOKEN_ID).free, 2 * DOLLARS);
+	})
+}
+
+#[test]
+fn control_subscription() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let bounded_str = BoundedVec::truncate_from(vec![1,2]);
+		let fee = 1 * DOLLARS;
+		let index = OrgCount::<Test>::get();
+		let org = types::Org {
+			index, creator: ALICE, prime: ALICE, name: bounded_str.clone(), cid: bounded_str.clone(),
+			org_type: OrgType::Individual, fee_model: FeeModel::Subscription, membership_fee: Some(fee),
+			gov_currency: PROTOCOL_TOKEN_ID, pay_currency: PAYMENT_TOKEN_ID, access_model: AccessModel::Open,
+			member_limit: <Test as Config>::MaxMembers::get(), created: current_block, mutated: current_block
+		};
+		let org_id = <Test as frame_system::Config>::Hashing::hash_of(&org);
+		assert_ok!(Control::create_org(
+			Origin::signed(ALICE), org.name, org.cid, org.org_type, org.access_model,
+			org.fee_model, None, org.membership_fee, None, None, None, None, None));
+		let treasury_id = OrgTreasury::<Test>::get(org_id).unwrap();
+		assert_eq!(MemberSubscriptions::<Test>::get(org_id, ALICE), Some(13));
+		assert_noop!(Control::pay_subscription(Origin::signed(CHARLIE), org_id), Error::<Test>::NotMember);
+
+		// First period is paid at join, the next fees are collected every period (10 blocks)
+		assert_ok!(Control::add_member(Origin::signed(BOB), org_id, BOB, None));
+		assert_eq!(MemberSubscriptions::<Test>::get(org_id, BOB), Some(13));
+		assert_eq!(Tokens::accounts(BOB, PAYMENT_TOKEN_ID).free, 1 * DOLLARS);
+		System::assert_has_event(Event::Control(crate::Event::SubscriptionPaid {
+			org_id, who: BOB, amount: fee, paid_until: 13, block_number: current_block
+		}));
+		run_to_block(13);
+		assert_eq!(MemberSubscriptions::<Test>::get(org_id, BOB), Some(23));
+		assert_eq!(Tokens::accounts(BOB, PAYMENT_TOKEN_ID).free, 0);
+
+		// Unpaid member stays active during the grace period (5 blocks)
+		run_to_block(27);
+		assert_eq!(MemberSubscriptions::<Test>::get(org_id, BOB), Some(23));
+		assert!(<Control as ControlTrait<AccountId, H256>>::is_org_member_active(&org_id, &BOB));
+		System::set_block_number(28);
+		assert!(!<Control as ControlTrait<AccountId, H256>>::is_org_member_active(&org_id, &BOB));
+		Control::on_initialize(28);
+		assert_eq!(MemberStates::<Test>::get(org_id, BOB), MemberState::Inactive);
+		assert!(<Control as ControlTrait<AccountId, H256>>::is_org_member_active(&org_id, &ALICE));
+
+		// Paying again restores the membership
+		assert_noop!(Control::pay_subscription(Origin::signed(BOB), org_id), Error::<Test>::BalanceLow);
+		set_balance(BOB, PAYMENT_TOKEN_ID, 5 * DOLLARS);
+		assert_ok!(Control::pay_subscription(Origin::signed(BOB), org_id));
+		assert_eq!(MemberStates::<Test>::get(org_id, BOB), MemberState::Active);
+		assert_eq!(MemberSubscriptions::<Test>::get(org_id, BOB), Some(38));
+		assert!(<Control as ControlTrait<AccountId, H256>>::is_org_member_active(&org_id, &BOB));
+		assert_eq!(Tokens::accounts(treasury_id, PAYMENT_TOKEN_ID).free, 6 * fee);
+
+		// Subscription is not collected twice for the period paid in advance
+		run_to_block(38);
+		assert_eq!(MemberSubscriptions::<Test>::get(org_id, BOB), Some(48));
+		assert_eq!(Tokens::accounts(BOB, PAYMENT_TOKEN_ID).free, 3 * DOLLARS);
+
+		// Removed member is not charged anymore
+		assert_ok!(Control::remove_member(Origin::signed(BOB), org_id, BOB));
+		assert!(!MemberSubscriptions::<Test>::contains_key(org_id, BOB));
+		run_to_block(48);
+		assert_eq!(Tokens::accounts(BOB, PAYMENT_TOKEN_ID).free, 3 * DOLLARS);
+
+		// Org sets its own period, applied from the next payment
+		assert_noop!(Control::set_subscription_period(Origin::signed(BOB), org_id, 20), BadOrigin);
+		assert_noop!(
+			Control::set_subscription_period(Origin::signed(ALICE), org_id, 0),
+			Error::<Test>::InvalidSubscriptionPeriod
+		);
+		assert_ok!(Control::set_subscription_period(Origin::signed(ALICE), org_id, 20));
+		System::assert_last_event(Event::Control(crate::Event::SubscriptionPeriodSet {
+			org_id, period: 20, block_number: 48
+		}));
+		assert_eq!(MemberSubscriptions::<Test>::get(org_id, ALICE), Some(53));
+		run_to_block(53);
+		assert_eq!(MemberSubscriptions::<Test>::get(org_id, ALICE), Some(73));
+
+		// Joining member has to pay the first period
+		set_balance(CHARLIE, PAYMENT_TOKEN_ID, 0);
+		assert_noop!(Control::add_member(Origin::signed(CHARLIE), org_id, CHARLIE, None), Error::<Test>::BalanceLow);
+	})
+}
+
+#[test]
+fn control_subscription_switch() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let fee = 1 * DOLLARS;
+		let org_id = create_org(AccessModel::Open);
+		assert_ok!(Control::add_member(Origin::signed(BOB), org_id, BOB, None));
+		assert!(<Control as ControlTrait<AccountId, H256>>::is_org_member_active(&org_id, &BOB));
+
+		// Existing members have not paid a subscription yet
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, None, None, None,
+			Some(FeeModel::Subscription), Some(fee)));
+		assert!(!MemberSubscriptions::<Test>::contains_key(org_id, BOB));
+		assert!(!<Control as ControlTrait<AccountId, H256>>::is_org_member_active(&org_id, &BOB));
+		assert!(!<Control as ControlTrait<AccountId, H256>>::is_org_member_active(&org_id, &ALICE));
+
+		// Paying the first period activates the member
+		assert_ok!(Control::pay_subscription(Origin::signed(BOB), org_id));
+		assert_eq!(MemberSubscriptions::<Test>::get(org_id, BOB), Some(13));
+		assert!(<Control as ControlTrait<AccountId, H256>>::is_org_member_active(&org_id, &BOB));
+		assert_eq!(Tokens::accounts(BOB, PAYMENT_TOKEN_ID).free, 1 * DOLLARS);
+	})
+}
+
+#[test]
+fn control_sub_orgs() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let parent_id = create_org(AccessModel::Prime);
+		let org_id = create_org(AccessModel::Open);
+		let sub_org_id = create_org(AccessModel::Open);
+
+		// Sub-org is attached by its prime
+		assert_noop!(Control::add_sub_org(Origin::signed(BOB), parent_id, org_id, true), BadOrigin);
+		assert_noop!(Control::add_sub_org(Origin::signed(ALICE), org_id, org_id, true), Error::<Test>::WrongParent);
+		assert_ok!(Control::add_sub_org(Origin::signed(ALICE), parent_id, org_id, true));
+		System::assert_has_event(Event::Control(crate::Event::SubOrgAdded {
+			parent_id, org_id, parent_members_only: true, block_number: current_block
+		}));
+		assert_ok!(Control::add_sub_org(Origin::root(), org_id, sub_org_id, false));
+		assert_noop!(Control::add_sub_org(Origin::root(), sub_org_id, org_id, false), Error::<Test>::ParentExists);
+		assert_noop!(Control::add_sub_org(Origin::root(), sub_org_id, parent_id, false), Error::<Test>::OrgHasSubOrgs);
+
+		// Hierarchy queries
+		assert_eq!(<Control as ControlTrait<AccountId, H256>>::org_parent(&sub_org_id), Some(org_id));
+		assert_eq!(<Control as ControlTrait<AccountId, H256>>::org_children(&parent_id), vec![org_id]);
+		assert_eq!(<Control as ControlTrait<AccountId, H256>>::org_subtree(&parent_id), vec![org_id, sub_org_id]);
+		assert!(<Control as ControlTrait<AccountId, H256>>::is_org_ancestor(&parent_id, &sub_org_id));
+		assert!(!<Control as ControlTrait<AccountId, H256>>::is_org_ancestor(&sub_org_id, &parent_id));
+
+		// Only parent org members can join
+		assert_noop!(Control::add_member(Origin::signed(BOB), org_id, BOB, None), Error::<Test>::NotParentMember);
+		assert_ok!(Control::add_member(Origin::signed(ALICE), parent_id, BOB, None));
+		assert_ok!(Control::add_member(Origin::signed(BOB), org_id, BOB, None));
+		assert_ok!(Control::add_member(Origin::signed(CHARLIE), sub_org_id, CHARLIE, None));
+
+		// Parent org funds its sub-orgs on any level and claws the funds back
+		// Treasury ids derived from the pallet id are truncated to the same mock account
+		let parent_treasury_id = OrgTreasury::<Test>::get(parent_id).unwrap();
+		let treasury_id = 1000;
+		OrgTreasury::<Test>::insert(sub_org_id, treasury_id);
+		set_balance(parent_treasury_id, PAYMENT_TOKEN_ID, 10 * DOLLARS);
+		assert_noop!(
+			Control::fund_sub_org(Origin::signed(BOB), parent_id, sub_org_id, PAYMENT_TOKEN_ID, 4 * DOLLARS),
+			BadOrigin);
+		assert_noop!(
+			Control::fund_sub_org(Origin::signed(ALICE), sub_org_id, parent_id, PAYMENT_TOKEN_ID, 4 * DOLLARS),
+			Error::<Test>::NotSubOrg);
+		assert_ok!(Control::fund_sub_org(Origin::signed(ALICE), parent_id, sub_org_id, PAYMENT_TOKEN_ID, 4 * DOLLARS));
+		assert_eq!(Tokens::accounts(treasury_id, PAYMENT_TOKEN_ID).free, 4 * DOLLARS);
+		assert_ok!(Control::claw_back_funds(Origin::signed(ALICE), parent_id, sub_org_id, PAYMENT_TOKEN_ID, 3 * DOLLARS));
+		assert_eq!(Tokens::accounts(treasury_id, PAYMENT_TOKEN_ID).free, 1 * DOLLARS);
+		assert_eq!(Tokens::accounts(parent_treasury_id, PAYMENT_TOKEN_ID).free, 9 * DOLLARS);
+		System::assert_has_event(Event::Control(crate::Event::SubOrgFundsClawedBack {
+			parent_id, org_id: sub_org_id, currency_id: PAYMENT_TOKEN_ID, amount: 3 * DOLLARS, block_number: current_block
+		}));
+
+		// Only the funds given by the parent org can be clawed back
+		set_balance(treasury_id, PAYMENT_TOKEN_ID, 6 * DOLLARS);
+		assert_noop!(
+			Control::claw_back_funds(Origin::signed(ALICE), parent_id, sub_org_id, PAYMENT_TOKEN_ID, 2 * DOLLARS),
+			Error::<Test>::ClawBackLimitExceeded);
+		assert_ok!(Control::claw_back_funds(Origin::signed(ALICE), parent_id, sub_org_id, PAYMENT_TOKEN_ID, 1 * DOLLARS));
+		assert_eq!(SubOrgFunding::<Test>::get(sub_org_id, (parent_id, PAYMENT_TOKEN_ID)), 0);
+		assert_eq!(Tokens::accounts(treasury_id, PAYMENT_TOKEN_ID).free, 5 * DOLLARS);
+
+		// Org with sub-orgs can't be dissolved
+		assert_noop!(Control::dissolve_org(Origin::signed(ALICE), parent_id), Error::<Test>::OrgHasSubOrgs);
+
+		// Sub-org is detached by the parent org
+		assert_noop!(Control::remove_sub_org(Origin::signed(CHARLIE), sub_org_id), BadOrigin);
+		assert_ok!(Control::remove_sub_org(Origin::signed(ALICE), sub_org_id));
+		assert_eq!(<Control as ControlTrait<AccountId, H256>>::org_parent(&sub_org_id), None);
+		assert_noop!(Control::remove_sub_org(Origin::signed(ALICE), sub_org_id), Error::<Test>::NotSubOrg);
+
+		// Approved parent org voting attaches the sub-org
+		assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+			&parent_id, OrgAction::AddSubOrg { org_id: sub_org_id, parent_members_only: false }, true));
+		assert_eq!(<Control as ControlTrait<AccountId, H256>>::org_parent(&sub_org_id), Some(parent_id));
+		// Stale voting result is skipped: the sub-org is attached already
+		assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+			&org_id, OrgAction::AddSubOrg { org_id: sub_org_id, parent_members_only: false }, true));
+		assert_eq!(<Control as ControlTrait<AccountId, H256>>::org_parent(&sub_org_id), Some(parent_id));
+
+		// Org is created as a sub-org by the parent org prime
+		let bounded_str = BoundedVec::truncate_from(vec![5, 6]);
+		assert_noop!(Control::create_org(
+			Origin::signed(BOB), bounded_str.clone(), bounded_str.clone(), OrgType::Individual, AccessModel::Open,
+			FeeModel::NoFees, None, None, None, None, None, Some(parent_id), Some(true)),
+			BadOrigin);
+		assert_ok!(Control::create_org(
+			Origin::signed(ALICE), bounded_str.clone(), bounded_str, OrgType::Individual, AccessModel::Open,
+			FeeModel::NoFees, None, None, None, None, None, Some(parent_id), Some(true)));
+		let children = <Control as ControlTrait<AccountId, H256>>::org_children(&parent_id);
+		assert_eq!(children.len(), 3);
+		let new_org_id = *children.iter().find(|id| **id != org_id && **id != sub_org_id).unwrap();
+		assert!(OrgParentMembersOnly::<Test>::get(new_org_id));
+	})
+}
+
+#[test]
+fn control_redeem_invitation() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let org_id = create_org(AccessModel::Prime);
+		let fee = 1 * DOLLARS;
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, None, None, None, Some(FeeModel::Transfer), Some(fee)));
+		let treasury_id = OrgTreasury::<Test>::get(org_id).unwrap();
+		let invitation = types::Invitation {
+			org_id, invitee: Some(BOB), expires: 10, nonce: 0, fee_waived: false
+		};
+		let signature = TestSignature(ALICE, Control::invitation_payload(&invitation));
+
+		// Invitation is checked before the invitee joins
+		assert_noop!(
+			Control::redeem_invitation(Origin::signed(CHARLIE), invitation.clone(), signature.clone(), ALICE),
+			Error::<Test>::WrongInvitee);
+		assert_noop!(
+			Control::redeem_invitation(
+				Origin::signed(BOB), invitation.clone(), TestSignature(ALICE, invitation.encode()), ALICE),
+			Error::<Test>::InvalidSignature);
+		assert_noop!(
+			Control::redeem_invitation(
+				Origin::signed(BOB), invitation.clone(), TestSignature(BOB, Control::invitation_payload(&invitation)), ALICE),
+			Error::<Test>::InvalidSignature);
+		assert_noop!(
+			Control::redeem_invitation(
+				Origin::signed(BOB), invitation.clone(), TestSignature(BOB, Control::invitation_payload(&invitation)), BOB),
+			BadOrigin);
+
+		// Invitee becomes active and pays the membership fee
+		let treasury_balance = Tokens::accounts(treasury_id, PROTOCOL_TOKEN_ID).free;
+		assert_ok!(Control::redeem_invitation(Origin::signed(BOB), invitation.clone(), signature.clone(), ALICE));
+		assert_eq!(MemberStates::<Test>::get(org_id, BOB), MemberState::Active);
+		assert_eq!(Tokens::accounts(BOB, PROTOCOL_TOKEN_ID).free, 2 * DOLLARS - fee);
+		assert_eq!(Tokens::accounts(treasury_id, PROTOCOL_TOKEN_ID).free, treasury_balance + fee);
+		System::assert_has_event(Event::Control(crate::Event::InvitationRedeemed {
+			org_id, who: BOB, inviter: ALICE, nonce: 0, block_number: current_block
+		}));
+		assert_noop!(
+			Control::redeem_invitation(Origin::signed(BOB), invitation, signature, ALICE),
+			Error::<Test>::InvitationUsed);
+
+		// Recruiter invites anyone, fee is waived
+		let invitation = types::Invitation {
+			org_id, invitee: None, expires: 10, nonce: 0, fee_waived: true
+		};
+		let signature = TestSignature(BOB, Control::invitation_payload(&invitation));
+		assert_noop!(
+			Control::redeem_invitation(Origin::signed(CHARLIE), invitation.clone(), signature.clone(), BOB),
+			BadOrigin);
+		assert_ok!(Control::grant_role(Origin::signed(ALICE), org_id, BOB, OrgRole::Recruiter));
+		assert_ok!(Control::redeem_invitation(Origin::signed(CHARLIE), invitation, signature, BOB));
+		assert_eq!(MemberStates::<Test>::get(org_id, CHARLIE), MemberState::Active);
+		assert_eq!(Tokens::accounts(CHARLIE, PROTOCOL_TOKEN_ID).free, 2 * DOLLARS);
+
+		// Expired invitation
+		let invitation = types::Invitation {
+			org_id, invitee: None, expires: 10, nonce: 1, fee_waived: true
+		};
+		let signature = TestSignature(ALICE, Control::invitation_payload(&invitation));
+		System::set_block_number(11);
+		assert_noop!(
+			Control::redeem_invitation(Origin::signed(100), invitation, signature, ALICE),
+			Error::<Test>::InvitationExpired);
+
+		// Voting orgs accept members by the members voting only
+		let org_id = create_org(AccessModel::Voting);
+		let invitation = types::Invitation {
+			org_id, invitee: None, expires: 20, nonce: 0, fee_waived: true
+		};
+		let signature = TestSignature(ALICE, Control::invitation_payload(&invitation));
+		assert_noop!(
+			Control::redeem_invitation(Origin::signed(100), invitation, signature, ALICE),
+			Error::<Test>::InvitationsNotAllowed);
+	})
+}
+
+#[test]
+fn control_reserved_fee_records() {
+	new_test_ext().execute_with(|| {
+		let org_id = create_org(AccessModel::Prime);
+		let fee = 1 * DOLLARS;
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, None, None, None, Some(FeeModel::Reserve), Some(fee)));
+		let reason: BoundedVec<u8, _> = BoundedVec::truncate_from(vec![1, 2]);
+
+		// Member invited with the fee waived has nothing reserved by the org
+		let invitation = types::Invitation {
+			org_id, invitee: Some(BOB), expires: 10, nonce: 0, fee_waived: true
+		};
+		let signature = TestSignature(ALICE, Control::invitation_payload(&invitation));
+		assert_ok!(Control::redeem_invitation(Origin::signed(BOB), invitation, signature, ALICE));
+		assert!(!MemberReservedFees::<Test>::contains_key(org_id, BOB));
+		assert_ok!(Tokens::reserve(PROTOCOL_TOKEN_ID, &BOB, fee));
+		assert_ok!(Control::leave_org(Origin::signed(BOB), org_id, reason.clone()));
+		assert_eq!(Tokens::accounts(BOB, PROTOCOL_TOKEN_ID).reserved, fee);
+
+		// Fee reserved at join is released, not the current fee
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, CHARLIE, None));
+		assert_eq!(MemberReservedFees::<Test>::get(org_id, CHARLIE), Some((CHARLIE, fee)));
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, None, None, None, None, Some(2 * fee)));
+		assert_ok!(Control::kick_member(Origin::signed(ALICE), org_id, CHARLIE, reason, false));
+		assert_eq!(Tokens::accounts(CHARLIE, PROTOCOL_TOKEN_ID).reserved, 0);
+		assert_eq!(Tokens::accounts(CHARLIE, PROTOCOL_TOKEN_ID).free, 2 * DOLLARS);
+		assert!(!MemberReservedFees::<Test>::contains_key(org_id, CHARLIE));
+	})
+}
+
+#[test]
+fn control_kick_ban_leave() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let org_id = create_org(AccessModel::Voting);
+		let fee = 1 * DOLLARS;
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, None, None, None, Some(FeeModel::Reserve), Some(fee)));
+		let treasury_id = OrgTreasury::<Test>::get(org_id).unwrap();
+		let reason: BoundedVec<u8, _> = BoundedVec::truncate_from(vec![1, 2]);
+		for who in vec![BOB, CHARLIE] {
+			assert_ok!(Control::add_member(Origin::signed(who), org_id, who, None));
+			assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+				&org_id, OrgAction::AddMember(who), true));
+			assert_eq!(Tokens::accounts(who, PROTOCOL_TOKEN_ID).reserved, fee);
+		}
+		let members_count = OrgMemberCount::<Test>::get(org_id);
+
+		// Kicked member's fee is slashed to the treasury
+		let treasury_balance = Tokens::accounts(treasury_id, PROTOCOL_TOKEN_ID).free;
+		assert_noop!(Control::kick_member(Origin::signed(CHARLIE), org_id, BOB, reason.clone(), true), BadOrigin);
+		assert_ok!(Control::kick_member(Origin::signed(ALICE), org_id, BOB, reason.clone(), true));
+		assert_eq!(MemberStates::<Test>::get(org_id, BOB), MemberState::Kicked);
+		assert_eq!(OrgMemberCount::<Test>::get(org_id), members_count - 1);
+		assert_eq!(Tokens::accounts(BOB, PROTOCOL_TOKEN_ID).reserved, 0);
+		assert_eq!(Tokens::accounts(BOB, PROTOCOL_TOKEN_ID).free, 2 * DOLLARS - fee);
+		assert_eq!(Tokens::accounts(treasury_id, PROTOCOL_TOKEN_ID).free, treasury_balance + fee);
+		System::assert_has_event(Event::Control(crate::Event::MemberKicked {
+			org_id, who: BOB, reason: reason.clone(), fee_slashed: true, block_number: current_block
+		}));
+		assert_noop!(
+			Control::kick_member(Origin::signed(ALICE), org_id, BOB, reason.clone(), true),
+			Error::<Test>::NotMember);
+
+		// Member leaves voluntarily, fee is unreserved
+		assert_ok!(Control::leave_org(Origin::signed(CHARLIE), org_id, reason.clone()));
+		assert_eq!(MemberStates::<Test>::get(org_id, CHARLIE), MemberState::Exited);
+		assert_eq!(OrgMemberCount::<Test>::get(org_id), members_count - 2);
+		assert_eq!(Tokens::accounts(CHARLIE, PROTOCOL_TOKEN_ID).reserved, 0);
+		assert_eq!(Tokens::accounts(CHARLIE, PROTOCOL_TOKEN_ID).free, 2 * DOLLARS);
+		System::assert_has_event(Event::Control(crate::Event::MemberLeft {
+			org_id, who: CHARLIE, reason: reason.clone(), block_number: current_block
+		}));
+		assert_noop!(Control::leave_org(Origin::signed(CHARLIE), org_id, reason.clone()), Error::<Test>::NotMember);
+
+		// Banned accounts can't join until the ban is lifted by the org
+		assert_ok!(Control::ban_member(Origin::signed(ALICE), org_id, CHARLIE, reason.clone(), false));
+		assert_ok!(Control::ban_member(Origin::signed(ALICE), org_id, 100, reason.clone(), false));
+		assert_eq!(OrgMemberCount::<Test>::get(org_id), members_count - 2);
+		System::assert_has_event(Event::Control(crate::Event::MemberBanned {
+			org_id, who: 100, reason: reason.clone(), fee_slashed: false, block_number: current_block
+		}));
+		assert_noop!(
+			Control::ban_member(Origin::signed(ALICE), org_id, 100, reason.clone(), false),
+			Error::<Test>::MemberBanned);
+		assert_noop!(Control::add_member(Origin::signed(CHARLIE), org_id, CHARLIE, None), Error::<Test>::MemberBanned);
+		assert_noop!(Control::add_member(Origin::root(), org_id, 100, None), Error::<Test>::MemberBanned);
+		assert_noop!(Control::remove_member(Origin::signed(CHARLIE), org_id, CHARLIE), BadOrigin);
+		assert_ok!(Control::remove_member(Origin::root(), org_id, CHARLIE));
+		assert_eq!(OrgMemberCount::<Test>::get(org_id), members_count - 2);
+		assert_ok!(Control::add_member(Origin::signed(CHARLIE), org_id, CHARLIE, None));
+
+		// Kicked member can apply again
+		assert_ok!(Control::add_member(Origin::signed(BOB), org_id, BOB, None));
+		assert_eq!(MemberStates::<Test>::get(org_id, BOB), MemberState::Pending);
+		assert_eq!(OrgMemberCount::<Test>::get(org_id), members_count);
+
+		// Prime is handed over before leaving, members are expelled by the same or higher roles only
+		for who in vec![BOB, CHARLIE] {
+			assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+				&org_id, OrgAction::AddMember(who), true));
+		}
+		assert_ok!(Control::grant_role(Origin::signed(ALICE), org_id, BOB, OrgRole::Admin));
+		assert_ok!(Control::grant_role(Origin::signed(ALICE), org_id, CHARLIE, OrgRole::Moderator));
+		assert_noop!(
+			Control::kick_member(Origin::signed(CHARLIE), org_id, ALICE, reason.clone(), true),
+			Error::<Test>::PrimeNotRemovable);
+		assert_noop!(
+			Control::ban_member(Origin::root(), org_id, ALICE, reason.clone(), true),
+			Error::<Test>::PrimeNotRemovable);
+		assert_noop!(Control::remove_member(Origin::root(), org_id, ALICE), Error::<Test>::PrimeNotRemovable);
+		assert_noop!(Control::leave_org(Origin::signed(ALICE), org_id, reason.clone()), Error::<Test>::PrimeNotRemovable);
+		assert_noop!(
+			Control::kick_member(Origin::signed(CHARLIE), org_id, BOB, reason.clone(), true),
+			Error::<Test>::RoleOutranked);
+		assert_noop!(
+			Control::ban_member(Origin::signed(CHARLIE), org_id, BOB, reason.clone(), true),
+			Error::<Test>::RoleOutranked);
+		assert_ok!(Control::kick_member(Origin::signed(BOB), org_id, CHARLIE, reason.clone(), false));
+		assert_eq!(MemberStates::<Test>::get(org_id, CHARLIE), MemberState::Kicked);
+	})
+}
+
+#[test]
+fn control_treasury_streams() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let org_id = create_org(AccessModel::Prime);
+		let treasury_id = OrgTreasury::<Test>::get(org_id).unwrap();
+		set_balance(treasury_id, PAYMENT_TOKEN_ID, 100 * DOLLARS);
+
+		assert_noop!(
+			Control::create_stream(Origin::signed(BOB), org_id, BOB, PAYMENT_TOKEN_ID, 10 * DOLLARS, 10, 5, Some(45)),
+			BadOrigin);
+		assert_noop!(
+			Control::create_stream(Origin::signed(ALICE), org_id, BOB, PAYMENT_TOKEN_ID, 0, 10, 5, Some(45)),
+			Error::<Test>::InvalidStream);
+		assert_noop!(
+			Control::create_stream(Origin::signed(ALICE), org_id, BOB, PAYMENT_TOKEN_ID, 10 * DOLLARS, 10, 2, Some(45)),
+			Error::<Test>::InvalidStream);
+		assert_noop!(
+			Control::create_stream(Origin::signed(ALICE), org_id, BOB, PAYMENT_TOKEN_ID, 10 * DOLLARS, 10, 5, Some(14)),
+			Error::<Test>::InvalidStream);
+
+		// Stream with the end commits the whole amount, 10 per 10 blocks for 4 periods
+		assert_ok!(
+			Control::create_stream(Origin::signed(ALICE), org_id, BOB, PAYMENT_TOKEN_ID, 10 * DOLLARS, 10, 5, Some(45)));
+		System::assert_has_event(Event::Control(crate::Event::StreamCreated {
+			org_id, stream_id: 0, beneficiary: BOB, currency_id: PAYMENT_TOKEN_ID, amount: 10 * DOLLARS,
+			period: 10, start: 5, end: Some(45), block_number: current_block
+		}));
+		assert_noop!(
+			Control::create_stream(Origin::signed(ALICE), org_id, BOB, PAYMENT_TOKEN_ID, 10 * DOLLARS, 1, 5, Some(15)),
+			Error::<Test>::FundsCommitted);
+		assert_ok!(
+			Control::create_stream(Origin::signed(ALICE), org_id, CHARLIE, PAYMENT_TOKEN_ID, 1 * DOLLARS, 1, 5, None));
+
+		// Committed funds can't be spent
+		assert_noop!(
+			Control::spend_funds(Origin::signed(ALICE), org_id, PAYMENT_TOKEN_ID, ALICE, 61 * DOLLARS),
+			Error::<Test>::FundsCommitted);
+		assert_ok!(Control::spend_funds(Origin::signed(ALICE), org_id, PAYMENT_TOKEN_ID, ALICE, 50 * DOLLARS));
+
+		// Beneficiary claims the accrued periods
+		System::set_block_number(15);
+		assert_noop!(Control::claim_stream(Origin::signed(CHARLIE), org_id, 0), BadOrigin);
+		assert_ok!(Control::claim_stream(Origin::signed(BOB), org_id, 0));
+		assert_eq!(Tokens::accounts(BOB, PAYMENT_TOKEN_ID).free, 12 * DOLLARS);
+		System::assert_has_event(Event::Control(crate::Event::StreamClaimed {
+			org_id, stream_id: 0, beneficiary: BOB, amount: 10 * DOLLARS, block_number: 15
+		}));
+		assert_noop!(Control::claim_stream(Origin::signed(BOB), org_id, 0), Error::<Test>::NothingAccrued);
+
+		// Accrued amount of the endless stream is committed
+		assert_noop!(
+			Control::spend_funds(Origin::signed(ALICE), org_id, PAYMENT_TOKEN_ID, ALICE, 1 * DOLLARS),
+			Error::<Test>::FundsCommitted);
+
+		// Cancelling pays out the accrued amount
+		assert_noop!(Control::cancel_stream(Origin::signed(BOB), org_id, 1), BadOrigin);
+		assert_ok!(Control::cancel_stream(Origin::signed(ALICE), org_id, 1));
+		assert_eq!(Tokens::accounts(CHARLIE, PAYMENT_TOKEN_ID).free, 12 * DOLLARS);
+		assert!(!Streams::<Test>::contains_key(org_id, 1));
+		System::assert_has_event(Event::Control(crate::Event::StreamCancelled {
+			org_id, stream_id: 1, amount: 10 * DOLLARS, block_number: 15
+		}));
+		assert_noop!(Control::claim_stream(Origin::signed(CHARLIE), org_id, 1), Error::<Test>::StreamUnknown);
+
+		// Max streams limit
+		for _ in 0..2 {
+			assert_ok!(
+				Control::create_stream(Origin::signed(ALICE), org_id, CHARLIE, PROTOCOL_TOKEN_ID, 1 * DOLLARS, 1, 15, None));
+		}
+		assert_noop!(
+			Control::create_stream(Origin::signed(ALICE), org_id, CHARLIE, PROTOCOL_TOKEN_ID, 1 * DOLLARS, 1, 15, None),
+			Error::<Test>::TooManyStreams);
+
+		// Finished stream is removed
+		System::set_block_number(50);
+		assert_ok!(Control::claim_stream(Origin::signed(BOB), org_id, 0));
+		assert_eq!(Tokens::accounts(BOB, PAYMENT_TOKEN_ID).free, 42 * DOLLARS);
+		assert!(!Streams::<Test>::contains_key(org_id, 0));
+		assert_eq!(Tokens::accounts(treasury_id, PAYMENT_TOKEN_ID).free, 0);
+	})
+}
+
+#[test]
+fn control_company_org() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let org_id = create_org(AccessModel::Prime);
+		assert_noop!(Control::set_min_reputation(Origin::signed(ALICE), org_id, 10), Error::<Test>::WrongOrganizationType);
+
+		// Prime without identity can't switch the org to Company
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, CHARLIE, None));
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, Some(CHARLIE), None, None, None, None, None));
+		assert_ok!(Control::accept_prime_handover(Origin::signed(CHARLIE), org_id));
+		assert_noop!(Control::update_org(
+			Origin::signed(CHARLIE), org_id, None, None, None, Some(OrgType::Company), None, None, None, None),
+			Error::<Test>::NoIdentity);
+		// Existing members without identity prevent the switch as well
+		assert_noop!(Control::update_org(
+			Origin::root(), org_id, None, None, Some(ALICE), Some(OrgType::Company), None, None, None, None),
+			Error::<Test>::NoIdentity);
+		assert_ok!(Control::update_org(
+			Origin::root(), org_id, None, None, Some(ALICE), None, None, None, None, None));
+		assert_ok!(Control::remove_member(Origin::signed(ALICE), org_id, CHARLIE));
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, Some(OrgType::Company), None, None, None, None));
+
+		// Members require identity and min reputation
+		assert_noop!(Control::add_member(Origin::signed(ALICE), org_id, CHARLIE, None), Error::<Test>::NoIdentity);
+		assert_noop!(Control::set_min_reputation(Origin::signed(BOB), org_id, 50), BadOrigin);
+		assert_ok!(Control::set_min_reputation(Origin::signed(ALICE), org_id, 50));
+		System::assert_has_event(Event::Control(crate::Event::MinReputationSet {
+			org_id, min_reputation: 50, block_number: current_block
+		}));
+		assert_noop!(Control::add_member(Origin::signed(ALICE), org_id, BOB, None), Error::<Test>::ReputationTooLow);
+		assert_ok!(Control::set_min_reputation(Origin::signed(ALICE), org_id, 10));
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, BOB, None));
+
+		// Min reputation is removed with the switch to another type
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, Some(OrgType::Individual), None, None, None, None));
+		assert!(!OrgMinReputation::<Test>::contains_key(org_id));
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, CHARLIE, None));
+	})
+}
+
+#[test]
+fn control_hybrid_org() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let org_id = create_org(AccessModel::Prime);
+		let treasury_id = OrgTreasury::<Test>::get(org_id).unwrap();
+		set_balance(treasury_id, PAYMENT_TOKEN_ID, 100 * DOLLARS);
+		assert_noop!(
+			Control::set_spend_threshold(Origin::signed(ALICE), org_id, PAYMENT_TOKEN_ID, Some(10 * DOLLARS)),
+			Error::<Test>::WrongOrganizationType);
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, Some(OrgType::Hybrid), None, None, None, None));
+
+		// Any spend is submitted to the members voting until the threshold is set
+		assert_ok!(Control::spend_funds(Origin::signed(ALICE), org_id, PAYMENT_TOKEN_ID, BOB, 1 * DOLLARS));
+		System::assert_has_event(Event::Control(crate::Event::SpendingProposed {
+			org_id, proposer: ALICE, beneficiary: BOB, currency_id: PAYMENT_TOKEN_ID, amount: 1 * DOLLARS,
+			proposal_id: H256::default(), block_number: current_block
+		}));
+		assert_eq!(Tokens::accounts(treasury_id, PAYMENT_TOKEN_ID).free, 100 * DOLLARS);
+		assert_ok!(Control::set_spend_threshold(Origin::signed(ALICE), org_id, PAYMENT_TOKEN_ID, Some(10 * DOLLARS)));
+		System::assert_has_event(Event::Control(crate::Event::SpendThresholdSet {
+			org_id, currency_id: PAYMENT_TOKEN_ID, threshold: Some(10 * DOLLARS), block_number: current_block
+		}));
+		assert_ok!(Control::spend_funds(Origin::signed(ALICE), org_id, PAYMENT_TOKEN_ID, BOB, 10 * DOLLARS));
+		assert_ok!(Control::spend_funds(Origin::signed(ALICE), org_id, PAYMENT_TOKEN_ID, BOB, 11 * DOLLARS));
+		assert_eq!(Tokens::accounts(treasury_id, PAYMENT_TOKEN_ID).free, 90 * DOLLARS);
+		assert_ok!(Control::spend_funds(Origin::root(), org_id, PAYMENT_TOKEN_ID, BOB, 11 * DOLLARS));
+		assert_eq!(Tokens::accounts(treasury_id, PAYMENT_TOKEN_ID).free, 79 * DOLLARS);
+
+		// Streams are checked against the threshold with their whole amount, endless ones need a voting
+		assert_noop!(
+			Control::create_stream(Origin::signed(ALICE), org_id, BOB, PAYMENT_TOKEN_ID, 1 * DOLLARS, 10, 5, None),
+			Error::<Test>::SpendingVoteRequired);
+		assert_noop!(
+			Control::create_stream(Origin::signed(ALICE), org_id, BOB, PAYMENT_TOKEN_ID, 5 * DOLLARS, 10, 5, Some(35)),
+			Error::<Test>::SpendingVoteRequired);
+		assert_ok!(
+			Control::create_stream(Origin::signed(ALICE), org_id, BOB, PAYMENT_TOKEN_ID, 5 * DOLLARS, 10, 5, Some(25)));
+
+		// Prime can only lower the threshold
+		assert_noop!(
+			Control::set_spend_threshold(Origin::signed(ALICE), org_id, PAYMENT_TOKEN_ID, Some(20 * DOLLARS)),
+			BadOrigin);
+		assert_ok!(Control::set_spend_threshold(Origin::signed(ALICE), org_id, PAYMENT_TOKEN_ID, Some(5 * DOLLARS)));
+		assert_ok!(Control::set_spend_threshold(Origin::root(), org_id, PAYMENT_TOKEN_ID, Some(20 * DOLLARS)));
+		assert_eq!(OrgSpendThresholds::<Test>::get(org_id).into_inner(), vec![(PAYMENT_TOKEN_ID, 20 * DOLLARS)]);
+
+		// Dissolution is decided by the members voting
+		assert_ok!(Control::dissolve_org(Origin::signed(ALICE), org_id));
+		assert_eq!(OrgStates::<Test>::get(org_id), OrgState::Active);
+
+		// Thresholds are removed with the switch to another type
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, Some(OrgType::Individual), None, None, None, None));
+		assert!(!OrgSpendThresholds::<Test>::contains_key(org_id));
+		assert_ok!(Control::spend_funds(Origin::signed(ALICE), org_id, PAYMENT_TOKEN_ID, BOB, 30 * DOLLARS));
+	})
+}
+
+#[test]
+fn control_dao_org_prime() {
+	new_test_ext().execute_with(|| {
+		let org_id = create_org(AccessModel::Prime);
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, BOB, None));
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, Some(OrgType::Dao), None, None, None, None));
+
+		// Dao prime keeps managing the org, the members can recall it by voting
+		assert_noop!(Control::update_org(
+			Origin::signed(BOB), org_id, None, None, None, Some(OrgType::Individual), None, None, None, None),
+			BadOrigin);
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, None, None, Some(100), None, None));
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, Some(OrgType::Individual), None, None, None, None));
+		assert_eq!(Orgs::<Test>::get(org_id).unwrap().org_type, OrgType::Individual);
+		assert_ok!(Control::update_org(
+			Origin::root(), org_id, None, None, Some(BOB), Some(OrgType::Dao), None, None, None, None));
+		assert_eq!(Orgs::<Test>::get(org_id).unwrap().prime, BOB);
+	})
+}
+
+#[test]
+fn control_prime_handover() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let org_id = create_org(AccessModel::Prime);
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, BOB, None));
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, CHARLIE, None));
+		assert_noop!(Control::accept_prime_handover(Origin::signed(BOB), org_id), Error::<Test>::NoPrimeHandover);
+		assert_noop!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, Some(ALICE), None, None, None, None, None),
+			Error::<Test>::AlreadyPrime);
+
+		// Handover is proposed by the prime and accepted by the proposed member only
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, Some(BOB), None, None, None, None, None));
+		System::assert_has_event(Event::Control(crate::Event::PrimeHandoverProposed {
+			org_id, prime: ALICE, new_prime: BOB, expires: current_block + 10, block_number: current_block
+		}));
+		assert_eq!(Orgs::<Test>::get(org_id).unwrap().prime, ALICE);
+		assert_noop!(Control::accept_prime_handover(Origin::signed(CHARLIE), org_id), BadOrigin);
+
+		// Proposed member can reject the handover
+		assert_noop!(Control::cancel_prime_handover(Origin::signed(CHARLIE), org_id), BadOrigin);
+		assert_ok!(Control::cancel_prime_handover(Origin::signed(BOB), org_id));
+		System::assert_has_event(Event::Control(crate::Event::PrimeHandoverCancelled {
+			org_id, new_prime: BOB, block_number: current_block
+		}));
+		assert_noop!(Control::accept_prime_handover(Origin::signed(BOB), org_id), Error::<Test>::NoPrimeHandover);
+
+		// Handover expires
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, Some(BOB), None, None, None, None, None));
+		System::set_block_number(current_block + 11);
+		assert_noop!(
+			Control::accept_prime_handover(Origin::signed(BOB), org_id),
+			Error::<Test>::PrimeHandoverExpired);
+
+		// New proposal replaces the expired one
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, Some(CHARLIE), None, None, None, None, None));
+		assert_noop!(Control::accept_prime_handover(Origin::signed(BOB), org_id), BadOrigin);
+		assert_ok!(Control::accept_prime_handover(Origin::signed(CHARLIE), org_id));
+		System::assert_has_event(Event::Control(crate::Event::PrimeChanged {
+			org_id, prime: ALICE, new_prime: CHARLIE, block_number: current_block + 11
+		}));
+		assert_eq!(Orgs::<Test>::get(org_id).unwrap().prime, CHARLIE);
+		assert!(!PrimeHandovers::<Test>::contains_key(org_id));
+
+		// Root sets the prime at once
+		assert_ok!(Control::update_org(Origin::root(), org_id, None, None, Some(ALICE), None, None, None, None, None));
+		assert_eq!(Orgs::<Test>::get(org_id).unwrap().prime, ALICE);
+	})	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let org_id = create_org(AccessModel::Prime);
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, BOB, None));
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, CHARLIE, None));
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, Some(OrgType::Dao), None, None, None, None));
+
+		// Dao prime hands the org over the same way
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, Some(BOB), None, None, None, None, None));
+		assert_eq!(Orgs::<Test>::get(org_id).unwrap().prime, ALICE);
+		assert_ok!(Control::accept_prime_handover(Origin::signed(BOB), org_id));
+		assert_eq!(Orgs::<Test>::get(org_id).unwrap().prime, BOB);
+		System::assert_has_event(Event::Control(crate::Event::PrimeChanged {
+			org_id, prime: ALICE, new_prime: BOB, block_number: current_block
+		}));
+
+		// Prime recalled by the members voting drops the pending handover
+		assert_ok!(Control::update_org(
+			Origin::signed(BOB), org_id, None, None, Some(CHARLIE), None, None, None, None, None));
+		assert_ok!(Control::recall_prime(Origin::signed(ALICE), org_id, ALICE));
+		assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+			&org_id, OrgAction::ReplacePrime(ALICE), true));
+		assert_eq!(Orgs::<Test>::get(org_id).unwrap().prime, ALICE);
+		assert!(!PrimeHandovers::<Test>::contains_key(org_id));
+		assert_noop!(Control::accept_prime_handover(Origin::signed(CHARLIE), org_id), Error::<Test>::NoPrimeHandover);
+	})
+}
+
+#[test]
+fn control_recall_prime() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let org_id = create_org(AccessModel::Prime);
+		assert_ok!(Control::add_member(Origin::signed(ALICE), org_id, BOB, None));
+		assert_noop!(Control::recall_prime(Origin::signed(BOB), org_id, BOB), Error::<Test>::WrongOrganizationType);
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, Some(OrgType::Dao), None, None, None, None));
+
+		assert_noop!(Control::recall_prime(Origin::signed(CHARLIE), org_id, BOB), BadOrigin);
+		assert_noop!(Control::recall_prime(Origin::signed(BOB), org_id, CHARLIE), Error::<Test>::NotMember);
+		assert_noop!(Control::recall_prime(Origin::signed(BOB), org_id, ALICE), Error::<Test>::AlreadyPrime);
+		assert_ok!(Control::recall_prime(Origin::signed(BOB), org_id, BOB));
+		System::assert_has_event(Event::Control(crate::Event::PrimeRecallProposed {
+			org_id, proposer: BOB, new_prime: BOB, proposal_id: H256::default(), block_number: current_block
+		}));
+
+		// Prime is replaced once the voting passes
+		assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+			&org_id, OrgAction::ReplacePrime(BOB), false));
+		assert_eq!(Orgs::<Test>::get(org_id).unwrap().prime, ALICE);
+		assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+			&org_id, OrgAction::ReplacePrime(BOB), true));
+		assert_eq!(Orgs::<Test>::get(org_id).unwrap().prime, BOB);
+		System::assert_has_event(Event::Control(crate::Event::PrimeChanged {
+			org_id, prime: ALICE, new_prime: BOB, block_number: current_block
+		}));
+
+		// Voting result is skipped if the new prime is not an active member anymore
+		assert_ok!(<Control as ControlTrait<AccountId, H256>>::apply_org_action(
+			&org_id, OrgAction::ReplacePrime(CHARLIE), true));
+		assert_eq!(Orgs::<Test>::get(org_id).unwrap().prime, BOB);
+	})
+}
+
+fn vault_rules(roles: Vec<OrgRole>, limits: Vec<(CurrencyId, Balance)>, period: BlockNumber) -> types::VaultRules<Balance, CurrencyId, BlockNumber> {
+	types::VaultRules { roles: BoundedVec::truncate_from(roles), limits: BoundedVec::truncate_from(limits), period }
+}
+
+/// Creates the org vault with a distinct account, mock sub-accounts are truncated to the same id.
+fn create_vault(org_id: H256, name: &[u8], rules: types::VaultRules<Balance, CurrencyId, BlockNumber>, account: AccountId) -> u32 {
+	assert_ok!(Control::create_vault(Origin::root(), org_id, BoundedVec::truncate_from(name.to_vec()), rules));
+	let vault_id = OrgVaultCount::<Test>::get(org_id);
+	OrgVaults::<Test>::mutate(org_id, vault_id, |vault| vault.as_mut().unwrap().account = account);
+	vault_id
+}
+
+#[test]
+fn control_vaults() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let org_id = create_org(AccessModel::Prime);
+		assert_ok!(Control::add_member(Origin::root(), org_id, BOB, None));
+		assert_ok!(Control::add_member(Origin::root(), org_id, CHARLIE, None));
+		assert_ok!(Control::grant_role(Origin::root(), org_id, BOB, OrgRole::Treasurer));
+		let name: BoundedVec<u8, _> = BoundedVec::truncate_from(b"prize-pool".to_vec());
+		let rules = vault_rules(vec![OrgRole::Treasurer], vec![(PAYMENT_TOKEN_ID, 10 * DOLLARS)], 10);
+
+		// Vaults are created by the prime, limits require the period
+		assert_noop!(Control::create_vault(Origin::signed(BOB), org_id, name.clone(), rules.clone()), BadOrigin);
+		assert_noop!(
+			Control::create_vault(Origin::signed(ALICE), org_id, name.clone(), vault_rules(vec![], vec![(PAYMENT_TOKEN_ID, 1)], 0)),
+			Error::<Test>::InvalidVaultRules);
+		assert_noop!(
+			Control::create_vault(Origin::signed(ALICE), org_id, name.clone(),
+				vault_rules(vec![], vec![(PAYMENT_TOKEN_ID, 1), (PAYMENT_TOKEN_ID, 2)], 10)),
+			Error::<Test>::InvalidVaultRules);
+		assert_ok!(Control::create_vault(Origin::signed(ALICE), org_id, name.clone(), rules.clone()));
+		let account = OrgVaults::<Test>::get(org_id, 1).unwrap().account;
+		System::assert_has_event(Event::Control(crate::Event::VaultCreated {
+			org_id, vault_id: 1, name: name.clone(), account, block_number: current_block
+		}));
+		assert_noop!(Control::create_vault(Origin::signed(ALICE), org_id, name, rules), Error::<Test>::VaultExists);
+		assert_eq!(<Control as ControlTrait<AccountId, H256>>::org_vault_account(&org_id, Some(1)), Some(account));
+		assert_eq!(<Control as ControlTrait<AccountId, H256>>::org_vault_account(&org_id, None), OrgTreasury::<Test>::get(org_id));
+		assert_eq!(<Control as ControlTrait<AccountId, H256>>::org_vault_account(&org_id, Some(2)), None);
+
+		let vault_account = 200;
+		let beneficiary = 201;
+		OrgVaults::<Test>::mutate(org_id, 1, |vault| vault.as_mut().unwrap().account = vault_account);
+		set_balance(vault_account, PAYMENT_TOKEN_ID, 100 * DOLLARS);
+		set_balance(vault_account, PROTOCOL_TOKEN_ID, 100 * DOLLARS);
+
+		// Members without the vault role can't spend
+		assert_noop!(
+			Control::spend_from_vault(Origin::signed(CHARLIE), org_id, 1, PAYMENT_TOKEN_ID, beneficiary, 1 * DOLLARS),
+			BadOrigin);
+		assert_ok!(Control::spend_from_vault(Origin::signed(BOB), org_id, 1, PAYMENT_TOKEN_ID, beneficiary, 6 * DOLLARS));
+		System::assert_has_event(Event::Control(crate::Event::VaultFundsSpent {
+			org_id, vault_id: 1, beneficiary, currency_id: PAYMENT_TOKEN_ID, amount: 6 * DOLLARS, block_number: current_block
+		}));
+
+		// Prime spendings count against the same limit, Root is not limited
+		assert_noop!(
+			Control::spend_from_vault(Origin::signed(ALICE), org_id, 1, PAYMENT_TOKEN_ID, beneficiary, 5 * DOLLARS),
+			Error::<Test>::VaultLimitExceeded);
+		assert_ok!(Control::spend_from_vault(Origin::signed(ALICE), org_id, 1, PAYMENT_TOKEN_ID, beneficiary, 4 * DOLLARS));
+		assert_ok!(Control::spend_from_vault(Origin::root(), org_id, 1, PAYMENT_TOKEN_ID, beneficiary, 20 * DOLLARS));
+		assert_noop!(
+			Control::spend_from_vault(Origin::signed(BOB), org_id, 1, PAYMENT_TOKEN_ID, beneficiary, 1),
+			Error::<Test>::VaultLimitExceeded);
+
+		// Limit is reset in the next period, currencies without a limit are not limited
+		System::set_block_number(10);
+		assert_ok!(Control::spend_from_vault(Origin::signed(BOB), org_id, 1, PAYMENT_TOKEN_ID, beneficiary, 10 * DOLLARS));
+		assert_ok!(Control::spend_from_vault(Origin::signed(BOB), org_id, 1, PROTOCOL_TOKEN_ID, beneficiary, 50 * DOLLARS));
+		assert_eq!(Tokens::accounts(beneficiary, PAYMENT_TOKEN_ID).free, 40 * DOLLARS);
+		assert_eq!(Tokens::accounts(vault_account, PAYMENT_TOKEN_ID).free, 60 * DOLLARS);
+
+		// Rules update revokes the role
+		assert_noop!(
+			Control::set_vault_rules(Origin::signed(ALICE), org_id, 2, vault_rules(vec![], vec![], 0)),
+			Error::<Test>::VaultUnknown);
+		assert_ok!(Control::set_vault_rules(Origin::signed(ALICE), org_id, 1, vault_rules(vec![], vec![], 0)));
+		System::assert_has_event(Event::Control(crate::Event::VaultRulesSet { org_id, vault_id: 1, block_number: 10 }));
+		assert_noop!(
+			Control::spend_from_vault(Origin::signed(BOB), org_id, 1, PAYMENT_TOKEN_ID, beneficiary, 1 * DOLLARS),
+			BadOrigin);
+		assert_ok!(Control::spend_from_vault(Origin::signed(ALICE), org_id, 1, PAYMENT_TOKEN_ID, beneficiary, 60 * DOLLARS));
+	})
+}
+
+#[test]
+fn control_vault_transfers() {
+	new_test_ext().execute_with(|| {
+		let current_block = 3;
+		System::set_block_number(current_block);
+		let org_id = create_org(AccessModel::Prime);
+		let treasury_id = OrgTreasury::<Test>::get(org_id).unwrap();
+		set_balance(treasury_id, PAYMENT_TOKEN_ID, 100 * DOLLARS);
+		let grants = create_vault(org_id, b"grants", vault_rules(vec![], vec![], 0), 200);
+		let operations = create_vault(org_id, b"operations", vault_rules(vec![], vec![], 0), 201);
+
+		assert_noop!(
+			Control::transfer_between_vaults(Origin::signed(BOB), org_id, None, Some(grants), PAYMENT_TOKEN_ID, 1 * DOLLARS),
+			BadOrigin);
+		assert_noop!(
+			Control::transfer_between_vaults(Origin::signed(ALICE), org_id, Some(grants), Some(grants), PAYMENT_TOKEN_ID, 1 * DOLLARS),
+			Error::<Test>::SameVault);
+		assert_noop!(
+			Control::transfer_between_vaults(Origin::signed(ALICE), org_id, None, Some(3), PAYMENT_TOKEN_ID, 1 * DOLLARS),
+			Error::<Test>::VaultUnknown);
+		assert_ok!(Control::transfer_between_vaults(
+			Origin::signed(ALICE), org_id, None, Some(grants), PAYMENT_TOKEN_ID, 30 * DOLLARS));
+		System::assert_has_event(Event::Control(crate::Event::VaultTransfer {
+			org_id, from: None, to: Some(grants), currency_id: PAYMENT_TOKEN_ID, amount: 30 * DOLLARS, block_number: current_block
+		}));
+		assert_ok!(Control::transfer_between_vaults(
+			Origin::signed(ALICE), org_id, Some(grants), Some(operations), PAYMENT_TOKEN_ID, 10 * DOLLARS));
+		assert_noop!(
+			Control::transfer_between_vaults(Origin::signed(ALICE), org_id, Some(grants), None, PAYMENT_TOKEN_ID, 21 * DOLLARS),
+			Error::<Test>::BalanceLow);
+
+		// Funds committed to the streams stay in the main treasury
+		assert_ok!(Control::create_stream(Origin::signed(ALICE), org_id, BOB, PAYMENT_TOKEN_ID, 10 * DOLLARS, 10, 3, Some(63)));
+		assert_noop!(
+			Control::transfer_between_vaults(Origin::signed(ALICE), org_id, None, Some(grants), PAYMENT_TOKEN_ID, 11 * DOLLARS),
+			Error::<Test>::FundsCommitted);
+		assert_ok!(Control::transfer_between_vaults(
+			Origin::signed(ALICE), org_id, None, Some(grants), PAYMENT_TOKEN_ID, 10 * DOLLARS));
+		assert_eq!(Tokens::accounts(treasury_id, PAYMENT_TOKEN_ID).free, 60 * DOLLARS);
+		assert_eq!(Tokens::accounts(200, PAYMENT_TOKEN_ID).free, 30 * DOLLARS);
+		assert_eq!(Tokens::accounts(201, PAYMENT_TOKEN_ID).free, 10 * DOLLARS);
+
+		// Transfers from a vault follow its rules
+		assert_ok!(Control::add_member(Origin::root(), org_id, BOB, None));
+		assert_ok!(Control::add_member(Origin::root(), org_id, CHARLIE, None));
+		assert_ok!(Control::grant_role(Origin::root(), org_id, BOB, OrgRole::Treasurer));
+		let payroll = create_vault(
+			org_id, b"payroll", vault_rules(vec![OrgRole::Treasurer], vec![(PAYMENT_TOKEN_ID, 5 * DOLLARS)], 10), 202);
+		assert_ok!(Control::transfer_between_vaults(
+			Origin::signed(ALICE), org_id, None, Some(payroll), PAYMENT_TOKEN_ID, 10 * DOLLARS));
+		assert_noop!(
+			Control::transfer_between_vaults(Origin::signed(CHARLIE), org_id, Some(payroll), Some(grants), PAYMENT_TOKEN_ID, 1 * DOLLARS),
+			BadOrigin);
+		assert_ok!(Control::transfer_between_vaults(
+			Origin::signed(BOB), org_id, Some(payroll), Some(grants), PAYMENT_TOKEN_ID, 4 * DOLLARS));
+		assert_noop!(
+			Control::transfer_between_vaults(Origin::signed(ALICE), org_id, Some(payroll), None, PAYMENT_TOKEN_ID, 2 * DOLLARS),
+			Error::<Test>::VaultLimitExceeded);
+		assert_eq!(VaultSpendings::<Test>::get((org_id, payroll), PAYMENT_TOKEN_ID), (0, 4 * DOLLARS));
+		assert_ok!(Control::transfer_between_vaults(
+			Origin::root(), org_id, Some(payroll), None, PAYMENT_TOKEN_ID, 6 * DOLLARS));
+		assert_eq!(Tokens::accounts(202, PAYMENT_TOKEN_ID).free, 0);
+
+		// Vault funds are moved to the main treasury on dissolution, vaults are removed with the org
+		assert_ok!(Control::dissolve_org(Origin::signed(ALICE), org_id));
+		assert_eq!(Tokens::accounts(200, PAYMENT_TOKEN_ID).free, 0);
+		assert_eq!(Tokens::accounts(201, PAYMENT_TOKEN_ID).free, 0);
+		assert_eq!(Tokens::accounts(treasury_id, PAYMENT_TOKEN_ID).free, 100 * DOLLARS);
+		run_to_block(10);
+		assert!(!Orgs::<Test>::contains_key(org_id));
+		assert!(!OrgVaults::<Test>::contains_key(org_id, grants));
+		assert_eq!(OrgVaultCount::<Test>::get(org_id), 0);
+	})
+}
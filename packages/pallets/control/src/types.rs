@@ -2,7 +2,29 @@ use super::*;
 use frame_support::pallet_prelude::*;
 use codec::MaxEncodedLen;
 
+pub use gamedao_traits::{OrgRole, OrgPermission, VaultId};
+
 pub type MemberLimit = u32;
+/// Max number of roles granted to one member.
+pub type MaxRoles = ConstU32<4>;
+/// Max number of permissions assigned to one role.
+pub type MaxPermissions = ConstU32<16>;
+/// Max number of treasury currencies distributed on the org dissolution.
+pub type MaxDissolutionCurrencies = ConstU32<16>;
+/// Max number of parent orgs above a sub-org.
+pub type MaxOrgDepth = ConstU32<8>;
+/// Max number of direct sub-orgs of an org.
+pub type MaxSubOrgs = ConstU32<16>;
+/// Max number of sub-orgs returned for the org subtree.
+pub type MaxSubtreeOrgs = ConstU32<128>;
+/// Max number of currencies with a spending threshold in a Hybrid org.
+pub type MaxSpendThresholds = ConstU32<8>;
+/// Max number of named treasury vaults of an org.
+pub type MaxVaults = ConstU32<8>;
+/// Max number of currencies with a spending limit in a vault.
+pub type MaxVaultLimits = ConstU32<8>;
+/// Max number of sponsored seats reserved for the named joiners.
+pub type MaxNamedSeats = ConstU32<32>;
 
 #[derive(Encode, Decode, PartialEq, Clone, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
@@ -43,6 +65,7 @@ pub enum OrgState {
 	Inactive = 0,
 	Active = 1,
 	Locked = 2,
+	Dissolving = 3, // frozen, settlement is in progress
 }
 
 impl Default for OrgState {
@@ -57,6 +80,7 @@ pub enum FeeModel {
 	NoFees = 0,   // feeless
 	Reserve = 1,  // amount is reserved in user account
 	Transfer = 2, // amount is transfered to Org treasury
+	Subscription = 3, // amount is collected every subscription period (in pay_currency)
 }
 impl Default for FeeModel {
 	fn default() -> Self {
@@ -96,3 +120,92 @@ pub struct Org<AccountId, Balance, CurrencyId, BlockNumber, BoundedString> {
 	pub created: BlockNumber,
 	pub mutated: BlockNumber,
 }
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+pub enum DissolutionPhase {
+	Counting = 0, // active members are counted to calculate the treasury shares
+	Settling = 1, // fees are unreserved, shares are paid out and members removed
+}
+
+/// Org dissolution progress, processed in batches.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Dissolution<AccountId, Balance, CurrencyId> {
+	pub phase: DissolutionPhase,
+	/// Last counted member, counting continues after it.
+	pub last_counted: Option<AccountId>,
+	pub active_members: u32,
+	/// Amount paid to every active member, per currency.
+	pub shares: BoundedVec<(CurrencyId, Balance), MaxDissolutionCurrencies>,
+}
+
+/// Org membership invitation, signed off-chain by the org prime or a member with AddMember permission.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Invitation<Hash, AccountId, BlockNumber> {
+	pub org_id: Hash,
+	/// Account allowed to redeem the invitation, anyone if not set.
+	pub invitee: Option<AccountId>,
+	/// Last block when the invitation can be redeemed.
+	pub expires: BlockNumber,
+	/// Unique per inviter, redeemed invitation can't be used again.
+	pub nonce: u64,
+	/// Membership fee is not charged from the invitee.
+	pub fee_waived: bool,
+}
+
+/// Org treasury payment stream, accrued amounts are claimed by the beneficiary.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Stream<AccountId, Balance, CurrencyId, BlockNumber> {
+	pub beneficiary: AccountId,
+	pub currency_id: CurrencyId,
+	/// Amount accrued every period.
+	pub amount: Balance,
+	/// Period length in blocks, 1 for the per block payments.
+	pub period: BlockNumber,
+	pub start: BlockNumber,
+	/// Periods ending after this block are not paid, endless stream if not set.
+	pub end: Option<BlockNumber>,
+	/// Accrued amounts are paid until this block.
+	pub claimed_until: BlockNumber,
+}
+
+/// Spending rules of an org treasury vault.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct VaultRules<Balance, CurrencyId, BlockNumber> {
+	/// Active members with any of these roles may spend from the vault, besides the org prime.
+	pub roles: BoundedVec<OrgRole, MaxRoles>,
+	/// Max amount spent per period by the members and the prime, by currency.
+	/// Currencies without a limit are not limited.
+	pub limits: BoundedVec<(CurrencyId, Balance), MaxVaultLimits>,
+	/// Spending period length in blocks.
+	pub period: BlockNumber,
+}
+
+/// Named org treasury vault with its own account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Vault<AccountId, Balance, CurrencyId, BlockNumber, BoundedString> {
+	pub name: BoundedString,
+	pub account: AccountId,
+	pub rules: VaultRules<Balance, CurrencyId, BlockNumber>,
+}
+
+/// Membership seats prepaid by a sponsor account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct SponsorPool<AccountId, Balance, CurrencyId> {
+	/// Currency of the reserved membership fees.
+	pub currency_id: CurrencyId,
+	/// Number of unused seats, including the named ones.
+	pub seats: u32,
+	/// Membership fee prepaid per seat.
+	pub price: Balance,
+	/// Membership fees reserved on the sponsor for the unused seats.
+	pub reserved: Balance,
+	/// Joiners with a seat reserved for them, other joiners may use the remaining seats.
+	pub named: BoundedVec<AccountId, MaxNamedSeats>,
+}
@@ -31,10 +31,40 @@ pub trait WeightInfo {
 	fn update_org() -> Weight;
 	fn disable_org() -> Weight;
 	fn enable_org() -> Weight;
-	fn add_member(r: u32, ) -> Weight;
+	fn add_member() -> Weight;
 	fn update_member_state() -> Weight;
-	fn remove_member(r: u32, ) -> Weight;
+	fn remove_member() -> Weight;
 	fn spend_funds() -> Weight;
+	fn grant_role() -> Weight;
+	fn revoke_role() -> Weight;
+	fn set_role_permissions() -> Weight;
+	fn dissolve_org() -> Weight;
+	fn on_initialize(m: u32, o: u32, ) -> Weight;
+	fn pay_subscription() -> Weight;
+	fn collect_subscriptions(s: u32, ) -> Weight;
+	fn add_sub_org() -> Weight;
+	fn remove_sub_org() -> Weight;
+	fn fund_sub_org() -> Weight;
+	fn claw_back_funds() -> Weight;
+	fn redeem_invitation() -> Weight;
+	fn kick_member() -> Weight;
+	fn ban_member() -> Weight;
+	fn leave_org() -> Weight;
+	fn create_stream() -> Weight;
+	fn claim_stream() -> Weight;
+	fn cancel_stream() -> Weight;
+	fn set_spend_threshold() -> Weight;
+	fn set_min_reputation() -> Weight;
+	fn accept_prime_handover() -> Weight;
+	fn cancel_prime_handover() -> Weight;
+	fn recall_prime() -> Weight;
+	fn create_vault() -> Weight;
+	fn set_vault_rules() -> Weight;
+	fn spend_from_vault() -> Weight;
+	fn transfer_between_vaults() -> Weight;
+	fn sponsor_seats() -> Weight;
+	fn refund_seats() -> Weight;
+	fn set_subscription_period() -> Weight;
 }
 
 /// Weights for gamedao_control using the Substrate node and recommended hardware.
@@ -48,9 +78,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
 	/// Storage: Tokens Accounts (r:2 w:2)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
-	/// Storage: Control Members (r:1 w:1)
-	/// Proof: Control Members (max_values: None, max_size: Some(32050), added: 34525, mode: MaxEncodedLen)
-	/// Storage: Control OrgMemberCount (r:0 w:1)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:1 w:1)
 	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// Storage: Control OrgTreasury (r:0 w:1)
 	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
@@ -58,19 +88,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
 	/// Storage: Control OrgStates (r:0 w:1)
 	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgDeposits (r:0 w:1)
+	/// Proof: Control OrgDeposits (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Control OrgSubscriptionPeriod (r:1 w:0)
+	/// Proof: Control OrgSubscriptionPeriod (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn create_org() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `377`
-		//  Estimated: `50510`
+		//  Estimated: `53037`
 		// Minimum execution time: 82_000 nanoseconds.
-		Weight::from_parts(85_000_000, 50510)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(10_u64))
+		Weight::from_parts(85_000_000, 53037)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
 	/// Storage: Control Orgs (r:1 w:1)
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
 	/// Storage: Control MemberStates (r:1 w:0)
 	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control OrgSpendThresholds (r:0 w:1)
+	/// Proof: Control OrgSpendThresholds (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	/// Storage: Control OrgMinReputation (r:0 w:1)
+	/// Proof: Control OrgMinReputation (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Control PrimeHandovers (r:0 w:1)
+	/// Proof: Control PrimeHandovers (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
 	fn update_org() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `534`
@@ -78,7 +118,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 26_000 nanoseconds.
 		Weight::from_parts(27_000_000, 7317)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Control Orgs (r:1 w:0)
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
@@ -110,55 +150,76 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
 	/// Storage: Control OrgTreasury (r:1 w:0)
 	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
-	/// Storage: Control Members (r:1 w:1)
-	/// Proof: Control Members (max_values: None, max_size: Some(32050), added: 34525, mode: MaxEncodedLen)
-	/// Storage: Control OrgMemberCount (r:0 w:1)
-	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Control MemberStates (r:0 w:1)
+	/// Storage: Control MemberStates (r:1 w:1)
 	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
-	/// The range of component `r` is `[1, 999]`.
-	fn add_member(r: u32, ) -> Weight {
+	/// Storage: Control OrgMemberCount (r:1 w:1)
+	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:1 w:1)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control SubscriptionDuesCursor (r:1 w:1)
+	/// Proof: Control SubscriptionDuesCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Control SubscriptionDues (r:0 w:1)
+	/// Proof: Control SubscriptionDues (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control SponsorPools (r:1 w:1)
+	/// Proof: Control SponsorPools (max_values: None, max_size: Some(353), added: 2828, mode: MaxEncodedLen)
+	/// Storage: Control MemberReservedFees (r:0 w:1)
+	/// Proof: Control MemberReservedFees (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: Control OrgSubscriptionPeriod (r:1 w:0)
+	/// Proof: Control OrgSubscriptionPeriod (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn add_member() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `597 + r * (32 ±0)`
-		//  Estimated: `42815`
-		// Minimum execution time: 32_000 nanoseconds.
-		Weight::from_parts(31_011_478, 42815)
-			// Standard Error: 933
-			.saturating_add(Weight::from_ref_time(36_876).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		//  Measured:  `661`
+		//  Estimated: `20823`
+		// Minimum execution time: 30_000 nanoseconds.
+		Weight::from_parts(33_000_000, 20823)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Control Orgs (r:1 w:0)
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
-	/// Storage: Control MemberStates (r:1 w:0)
+	/// Storage: Control MemberStates (r:1 w:1)
 	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberReservedFees (r:1 w:1)
+	/// Proof: Control MemberReservedFees (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:1 w:1)
+	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:0 w:1)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:0 w:1)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
 	fn update_member_state() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `573`
-		//  Estimated: `7317`
+		//  Estimated: `20138`
 		// Minimum execution time: 16_000 nanoseconds.
-		Weight::from_parts(17_000_000, 7317)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		Weight::from_parts(17_000_000, 20138)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Control Orgs (r:1 w:0)
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
-	/// Storage: Control Members (r:1 w:1)
-	/// Proof: Control Members (max_values: None, max_size: Some(32050), added: 34525, mode: MaxEncodedLen)
-	/// Storage: Control OrgMemberCount (r:0 w:1)
-	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Control MemberStates (r:0 w:1)
+	/// Storage: Control MemberStates (r:2 w:1)
 	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
-	/// The range of component `r` is `[1, 999]`.
-	fn remove_member(r: u32, ) -> Weight {
+	/// Storage: Control OrgMemberCount (r:1 w:1)
+	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:2 w:1)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:0 w:1)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control MemberReservedFees (r:1 w:1)
+	/// Proof: Control MemberReservedFees (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	fn remove_member() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `522 + r * (32 ±0)`
-		//  Estimated: `39270`
-		// Minimum execution time: 28_000 nanoseconds.
-		Weight::from_parts(27_753_161, 39270)
-			// Standard Error: 784
-			.saturating_add(Weight::from_ref_time(31_088).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		//  Measured:  `603`
+		//  Estimated: `18143`
+		// Minimum execution time: 27_000 nanoseconds.
+		Weight::from_parts(28_000_000, 18143)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: Control Orgs (r:1 w:0)
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
@@ -166,156 +227,1533 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Tokens Accounts (r:2 w:2)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control Streams (r:11 w:0)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Control OrgSpendThresholds (r:1 w:0)
+	/// Proof: Control OrgSpendThresholds (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
 	fn spend_funds() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `946`
-		//  Estimated: `19654`
-		// Minimum execution time: 64_000 nanoseconds.
-		Weight::from_parts(65_000_000, 19654)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+		//  Measured:  `2186`
+		//  Estimated: `51070`
+		// Minimum execution time: 79_000 nanoseconds.
+		Weight::from_parts(80_000_000, 51070)
+			.saturating_add(T::DbWeight::get().reads(18_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-}
-
-// For backwards compatibility and tests
-impl WeightInfo for () {
-	/// Storage: Control OrgCount (r:1 w:1)
-	/// Proof: Control OrgCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Control Orgs (r:1 w:1)
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:2 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:2 w:1)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:5 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	fn grant_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `27953`
+		// Minimum execution time: 29_000 nanoseconds.
+		Weight::from_parts(30_000_000, 27953)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:2 w:1)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:5 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	fn revoke_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `745`
+		//  Estimated: `25381`
+		// Minimum execution time: 28_000 nanoseconds.
+		Weight::from_parts(29_000_000, 25381)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:5 w:1)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	fn set_role_permissions() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `634`
+		//  Estimated: `22618`
+		// Minimum execution time: 24_000 nanoseconds.
+		Weight::from_parts(25_000_000, 22618)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:1)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control OrgDeposits (r:1 w:1)
+	/// Proof: Control OrgDeposits (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
 	/// Storage: Tokens Accounts (r:2 w:2)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
-	/// Storage: Control Members (r:1 w:1)
-	/// Proof: Control Members (max_values: None, max_size: Some(32050), added: 34525, mode: MaxEncodedLen)
-	/// Storage: Control OrgMemberCount (r:0 w:1)
+	/// Storage: Control OrgDissolutions (r:0 w:1)
+	/// Proof: Control OrgDissolutions (max_values: None, max_size: Some(167), added: 2642, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaults (r:1 w:0)
+	/// Proof: Control OrgVaults (max_values: None, max_size: Some(336), added: 2811, mode: MaxEncodedLen)
+	fn dissolve_org() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1284`
+		//  Estimated: `18361`
+		// Minimum execution time: 52_000 nanoseconds.
+		Weight::from_parts(53_000_000, 18361)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Control OrgDissolutions (r:11 w:10)
+	/// Proof: Control OrgDissolutions (max_values: None, max_size: Some(167), added: 2642, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:10 w:10)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:10 w:10)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:101 w:100)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:140 w:140)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:10 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:0 w:100)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:0 w:10)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:0 w:10)
 	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Control OrgTreasury (r:0 w:1)
+	/// Storage: Control OrgDeposits (r:0 w:10)
+	/// Proof: Control OrgDeposits (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Control DissolutionCursor (r:1 w:1)
+	/// Proof: Control DissolutionCursor (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// The range of component `m` is `[0, 100]`.
+	/// The range of component `o` is `[1, 10]`.
+	fn on_initialize(m: u32, o: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `720 + m * (201 ±0) + o * (412 ±0)`
+		//  Estimated: `6732 + m * (7740 ±0) + o * (15970 ±0)`
+		// Minimum execution time: 38_000 nanoseconds.
+		Weight::from_parts(21_482_310, 6732)
+			// Standard Error: 3_140
+			.saturating_add(Weight::from_parts(24_611_087, 0).saturating_mul(m.into()))
+			// Standard Error: 34_215
+			.saturating_add(Weight::from_parts(61_007_530, 0).saturating_mul(o.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(o.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes((11_u64).saturating_mul(o.into())))
+			.saturating_add(Weight::from_parts(0, 7740).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 15970).saturating_mul(o.into()))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:1)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
 	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
-	/// Storage: Control MemberStates (r:0 w:1)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:2 w:1)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control SubscriptionDuesCursor (r:1 w:1)
+	/// Proof: Control SubscriptionDuesCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Control SubscriptionDues (r:0 w:1)
+	/// Proof: Control SubscriptionDues (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control OrgSubscriptionPeriod (r:1 w:0)
+	/// Proof: Control OrgSubscriptionPeriod (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn pay_subscription() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1096`
+		//  Estimated: `25675`
+		// Minimum execution time: 48_000 nanoseconds.
+		Weight::from_parts(49_000_000, 25675)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: Control SubscriptionDuesCursor (r:1 w:1)
+	/// Proof: Control SubscriptionDuesCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Control SubscriptionDues (r:101 w:200)
+	/// Proof: Control SubscriptionDues (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:200 w:100)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:100 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:100 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:100 w:0)
 	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
-	/// Storage: Control OrgStates (r:0 w:1)
+	/// Storage: Control OrgTreasury (r:100 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:101 w:101)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control OrgSubscriptionPeriod (r:100 w:0)
+	/// Proof: Control OrgSubscriptionPeriod (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 100]`.
+	fn collect_subscriptions(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `430 + s * (268 ±0)`
+		//  Estimated: `5862 + s * (20673 ±0)`
+		// Minimum execution time: 9_000 nanoseconds.
+		Weight::from_parts(10_214_530, 5862)
+			// Standard Error: 4_118
+			.saturating_add(Weight::from_parts(36_902_114, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 20673).saturating_mul(s.into()))
+	}
+	/// Storage: Control Orgs (r:2 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:2 w:0)
 	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	fn create_org() -> Weight {
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:1 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Control OrgParent (r:2 w:1)
+	/// Proof: Control OrgParent (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control OrgChildren (r:17 w:1)
+	/// Proof: Control OrgChildren (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: Control OrgParentMembersOnly (r:0 w:1)
+	/// Proof: Control OrgParentMembersOnly (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn add_sub_org() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `377`
-		//  Estimated: `50510`
-		// Minimum execution time: 82_000 nanoseconds.
-		Weight::from_parts(85_000_000, 50510)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(10_u64))
+		//  Measured:  `1062`
+		//  Estimated: `69633`
+		// Minimum execution time: 41_000 nanoseconds.
+		Weight::from_parts(42_000_000, 69633)
+			.saturating_add(T::DbWeight::get().reads(26_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
-	/// Storage: Control Orgs (r:1 w:1)
+	/// Storage: Control OrgParent (r:1 w:1)
+	/// Proof: Control OrgParent (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:1 w:0)
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
 	/// Storage: Control MemberStates (r:1 w:0)
 	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
-	fn update_org() -> Weight {
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:1 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Control OrgChildren (r:0 w:1)
+	/// Proof: Control OrgChildren (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: Control OrgParentMembersOnly (r:0 w:1)
+	/// Proof: Control OrgParentMembersOnly (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn remove_sub_org() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `534`
-		//  Estimated: `7317`
+		//  Measured:  `812`
+		//  Estimated: `13009`
 		// Minimum execution time: 26_000 nanoseconds.
-		Weight::from_parts(27_000_000, 7317)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(27_000_000, 13009)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: Control Orgs (r:1 w:0)
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
-	/// Storage: Control OrgStates (r:0 w:1)
+	/// Storage: Control OrgStates (r:2 w:0)
 	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	fn disable_org() -> Weight {
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:1 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Control OrgParent (r:1 w:0)
+	/// Proof: Control OrgParent (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:2 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Control OrgSpendThresholds (r:1 w:0)
+	/// Proof: Control OrgSpendThresholds (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	/// Storage: Control SubOrgFunding (r:1 w:1)
+	/// Proof: Control SubOrgFunding (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	fn fund_sub_org() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `449`
-		//  Estimated: `3755`
-		// Minimum execution time: 19_000 nanoseconds.
-		Weight::from_parts(20_000_000, 3755)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `1384`
+		//  Estimated: `39200`
+		// Minimum execution time: 71_000 nanoseconds.
+		Weight::from_parts(72_000_000, 39200)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Control Orgs (r:1 w:0)
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
-	/// Storage: Control OrgStates (r:0 w:1)
+	/// Storage: Control OrgStates (r:2 w:0)
 	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	fn enable_org() -> Weight {
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:1 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Control OrgParent (r:1 w:0)
+	/// Proof: Control OrgParent (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:2 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Control SubOrgFunding (r:1 w:1)
+	/// Proof: Control SubOrgFunding (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	fn claw_back_funds() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `449`
-		//  Estimated: `3755`
-		// Minimum execution time: 18_000 nanoseconds.
-		Weight::from_parts(19_000_000, 3755)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `1384`
+		//  Estimated: `36516`
+		// Minimum execution time: 70_000 nanoseconds.
+		Weight::from_parts(71_000_000, 36516)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Control Orgs (r:1 w:0)
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control UsedInvitations (r:1 w:1)
+	/// Proof: Control UsedInvitations (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:2 w:1)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control OrgParentMembersOnly (r:1 w:0)
+	/// Proof: Control OrgParentMembersOnly (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: Control OrgTreasury (r:1 w:0)
 	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
-	/// Storage: Control Members (r:1 w:1)
-	/// Proof: Control Members (max_values: None, max_size: Some(32050), added: 34525, mode: MaxEncodedLen)
-	/// Storage: Control OrgMemberCount (r:0 w:1)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:1 w:1)
 	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Control MemberStates (r:0 w:1)
-	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
-	/// The range of component `r` is `[1, 999]`.
-	fn add_member(r: u32, ) -> Weight {
+	/// Storage: Control OrgSubscriptionPeriod (r:1 w:0)
+	/// Proof: Control OrgSubscriptionPeriod (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn redeem_invitation() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `597 + r * (32 ±0)`
-		//  Estimated: `42815`
-		// Minimum execution time: 32_000 nanoseconds.
-		Weight::from_parts(31_011_478, 42815)
-			// Standard Error: 933
-			.saturating_add(Weight::from_ref_time(36_876).saturating_mul(r.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `1157`
+		//  Estimated: `33740`
+		// Minimum execution time: 68_000 nanoseconds.
+		Weight::from_parts(69_000_000, 33740)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Control Orgs (r:1 w:0)
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
-	/// Storage: Control MemberStates (r:1 w:0)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:1)
 	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
-	fn update_member_state() -> Weight {
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:1 w:1)
+	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:2 w:1)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:0 w:1)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control MemberReservedFees (r:1 w:1)
+	/// Proof: Control MemberReservedFees (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	fn kick_member() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `573`
-		//  Estimated: `7317`
-		// Minimum execution time: 16_000 nanoseconds.
-		Weight::from_parts(17_000_000, 7317)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		//  Measured:  `1121`
+		//  Estimated: `26673`
+		// Minimum execution time: 52_000 nanoseconds.
+		Weight::from_parts(53_000_000, 26673)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: Control Orgs (r:1 w:0)
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
-	/// Storage: Control Members (r:1 w:1)
-	/// Proof: Control Members (max_values: None, max_size: Some(32050), added: 34525, mode: MaxEncodedLen)
-	/// Storage: Control OrgMemberCount (r:0 w:1)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:1)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:1 w:1)
 	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
-	/// Storage: Control MemberStates (r:0 w:1)
+	/// Storage: Control MemberRoles (r:2 w:1)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:0 w:1)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control MemberReservedFees (r:1 w:1)
+	/// Proof: Control MemberReservedFees (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	fn ban_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1121`
+		//  Estimated: `26673`
+		// Minimum execution time: 53_000 nanoseconds.
+		Weight::from_parts(54_000_000, 26673)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:1)
 	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
-	/// The range of component `r` is `[1, 999]`.
-	fn remove_member(r: u32, ) -> Weight {
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:1 w:1)
+	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:0 w:1)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:0 w:1)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control MemberReservedFees (r:1 w:1)
+	/// Proof: Control MemberReservedFees (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	fn leave_org() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `522 + r * (32 ±0)`
-		//  Estimated: `39270`
-		// Minimum execution time: 28_000 nanoseconds.
-		Weight::from_parts(27_753_161, 39270)
-			// Standard Error: 784
-			.saturating_add(Weight::from_ref_time(31_088).saturating_mul(r.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `876`
+		//  Estimated: `15927`
+		// Minimum execution time: 35_000 nanoseconds.
+		Weight::from_parts(36_000_000, 15927)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control Streams (r:11 w:1)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:0)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control NextStreamId (r:1 w:1)
+	/// Proof: Control NextStreamId (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgSpendThresholds (r:1 w:0)
+	/// Proof: Control OrgSpendThresholds (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	fn create_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2201`
+		//  Estimated: `40086`
+		// Minimum execution time: 61_000 nanoseconds.
+		Weight::from_parts(62_000_000, 40086)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Control Streams (r:1 w:1)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1043`
+		//  Estimated: `12968`
+		// Minimum execution time: 48_000 nanoseconds.
+		Weight::from_parts(49_000_000, 12968)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: Control Orgs (r:1 w:0)
 	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:1 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Control Streams (r:1 w:1)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	/// Storage: Control OrgTreasury (r:1 w:0)
 	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Tokens Accounts (r:2 w:2)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1388`
+		//  Estimated: `23421`
+		// Minimum execution time: 55_000 nanoseconds.
+		Weight::from_parts(56_000_000, 23421)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgSpendThresholds (r:1 w:1)
+	/// Proof: Control OrgSpendThresholds (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	fn set_spend_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `10946`
+		// Minimum execution time: 24_000 nanoseconds.
+		Weight::from_parts(25_000_000, 10946)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgMinReputation (r:0 w:1)
+	/// Proof: Control OrgMinReputation (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn set_min_reputation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `534`
+		//  Estimated: `7279`
+		// Minimum execution time: 21_000 nanoseconds.
+		Weight::from_parts(22_000_000, 7279)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:1)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control PrimeHandovers (r:1 w:1)
+	/// Proof: Control PrimeHandovers (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn accept_prime_handover() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `14378`
+		// Minimum execution time: 27_000 nanoseconds.
+		Weight::from_parts(28_000_000, 14378)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control PrimeHandovers (r:1 w:1)
+	/// Proof: Control PrimeHandovers (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	fn cancel_prime_handover() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `584`
+		//  Estimated: `7308`
+		// Minimum execution time: 20_000 nanoseconds.
+		Weight::from_parts(21_000_000, 7308)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:2 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn recall_prime() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `14338`
+		// Minimum execution time: 26_000 nanoseconds.
+		Weight::from_parts(27_000_000, 14338)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaults (r:8 w:1)
+	/// Proof: Control OrgVaults (max_values: None, max_size: Some(336), added: 2811, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaultCount (r:1 w:1)
+	/// Proof: Control OrgVaultCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn create_vault() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1745`
+		//  Estimated: `30311`
+		// Minimum execution time: 41_000 nanoseconds.
+		Weight::from_parts(42_000_000, 30311)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaults (r:1 w:1)
+	/// Proof: Control OrgVaults (max_values: None, max_size: Some(336), added: 2811, mode: MaxEncodedLen)
+	fn set_vault_rules() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `794`
+		//  Estimated: `11090`
+		// Minimum execution time: 24_000 nanoseconds.
+		Weight::from_parts(25_000_000, 11090)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaults (r:1 w:0)
+	/// Proof: Control OrgVaults (max_values: None, max_size: Some(336), added: 2811, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control OrgSpendThresholds (r:1 w:0)
+	/// Proof: Control OrgSpendThresholds (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	/// Storage: Control VaultSpendings (r:1 w:1)
+	/// Proof: Control VaultSpendings (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	fn spend_funds() -> Weight {
+	fn spend_from_vault() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `946`
-		//  Estimated: `19654`
-		// Minimum execution time: 64_000 nanoseconds.
-		Weight::from_parts(65_000_000, 19654)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `1604`
+		//  Estimated: `28296`
+		// Minimum execution time: 58_000 nanoseconds.
+		Weight::from_parts(59_000_000, 28296)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:1 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaults (r:2 w:0)
+	/// Proof: Control OrgVaults (max_values: None, max_size: Some(336), added: 2811, mode: MaxEncodedLen)
+	/// Storage: Control VaultSpendings (r:1 w:1)
+	/// Proof: Control VaultSpendings (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Control Streams (r:11 w:0)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn transfer_between_vaults() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2412`
+		//  Estimated: `64510`
+		// Minimum execution time: 84_000 nanoseconds.
+		Weight::from_parts(85_000_000, 64510)
+			.saturating_add(T::DbWeight::get().reads(24_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control SponsorPools (r:1 w:1)
+	/// Proof: Control SponsorPools (max_values: None, max_size: Some(353), added: 2828, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn sponsor_seats() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `10701`
+		// Minimum execution time: 38_000 nanoseconds.
+		Weight::from_parts(39_000_000, 10701)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Control SponsorPools (r:1 w:1)
+	/// Proof: Control SponsorPools (max_values: None, max_size: Some(353), added: 2828, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn refund_seats() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `853`
+		//  Estimated: `5412`
+		// Minimum execution time: 26_000 nanoseconds.
+		Weight::from_parts(27_000_000, 5412)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgSubscriptionPeriod (r:0 w:1)
+	/// Proof: Control OrgSubscriptionPeriod (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn set_subscription_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `534`
+		//  Estimated: `7816`
+		// Minimum execution time: 21_000 nanoseconds.
+		Weight::from_parts(22_000_000, 7816)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: Control OrgCount (r:1 w:1)
+	/// Proof: Control OrgCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:1 w:1)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:1 w:1)
+	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:0 w:1)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:0 w:1)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:0 w:1)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgDeposits (r:0 w:1)
+	/// Proof: Control OrgDeposits (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Control OrgSubscriptionPeriod (r:1 w:0)
+	/// Proof: Control OrgSubscriptionPeriod (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn create_org() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `377`
+		//  Estimated: `53037`
+		// Minimum execution time: 82_000 nanoseconds.
+		Weight::from_parts(85_000_000, 53037)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:1)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control OrgSpendThresholds (r:0 w:1)
+	/// Proof: Control OrgSpendThresholds (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	/// Storage: Control OrgMinReputation (r:0 w:1)
+	/// Proof: Control OrgMinReputation (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Control PrimeHandovers (r:0 w:1)
+	/// Proof: Control PrimeHandovers (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	fn update_org() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `534`
+		//  Estimated: `7317`
+		// Minimum execution time: 26_000 nanoseconds.
+		Weight::from_parts(27_000_000, 7317)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:0 w:1)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn disable_org() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `449`
+		//  Estimated: `3755`
+		// Minimum execution time: 19_000 nanoseconds.
+		Weight::from_parts(20_000_000, 3755)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:0 w:1)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn enable_org() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `449`
+		//  Estimated: `3755`
+		// Minimum execution time: 18_000 nanoseconds.
+		Weight::from_parts(19_000_000, 3755)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:1)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:1 w:1)
+	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:1 w:1)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control SubscriptionDuesCursor (r:1 w:1)
+	/// Proof: Control SubscriptionDuesCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Control SubscriptionDues (r:0 w:1)
+	/// Proof: Control SubscriptionDues (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control SponsorPools (r:1 w:1)
+	/// Proof: Control SponsorPools (max_values: None, max_size: Some(353), added: 2828, mode: MaxEncodedLen)
+	/// Storage: Control MemberReservedFees (r:0 w:1)
+	/// Proof: Control MemberReservedFees (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: Control OrgSubscriptionPeriod (r:1 w:0)
+	/// Proof: Control OrgSubscriptionPeriod (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn add_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `661`
+		//  Estimated: `20823`
+		// Minimum execution time: 30_000 nanoseconds.
+		Weight::from_parts(33_000_000, 20823)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:1)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberReservedFees (r:1 w:1)
+	/// Proof: Control MemberReservedFees (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:1 w:1)
+	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:0 w:1)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:0 w:1)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	fn update_member_state() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `573`
+		//  Estimated: `20138`
+		// Minimum execution time: 16_000 nanoseconds.
+		Weight::from_parts(17_000_000, 20138)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:2 w:1)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:1 w:1)
+	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:2 w:1)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:0 w:1)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control MemberReservedFees (r:1 w:1)
+	/// Proof: Control MemberReservedFees (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	fn remove_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `603`
+		//  Estimated: `18143`
+		// Minimum execution time: 27_000 nanoseconds.
+		Weight::from_parts(28_000_000, 18143)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control Streams (r:11 w:0)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Control OrgSpendThresholds (r:1 w:0)
+	/// Proof: Control OrgSpendThresholds (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	fn spend_funds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2186`
+		//  Estimated: `51070`
+		// Minimum execution time: 79_000 nanoseconds.
+		Weight::from_parts(80_000_000, 51070)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:2 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:2 w:1)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:5 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	fn grant_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `27953`
+		// Minimum execution time: 29_000 nanoseconds.
+		Weight::from_parts(30_000_000, 27953)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:2 w:1)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:5 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	fn revoke_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `745`
+		//  Estimated: `25381`
+		// Minimum execution time: 28_000 nanoseconds.
+		Weight::from_parts(29_000_000, 25381)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:5 w:1)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	fn set_role_permissions() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `634`
+		//  Estimated: `22618`
+		// Minimum execution time: 24_000 nanoseconds.
+		Weight::from_parts(25_000_000, 22618)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:1)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control OrgDeposits (r:1 w:1)
+	/// Proof: Control OrgDeposits (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control OrgDissolutions (r:0 w:1)
+	/// Proof: Control OrgDissolutions (max_values: None, max_size: Some(167), added: 2642, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaults (r:1 w:0)
+	/// Proof: Control OrgVaults (max_values: None, max_size: Some(336), added: 2811, mode: MaxEncodedLen)
+	fn dissolve_org() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1284`
+		//  Estimated: `18361`
+		// Minimum execution time: 52_000 nanoseconds.
+		Weight::from_parts(53_000_000, 18361)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Control OrgDissolutions (r:11 w:10)
+	/// Proof: Control OrgDissolutions (max_values: None, max_size: Some(167), added: 2642, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:10 w:10)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:10 w:10)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:101 w:100)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:140 w:140)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:10 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:0 w:100)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:0 w:10)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:0 w:10)
+	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgDeposits (r:0 w:10)
+	/// Proof: Control OrgDeposits (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Control DissolutionCursor (r:1 w:1)
+	/// Proof: Control DissolutionCursor (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// The range of component `m` is `[0, 100]`.
+	/// The range of component `o` is `[1, 10]`.
+	fn on_initialize(m: u32, o: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `720 + m * (201 ±0) + o * (412 ±0)`
+		//  Estimated: `6732 + m * (7740 ±0) + o * (15970 ±0)`
+		// Minimum execution time: 38_000 nanoseconds.
+		Weight::from_parts(21_482_310, 6732)
+			// Standard Error: 3_140
+			.saturating_add(Weight::from_parts(24_611_087, 0).saturating_mul(m.into()))
+			// Standard Error: 34_215
+			.saturating_add(Weight::from_parts(61_007_530, 0).saturating_mul(o.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(o.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes((11_u64).saturating_mul(o.into())))
+			.saturating_add(Weight::from_parts(0, 7740).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 15970).saturating_mul(o.into()))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:1)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:2 w:1)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control SubscriptionDuesCursor (r:1 w:1)
+	/// Proof: Control SubscriptionDuesCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Control SubscriptionDues (r:0 w:1)
+	/// Proof: Control SubscriptionDues (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control OrgSubscriptionPeriod (r:1 w:0)
+	/// Proof: Control OrgSubscriptionPeriod (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn pay_subscription() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1096`
+		//  Estimated: `25675`
+		// Minimum execution time: 48_000 nanoseconds.
+		Weight::from_parts(49_000_000, 25675)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Control SubscriptionDuesCursor (r:1 w:1)
+	/// Proof: Control SubscriptionDuesCursor (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Control SubscriptionDues (r:101 w:200)
+	/// Proof: Control SubscriptionDues (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:200 w:100)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:100 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:100 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:100 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:100 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:101 w:101)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control OrgSubscriptionPeriod (r:100 w:0)
+	/// Proof: Control OrgSubscriptionPeriod (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `s` is `[0, 100]`.
+	fn collect_subscriptions(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `430 + s * (268 ±0)`
+		//  Estimated: `5862 + s * (20673 ±0)`
+		// Minimum execution time: 9_000 nanoseconds.
+		Weight::from_parts(10_214_530, 5862)
+			// Standard Error: 4_118
+			.saturating_add(Weight::from_parts(36_902_114, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((9_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 20673).saturating_mul(s.into()))
+	}
+	/// Storage: Control Orgs (r:2 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:2 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:1 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Control OrgParent (r:2 w:1)
+	/// Proof: Control OrgParent (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control OrgChildren (r:17 w:1)
+	/// Proof: Control OrgChildren (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: Control OrgParentMembersOnly (r:0 w:1)
+	/// Proof: Control OrgParentMembersOnly (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn add_sub_org() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1062`
+		//  Estimated: `69633`
+		// Minimum execution time: 41_000 nanoseconds.
+		Weight::from_parts(42_000_000, 69633)
+			.saturating_add(RocksDbWeight::get().reads(26_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Control OrgParent (r:1 w:1)
+	/// Proof: Control OrgParent (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:1 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Control OrgChildren (r:0 w:1)
+	/// Proof: Control OrgChildren (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	/// Storage: Control OrgParentMembersOnly (r:0 w:1)
+	/// Proof: Control OrgParentMembersOnly (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn remove_sub_org() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `13009`
+		// Minimum execution time: 26_000 nanoseconds.
+		Weight::from_parts(27_000_000, 13009)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:2 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:1 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Control OrgParent (r:1 w:0)
+	/// Proof: Control OrgParent (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:2 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Control OrgSpendThresholds (r:1 w:0)
+	/// Proof: Control OrgSpendThresholds (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	/// Storage: Control SubOrgFunding (r:1 w:1)
+	/// Proof: Control SubOrgFunding (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	fn fund_sub_org() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1384`
+		//  Estimated: `39200`
+		// Minimum execution time: 71_000 nanoseconds.
+		Weight::from_parts(72_000_000, 39200)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:2 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:1 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Control OrgParent (r:1 w:0)
+	/// Proof: Control OrgParent (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:2 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Control SubOrgFunding (r:1 w:1)
+	/// Proof: Control SubOrgFunding (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	fn claw_back_funds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1384`
+		//  Estimated: `36516`
+		// Minimum execution time: 70_000 nanoseconds.
+		Weight::from_parts(71_000_000, 36516)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control UsedInvitations (r:1 w:1)
+	/// Proof: Control UsedInvitations (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:2 w:1)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control OrgParentMembersOnly (r:1 w:0)
+	/// Proof: Control OrgParentMembersOnly (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:1 w:1)
+	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgSubscriptionPeriod (r:1 w:0)
+	/// Proof: Control OrgSubscriptionPeriod (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn redeem_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1157`
+		//  Estimated: `33740`
+		// Minimum execution time: 68_000 nanoseconds.
+		Weight::from_parts(69_000_000, 33740)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:1)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:1 w:1)
+	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:2 w:1)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:0 w:1)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control MemberReservedFees (r:1 w:1)
+	/// Proof: Control MemberReservedFees (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	fn kick_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1121`
+		//  Estimated: `26673`
+		// Minimum execution time: 52_000 nanoseconds.
+		Weight::from_parts(53_000_000, 26673)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:1)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:1 w:1)
+	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:2 w:1)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:0 w:1)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control MemberReservedFees (r:1 w:1)
+	/// Proof: Control MemberReservedFees (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	fn ban_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1121`
+		//  Estimated: `26673`
+		// Minimum execution time: 53_000 nanoseconds.
+		Weight::from_parts(54_000_000, 26673)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:1)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control OrgMemberCount (r:1 w:1)
+	/// Proof: Control OrgMemberCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:0 w:1)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control MemberSubscriptions (r:0 w:1)
+	/// Proof: Control MemberSubscriptions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Control MemberReservedFees (r:1 w:1)
+	/// Proof: Control MemberReservedFees (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	fn leave_org() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `876`
+		//  Estimated: `15927`
+		// Minimum execution time: 35_000 nanoseconds.
+		Weight::from_parts(36_000_000, 15927)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control Streams (r:11 w:1)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:0)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control NextStreamId (r:1 w:1)
+	/// Proof: Control NextStreamId (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgSpendThresholds (r:1 w:0)
+	/// Proof: Control OrgSpendThresholds (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	fn create_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2201`
+		//  Estimated: `40086`
+		// Minimum execution time: 61_000 nanoseconds.
+		Weight::from_parts(62_000_000, 40086)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Control Streams (r:1 w:1)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1043`
+		//  Estimated: `12968`
+		// Minimum execution time: 48_000 nanoseconds.
+		Weight::from_parts(49_000_000, 12968)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:1 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Control Streams (r:1 w:1)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_stream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1388`
+		//  Estimated: `23421`
+		// Minimum execution time: 55_000 nanoseconds.
+		Weight::from_parts(56_000_000, 23421)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgSpendThresholds (r:1 w:1)
+	/// Proof: Control OrgSpendThresholds (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	fn set_spend_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `10946`
+		// Minimum execution time: 24_000 nanoseconds.
+		Weight::from_parts(25_000_000, 10946)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgMinReputation (r:0 w:1)
+	/// Proof: Control OrgMinReputation (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn set_min_reputation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `534`
+		//  Estimated: `7279`
+		// Minimum execution time: 21_000 nanoseconds.
+		Weight::from_parts(22_000_000, 7279)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:1)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control PrimeHandovers (r:1 w:1)
+	/// Proof: Control PrimeHandovers (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn accept_prime_handover() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `14378`
+		// Minimum execution time: 27_000 nanoseconds.
+		Weight::from_parts(28_000_000, 14378)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control PrimeHandovers (r:1 w:1)
+	/// Proof: Control PrimeHandovers (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	fn cancel_prime_handover() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `584`
+		//  Estimated: `7308`
+		// Minimum execution time: 20_000 nanoseconds.
+		Weight::from_parts(21_000_000, 7308)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:2 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	fn recall_prime() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `14338`
+		// Minimum execution time: 26_000 nanoseconds.
+		Weight::from_parts(27_000_000, 14338)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaults (r:8 w:1)
+	/// Proof: Control OrgVaults (max_values: None, max_size: Some(336), added: 2811, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaultCount (r:1 w:1)
+	/// Proof: Control OrgVaultCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn create_vault() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1745`
+		//  Estimated: `30311`
+		// Minimum execution time: 41_000 nanoseconds.
+		Weight::from_parts(42_000_000, 30311)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaults (r:1 w:1)
+	/// Proof: Control OrgVaults (max_values: None, max_size: Some(336), added: 2811, mode: MaxEncodedLen)
+	fn set_vault_rules() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `794`
+		//  Estimated: `11090`
+		// Minimum execution time: 24_000 nanoseconds.
+		Weight::from_parts(25_000_000, 11090)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaults (r:1 w:0)
+	/// Proof: Control OrgVaults (max_values: None, max_size: Some(336), added: 2811, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control OrgSpendThresholds (r:1 w:0)
+	/// Proof: Control OrgSpendThresholds (max_values: None, max_size: Some(209), added: 2684, mode: MaxEncodedLen)
+	/// Storage: Control VaultSpendings (r:1 w:1)
+	/// Proof: Control VaultSpendings (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn spend_from_vault() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1604`
+		//  Estimated: `28296`
+		// Minimum execution time: 58_000 nanoseconds.
+		Weight::from_parts(59_000_000, 28296)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control MemberStates (r:1 w:0)
+	/// Proof: Control MemberStates (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Control MemberRoles (r:1 w:0)
+	/// Proof: Control MemberRoles (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Control RolePermissions (r:1 w:0)
+	/// Proof: Control RolePermissions (max_values: None, max_size: Some(66), added: 2541, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaults (r:2 w:0)
+	/// Proof: Control OrgVaults (max_values: None, max_size: Some(336), added: 2811, mode: MaxEncodedLen)
+	/// Storage: Control VaultSpendings (r:1 w:1)
+	/// Proof: Control VaultSpendings (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	/// Storage: Control Streams (r:11 w:0)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn transfer_between_vaults() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2412`
+		//  Estimated: `64510`
+		// Minimum execution time: 84_000 nanoseconds.
+		Weight::from_parts(85_000_000, 64510)
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control SponsorPools (r:1 w:1)
+	/// Proof: Control SponsorPools (max_values: None, max_size: Some(353), added: 2828, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn sponsor_seats() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `10701`
+		// Minimum execution time: 38_000 nanoseconds.
+		Weight::from_parts(39_000_000, 10701)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Control SponsorPools (r:1 w:1)
+	/// Proof: Control SponsorPools (max_values: None, max_size: Some(353), added: 2828, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn refund_seats() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `853`
+		//  Estimated: `5412`
+		// Minimum execution time: 26_000 nanoseconds.
+		Weight::from_parts(27_000_000, 5412)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgStates (r:1 w:0)
+	/// Proof: Control OrgStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgSubscriptionPeriod (r:0 w:1)
+	/// Proof: Control OrgSubscriptionPeriod (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn set_subscription_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `534`
+		//  Estimated: `7816`
+		// Minimum execution time: 21_000 nanoseconds.
+		Weight::from_parts(22_000_000, 7816)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 }
\ No newline at end of file
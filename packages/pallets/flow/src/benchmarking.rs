@@ -4,8 +4,8 @@ use crate::Pallet as Flow;
 
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_system::RawOrigin;
-use frame_support::traits::Hooks;
-use sp_runtime::{DispatchError, traits::{Saturating, SaturatedConversion}};
+use frame_support::traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion};
+use sp_runtime::{DispatchError, traits::{IntegerSquareRoot, Saturating, SaturatedConversion}};
 use sp_std::vec;
 
 
@@ -16,8 +16,8 @@ const DEPOSIT_AMOUNT: u128 = 10_000_000_000_000_000_000;
 /// Fund account with tokens, needed for org and campaign interactions
 fn fund_account<T: Config>(account_id: &T::AccountId) -> Result<(), DispatchError> {
 	let balance_amount: T::Balance = DEPOSIT_AMOUNT.saturated_into();
-	T::Currency::deposit(T::ProtocolTokenId::get(), account_id, balance_amount)?;
-	T::Currency::deposit(T::PaymentTokenId::get(), account_id, balance_amount)?;
+	<T as Config>::Currency::deposit(T::ProtocolTokenId::get(), account_id, balance_amount)?;
+	<T as Config>::Currency::deposit(T::PaymentTokenId::get(), account_id, balance_amount)?;
 	Ok(())
 }
 
@@ -28,6 +28,102 @@ fn fund_accounts<T: Config>(account_ids: &Vec<T::AccountId>) -> Result<(), Dispa
 	Ok(())
 }
 
+/// Max reward tiers, a single NFT each
+fn reward_tiers<T: Config>() -> RewardTiers<T> {
+	let tiers = (1..=MaxRewardTiers::get()).map(|i| RewardTier::<T> {
+		min_contribution: T::MinContribution::get().saturating_mul(i.into()),
+		quantity: 1,
+		cid: BoundedVec::truncate_from(vec![0; <T as Config>::StringLimit::get() as usize]),
+	}).collect::<Vec<_>>();
+	BoundedVec::truncate_from(tiers)
+}
+
+/// Single milestone with the full amount and a deadline right after the campaign expiry
+fn grant_milestones<T: Config>(amount: T::Balance, expiry: T::BlockNumber) -> Milestones<T> {
+	let milestone = Milestone::<T> {
+		cid: BoundedVec::truncate_from(vec![0; <T as Config>::StringLimit::get() as usize]),
+		amount,
+		deadline: expiry + 1_u32.into(),
+	};
+	BoundedVec::truncate_from(vec![milestone])
+}
+
+/// Grant campaign with a single milestone, the target is two min contributions
+fn create_grant_campaign<T: Config>(owner: &T::AccountId, org_id: T::Hash, expiry: T::BlockNumber) -> Result<T::Hash, DispatchError> {
+	let bounded_vec: BoundedVec<u8, <T as Config>::StringLimit> = BoundedVec::truncate_from(vec![0; <T as Config>::StringLimit::get() as usize]);
+	let now = frame_system::Pallet::<T>::block_number();
+	let target = T::MinContribution::get().saturating_mul(2u32.into());
+	let campaign_id = T::Hashing::hash_of(&types::Campaign {
+		index: CampaignCount::<T>::get(), org_id, name: bounded_vec.clone(),
+		owner: owner.clone(), admin: owner.clone(), deposit: T::MinContribution::get(),
+		start: now, expiry, cap: target,
+		protocol: FlowProtocol::Grant, governance: FlowGovernance::No, cid: bounded_vec.clone(),
+		token_symbol: None, token_name: None, created: now,
+	});
+	Flow::<T>::create_campaign(
+		RawOrigin::Signed(owner.clone()).into(), org_id, owner.clone(), bounded_vec.clone(), target,
+		T::MinContribution::get(), expiry, FlowProtocol::Grant, FlowGovernance::No, bounded_vec,
+		None, None, None, None, grant_milestones::<T>(target, expiry), None, None, None, None, None,
+		BoundedVec::new(), BoundedVec::new()
+	)?;
+	Ok(campaign_id)
+}
+
+/// Succeeded Loan campaign fully lent by the contributor
+fn create_loan_campaign<T: Config>(caller: &T::AccountId, contributor: &T::AccountId) -> Result<T::Hash, DispatchError> {
+	let org_id = T::ControlBenchmarkHelper::create_org(caller.clone().into())?;
+	let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+	fund_account::<T>(&treasury_id)?;
+
+	let bounded_vec: BoundedVec<u8, <T as Config>::StringLimit> = BoundedVec::truncate_from(vec![0; <T as Config>::StringLimit::get() as usize]);
+	let now = frame_system::Pallet::<T>::block_number();
+	let target = T::MinContribution::get().saturating_mul(10u32.into());
+	let expiry = now + 57_600_u32.into();
+	let campaign_id = T::Hashing::hash_of(&types::Campaign {
+		index: CampaignCount::<T>::get(), org_id, name: bounded_vec.clone(),
+		owner: caller.clone(), admin: caller.clone(), deposit: T::MinContribution::get(),
+		start: now, expiry, cap: target,
+		protocol: FlowProtocol::Loan, governance: FlowGovernance::No, cid: bounded_vec.clone(),
+		token_symbol: None, token_name: None, created: now,
+	});
+	let terms = LoanTerms { interest: Permill::from_percent(10), installments: MaxInstallments::get(), period: 100_u32.into() };
+	Flow::<T>::create_campaign(
+		RawOrigin::Signed(caller.clone()).into(), org_id, caller.clone(), bounded_vec.clone(), target,
+		T::MinContribution::get(), expiry, FlowProtocol::Loan, FlowGovernance::No, bounded_vec,
+		None, None, None, None, BoundedVec::new(), Some(terms), None, None, None, None, BoundedVec::new(), BoundedVec::new()
+	)?;
+	Flow::<T>::contribute(RawOrigin::Signed(contributor.clone()).into(), campaign_id.clone(), target)?;
+	<Flow::<T> as FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash>>::finalize_campaigns_by_block(expiry);
+	Ok(campaign_id)
+}
+
+/// Succeeded campaign with reward tiers fully funded by the contributor
+fn create_reward_campaign<T: Config>(caller: &T::AccountId, contributor: &T::AccountId) -> Result<T::Hash, DispatchError> {
+	let org_id = T::ControlBenchmarkHelper::create_org(caller.clone().into())?;
+	let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+	fund_account::<T>(&treasury_id)?;
+
+	let bounded_vec: BoundedVec<u8, <T as Config>::StringLimit> = BoundedVec::truncate_from(vec![0; <T as Config>::StringLimit::get() as usize]);
+	let now = frame_system::Pallet::<T>::block_number();
+	let target = T::MinContribution::get().saturating_mul(10u32.into());
+	let expiry = now + 57_600_u32.into();
+	let campaign_id = T::Hashing::hash_of(&types::Campaign {
+		index: CampaignCount::<T>::get(), org_id, name: bounded_vec.clone(),
+		owner: caller.clone(), admin: caller.clone(), deposit: T::MinContribution::get(),
+		start: now, expiry, cap: target,
+		protocol: FlowProtocol::Raise, governance: FlowGovernance::No, cid: bounded_vec.clone(),
+		token_symbol: None, token_name: None, created: now,
+	});
+	Flow::<T>::create_campaign(
+		RawOrigin::Signed(caller.clone()).into(), org_id, caller.clone(), bounded_vec.clone(), target,
+		T::MinContribution::get(), expiry, FlowProtocol::Raise, FlowGovernance::No, bounded_vec,
+		None, None, None, None, BoundedVec::new(), None, None, None, None, None, reward_tiers::<T>(), BoundedVec::new()
+	)?;
+	Flow::<T>::contribute(RawOrigin::Signed(contributor.clone()).into(), campaign_id.clone(), target)?;
+	<Flow::<T> as FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash>>::finalize_campaigns_by_block(expiry);
+	Ok(campaign_id)
+}
+
 /// Switch to next block number
 fn next_block<T: Config>() {
 	let current_block = frame_system::Pallet::<T>::block_number();
@@ -44,21 +140,27 @@ benchmarks! {
 		// Prepare organization and treasury
 		let org_id = T::ControlBenchmarkHelper::create_org(caller.clone().into())?;
 		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
-		let bounded_vec = BoundedVec::truncate_from(vec![0; T::StringLimit::get() as usize]);
+		let bounded_vec = BoundedVec::truncate_from(vec![0; <T as Config>::StringLimit::get() as usize]);
 		fund_account::<T>(&treasury_id)?;
 
+		// Grant campaign with a single milestone and the reward collection
+		let target = T::MinContribution::get().saturating_mul(10u32.into());
+		let expiry = frame_system::Pallet::<T>::block_number() + 57_600_u32.into(); // 60/3*60*24*2 (2 days with 3 sec block time)
+		let milestones = grant_milestones::<T>(target, expiry);
+
 		// Save number of existing campaigns to compare to new count after extrinsic called
 		let count_before = CampaignCount::<T>::get();
 	}: _(
 		RawOrigin::Signed(caller.clone()),
 		org_id, caller.clone(),
 		bounded_vec.clone(),
-		T::MinContribution::get().saturating_mul(10u32.into()),
+		target,
 		T::MinContribution::get(),
-		frame_system::Pallet::<T>::block_number() + 57_600_u32.into(), // 60/3*60*24*2 (2 days with 3 sec block time)
-		Default::default(), Default::default(),
+		expiry,
+		FlowProtocol::Grant, Default::default(),
 		bounded_vec.clone(), None,
-		Some(bounded_vec.clone()), Some(bounded_vec.clone())
+		Some(bounded_vec.clone()), Some(bounded_vec.clone()),
+		None, milestones, None, None, None, None, None, reward_tiers::<T>(), BoundedVec::new()
 	)
 	verify {
 		assert!(CampaignCount::<T>::get() == count_before + 1);
@@ -75,16 +177,114 @@ benchmarks! {
 		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
 		fund_account::<T>(&treasury_id)?;
 
-		// Create campaign to use for contributions
+		// Create campaign to use for contributions, the contribution exceeds the hard cap
+		let bounded_vec: BoundedVec<u8, <T as Config>::StringLimit> = BoundedVec::truncate_from(vec![0; <T as Config>::StringLimit::get() as usize]);
 		let now = frame_system::Pallet::<T>::block_number();
-		let campaign_id = <Flow::<T> as FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash>>::create_campaign(&owner, &org_id, now)?;
+		let target = T::MinContribution::get().saturating_mul(10u32.into());
+		let expiry = now + 57_600_u32.into();
+		let campaign_id = T::Hashing::hash_of(&types::Campaign {
+			index: CampaignCount::<T>::get(), org_id, name: bounded_vec.clone(),
+			owner: owner.clone(), admin: owner.clone(), deposit: T::MinContribution::get(),
+			start: now, expiry, cap: target,
+			protocol: FlowProtocol::Raise, governance: FlowGovernance::No, cid: bounded_vec.clone(),
+			token_symbol: None, token_name: None, created: now,
+		});
+		Flow::<T>::create_campaign(
+			RawOrigin::Signed(owner.clone()).into(), org_id, owner.clone(), bounded_vec.clone(), target,
+			T::MinContribution::get(), expiry, FlowProtocol::Raise, FlowGovernance::No, bounded_vec,
+			None, None, None, None, BoundedVec::new(), None, None, None, None,
+			Some(HardCap { amount: target, policy: OverfundingPolicy::Refund }), BoundedVec::new(), BoundedVec::new()
+		)?;
+		let contribution = target.saturating_add(T::MinContribution::get());
+	}: _(
+		RawOrigin::Signed(contributor.clone()),
+		campaign_id.clone(),
+		contribution
+	)
+	verify {
+		assert!(CampaignStates::<T>::get(&campaign_id) == CampaignState::Succeeded);
+		assert!(ContributionExcess::<T>::contains_key(&campaign_id, &contributor));
+	}
+
+	contribute_asset {
+		let contributor: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		fund_accounts::<T>(&vec![owner.clone(), contributor.clone()])?;
+		let currency_id = T::BenchmarkCurrencyId::get();
+		let amount: T::Balance = (DEPOSIT_AMOUNT / 2).saturated_into();
+		<T as Config>::Currency::deposit(currency_id, &contributor, amount)?;
+		let org_id = T::ControlBenchmarkHelper::create_org(owner.clone().into())?;
+		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+		fund_account::<T>(&treasury_id)?;
+
+		// Raise campaign accepting the currency
+		let bounded_vec: BoundedVec<u8, <T as Config>::StringLimit> = BoundedVec::truncate_from(vec![0; <T as Config>::StringLimit::get() as usize]);
+		let now = frame_system::Pallet::<T>::block_number();
+		let target = T::MinContribution::get().saturating_mul(10u32.into());
+		let expiry = now + 57_600_u32.into();
+		let campaign_id = T::Hashing::hash_of(&types::Campaign {
+			index: CampaignCount::<T>::get(), org_id, name: bounded_vec.clone(),
+			owner: owner.clone(), admin: owner.clone(), deposit: T::MinContribution::get(),
+			start: now, expiry, cap: target,
+			protocol: FlowProtocol::Raise, governance: FlowGovernance::No, cid: bounded_vec.clone(),
+			token_symbol: None, token_name: None, created: now,
+		});
+		Flow::<T>::create_campaign(
+			RawOrigin::Signed(owner.clone()).into(), org_id, owner.clone(), bounded_vec.clone(), target,
+			T::MinContribution::get(), expiry, FlowProtocol::Raise, FlowGovernance::No, bounded_vec,
+			None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(),
+			BoundedVec::truncate_from(vec![currency_id])
+		)?;
 	}: _(
 		RawOrigin::Signed(contributor.clone()),
 		campaign_id.clone(),
-		T::MinContribution::get()
+		currency_id,
+		amount
 	)
 	verify {
-		assert!(CampaignContribution::<T>::contains_key(&campaign_id, &contributor));
+		assert!(ContributionAssets::<T>::get(&campaign_id, &contributor).len() == 1);
+	}
+
+	withdraw_contribution {
+		let contributor: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, SEED);
+		fund_accounts::<T>(&vec![owner.clone(), contributor.clone()])?;
+		let org_id = T::ControlBenchmarkHelper::create_org(owner.clone().into())?;
+		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+		fund_account::<T>(&treasury_id)?;
+		let currency_id = T::BenchmarkCurrencyId::get();
+		let amount: T::Balance = (DEPOSIT_AMOUNT / 2).saturated_into();
+		<T as Config>::Currency::deposit(currency_id, &contributor, amount)?;
+
+		// Raise campaign accepting the currency, the whole contribution is withdrawn in kind
+		let bounded_vec: BoundedVec<u8, <T as Config>::StringLimit> = BoundedVec::truncate_from(vec![0; <T as Config>::StringLimit::get() as usize]);
+		let now = frame_system::Pallet::<T>::block_number();
+		let target = T::MinContribution::get().saturating_mul(10u32.into());
+		let expiry = now + 57_600_u32.into();
+		let campaign_id = T::Hashing::hash_of(&types::Campaign {
+			index: CampaignCount::<T>::get(), org_id, name: bounded_vec.clone(),
+			owner: owner.clone(), admin: owner.clone(), deposit: T::MinContribution::get(),
+			start: now, expiry, cap: target,
+			protocol: FlowProtocol::Raise, governance: FlowGovernance::No, cid: bounded_vec.clone(),
+			token_symbol: None, token_name: None, created: now,
+		});
+		Flow::<T>::create_campaign(
+			RawOrigin::Signed(owner.clone()).into(), org_id, owner.clone(), bounded_vec.clone(), target,
+			T::MinContribution::get(), expiry, FlowProtocol::Raise, FlowGovernance::No, bounded_vec,
+			None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(),
+			BoundedVec::truncate_from(vec![currency_id])
+		)?;
+		Flow::<T>::contribute_asset(RawOrigin::Signed(contributor.clone()).into(), campaign_id.clone(), currency_id, amount)?;
+		Flow::<T>::contribute(RawOrigin::Signed(contributor.clone()).into(), campaign_id.clone(), T::MinContribution::get())?;
+		let contribution = CampaignContribution::<T>::get(&campaign_id, &contributor);
+	}: _(
+		RawOrigin::Signed(contributor.clone()),
+		campaign_id.clone(),
+		contribution
+	)
+	verify {
+		assert!(!CampaignContribution::<T>::contains_key(&campaign_id, &contributor));
+		assert!(!ContributionAssets::<T>::contains_key(&campaign_id, &contributor));
 	}
 
 	on_initialize {
@@ -98,37 +298,370 @@ benchmarks! {
 		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
 		fund_account::<T>(&treasury_id)?;
 
-		// 1. Prepare for Campaign finalization. Create campaign and contributions
-		let campaign_id = <Flow::<T> as FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash>>
-			::create_campaign(&owner, &org_id, now)?;
+		// 1. Prepare for the matching tally. Create Grant campaign with contributions in the matching round
+		let expiry = now + 57_600_u32.into();
+		let campaign_id = create_grant_campaign::<T>(&owner, org_id, expiry)?;
 		for i in 0 .. c {
 			let account: T::AccountId = account("contributor", i, SEED);
 			fund_account::<T>(&account)?;
 			Flow::<T>::contribute(RawOrigin::Signed(account).into(), campaign_id.clone(), T::MinContribution::get())?;
 		}
-		let expiry_block = CampaignOf::<T>::get(&campaign_id).unwrap().expiry;
+		let round_id = MatchingRoundCount::<T>::get();
+		Flow::<T>::create_matching_round(
+			RawOrigin::Signed(owner.clone()).into(), Some(org_id), T::MinContribution::get(), now, expiry,
+			T::MinContribution::get(), BoundedVec::truncate_from(vec![campaign_id.clone()])
+		)?;
 
-		// 2. Prepare for Campaign activation. Create campaigns to be Activated at the same block when finalization happens
-		let finalization_block = expiry_block.clone().saturating_add(1_u32.into());
+		// 2. Prepare for Campaign activation. Create campaigns to be Activated at the same block when the tally happens
+		let tally_block = expiry.clone().saturating_add(1_u32.into());
 		for j in 0 .. p {
 			let _ = <Flow::<T> as FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash>>
-				::create_campaign(&owner, &org_id, finalization_block)?;
+				::create_campaign(&owner, &org_id, tally_block)?;
+		}
+
+		frame_system::Pallet::<T>::set_block_number(expiry);
+		Flow::<T>::on_finalize(expiry.clone());
+
+		frame_system::Pallet::<T>::set_block_number(tally_block);
+	}: { Flow::<T>::on_initialize(tally_block); }
+
+	verify {
+		assert!(MatchingRoundStates::<T>::get(round_id) != RoundState::Open);
+	}
+
+	settle_contribution {
+		let caller: T::AccountId = whitelisted_caller();
+		let contributor: T::AccountId = account("contributor", 0, SEED);
+		fund_accounts::<T>(&vec![caller.clone(), contributor.clone()])?;
+
+		// Succeeded campaign, the contributor gets the highest tier reward NFT
+		let campaign_id = create_reward_campaign::<T>(&caller, &contributor)?;
+		assert!(CampaignStates::<T>::get(&campaign_id) == CampaignState::Succeeded);
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		campaign_id.clone(),
+		contributor.clone()
+	)
+	verify {
+		assert!(ContributionSettled::<T>::get(&campaign_id, &contributor));
+		assert!(RewardItemCount::<T>::get(&campaign_id) == 1);
+	}
+
+	pause_campaign {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = T::ControlBenchmarkHelper::create_org(caller.clone().into())?;
+		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+		fund_account::<T>(&treasury_id)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let campaign_id = <Flow::<T> as FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash>>::create_campaign(&caller, &org_id, now)?;
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		campaign_id.clone()
+	)
+	verify {
+		assert!(CampaignStates::<T>::get(&campaign_id) == CampaignState::Paused);
+	}
+
+	resume_campaign {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = T::ControlBenchmarkHelper::create_org(caller.clone().into())?;
+		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+		fund_account::<T>(&treasury_id)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let campaign_id = <Flow::<T> as FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash>>::create_campaign(&caller, &org_id, now)?;
+		Flow::<T>::pause_campaign(RawOrigin::Signed(caller.clone()).into(), campaign_id.clone())?;
+		let expiry = CampaignOf::<T>::get(&campaign_id).unwrap().expiry + 1_u32.into();
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		campaign_id.clone(),
+		Some(expiry)
+	)
+	verify {
+		assert!(CampaignOf::<T>::get(&campaign_id).unwrap().expiry == expiry);
+	}
+
+	lock_campaign {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = T::ControlBenchmarkHelper::create_org(caller.clone().into())?;
+		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+		fund_account::<T>(&treasury_id)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let campaign_id = <Flow::<T> as FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash>>::create_campaign(&caller, &org_id, now)?;
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		campaign_id.clone()
+	)
+	verify {
+		assert!(CampaignStates::<T>::get(&campaign_id) == CampaignState::Locked);
+	}
+
+	unlock_campaign {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = T::ControlBenchmarkHelper::create_org(caller.clone().into())?;
+		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+		fund_account::<T>(&treasury_id)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let campaign_id = <Flow::<T> as FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash>>::create_campaign(&caller, &org_id, now)?;
+		Flow::<T>::lock_campaign(RawOrigin::Root.into(), campaign_id.clone())?;
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		campaign_id.clone()
+	)
+	verify {
+		assert!(CampaignStates::<T>::get(&campaign_id) != CampaignState::Locked);
+	}
+
+	cancel_campaign {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&caller)?;
+		let org_id = T::ControlBenchmarkHelper::create_org(caller.clone().into())?;
+		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+		fund_account::<T>(&treasury_id)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let campaign_id = <Flow::<T> as FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash>>::create_campaign(&caller, &org_id, now)?;
+		Flow::<T>::lock_campaign(RawOrigin::Root.into(), campaign_id.clone())?;
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		campaign_id.clone()
+	)
+	verify {
+		assert!(CampaignStates::<T>::get(&campaign_id) == CampaignState::Cancelled);
+	}
+
+	approve_milestone {
+		let caller: T::AccountId = whitelisted_caller();
+		let contributor: T::AccountId = account("contributor", 0, SEED);
+		fund_accounts::<T>(&vec![caller.clone(), contributor.clone()])?;
+		let org_id = T::ControlBenchmarkHelper::create_org(caller.clone().into())?;
+		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+		fund_account::<T>(&treasury_id)?;
+
+		// Succeeded Grant campaign with two milestones
+		let bounded_vec: BoundedVec<u8, <T as Config>::StringLimit> = BoundedVec::truncate_from(vec![0; <T as Config>::StringLimit::get() as usize]);
+		let target = T::MinContribution::get().saturating_mul(10u32.into());
+		let expiry = frame_system::Pallet::<T>::block_number() + 57_600_u32.into();
+		let first = grant_milestones::<T>(target / 2u32.into(), expiry).into_inner();
+		let last = grant_milestones::<T>(target - target / 2u32.into(), expiry + 1u32.into()).into_inner();
+		let campaign_id = T::Hashing::hash_of(&types::Campaign {
+			index: CampaignCount::<T>::get(), org_id, name: bounded_vec.clone(),
+			owner: caller.clone(), admin: caller.clone(), deposit: T::MinContribution::get(),
+			start: frame_system::Pallet::<T>::block_number(), expiry, cap: target,
+			protocol: FlowProtocol::Grant, governance: FlowGovernance::No, cid: bounded_vec.clone(),
+			token_symbol: None, token_name: None, created: frame_system::Pallet::<T>::block_number(),
+		});
+		Flow::<T>::create_campaign(
+			RawOrigin::Signed(caller.clone()).into(), org_id, caller.clone(), bounded_vec.clone(), target,
+			T::MinContribution::get(), expiry, FlowProtocol::Grant, FlowGovernance::No, bounded_vec.clone(),
+			None, None, None, None, BoundedVec::truncate_from([first, last].concat()), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
+		)?;
+		Flow::<T>::contribute(RawOrigin::Signed(contributor).into(), campaign_id.clone(), target)?;
+		<Flow::<T> as FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash>>::finalize_campaigns_by_block(expiry);
+		assert!(CampaignStates::<T>::get(&campaign_id) == CampaignState::Succeeded);
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		campaign_id.clone(),
+		0, true
+	)
+	verify {
+		assert!(MilestoneStates::<T>::get(&campaign_id, 0) == MilestoneState::Released);
+	}
+
+	claim_milestone_refund {
+		let caller: T::AccountId = whitelisted_caller();
+		let contributor: T::AccountId = account("contributor", 0, SEED);
+		fund_accounts::<T>(&vec![caller.clone(), contributor.clone()])?;
+		let org_id = T::ControlBenchmarkHelper::create_org(caller.clone().into())?;
+		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+		fund_account::<T>(&treasury_id)?;
+
+		// Succeeded Grant campaign with the rejected milestone
+		let expiry = frame_system::Pallet::<T>::block_number() + 57_600_u32.into();
+		let campaign_id = create_grant_campaign::<T>(&caller, org_id, expiry)?;
+		let target = T::MinContribution::get().saturating_mul(2u32.into());
+		Flow::<T>::contribute(RawOrigin::Signed(contributor.clone()).into(), campaign_id.clone(), target)?;
+		<Flow::<T> as FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash>>::finalize_campaigns_by_block(expiry);
+		Flow::<T>::approve_milestone(RawOrigin::Root.into(), campaign_id.clone(), 0, false)?;
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		campaign_id.clone(),
+		0,
+		contributor.clone()
+	)
+	verify {
+		assert!(MilestoneRefundClaimed::<T>::get((campaign_id, 0), &contributor));
+	}
+
+	repay_loan {
+		let caller: T::AccountId = whitelisted_caller();
+		let contributor: T::AccountId = account("contributor", 0, SEED);
+		fund_accounts::<T>(&vec![caller.clone(), contributor.clone()])?;
+		let campaign_id = create_loan_campaign::<T>(&caller, &contributor)?;
+		let amount = T::MinContribution::get();
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		campaign_id.clone(),
+		amount
+	)
+	verify {
+		assert!(Loans::<T>::get(&campaign_id).unwrap().repaid == amount);
+	}
+
+	claim_repayment {
+		let caller: T::AccountId = whitelisted_caller();
+		let contributor: T::AccountId = account("contributor", 0, SEED);
+		fund_accounts::<T>(&vec![caller.clone(), contributor.clone()])?;
+		let campaign_id = create_loan_campaign::<T>(&caller, &contributor)?;
+		Flow::<T>::repay_loan(RawOrigin::Signed(caller).into(), campaign_id.clone(), T::MinContribution::get())?;
+	}: _(
+		RawOrigin::Signed(contributor.clone()),
+		campaign_id.clone()
+	)
+	verify {
+		assert!(LoanClaims::<T>::get(&campaign_id, &contributor) == T::MinContribution::get());
+	}
+
+	redeem_shares {
+		let caller: T::AccountId = whitelisted_caller();
+		let contributor: T::AccountId = account("contributor", 0, SEED);
+		fund_accounts::<T>(&vec![caller.clone(), contributor.clone()])?;
+		let org_id = T::ControlBenchmarkHelper::create_org(caller.clone().into())?;
+		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+		fund_account::<T>(&treasury_id)?;
+
+		// Pool campaign with an open redemption window
+		let bounded_vec: BoundedVec<u8, <T as Config>::StringLimit> = BoundedVec::truncate_from(vec![0; <T as Config>::StringLimit::get() as usize]);
+		let now = frame_system::Pallet::<T>::block_number();
+		let target = T::MinContribution::get().saturating_mul(10u32.into());
+		let expiry = now + 57_600_u32.into();
+		let campaign_id = T::Hashing::hash_of(&types::Campaign {
+			index: CampaignCount::<T>::get(), org_id, name: bounded_vec.clone(),
+			owner: caller.clone(), admin: caller.clone(), deposit: T::MinContribution::get(),
+			start: now, expiry, cap: target,
+			protocol: FlowProtocol::Pool, governance: FlowGovernance::No, cid: bounded_vec.clone(),
+			token_symbol: None, token_name: None, created: now,
+		});
+		let terms = PoolTerms { redemption_period: 100_u32.into(), redemption_window: 10_u32.into() };
+		Flow::<T>::create_campaign(
+			RawOrigin::Signed(caller.clone()).into(), org_id, caller.clone(), bounded_vec.clone(), target,
+			T::MinContribution::get(), expiry, FlowProtocol::Pool, FlowGovernance::No, bounded_vec,
+			None, None, None, None, BoundedVec::new(), None, None, Some(terms), None, None, BoundedVec::new(), BoundedVec::new()
+		)?;
+		Flow::<T>::contribute(RawOrigin::Signed(contributor.clone()).into(), campaign_id.clone(), target)?;
+		let shares = PoolShares::<T>::get(&campaign_id, &contributor);
+	}: _(
+		RawOrigin::Signed(contributor.clone()),
+		campaign_id.clone(),
+		shares
+	)
+	verify {
+		assert!(PoolShares::<T>::get(&campaign_id, &contributor).is_zero());
+	}
+
+	create_matching_round {
+		let c in 1 .. MaxRoundCampaigns::get();
+
+		let owner: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&owner)?;
+		let org_id = T::ControlBenchmarkHelper::create_org(owner.clone().into())?;
+		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+		fund_account::<T>(&treasury_id)?;
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let expiry = now + 57_600_u32.into();
+		let mut campaigns = Vec::new();
+		for i in 0 .. c {
+			campaigns.push(create_grant_campaign::<T>(&owner, org_id, expiry + i.into())?);
 		}
+		let end = expiry + c.into();
+		let round_id = MatchingRoundCount::<T>::get();
+	}: _(
+		RawOrigin::Signed(owner),
+		Some(org_id),
+		T::MinContribution::get(),
+		now,
+		end,
+		T::MinContribution::get(),
+		BoundedVec::truncate_from(campaigns)
+	)
+	verify {
+		assert!(MatchingRounds::<T>::contains_key(round_id));
+	}
 
-		frame_system::Pallet::<T>::set_block_number(expiry_block);
-		Flow::<T>::on_finalize(expiry_block.clone());
+	settle_matching_round {
+		let c in 1 .. MaxRoundCampaigns::get();
 
-		frame_system::Pallet::<T>::set_block_number(finalization_block);
-		assert!(CampaignStates::<T>::get(&campaign_id) == CampaignState::Active);
-	}: { Flow::<T>::on_initialize(finalization_block); }
+		let owner: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&owner)?;
+		let org_id = T::ControlBenchmarkHelper::create_org(owner.clone().into())?;
+		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+		fund_account::<T>(&treasury_id)?;
 
+		// Succeeded Grant campaigns with two contributors each
+		let now = frame_system::Pallet::<T>::block_number();
+		let expiry = now + 57_600_u32.into();
+		let mut campaigns = Vec::new();
+		for i in 0 .. c {
+			let campaign_id = create_grant_campaign::<T>(&owner, org_id, expiry + i.into())?;
+			for j in 0 .. 2 {
+				let contributor: T::AccountId = account("contributor", j, SEED);
+				fund_account::<T>(&contributor)?;
+				Flow::<T>::contribute(RawOrigin::Signed(contributor).into(), campaign_id.clone(), T::MinContribution::get())?;
+			}
+			campaigns.push(campaign_id);
+		}
+		let end = expiry + c.into();
+		let round_id = MatchingRoundCount::<T>::get();
+		Flow::<T>::create_matching_round(
+			RawOrigin::Signed(owner).into(), Some(org_id), T::MinContribution::get(), now, end,
+			T::MinContribution::get(), BoundedVec::truncate_from(campaigns.clone())
+		)?;
+		for i in 0 ..= c {
+			let block_number = expiry + i.into();
+			frame_system::Pallet::<T>::set_block_number(block_number);
+			Flow::<T>::on_finalize(block_number);
+		}
+
+		// Contributions are tallied, the pool is distributed
+		let sqrt_sum = T::MinContribution::get().integer_sqrt().saturating_mul(2u32.into());
+		for campaign_id in &campaigns {
+			let sum = T::MinContribution::get().saturating_mul(2u32.into());
+			MatchingTallies::<T>::insert(round_id, campaign_id, MatchingTally { sqrt_sum, sum });
+		}
+	}: {
+		Flow::<T>::tally_matching_round(round_id, c, None, 1);
+	}
 	verify {
-		// TODO: each status check panics, not sure what's going on here
+		assert!(MatchingRoundStates::<T>::get(round_id) == RoundState::Settled);
+	}
 
-		// assert!(CampaignStates::<T>::get(&campaign_id) == CampaignState::Created);
-		// assert!(CampaignStates::<T>::get(&campaign_id) == CampaignState::Failed);
-		// assert!(CampaignStates::<T>::get(&campaign_id) == CampaignState::Active);
-		// assert!(CampaignStates::<T>::get(&campaign_id) == CampaignState::Succeeded);
+	migrate_to_v2 {
+		let c in 0 .. T::MaxCampaignContributors::get();
+
+		let owner: T::AccountId = whitelisted_caller();
+		fund_account::<T>(&owner)?;
+		let org_id = T::ControlBenchmarkHelper::create_org(owner.clone().into())?;
+		let treasury_id = T::Control::org_treasury_account(&org_id).unwrap();
+		fund_account::<T>(&treasury_id)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let campaign_id = <Flow::<T> as FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash>>::create_campaign(&owner, &org_id, now)?;
+
+		// Open campaign with the contributions reserved by the contributors in v1
+		for i in 0 .. c {
+			let contributor: T::AccountId = account("contributor", i, SEED);
+			fund_account::<T>(&contributor)?;
+			<T as Config>::Currency::reserve(T::PaymentTokenId::get(), &contributor, T::MinContribution::get())?;
+			CampaignContribution::<T>::insert(&campaign_id, &contributor, T::MinContribution::get());
+		}
+		StorageVersion::new(1).put::<Flow<T>>();
+	}: {
+		migrations::v2::MigrateToV2::<T>::on_runtime_upgrade();
+	}
+	verify {
+		assert!(Flow::<T>::on_chain_storage_version() == 2);
 	}
 
 	impl_benchmark_test_suite!(Flow, crate::mock::new_test_ext(), crate::mock::Test);
@@ -37,12 +37,17 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(deprecated)] // TODO: tests are not working without transactional macro
 pub mod types;
-pub use types::{FlowProtocol, CampaignState, FlowGovernance, BlockType};
+pub use types::{
+	FlowProtocol, CampaignState, FlowGovernance, BlockType, MilestoneState, MaxMilestones, LoanTerms, Loan,
+	MaxInstallments, MaxInstallmentDelay, PoolTerms, PoolInfo, WithdrawalTerms, HardCap, OverfundingPolicy, MaxRewardTiers,
+	MaxCampaignCurrencies, MaxRoundCampaigns, RoundState, MatchingTally,
+};
 
 mod mock;
 mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 
 use frame_support::{
@@ -51,23 +56,90 @@ use frame_support::{
 	weights::Weight, BoundedVec, log, transactional
 };
 
+use frame_system::{ensure_root, ensure_signed};
+use pallet_nfts::{
+	CollectionConfig, CollectionSetting, CollectionSettings, ItemConfig, ItemSettings, MintSettings,
+	NextCollectionId, Incrementable,
+};
 use scale_info::TypeInfo;
-use sp_runtime::{traits::{AtLeast32BitUnsigned, Hash}, Permill, ArithmeticError::Overflow};
-use sp_std::{vec::Vec, convert::{TryFrom, TryInto}};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{
+		AtLeast32BitUnsigned, CheckedDiv, Convert, Hash, IntegerSquareRoot, SaturatedConversion, Saturating,
+		Zero,
+	},
+	FixedPointNumber, FixedPointOperand, FixedU128, Perbill, Permill, Rounding, ArithmeticError::Overflow,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
 
 #[cfg(feature = "runtime-benchmarks")]
 use gamedao_traits::{ControlBenchmarkingTrait, FlowBenchmarkingTrait};
-use gamedao_traits::{ControlTrait, FlowTrait};
-use orml_traits::{MultiCurrency, MultiReservableCurrency};
+use gamedao_traits::{ControlTrait, FlowTrait, OrgPermission, TreasuryTrait, VaultId};
+use orml_traits::{DataProvider, MultiCurrency, MultiReservableCurrency};
 
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+pub trait RewardHelper<ItemId> {
+	fn item(i: u32) -> ItemId;
+}
+
+pub struct RewardItems;
+
+impl<ItemId: From<u32>> RewardHelper<ItemId> for RewardItems {
+	fn item(id: u32) -> ItemId {
+		id.into()
+	}
+}
+
+/// Converts the amounts between the currencies by their prices.
+pub trait PriceOracle<CurrencyId, Balance> {
+	/// Value of the amount in the `from` currency in the `to` currency, `None` if there is no price.
+	fn convert(from: CurrencyId, to: CurrencyId, amount: Balance) -> Option<Balance>;
+}
+
+/// Price oracle backed by the oracle data provider (e.g. `orml_oracle`) with the currency prices
+/// quoted in a common unit.
+pub struct OraclePrices<Provider>(PhantomData<Provider>);
+
+impl<CurrencyId, Balance, Provider> PriceOracle<CurrencyId, Balance> for OraclePrices<Provider>
+where
+	CurrencyId: PartialEq,
+	Balance: FixedPointOperand,
+	Provider: DataProvider<CurrencyId, FixedU128>,
+{
+	fn convert(from: CurrencyId, to: CurrencyId, amount: Balance) -> Option<Balance> {
+		if from == to {
+			return Some(amount)
+		}
+		let price = Provider::get(&from)?.checked_div(&Provider::get(&to)?)?;
+		price.checked_mul_int(amount)
+	}
+}
+
 pub type Campaign<T> = types::Campaign<
 	<T as frame_system::Config>::Hash, <T as frame_system::Config>::AccountId,
 	<T as pallet::Config>::Balance, <T as frame_system::Config>::BlockNumber,
 	BoundedVec<u8, <T as pallet::Config>::StringLimit>,
 >;
+pub type Milestone<T> = types::Milestone<
+	<T as pallet::Config>::Balance, <T as frame_system::Config>::BlockNumber,
+	BoundedVec<u8, <T as pallet::Config>::StringLimit>,
+>;
+pub type Milestones<T> = BoundedVec<Milestone<T>, MaxMilestones>;
+pub type RewardTier<T> = types::RewardTier<
+	<T as pallet::Config>::Balance, BoundedVec<u8, <T as pallet::Config>::StringLimit>,
+>;
+pub type RewardTiers<T> = BoundedVec<RewardTier<T>, MaxRewardTiers>;
+pub type TokenMetadata<T> = types::TokenMetadata<BoundedVec<u8, <T as pallet::Config>::StringLimit>>;
+pub type CampaignCurrencies<T> = BoundedVec<<T as pallet::Config>::CurrencyId, MaxCampaignCurrencies>;
+pub type AssetContribution<T> = types::AssetContribution<<T as pallet::Config>::CurrencyId, <T as pallet::Config>::Balance>;
+pub type AssetContributions<T> = BoundedVec<AssetContribution<T>, MaxCampaignCurrencies>;
+pub type MatchingRound<T> = types::MatchingRound<
+	<T as frame_system::Config>::Hash, <T as frame_system::Config>::AccountId,
+	<T as pallet::Config>::Balance, <T as frame_system::Config>::BlockNumber,
+>;
+pub type RoundCampaigns<T> = BoundedVec<<T as frame_system::Config>::Hash, MaxRoundCampaigns>;
 pub type Contributors<T> = BoundedVec<<T as frame_system::Config>::AccountId, <T as pallet::Config>::MaxCampaignContributors>;
 
 #[frame_support::pallet]
@@ -76,11 +148,15 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: frame_system::Config + pallet_nfts::Config {
 		type RuntimeEvent: From<Event<Self>>
 			+ IsType<<Self as frame_system::Config>::RuntimeEvent>
 			+ Into<<Self as frame_system::Config>::RuntimeEvent>;
@@ -110,7 +186,8 @@ pub mod pallet {
 		type Currency: MultiCurrency<Self::AccountId, CurrencyId = Self::CurrencyId, Balance = Self::Balance>
 			+ MultiReservableCurrency<Self::AccountId>;
 
-		type Control: ControlTrait<Self::AccountId, Self::Hash>;
+		type Control: ControlTrait<Self::AccountId, Self::Hash>
+			+ TreasuryTrait<Self::AccountId, Self::CurrencyId, Self::Hash>;
 
 		#[cfg(feature = "runtime-benchmarks")]
 		type ControlBenchmarkHelper: ControlBenchmarkingTrait<Self::AccountId, Self::Hash>;
@@ -127,12 +204,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxCampaignsPerBlock: Get<u32>;
 
-		/// The max number of contributors per one Campaign.
+		/// The max number of contributors refunded by one Grant campaign milestone.
 		#[pallet::constant]
 		type MaxCampaignContributors: Get<u32>;
 
 		/// The max number of contributors for processing in one block (batch size)
-		/// during Campaign finalization.
+		/// during matching round tallies.
 		#[pallet::constant]
 		type MaxContributorsProcessing: Get<u32>;
 
@@ -152,6 +229,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type PaymentTokenId: Get<Self::CurrencyId>;
 
+		/// The CurrencyId of the Share campaign token by the campaign index.
+		/// Should not overlap with other currencies of the runtime.
+		type CampaignTokenId: Convert<u32, Self::CurrencyId>;
+
 		/// The amount of comission to be paid from the Org treasury to GameDAO treasury
 		/// after successfull Campaign finalization
 		#[pallet::constant]
@@ -161,6 +242,16 @@ pub mod pallet {
 		#[pallet::constant]
 		type StringLimit: Get<u32>;
 
+		/// The NFT item id of the contribution reward by its index in the campaign collection.
+		type RewardHelper: RewardHelper<Self::ItemId>;
+
+		/// The price oracle valuing the contributions in other currencies in the payment token.
+		type PriceOracle: PriceOracle<Self::CurrencyId, Self::Balance>;
+
+		/// The currency accepted by the benchmarked campaign, priced by the price oracle.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkCurrencyId: Get<Self::CurrencyId>;
+
 		/// Default time limit for a campaign in blocks.
 		#[pallet::constant]
 		type CampaignDurationLimits: Get<(Self::BlockNumber, Self::BlockNumber)>;
@@ -208,17 +299,12 @@ pub mod pallet {
 	pub(super) type CampaignsByBlock<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, BlockType, Blake2_128Concat, T::BlockNumber, BoundedVec<T::Hash, T::MaxCampaignsPerBlock>, ValueQuery>;
 
-	#[pallet::storage]
-	pub(super) type CampaignFinalizationQueue<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::Hash, (Campaign<T>, T::Balance, CampaignState, T::AccountId, Contributors<T>), OptionQuery>;
-
-	/// Offset value - number of processed and sucessfully finalized contributions.
-	/// Used during campaign finalization for processing contributors in batches.
-	/// When MaxContributorsProcessing is achieved, set this offset to save the progress.
+	/// Contributions settled after the campaign outcome was fixed.
 	///
-	/// ProcessingOffset: map Hash => u32
+	/// ContributionSettled: double map Hash, AccountId => bool
 	#[pallet::storage]
-	pub(super) type ProcessingOffset<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, u32, ValueQuery>;
+	pub(super) type ContributionSettled<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::Hash, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
 
 	/// Total number of contributors for particular campaign. This is needed for voting
 	/// in order do determine eligible voters for Withdrawal proposal.
@@ -227,6 +313,245 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type CampaignContributorsCount<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, u64, ValueQuery>;
 
+	/// Org treasury vault receiving the campaign funds, the main org treasury if not set.
+	///
+	/// CampaignVault: map Hash => VaultId
+	#[pallet::storage]
+	pub(super) type CampaignVault<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, VaultId, OptionQuery>;
+
+	/// Milestones of the Grant campaign.
+	///
+	/// CampaignMilestones: map Hash => BoundedVec<Milestone>
+	#[pallet::storage]
+	pub(super) type CampaignMilestones<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, Milestones<T>, ValueQuery>;
+
+	/// Milestone state by campaign id and milestone index.
+	///
+	/// MilestoneStates: double map Hash, u32 => MilestoneState
+	#[pallet::storage]
+	pub(super) type MilestoneStates<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::Hash, Blake2_128Concat, u32, MilestoneState, ValueQuery>;
+
+	/// Milestones with the deadline in block x.
+	///
+	/// MilestonesByBlock: map BlockNumber => BoundedVec<(Hash, u32)>
+	#[pallet::storage]
+	pub(super) type MilestonesByBlock<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::BlockNumber, BoundedVec<(T::Hash, u32), T::MaxCampaignsPerBlock>, ValueQuery>;
+
+	/// Refunded milestones claimed by the contributors pro rata: refunded amount and total contributions.
+	///
+	/// MilestoneRefunds: double map Hash, u32 => (Balance, Balance)
+	#[pallet::storage]
+	pub(super) type MilestoneRefunds<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::Hash,
+		Blake2_128Concat, u32, (T::Balance, T::Balance), OptionQuery>;
+
+	/// Milestone refund was claimed by the contributor.
+	///
+	/// MilestoneRefundClaimed: double map (Hash, u32), AccountId => bool
+	#[pallet::storage]
+	pub(super) type MilestoneRefundClaimed<T: Config> = StorageDoubleMap<_, Blake2_128Concat, (T::Hash, u32),
+		Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+	/// Total contributions to the succeeded Grant campaign, shares of the milestone refunds.
+	///
+	/// CampaignRaised: map Hash => Balance
+	#[pallet::storage]
+	pub(super) type CampaignRaised<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, T::Balance, ValueQuery>;
+
+	/// Share of the campaign token kept by the org treasury.
+	///
+	/// CampaignRetainedShare: map Hash => Permill
+	#[pallet::storage]
+	pub(super) type CampaignRetainedShare<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, Permill, OptionQuery>;
+
+	/// Token issued by the succeeded Share campaign.
+	///
+	/// CampaignToken: map Hash => CurrencyId
+	#[pallet::storage]
+	pub(super) type CampaignToken<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, T::CurrencyId, OptionQuery>;
+
+	/// Metadata of the Share campaign token, registered when the campaign is created.
+	///
+	/// CampaignTokenMetadata: map CurrencyId => TokenMetadata
+	#[pallet::storage]
+	pub(super) type CampaignTokenMetadata<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CurrencyId, TokenMetadata<T>, OptionQuery>;
+
+	/// Redemption terms of the Pool campaign.
+	///
+	/// CampaignPoolTerms: map Hash => PoolTerms
+	#[pallet::storage]
+	pub(super) type CampaignPoolTerms<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, PoolTerms<T::BlockNumber>, OptionQuery>;
+
+	/// Contribution reward tiers of the campaign, ordered by the minimum contribution.
+	///
+	/// CampaignRewardTiers: map Hash => RewardTiers
+	#[pallet::storage]
+	pub(super) type CampaignRewardTiers<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, RewardTiers<T>, ValueQuery>;
+
+	/// NFT collection of the campaign contribution rewards.
+	///
+	/// RewardCollection: map Hash => CollectionId
+	#[pallet::storage]
+	pub(super) type RewardCollection<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, T::CollectionId, OptionQuery>;
+
+	/// Number of reward NFTs minted by the campaign reward tier.
+	///
+	/// RewardsMinted: double map Hash, u32 => u32
+	#[pallet::storage]
+	pub(super) type RewardsMinted<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::Hash, Blake2_128Concat, u32, u32, ValueQuery>;
+
+	/// Number of reward NFTs minted by the campaign, the next item index in the collection.
+	///
+	/// RewardItemCount: map Hash => u32
+	#[pallet::storage]
+	pub(super) type RewardItemCount<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, u32, ValueQuery>;
+
+	/// Currencies accepted by the campaign besides the payment token.
+	///
+	/// CampaignCurrencyIds: map Hash => CampaignCurrencies
+	#[pallet::storage]
+	pub(super) type CampaignCurrencyIds<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, CampaignCurrencies<T>, ValueQuery>;
+
+	/// Campaign contributions in the accepted currencies, held in kind by the org treasury.
+	/// The values are included in the campaign balance.
+	///
+	/// CampaignAssets: map Hash => AssetContributions
+	#[pallet::storage]
+	pub(super) type CampaignAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, AssetContributions<T>, ValueQuery>;
+
+	/// Contributions in the accepted currencies made by account id for particular campaign.
+	/// The values are included in the contribution.
+	///
+	/// ContributionAssets: double map Hash, AccountId => AssetContributions
+	#[pallet::storage]
+	pub(super) type ContributionAssets<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::Hash, Blake2_128Concat, T::AccountId, AssetContributions<T>, ValueQuery>;
+
+	/// Total number of matching rounds.
+	///
+	/// MatchingRoundCount: u32
+	#[pallet::storage]
+	pub(super) type MatchingRoundCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Matching round by its index.
+	///
+	/// MatchingRounds: map u32 => MatchingRound
+	#[pallet::storage]
+	pub(super) type MatchingRounds<T: Config> = StorageMap<_, Blake2_128Concat, u32, MatchingRound<T>, OptionQuery>;
+
+	/// Campaigns matched by the round.
+	///
+	/// MatchingRoundCampaigns: map u32 => RoundCampaigns
+	#[pallet::storage]
+	pub(super) type MatchingRoundCampaigns<T: Config> =
+		StorageMap<_, Blake2_128Concat, u32, RoundCampaigns<T>, ValueQuery>;
+
+	/// Matching round state by its index.
+	///
+	/// MatchingRoundStates: map u32 => RoundState
+	#[pallet::storage]
+	pub(super) type MatchingRoundStates<T: Config> = StorageMap<_, Blake2_128Concat, u32, RoundState, ValueQuery>;
+
+	/// Matching rounds ending at the block.
+	///
+	/// MatchingRoundsByBlock: map BlockNumber => BoundedVec<u32>
+	#[pallet::storage]
+	pub(super) type MatchingRoundsByBlock<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::BlockNumber, BoundedVec<u32, T::MaxCampaignsPerBlock>, ValueQuery>;
+
+	/// Quadratic funding tally of the campaign matched by the round.
+	///
+	/// MatchingTallies: double map u32, Hash => MatchingTally
+	#[pallet::storage]
+	pub(super) type MatchingTallies<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, T::Hash, MatchingTally<T::Balance>, ValueQuery>;
+
+	/// Ended matching rounds being tallied: index of the campaign and the last tallied contributor.
+	///
+	/// MatchingTallyQueue: map u32 => (u32, Option<AccountId>)
+	#[pallet::storage]
+	pub(super) type MatchingTallyQueue<T: Config> =
+		StorageMap<_, Blake2_128Concat, u32, (u32, Option<T::AccountId>), OptionQuery>;
+
+	/// Matching round of the campaign, a campaign is matched by a single round.
+	///
+	/// CampaignMatchingRound: map Hash => u32
+	#[pallet::storage]
+	pub(super) type CampaignMatchingRound<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, u32, OptionQuery>;
+
+	/// Matching funds of the campaign not yet released or returned to the round funder.
+	///
+	/// CampaignMatchingFunds: map Hash => Balance
+	#[pallet::storage]
+	pub(super) type CampaignMatchingFunds<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, T::Balance, ValueQuery>;
+
+	/// Hard cap and overfunding policy of the campaign.
+	///
+	/// CampaignHardCap: map Hash => HardCap
+	#[pallet::storage]
+	pub(super) type CampaignHardCap<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, HardCap<T::Balance>, OptionQuery>;
+
+	/// Contribution part exceeding the hard cap, refunded on settlement.
+	///
+	/// ContributionExcess: double map Hash, AccountId => Balance
+	#[pallet::storage]
+	pub(super) type ContributionExcess<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::Hash, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
+	/// Withdrawal terms of the campaign contributions.
+	///
+	/// CampaignWithdrawalTerms: map Hash => WithdrawalTerms
+	#[pallet::storage]
+	pub(super) type CampaignWithdrawalTerms<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, WithdrawalTerms<T::BlockNumber>, OptionQuery>;
+
+	/// Shares and balance of the Pool campaign.
+	///
+	/// Pools: map Hash => PoolInfo
+	#[pallet::storage]
+	pub(super) type Pools<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, PoolInfo<T::Balance>, ValueQuery>;
+
+	/// Pool shares held by the contributor.
+	///
+	/// PoolShares: double map Hash, AccountId => Balance
+	#[pallet::storage]
+	pub(super) type PoolShares<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::Hash, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
+	/// Repayment terms of the Lend or Loan campaign.
+	///
+	/// CampaignLoanTerms: map Hash => LoanTerms
+	#[pallet::storage]
+	pub(super) type CampaignLoanTerms<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, LoanTerms<T::BlockNumber>, OptionQuery>;
+
+	/// Repayment state of the succeeded Lend or Loan campaign.
+	///
+	/// Loans: map Hash => Loan
+	#[pallet::storage]
+	pub(super) type Loans<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, Loan<T::Balance>, OptionQuery>;
+
+	/// Loans with the installment due in block x.
+	///
+	/// LoansByBlock: map BlockNumber => BoundedVec<Hash>
+	#[pallet::storage]
+	pub(super) type LoansByBlock<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::BlockNumber, BoundedVec<T::Hash, T::MaxCampaignsPerBlock>, ValueQuery>;
+
+	/// Repayments claimed by the lender.
+	///
+	/// LoanClaims: double map Hash, AccountId => Balance
+	#[pallet::storage]
+	pub(super) type LoanClaims<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::Hash, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -238,11 +563,34 @@ pub mod pallet {
 			target: T::Balance,
 			deposit: T::Balance,
 			expiry: T::BlockNumber,
-			name: BoundedVec<u8, T::StringLimit>,
+			name: BoundedVec<u8, <T as Config>::StringLimit>,
 		},
 		Activated {
 			campaign_id: T::Hash,
 		},
+		/// Campaign was paused by the admin, contributions are blocked.
+		Paused {
+			campaign_id: T::Hash,
+		},
+		/// Campaign was resumed by the admin.
+		Resumed {
+			campaign_id: T::Hash,
+			expiry: T::BlockNumber,
+		},
+		/// Campaign was locked by the root or the org.
+		Locked {
+			campaign_id: T::Hash,
+		},
+		/// Campaign was unlocked by the root or the org, it waits for the start or is paused.
+		Unlocked {
+			campaign_id: T::Hash,
+			state: CampaignState,
+		},
+		/// Campaign was cancelled, contributions are refunded.
+		Cancelled {
+			campaign_id: T::Hash,
+			block_number: T::BlockNumber,
+		},
 		/// Campaign was contributed.
 		Contributed {
 			campaign_id: T::Hash,
@@ -250,6 +598,15 @@ pub mod pallet {
 			contribution: T::Balance,
 			block_number: T::BlockNumber,
 		},
+		/// Campaign was contributed in the accepted currency.
+		AssetContributed {
+			campaign_id: T::Hash,
+			sender: T::AccountId,
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+			value: T::Balance,
+			block_number: T::BlockNumber,
+		},
 		/// Campaign was finalized.
 		Succeeded {
 			campaign_id: T::Hash,
@@ -262,6 +619,123 @@ pub mod pallet {
 			campaign_balance: T::Balance,
 			block_number: T::BlockNumber,
 		},
+		/// Milestone funds were released to the org treasury.
+		MilestoneReleased {
+			campaign_id: T::Hash,
+			index: u32,
+			amount: T::Balance,
+			block_number: T::BlockNumber,
+		},
+		/// Milestone funds are refunded, contributors claim their part.
+		MilestoneRefunded {
+			campaign_id: T::Hash,
+			index: u32,
+			amount: T::Balance,
+			block_number: T::BlockNumber,
+		},
+		/// Milestone refund was claimed for the contributor.
+		MilestoneRefundClaimed {
+			campaign_id: T::Hash,
+			index: u32,
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+		/// Loan was repaid by the org, partially or early in full.
+		LoanRepaid {
+			campaign_id: T::Hash,
+			amount: T::Balance,
+			repaid: T::Balance,
+			block_number: T::BlockNumber,
+		},
+		/// Loan installment was not repaid in time.
+		LoanDefaulted {
+			campaign_id: T::Hash,
+			installment: u32,
+			block_number: T::BlockNumber,
+		},
+		/// Lender claimed the share of the repayments.
+		RepaymentClaimed {
+			campaign_id: T::Hash,
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+		/// Pool shares were issued for the contribution.
+		PoolSharesIssued {
+			campaign_id: T::Hash,
+			who: T::AccountId,
+			shares: T::Balance,
+		},
+		/// Pool shares were redeemed for the pooled balance.
+		PoolSharesRedeemed {
+			campaign_id: T::Hash,
+			who: T::AccountId,
+			shares: T::Balance,
+			amount: T::Balance,
+		},
+		/// Contribution was refunded or the campaign token was issued for it.
+		ContributionSettled {
+			campaign_id: T::Hash,
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+		/// Contribution was withdrawn from the active campaign.
+		ContributionWithdrawn {
+			campaign_id: T::Hash,
+			who: T::AccountId,
+			amount: T::Balance,
+			fee: T::Balance,
+		},
+		/// Contribution reward NFT was minted to the contributor.
+		RewardMinted {
+			campaign_id: T::Hash,
+			who: T::AccountId,
+			tier: u32,
+			collection_id: T::CollectionId,
+			item_id: T::ItemId,
+		},
+		/// Contribution part exceeding the hard cap was refunded.
+		ExcessRefunded {
+			campaign_id: T::Hash,
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+		/// Pooled funds were deployed by the org.
+		PoolSpent {
+			campaign_id: T::Hash,
+			amount: T::Balance,
+		},
+		/// Share campaign token was issued to the contributors and the org treasury.
+		CampaignTokenIssued {
+			campaign_id: T::Hash,
+			currency_id: T::CurrencyId,
+			retained: T::Balance,
+		},
+		/// Matching round was created, the matching pool is reserved.
+		MatchingRoundCreated {
+			round_id: u32,
+			org_id: Option<T::Hash>,
+			pool: T::Balance,
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+		},
+		/// Matching funds were reserved for the campaign, they are released with the remaining milestones.
+		MatchingFundsDistributed {
+			round_id: u32,
+			campaign_id: T::Hash,
+			amount: T::Balance,
+		},
+		/// Matching funds of the refunded milestone were returned to the round funder.
+		MatchingFundsReturned {
+			round_id: u32,
+			campaign_id: T::Hash,
+			amount: T::Balance,
+		},
+		/// Matching round was settled, the undistributed part of the pool was returned to the funder.
+		MatchingRoundSettled {
+			round_id: u32,
+			distributed: T::Balance,
+			returned: T::Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -283,6 +757,69 @@ pub mod pallet {
 		OutOfBounds,
 		TreasuryBalanceLow,
 		TreasuryNotExist,
+		/// Org treasury vault does not exist.
+		VaultUnknown,
+		/// Campaign has not succeeded.
+		CampaignUnsucceeded,
+		/// Milestones are missing for a Grant campaign, set for another protocol,
+		/// don't sum up to the target or deadlines are not increasing after the expiry.
+		InvalidMilestones,
+		/// Milestone does not exist, is settled or is not the next one to be approved.
+		MilestoneUnknown,
+		/// Milestone deadline has passed.
+		MilestoneExpired,
+		/// Loan terms are missing for a Lend or Loan campaign, set for another protocol or invalid.
+		InvalidLoanTerms,
+		/// Loan does not exist or is repaid in full.
+		LoanUnknown,
+		/// Nothing to be claimed from the loan repayments.
+		NothingToClaim,
+		/// Token symbol or name is missing for a Share campaign or retained share is invalid.
+		InvalidShareTerms,
+		/// Campaign did not issue a token.
+		CampaignTokenUnknown,
+		/// Campaign token id is already registered or has been issued.
+		CampaignTokenExists,
+		/// Redemption terms are missing for a Pool campaign, set for another protocol or invalid.
+		InvalidPoolTerms,
+		/// Campaign is not a Pool campaign.
+		PoolUnknown,
+		/// Pooled balance was deployed completely, no shares can be issued.
+		PoolDepleted,
+		/// Redemption window is closed.
+		RedemptionClosed,
+		/// Not enough pool shares or the shares are worth nothing.
+		SharesInsufficient,
+		/// Campaign outcome is not fixed yet.
+		CampaignNotFinalized,
+		/// Contribution was settled already.
+		AlreadySettled,
+		/// Campaign state doesn't allow the action.
+		InvalidCampaignState,
+		/// Withdrawal terms are set for a Pool campaign.
+		InvalidWithdrawalTerms,
+		/// Contribution can't be withdrawn from the campaign anymore.
+		WithdrawalClosed,
+		/// Hard cap is below the campaign target or set for a Pool campaign.
+		InvalidHardCap,
+		/// Contribution exceeds the campaign hard cap.
+		HardCapExceeded,
+		/// Reward tiers are set for a Pool campaign, have no quantity or minimum contributions are not increasing.
+		InvalidRewardTiers,
+		/// Currencies are set for a Pool, Lend or Loan campaign or a campaign with milestones,
+		/// include the payment token, are duplicated or have no price.
+		InvalidCurrencies,
+		/// Currency is not accepted by the campaign.
+		CurrencyNotAccepted,
+		/// Price oracle has no price for the currency.
+		PriceUnavailable,
+		/// Matching round has no pool, cap or campaigns, its window is invalid or the campaigns are not
+		/// Grant campaigns running within the window and matched by no other round.
+		InvalidMatchingRound,
+		/// Max number of matching rounds ending at the block is reached.
+		MatchingRoundsPerBlockExceeded,
+		/// Matching round is unknown.
+		MatchingRoundUnknown,
 	}
 
 	#[pallet::hooks]
@@ -300,37 +837,28 @@ pub mod pallet {
 				Self::deposit_event(Event::<T>::Activated { campaign_id: *campaign_id });
 			}
 
-			// Finalize campaigns
+			// Tally the ended matching rounds in batches
 			let mut processed: u32 = 0;
-			let queue = CampaignFinalizationQueue::<T>::iter().collect::<Vec<_>>();
-			for item in queue {
+			let mut weight = Weight::zero();
+			// The tally mutates the queue, so only the rounds which can fit into the batch are read in advance
+			let max_rounds = T::MaxContributorsProcessing::get() as usize;
+			let tallies = MatchingTallyQueue::<T>::iter().take(max_rounds).collect::<Vec<_>>();
+			for (round_id, (index, last_contributor)) in tallies {
 				if processed >= T::MaxContributorsProcessing::get() {
 					break
 				}
-				let (campaign_id, (campaign, campaign_balance, campaign_state, treasury_id, contributors)): (T::Hash, (
-					Campaign<T>, T::Balance, CampaignState, T::AccountId, Contributors<T>)) = item;
-				let mut contributors_finalized = true;
-				for (i, c) in contributors.clone().into_iter().enumerate() {
-					if processed >= T::MaxContributorsProcessing::get() {
-						let not_finalized = BoundedVec::truncate_from(contributors[i..].into());
-						CampaignFinalizationQueue::<T>::insert(campaign_id,
-							(&campaign, &campaign_balance, &campaign_state, &treasury_id, not_finalized));
-						contributors_finalized = false;
-						break
-					}
-					Self::finalize_contributor(&campaign_state, c, campaign_id, &treasury_id);
-					processed += 1;
-				}
-				if contributors_finalized {
-					Self::finalize_campaign(&campaign_state, campaign_id, &campaign, campaign_balance, treasury_id, block_number);
-					CampaignFinalizationQueue::<T>::remove(campaign_id);
+				let batch_size = T::MaxContributorsProcessing::get() - processed;
+				let (tallied, settled) = Self::tally_matching_round(round_id, index, last_contributor, batch_size);
+				processed += tallied;
+				if let Some(matched) = settled {
+					weight = weight.saturating_add(<T as Config>::WeightInfo::settle_matching_round(matched));
 				}
 			}
-			T::WeightInfo::on_initialize(processed, campaigns.len() as u32)
+			<T as Config>::WeightInfo::on_initialize(processed, campaigns.len() as u32).saturating_add(weight)
 		}
 
 		fn on_finalize(block_number: T::BlockNumber) {
-			// Prepare and validate data for campaign settlement
+			// Fix the campaign outcome, contributions are settled by the contributors afterwards
 			for campaign_id in &CampaignsByBlock::<T>::get(BlockType::Expiry, block_number) {
 				let maybe_campaign = CampaignOf::<T>::get(campaign_id);
 				if maybe_campaign.is_none() {
@@ -338,30 +866,54 @@ pub mod pallet {
 					continue
 				}
 				let campaign = maybe_campaign.unwrap();
-				let maybe_treasury_id = T::Control::org_treasury_account(&campaign.org_id);
+				// Paused and locked campaigns wait for the admin or the org, cancelled ones are settled.
+				// Pool campaigns are not settled, only the deposit is returned whatever the state.
+				let is_pool = campaign.protocol == FlowProtocol::Pool;
+				if !is_pool && CampaignStates::<T>::get(campaign_id) != CampaignState::Active {
+					continue
+				}
+				let maybe_treasury_id = T::Control::org_vault_account(
+					&campaign.org_id, CampaignVault::<T>::get(campaign_id)
+				);
 				if maybe_treasury_id.is_none() {
 					log::error!(target: "runtime::gamedao_flow", "Treasury unknown for Org: '{:?}'", &campaign.org_id);
 					continue
 				}
 				let treasury_id = maybe_treasury_id.unwrap();
+				if is_pool {
+					<T as Config>::Currency::unreserve(T::ProtocolTokenId::get(), &treasury_id, campaign.deposit);
+					continue
+				}
 				let campaign_balance = CampaignBalance::<T>::get(campaign_id);
-				let contributors = CampaignContribution::<T>::iter_key_prefix(campaign_id).collect::<Vec<_>>();
 				let state =
 					if campaign_balance >= campaign.cap {
 						CampaignState::Succeeded
 					} else {
 						CampaignState::Failed
 					};
-				if contributors.len() as u32 > T::MaxCampaignContributors::get() {
-					log::error!(
-						target: "runtime::gamedao_flow", "MaxCampaignContributors exceeds limits {},
-						campaign '{:?}' haven't been scheduled for settlement",
-						T::MaxCampaignContributors::get(), campaign_id,
-					);
+				Self::finalize_campaign(&state, *campaign_id, &campaign, campaign_balance, treasury_id, block_number);
+			}
+
+			// Close the matching rounds, contributions are tallied in batches afterwards
+			for round_id in MatchingRoundsByBlock::<T>::take(block_number) {
+				MatchingRoundStates::<T>::insert(round_id, RoundState::Tallying);
+				MatchingTallyQueue::<T>::insert(round_id, (0, None::<T::AccountId>));
+			}
+
+			// Check loans for the missed installments
+			for campaign_id in LoansByBlock::<T>::take(block_number) {
+				Self::check_installment(&campaign_id, block_number);
+			}
+
+			// Refund milestones which missed the deadline
+			for (campaign_id, index) in MilestonesByBlock::<T>::take(block_number) {
+				if MilestoneStates::<T>::get(campaign_id, index) != MilestoneState::Pending ||
+					CampaignStates::<T>::get(campaign_id) != CampaignState::Succeeded {
 					continue
 				}
-				let c = BoundedVec::try_from(contributors.clone()).unwrap();
-				CampaignFinalizationQueue::<T>::insert(campaign_id, (campaign, campaign_balance, state, treasury_id, c));
+				if let Err(e) = Self::do_settle_milestone(&campaign_id, index, false) {
+					log::error!(target: "runtime::gamedao_flow", "Milestone refund failed for '{:?}': {:?}", campaign_id, e);
+				}
 			}
 		}
 	}
@@ -383,6 +935,35 @@ pub mod pallet {
 		/// - `token_symbol`: a new custom token symbol
 		/// - `token_name`: a new custom token name
 		/// - `start`:
+		/// - `vault_id`: org treasury vault funding the deposit and receiving the raised funds,
+		///   the main org treasury if not set
+		/// - `milestones`: Grant campaign milestones, amounts sum up to the target
+		/// - `loan_terms`: Lend or Loan campaign repayment terms
+		/// - `retained_share`: Share campaign token kept by the org treasury
+		/// - `pool_terms`: Pool campaign redemption terms
+		/// - `withdrawal_terms`: fee and cut-off window of the contribution withdrawals,
+		///   contributions are withdrawn free of charge until the expiry if not set
+		/// - `hard_cap`: campaign balance finishing the campaign early and the overfunding policy,
+		///   the campaign is open until the expiry if not set
+		/// - `reward_tiers`: contribution reward tiers ordered by the minimum contribution
+		/// - `currencies`: currencies accepted besides the payment token, valued in the payment token
+		///   by the price oracle
+		///
+		/// Raised funds of a Grant campaign stay reserved in the org treasury and are released
+		/// milestone by milestone, see `approve_milestone`.
+		/// Raised funds of a Lend or Loan campaign are lent to the org and repaid by installments,
+		/// see `repay_loan`.
+		/// Succeeded Share campaign issues a token with the campaign symbol and name to the contributors
+		/// pro rata, the retained share goes to the org treasury. The token metadata is registered when
+		/// the campaign is created, the token id must not be in use.
+		/// Campaign succeeds as soon as the hard cap is reached, the target is the soft cap fixing the outcome
+		/// at the expiry.
+		/// Reward tiers create the campaign NFT collection held by the org treasury, the contributors of the succeeded
+		/// campaign get the NFT of the highest tier they qualify for when settling the contributions.
+		/// Contributions in the accepted currencies are held in kind and count towards the target and the hard cap
+		/// by their value at the contribution time.
+		/// Pool campaign has no cap and is not settled at the expiry: contributors get the pool shares
+		/// and redeem them during the redemption windows, see `redeem_shares`.
 		///
 		/// The two params `token_symbol` and `token_name` are meant for setting up a new custom token if creator wants to
 		/// conduct a token generation event. Therefore these two are optionals and would result in a TGE dropping
@@ -391,26 +972,41 @@ pub mod pallet {
 		/// Emits `CampaignCreated` event when successful.
 		///
 		/// Weight: `O(1)`
-		#[pallet::weight(T::WeightInfo::create_campaign())]
+		#[pallet::weight(<T as Config>::WeightInfo::create_campaign())]
 		#[transactional]
 		pub fn create_campaign(
 			origin: OriginFor<T>,
 			org_id: T::Hash,
 			admin_id: T::AccountId,
-			name: BoundedVec<u8, T::StringLimit>,
+			name: BoundedVec<u8, <T as Config>::StringLimit>,
 			target: T::Balance,
 			deposit: T::Balance,
 			expiry: T::BlockNumber,
 			protocol: FlowProtocol,
 			governance: FlowGovernance,
-			cid: BoundedVec<u8, T::StringLimit>,
+			cid: BoundedVec<u8, <T as Config>::StringLimit>,
 			start: Option<T::BlockNumber>,
-			token_symbol: Option<BoundedVec<u8, T::StringLimit>>,
-			token_name: Option<BoundedVec<u8, T::StringLimit>>,
+			token_symbol: Option<BoundedVec<u8, <T as Config>::StringLimit>>,
+			token_name: Option<BoundedVec<u8, <T as Config>::StringLimit>>,
+			vault_id: Option<VaultId>,
+			milestones: Milestones<T>,
+			loan_terms: Option<LoanTerms<T::BlockNumber>>,
+			retained_share: Option<Permill>,
+			pool_terms: Option<PoolTerms<T::BlockNumber>>,
+			withdrawal_terms: Option<WithdrawalTerms<T::BlockNumber>>,
+			hard_cap: Option<HardCap<T::Balance>>,
+			reward_tiers: RewardTiers<T>,
+			currencies: CampaignCurrencies<T>,
 		) -> DispatchResult {
 			let creator = ensure_signed(origin)?;
-			let prime = T::Control::org_prime_account(&org_id).ok_or(Error::<T>::OrgPrimeUnknown)?;
-			ensure!(creator == prime, Error::<T>::AuthorizationError);
+			T::Control::org_prime_account(&org_id).ok_or(Error::<T>::OrgPrimeUnknown)?;
+			if vault_id.is_some() {
+				T::Control::org_vault_account(&org_id, vault_id).ok_or(Error::<T>::VaultUnknown)?;
+			}
+			ensure!(
+				T::Control::has_org_permission(&org_id, &creator, OrgPermission::CreateCampaign),
+				Error::<T>::AuthorizationError
+			);
 			ensure!((name.len() as u32) >= T::MinNameLength::get(), Error::<T>::NameTooShort);
 
 			// Campaign deposit validation:
@@ -426,6 +1022,19 @@ pub mod pallet {
 			ensure!(expiry > current_block, Error::<T>::OutOfBounds);
 			ensure!(expiry <= starts + max_duration, Error::<T>::OutOfBounds);
 			ensure!(expiry >= starts + min_duration, Error::<T>::OutOfBounds);
+			Self::ensure_milestones(&protocol, &milestones, target, expiry)?;
+			Self::ensure_loan_terms(&protocol, &loan_terms)?;
+			Self::ensure_share_terms(&protocol, &token_symbol, &token_name, &retained_share)?;
+			Self::ensure_pool_terms(&protocol, &pool_terms)?;
+			ensure!(
+				protocol != FlowProtocol::Pool || withdrawal_terms.is_none(),
+				Error::<T>::InvalidWithdrawalTerms
+			);
+			if let Some(hard_cap) = &hard_cap {
+				ensure!(protocol != FlowProtocol::Pool && hard_cap.amount >= target, Error::<T>::InvalidHardCap);
+			}
+			Self::ensure_reward_tiers(&protocol, &reward_tiers)?;
+			Self::ensure_currencies(&protocol, &milestones, &currencies)?;
 
 			let index = CampaignCount::<T>::get();
 			let campaign = types::Campaign {
@@ -435,7 +1044,35 @@ pub mod pallet {
 			};
 
 			let campaign_id: T::Hash = T::Hashing::hash_of(&campaign);
-			Self::mint_campaign(&campaign_id, campaign)?;
+			if campaign.protocol == FlowProtocol::Share {
+				Self::register_campaign_token(&campaign)?;
+			}
+			if !reward_tiers.is_empty() {
+				let max_supply = reward_tiers.iter().fold(0u32, |total, tier| total.saturating_add(tier.quantity));
+				let collection_owner = T::Control::org_treasury_account(&org_id).ok_or(Error::<T>::TreasuryNotExist)?;
+				let collection_id = Self::create_collection(collection_owner, Some(max_supply), campaign.cid.clone())?;
+				RewardCollection::<T>::insert(&campaign_id, collection_id);
+				CampaignRewardTiers::<T>::insert(&campaign_id, reward_tiers);
+			}
+			Self::mint_campaign(&campaign_id, campaign, vault_id, milestones)?;
+			if let Some(loan_terms) = loan_terms {
+				CampaignLoanTerms::<T>::insert(&campaign_id, loan_terms);
+			}
+			if let Some(retained_share) = retained_share {
+				CampaignRetainedShare::<T>::insert(&campaign_id, retained_share);
+			}
+			if let Some(pool_terms) = pool_terms {
+				CampaignPoolTerms::<T>::insert(&campaign_id, pool_terms);
+			}
+			if let Some(withdrawal_terms) = withdrawal_terms {
+				CampaignWithdrawalTerms::<T>::insert(&campaign_id, withdrawal_terms);
+			}
+			if let Some(hard_cap) = hard_cap {
+				CampaignHardCap::<T>::insert(&campaign_id, hard_cap);
+			}
+			if !currencies.is_empty() {
+				CampaignCurrencyIds::<T>::insert(&campaign_id, currencies);
+			}
 			Self::deposit_event(Event::Created {
 				campaign_id, creator, admin: admin_id, target, deposit, expiry, name
 			});
@@ -444,41 +1081,690 @@ pub mod pallet {
 
 		/// Contribute to project
 		///
+		/// Contribution is held by the org treasury until the campaign outcome is fixed.
+		/// Campaign succeeds at once when the contribution reaches the hard cap, the part exceeding
+		/// the hard cap is handled by the campaign overfunding policy.
+		///
 		/// - `campaign_id`:
 		/// - `contribution`:
 		///
 		/// Emits `CampaignContributed` event when successful.
 		///
 		/// Weight: O(1)
-		#[pallet::weight(T::WeightInfo::contribute())]
+		#[pallet::weight(<T as Config>::WeightInfo::contribute())]
 		#[transactional]
 		pub fn contribute(origin: OriginFor<T>, campaign_id: T::Hash, contribution: T::Balance) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_contribute(sender, campaign_id, T::PaymentTokenId::get(), contribution)
+		}
+
+		/// Contribute to project in the currency accepted by the campaign
+		///
+		/// Contribution is held in kind by the org treasury and valued in the payment token by the price oracle,
+		/// the value counts towards the target and the hard cap. Contribution exceeding the hard cap is rejected.
+		///
+		/// - `campaign_id`: Campaign id
+		/// - `currency_id`: Accepted currency, the payment token is contributed as by `contribute`
+		/// - `amount`: Amount in the currency
+		///
+		/// Emits `AssetContributed` event when successful.
+		///
+		/// Weight: O(1)
+		#[pallet::weight(<T as Config>::WeightInfo::contribute_asset())]
+		#[transactional]
+		pub fn contribute_asset(
+			origin: OriginFor<T>,
+			campaign_id: T::Hash,
+			currency_id: T::CurrencyId,
+			amount: T::Balance
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_contribute(sender, campaign_id, currency_id, amount)
+		}
+
+		/// Withdraw the contribution from the active campaign
+		///
+		/// Withdrawn amount less the withdrawal fee is returned to the contributor, the fee goes to
+		/// the org treasury. Withdrawals are closed within the cut-off window before the expiry,
+		/// Pool contributions are redeemed instead. Contributions in other currencies than the payment token
+		/// are withdrawn in kind, together with the whole contribution only.
+		///
+		/// - `campaign_id`: Campaign id
+		/// - `amount`: Amount to withdraw, the remaining contribution is zero or at least the minimum contribution
+		///
+		/// Emits `ContributionWithdrawn` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_contribution())]
+		#[transactional]
+		pub fn withdraw_contribution(origin: OriginFor<T>, campaign_id: T::Hash, amount: T::Balance) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+			let terms = CampaignWithdrawalTerms::<T>::get(campaign_id);
 			let block_number = <frame_system::Pallet<T>>::block_number();
-
-			ensure!(block_number < campaign.expiry, Error::<T>::CampaignExpired);
-			ensure!(campaign.owner != sender, Error::<T>::NoContributionToOwnCampaign);
+			let cutoff = terms.as_ref().map(|t| t.cutoff).unwrap_or_else(Zero::zero);
+			ensure!(campaign.protocol != FlowProtocol::Pool, Error::<T>::WithdrawalClosed);
 			ensure!(
-				CampaignStates::<T>::get(campaign_id) == CampaignState::Active,
-				Error::<T>::NoContributionsAllowed
+				matches!(CampaignStates::<T>::get(campaign_id), CampaignState::Active | CampaignState::Paused),
+				Error::<T>::WithdrawalClosed
 			);
-			ensure!(contribution >= T::MinContribution::get(), Error::<T>::ContributionInsufficient);
+			ensure!(block_number.saturating_add(cutoff) < campaign.expiry, Error::<T>::WithdrawalClosed);
 
-			Self::create_contribution(sender.clone(), campaign_id, contribution)?;
-			Self::deposit_event(Event::Contributed {
-				campaign_id, sender,
-				contribution, block_number,
-			});
+			let contribution = CampaignContribution::<T>::get(campaign_id, &sender);
+			let assets_value = Self::assets_value(&ContributionAssets::<T>::get(campaign_id, &sender));
+			ensure!(
+				!amount.is_zero() && (amount == contribution || amount <= contribution.saturating_sub(assets_value)),
+				Error::<T>::BalanceLow
+			);
+			let remaining = contribution - amount;
+			ensure!(
+				remaining.is_zero() || remaining >= T::MinContribution::get(),
+				Error::<T>::ContributionInsufficient
+			);
 
-			Ok(())
+			// Contributions in other currencies are withdrawn in kind with the whole contribution
+			let assets = if remaining.is_zero() {
+				ContributionAssets::<T>::take(campaign_id, &sender)
+			} else {
+				Default::default()
+			};
+			let fee_rate = terms.map(|t| t.fee).unwrap_or_else(Zero::zero);
+			let fee = fee_rate.mul_floor(amount);
+			let treasury_id = T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+				.ok_or(Error::<T>::TreasuryNotExist)?;
+			let withdrawals = assets.iter()
+				.map(|asset| (asset.currency_id, asset.amount))
+				.chain(sp_std::iter::once((T::PaymentTokenId::get(), amount.saturating_sub(Self::assets_value(&assets)))));
+			for (currency_id, amount) in withdrawals {
+				let fee = fee_rate.mul_floor(amount);
+				let not_returned = <T as Config>::Currency::repatriate_reserved(
+					currency_id, &treasury_id, &sender, amount - fee, BalanceStatus::Free
+				)?;
+				ensure!(not_returned.is_zero(), Error::<T>::TreasuryBalanceLow);
+				<T as Config>::Currency::unreserve(currency_id, &treasury_id, fee);
+			}
+			if !assets.is_empty() {
+				CampaignAssets::<T>::mutate(campaign_id, |campaign_assets| Self::sub_assets(campaign_assets, &assets));
+			}
+
+			if remaining.is_zero() {
+				CampaignContribution::<T>::remove(campaign_id, &sender);
+				CampaignContributorsCount::<T>::mutate(campaign_id, |count| *count = count.saturating_sub(1));
+			} else {
+				CampaignContribution::<T>::insert(campaign_id, &sender, remaining);
+			}
+			CampaignBalance::<T>::mutate(campaign_id, |balance| *balance = balance.saturating_sub(amount));
+
+			Self::deposit_event(Event::ContributionWithdrawn { campaign_id, who: sender, amount, fee });
+			Ok(())
+		}
+
+		/// Settle the contribution to the finalized campaign
+		///
+		/// Failed or cancelled campaign contribution is refunded in kind, succeeded Share campaign issues
+		/// the campaign token for the contribution. Contribution part exceeding the hard cap is refunded.
+		/// Contributor of the succeeded campaign gets the NFT of the highest reward tier the contribution
+		/// qualifies for, sold out tiers are skipped.
+		/// Anyone can settle the contribution on behalf of the contributor.
+		///
+		/// - `campaign_id`: Campaign id
+		/// - `contributor`: Contributor account
+		///
+		/// Emits `ContributionSettled` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::settle_contribution())]
+		#[transactional]
+		pub fn settle_contribution(
+			origin: OriginFor<T>,
+			campaign_id: T::Hash,
+			contributor: T::AccountId
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+			ensure!(CampaignContribution::<T>::contains_key(campaign_id, &contributor), Error::<T>::NothingToClaim);
+			ensure!(!ContributionSettled::<T>::get(campaign_id, &contributor), Error::<T>::AlreadySettled);
+			let contribution = CampaignContribution::<T>::get(campaign_id, &contributor);
+
+			let amount = match CampaignStates::<T>::get(campaign_id) {
+				CampaignState::Failed | CampaignState::Cancelled => {
+					let treasury_id = T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+						.ok_or(Error::<T>::TreasuryNotExist)?;
+					// Contributions in other currencies are refunded in kind
+					let assets = ContributionAssets::<T>::get(campaign_id, &contributor);
+					let refunds = assets.iter()
+						.map(|asset| (asset.currency_id, asset.amount))
+						.chain(sp_std::iter::once((
+							T::PaymentTokenId::get(), contribution.saturating_sub(Self::assets_value(&assets))
+						)));
+					for (currency_id, amount) in refunds {
+						let remaining = <T as Config>::Currency::repatriate_reserved(
+							currency_id, &treasury_id, &contributor, amount, BalanceStatus::Free
+						)?;
+						ensure!(remaining.is_zero(), Error::<T>::TreasuryBalanceLow);
+					}
+					contribution
+				},
+				CampaignState::Succeeded | CampaignState::Defaulted => {
+					let excess = ContributionExcess::<T>::take(campaign_id, &contributor);
+					if !excess.is_zero() {
+						let treasury_id = T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+							.ok_or(Error::<T>::TreasuryNotExist)?;
+						let remaining = <T as Config>::Currency::repatriate_reserved(
+							T::PaymentTokenId::get(), &treasury_id, &contributor, excess, BalanceStatus::Free
+						)?;
+						ensure!(remaining.is_zero(), Error::<T>::TreasuryBalanceLow);
+						Self::deposit_event(Event::ExcessRefunded { campaign_id, who: contributor.clone(), amount: excess });
+					}
+					let rewarded = Self::mint_reward(campaign_id, &contributor, contribution);
+					// Share campaign token pro rata to the contribution
+					match CampaignToken::<T>::get(campaign_id) {
+						Some(currency_id) => {
+							let retained = CampaignRetainedShare::<T>::get(campaign_id).unwrap_or_default();
+							let amount = contribution.saturating_sub(retained.mul_floor(contribution));
+							<T as Config>::Currency::deposit(currency_id, &contributor, amount)?;
+							amount
+						},
+						None if !excess.is_zero() || rewarded => Zero::zero(),
+						None => return Err(Error::<T>::NothingToClaim.into()),
+					}
+				},
+				_ => return Err(Error::<T>::CampaignNotFinalized.into()),
+			};
+			ContributionSettled::<T>::insert(campaign_id, &contributor, true);
+
+			Self::deposit_event(Event::ContributionSettled { campaign_id, who: contributor, amount });
+			Ok(())
+		}
+
+		/// Pause the campaign
+		///
+		/// Contributions are blocked until the campaign is resumed. Paused campaign is not
+		/// finalized at the expiry, the expiry is shifted when the campaign is resumed.
+		///
+		/// Allowed origins: Root or the campaign admin.
+		///
+		/// - `campaign_id`: Campaign id
+		///
+		/// Emits `Paused` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::pause_campaign())]
+		pub fn pause_campaign(origin: OriginFor<T>, campaign_id: T::Hash) -> DispatchResult {
+			let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+			Self::ensure_campaign_admin(origin, &campaign)?;
+			ensure!(CampaignStates::<T>::get(campaign_id) == CampaignState::Active, Error::<T>::InvalidCampaignState);
+
+			CampaignStates::<T>::insert(campaign_id, CampaignState::Paused);
+			Self::deposit_event(Event::Paused { campaign_id });
+			Ok(())
+		}
+
+		/// Resume the paused campaign
+		///
+		/// Pool campaigns stay open after the expiry, their expiry can't be changed.
+		///
+		/// Allowed origins: Root or the campaign admin.
+		///
+		/// - `campaign_id`: Campaign id
+		/// - `expiry`: New campaign expiry, required if the campaign expired while paused
+		///
+		/// Emits `Resumed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::resume_campaign())]
+		#[transactional]
+		pub fn resume_campaign(
+			origin: OriginFor<T>,
+			campaign_id: T::Hash,
+			expiry: Option<T::BlockNumber>
+		) -> DispatchResult {
+			let mut campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+			Self::ensure_campaign_admin(origin, &campaign)?;
+			ensure!(CampaignStates::<T>::get(campaign_id) == CampaignState::Paused, Error::<T>::InvalidCampaignState);
+			if campaign.protocol == FlowProtocol::Pool {
+				// Deposit is returned once at the expiry
+				ensure!(expiry.map_or(true, |expiry| expiry == campaign.expiry), Error::<T>::OutOfBounds);
+				CampaignStates::<T>::insert(campaign_id, CampaignState::Active);
+				Self::deposit_event(Event::Resumed { campaign_id, expiry: campaign.expiry });
+				return Ok(())
+			}
+
+			let expiry = expiry.unwrap_or(campaign.expiry);
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			let (min_duration, max_duration) = T::CampaignDurationLimits::get();
+			ensure!(expiry > current_block, Error::<T>::OutOfBounds);
+			ensure!(expiry <= campaign.start + max_duration, Error::<T>::OutOfBounds);
+			ensure!(expiry >= campaign.start + min_duration, Error::<T>::OutOfBounds);
+			if expiry != campaign.expiry {
+				let milestones = CampaignMilestones::<T>::get(campaign_id);
+				Self::ensure_milestones(&campaign.protocol, &milestones, campaign.cap, expiry)?;
+				CampaignsByBlock::<T>::mutate(BlockType::Expiry, campaign.expiry, |campaigns| {
+					campaigns.retain(|id| *id != campaign_id)
+				});
+				CampaignsByBlock::<T>::try_mutate(
+					BlockType::Expiry, expiry, |campaigns| -> Result<(), DispatchError> {
+						campaigns.try_push(campaign_id).map_err(|_| Error::<T>::CampaignsPerBlockExceeded)?;
+						Ok(())
+					}
+				)?;
+				campaign.expiry = expiry;
+				CampaignOf::<T>::insert(campaign_id, campaign);
+			}
+
+			CampaignStates::<T>::insert(campaign_id, CampaignState::Active);
+			Self::deposit_event(Event::Resumed { campaign_id, expiry });
+			Ok(())
+		}
+
+		/// Lock the campaign
+		///
+		/// Circuit breaker: contributions, settlement and redemptions are blocked, the campaign is not
+		/// finalized at the expiry. Locked campaign is unlocked or cancelled.
+		///
+		/// Allowed origins: Root or member with ManageState permission.
+		///
+		/// - `campaign_id`: Campaign id
+		///
+		/// Emits `Locked` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::lock_campaign())]
+		pub fn lock_campaign(origin: OriginFor<T>, campaign_id: T::Hash) -> DispatchResult {
+			let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+			Self::ensure_campaign_manager(origin, &campaign)?;
+			ensure!(
+				matches!(
+					CampaignStates::<T>::get(campaign_id),
+					CampaignState::Created | CampaignState::Active | CampaignState::Paused
+				),
+				Error::<T>::InvalidCampaignState
+			);
+
+			CampaignStates::<T>::insert(campaign_id, CampaignState::Locked);
+			Self::deposit_event(Event::Locked { campaign_id });
+			Ok(())
+		}
+
+		/// Unlock the campaign
+		///
+		/// Unlocked campaign waits for the start or is paused until resumed by the admin,
+		/// with the new expiry if the campaign expired while locked.
+		///
+		/// Allowed origins: Root or member with ManageState permission.
+		///
+		/// - `campaign_id`: Campaign id
+		///
+		/// Emits `Unlocked` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::unlock_campaign())]
+		pub fn unlock_campaign(origin: OriginFor<T>, campaign_id: T::Hash) -> DispatchResult {
+			let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+			Self::ensure_campaign_manager(origin, &campaign)?;
+			ensure!(CampaignStates::<T>::get(campaign_id) == CampaignState::Locked, Error::<T>::InvalidCampaignState);
+
+			let state = if <frame_system::Pallet<T>>::block_number() < campaign.start {
+				CampaignState::Created
+			} else {
+				CampaignState::Paused
+			};
+			CampaignStates::<T>::insert(campaign_id, state.clone());
+			Self::deposit_event(Event::Unlocked { campaign_id, state });
+			Ok(())
+		}
+
+		/// Cancel the campaign
+		///
+		/// Campaign deposit is returned to the org treasury, contributors get the refunds by settling
+		/// the contributions. Pool campaigns can't be cancelled, the shares are redeemed instead.
+		///
+		/// Allowed origins: Root, the campaign admin or member with ManageState permission.
+		/// Locked campaign is cancelled by Root or member with ManageState permission only.
+		///
+		/// - `campaign_id`: Campaign id
+		///
+		/// Emits `Cancelled` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_campaign())]
+		#[transactional]
+		pub fn cancel_campaign(origin: OriginFor<T>, campaign_id: T::Hash) -> DispatchResult {
+			let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+			let state = CampaignStates::<T>::get(campaign_id);
+			if state == CampaignState::Locked || Self::ensure_campaign_admin(origin.clone(), &campaign).is_err() {
+				Self::ensure_campaign_manager(origin, &campaign)?;
+			}
+			ensure!(
+				matches!(
+					state,
+					CampaignState::Created | CampaignState::Active | CampaignState::Paused | CampaignState::Locked
+				),
+				Error::<T>::InvalidCampaignState
+			);
+			ensure!(campaign.protocol != FlowProtocol::Pool, Error::<T>::InvalidCampaignState);
+
+			let treasury_id = T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+				.ok_or(Error::<T>::TreasuryNotExist)?;
+			<T as Config>::Currency::unreserve(T::ProtocolTokenId::get(), &treasury_id, campaign.deposit);
+			CampaignsByBlock::<T>::mutate(BlockType::Start, campaign.start, |campaigns| {
+				campaigns.retain(|id| *id != campaign_id)
+			});
+			CampaignsByBlock::<T>::mutate(BlockType::Expiry, campaign.expiry, |campaigns| {
+				campaigns.retain(|id| *id != campaign_id)
+			});
+
+			CampaignStates::<T>::insert(campaign_id, CampaignState::Cancelled);
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			Self::deposit_event(Event::Cancelled { campaign_id, block_number });
+			Ok(())
+		}
+
+		/// Approve or reject the Grant campaign milestone
+		///
+		/// Approved milestone funds are released to the org treasury, milestones are approved in order.
+		/// Rejected milestone funds are claimed back by the contributors pro rata.
+		/// Contributors approve milestones by the Withdrawal proposals as well.
+		///
+		/// Allowed origins: Root or, if the campaign is not governed by the contributors,
+		/// member with SpendFunds permission.
+		///
+		/// - `campaign_id`: Campaign id
+		/// - `index`: Milestone index
+		/// - `approved`: release or refund the milestone funds
+		///
+		/// Emits `MilestoneReleased` or `MilestoneRefunded` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::approve_milestone())]
+		#[transactional]
+		pub fn approve_milestone(
+			origin: OriginFor<T>,
+			campaign_id: T::Hash,
+			index: u32,
+			approved: bool
+		) -> DispatchResult {
+			let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+			if let Ok(who) = ensure_signed(origin.clone()) {
+				ensure!(campaign.governance == FlowGovernance::No, Error::<T>::AuthorizationError);
+				ensure!(
+					T::Control::has_org_permission(&campaign.org_id, &who, OrgPermission::SpendFunds),
+					Error::<T>::AuthorizationError
+				);
+			} else {
+				ensure_root(origin)?;
+			}
+			Self::do_settle_milestone(&campaign_id, index, approved)
+		}
+
+		/// Claim the milestone refund
+		///
+		/// Refunds the contributor's part of the refunded milestone, pro rata to the contribution.
+		/// Anyone can claim the refund on behalf of the contributor.
+		///
+		/// - `campaign_id`: Campaign id
+		/// - `index`: Milestone index
+		/// - `contributor`: Contributor account
+		///
+		/// Emits `MilestoneRefundClaimed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::claim_milestone_refund())]
+		#[transactional]
+		pub fn claim_milestone_refund(
+			origin: OriginFor<T>,
+			campaign_id: T::Hash,
+			index: u32,
+			contributor: T::AccountId
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+			let (amount, total) = MilestoneRefunds::<T>::get(campaign_id, index).ok_or(Error::<T>::MilestoneUnknown)?;
+			ensure!(CampaignContribution::<T>::contains_key(campaign_id, &contributor), Error::<T>::NothingToClaim);
+			ensure!(!MilestoneRefundClaimed::<T>::get((campaign_id, index), &contributor), Error::<T>::AlreadySettled);
+			let treasury_id = T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+				.ok_or(Error::<T>::TreasuryNotExist)?;
+
+			let contribution = CampaignContribution::<T>::get(campaign_id, &contributor);
+			let refund = Self::mul_div_floor(contribution, amount, total);
+			let remaining = <T as Config>::Currency::repatriate_reserved(
+				T::PaymentTokenId::get(), &treasury_id, &contributor, refund, BalanceStatus::Free
+			)?;
+			ensure!(remaining.is_zero(), Error::<T>::TreasuryBalanceLow);
+			MilestoneRefundClaimed::<T>::insert((campaign_id, index), &contributor, true);
+
+			Self::deposit_event(Event::MilestoneRefundClaimed { campaign_id, index, who: contributor, amount: refund });
+			Ok(())
+		}
+
+		/// Redeem the Pool campaign shares
+		///
+		/// Shares are redeemed for the proportional part of the pooled balance, rounded down.
+		///
+		/// - `campaign_id`: Campaign id
+		/// - `shares`: Redeemed shares
+		///
+		/// Emits `PoolSharesRedeemed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::redeem_shares())]
+		#[transactional]
+		pub fn redeem_shares(
+			origin: OriginFor<T>,
+			campaign_id: T::Hash,
+			shares: T::Balance
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+			let terms = CampaignPoolTerms::<T>::get(campaign_id).ok_or(Error::<T>::PoolUnknown)?;
+			ensure!(CampaignStates::<T>::get(campaign_id) != CampaignState::Locked, Error::<T>::InvalidCampaignState);
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			let elapsed = block_number.saturating_sub(campaign.start);
+			ensure!(
+				block_number >= campaign.start && elapsed % terms.redemption_period < terms.redemption_window,
+				Error::<T>::RedemptionClosed
+			);
+
+			let held = PoolShares::<T>::get(campaign_id, &who);
+			ensure!(!shares.is_zero() && shares <= held, Error::<T>::SharesInsufficient);
+			let mut pool = Pools::<T>::get(campaign_id);
+			let amount = Self::mul_div_floor(shares, pool.balance, pool.shares);
+			ensure!(!amount.is_zero(), Error::<T>::SharesInsufficient);
+
+			let treasury_id = T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+				.ok_or(Error::<T>::TreasuryNotExist)?;
+			let remaining = <T as Config>::Currency::repatriate_reserved(
+				T::PaymentTokenId::get(), &treasury_id, &who, amount, BalanceStatus::Free
+			)?;
+			ensure!(remaining.is_zero(), Error::<T>::TreasuryBalanceLow);
+
+			PoolShares::<T>::insert(campaign_id, &who, held - shares);
+			pool.shares = pool.shares.saturating_sub(shares);
+			pool.balance = pool.balance.saturating_sub(amount);
+			CampaignBalance::<T>::insert(campaign_id, pool.balance);
+			Pools::<T>::insert(campaign_id, pool);
+
+			Self::deposit_event(Event::PoolSharesRedeemed { campaign_id, who, shares, amount });
+			Ok(())
+		}
+
+		/// Repay the Lend or Loan campaign
+		///
+		/// Repayments are reserved in the org treasury and claimed by the lenders pro rata.
+		/// Org can repay any time ahead of the installments, the amount exceeding the due one is ignored.
+		///
+		/// Allowed origins: Root or member with SpendFunds permission.
+		///
+		/// - `campaign_id`: Campaign id
+		/// - `amount`: Repaid amount
+		///
+		/// Emits `LoanRepaid` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::repay_loan())]
+		#[transactional]
+		pub fn repay_loan(
+			origin: OriginFor<T>,
+			campaign_id: T::Hash,
+			amount: T::Balance
+		) -> DispatchResult {
+			let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+			if let Ok(who) = ensure_signed(origin.clone()) {
+				ensure!(
+					T::Control::has_org_permission(&campaign.org_id, &who, OrgPermission::SpendFunds),
+					Error::<T>::AuthorizationError
+				);
+			} else {
+				ensure_root(origin)?;
+			}
+			let mut loan = Loans::<T>::get(campaign_id).ok_or(Error::<T>::LoanUnknown)?;
+			let amount = amount.min(loan.due.saturating_sub(loan.repaid));
+			ensure!(!amount.is_zero(), Error::<T>::LoanUnknown);
+
+			let treasury_id = T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+				.ok_or(Error::<T>::TreasuryNotExist)?;
+			<T as Config>::Currency::reserve(T::PaymentTokenId::get(), &treasury_id, amount)
+				.map_err(|_| Error::<T>::TreasuryBalanceLow)?;
+			T::Control::ensure_committed_funds(&campaign.org_id, &treasury_id, T::PaymentTokenId::get())?;
+			loan.repaid = loan.repaid.saturating_add(amount);
+			Loans::<T>::insert(campaign_id, &loan);
+
+			Self::deposit_event(Event::LoanRepaid {
+				campaign_id, amount, repaid: loan.repaid,
+				block_number: <frame_system::Pallet<T>>::block_number(),
+			});
+			Ok(())
+		}
+
+		/// Claim the share of the loan repayments
+		///
+		/// Lenders get the repaid amount pro rata to their contributions.
+		///
+		/// - `campaign_id`: Campaign id
+		///
+		/// Emits `RepaymentClaimed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(<T as Config>::WeightInfo::claim_repayment())]
+		pub fn claim_repayment(
+			origin: OriginFor<T>,
+			campaign_id: T::Hash
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+			let loan = Loans::<T>::get(campaign_id).ok_or(Error::<T>::LoanUnknown)?;
+			let contribution = CampaignContribution::<T>::get(campaign_id, &who);
+			let claimed = LoanClaims::<T>::get(campaign_id, &who);
+			let amount = Self::mul_div_floor(loan.repaid, contribution, loan.principal).saturating_sub(claimed);
+			ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+			let treasury_id = T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+				.ok_or(Error::<T>::TreasuryNotExist)?;
+			let remaining = <T as Config>::Currency::repatriate_reserved(
+				T::PaymentTokenId::get(), &treasury_id, &who, amount, BalanceStatus::Free
+			)?;
+			let amount = amount.saturating_sub(remaining);
+			LoanClaims::<T>::insert(campaign_id, &who, claimed.saturating_add(amount));
+
+			Self::deposit_event(Event::RepaymentClaimed { campaign_id, who, amount });
+			Ok(())
+		}
+
+		/// Create the quadratic funding matching round
+		///
+		/// Matching pool is reserved in the org treasury, or in the protocol treasury if no org is set.
+		/// When the round ends the contributions to the matched campaigns are tallied in batches and the pool
+		/// is distributed to the succeeded campaigns pro rata to the square of the sum of the contribution
+		/// square roots less the sum of the contributions. Contributions above the contributor cap count as the cap.
+		/// Matching funds stay reserved in the campaign treasury and are released with the remaining milestones,
+		/// the share of a refunded milestone is returned to the funder. A campaign is matched by a single round.
+		///
+		/// Allowed origins: Root or member with SpendFunds permission of the funding org.
+		///
+		/// - `org_id`: Org funding the matching pool, the protocol treasury funds it for Root origin if not set
+		/// - `pool`: Matching pool in the payment token
+		/// - `start`: First block of the contribution window
+		/// - `end`: Last block of the contribution window
+		/// - `contributor_cap`: Contribution of a single contributor counted towards the matching
+		/// - `campaigns`: Matched Grant campaigns, running within the contribution window
+		///
+		/// Emits `MatchingRoundCreated` event when successful.
+		///
+		/// Weight: `O(C)` where C is the number of campaigns
+		#[pallet::weight(<T as Config>::WeightInfo::create_matching_round(campaigns.len() as u32))]
+		#[transactional]
+		pub fn create_matching_round(
+			origin: OriginFor<T>,
+			org_id: Option<T::Hash>,
+			pool: T::Balance,
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+			contributor_cap: T::Balance,
+			campaigns: RoundCampaigns<T>,
+		) -> DispatchResult {
+			let funder = match org_id {
+				Some(org_id) => {
+					if let Ok(who) = ensure_signed(origin.clone()) {
+						ensure!(
+							T::Control::has_org_permission(&org_id, &who, OrgPermission::SpendFunds),
+							Error::<T>::AuthorizationError
+						);
+					} else {
+						ensure_root(origin)?;
+					}
+					T::Control::org_treasury_account(&org_id).ok_or(Error::<T>::TreasuryNotExist)?
+				},
+				None => {
+					ensure_root(origin)?;
+					T::GameDAOTreasury::get()
+				},
+			};
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			ensure!(
+				!pool.is_zero() && !contributor_cap.is_zero() && !campaigns.is_empty(),
+				Error::<T>::InvalidMatchingRound
+			);
+			ensure!(end > start && end > current_block, Error::<T>::InvalidMatchingRound);
+			for (index, campaign_id) in campaigns.iter().enumerate() {
+				let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+				ensure!(
+					campaign.protocol == FlowProtocol::Grant && campaign.start >= start && campaign.expiry <= end &&
+						!campaigns[..index].contains(campaign_id) && !CampaignMatchingRound::<T>::contains_key(campaign_id),
+					Error::<T>::InvalidMatchingRound
+				);
+			}
+
+			<T as Config>::Currency::reserve(T::PaymentTokenId::get(), &funder, pool)
+				.map_err(|_| Error::<T>::TreasuryBalanceLow)?;
+			if let Some(org_id) = org_id {
+				T::Control::ensure_committed_funds(&org_id, &funder, T::PaymentTokenId::get())?;
+			}
+			let round_id = MatchingRoundCount::<T>::get();
+			MatchingRoundsByBlock::<T>::try_mutate(end, |rounds| -> Result<(), DispatchError> {
+				rounds.try_push(round_id).map_err(|_| Error::<T>::MatchingRoundsPerBlockExceeded)?;
+				Ok(())
+			})?;
+			MatchingRounds::<T>::insert(round_id, MatchingRound::<T> { org_id, funder, pool, start, end, contributor_cap });
+			for campaign_id in campaigns.iter() {
+				CampaignMatchingRound::<T>::insert(campaign_id, round_id);
+			}
+			MatchingRoundCampaigns::<T>::insert(round_id, campaigns);
+			MatchingRoundCount::<T>::set(round_id.checked_add(1).ok_or(Overflow)?);
+
+			Self::deposit_event(Event::MatchingRoundCreated { round_id, org_id, pool, start, end });
+			Ok(())
 		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
 
-	fn mint_campaign(campaign_id: &T::Hash, campaign: Campaign<T>) -> DispatchResult {
+	fn mint_campaign(
+		campaign_id: &T::Hash,
+		campaign: Campaign<T>,
+		vault_id: Option<VaultId>,
+		milestones: Milestones<T>
+	) -> DispatchResult {
 		let campaign_state =
 			if campaign.start > <frame_system::Pallet<T>>::block_number() {
 				CampaignState::Created
@@ -501,22 +1787,516 @@ impl<T: Config> Pallet<T> {
 		CampaignOf::<T>::insert(campaign_id, campaign.clone());
 		CampaignCount::<T>::set(campaign.index.checked_add(1).ok_or(Overflow)?);
 
-		let treasury_id = T::Control::org_treasury_account(&campaign.org_id).ok_or(Error::<T>::TreasuryNotExist)?;
-		T::Currency::reserve(
+		if let Some(vault_id) = vault_id {
+			CampaignVault::<T>::insert(campaign_id, vault_id);
+		}
+		for (index, milestone) in milestones.iter().enumerate() {
+			MilestonesByBlock::<T>::try_mutate(
+				milestone.deadline, |items| -> Result<(), DispatchError> {
+					items.try_push((*campaign_id, index as u32)).map_err(|_| Error::<T>::CampaignsPerBlockExceeded)?;
+					Ok(())
+				}
+			)?;
+		}
+		if !milestones.is_empty() {
+			CampaignMilestones::<T>::insert(campaign_id, milestones);
+		}
+		let treasury_id = T::Control::org_vault_account(&campaign.org_id, vault_id)
+			.ok_or(Error::<T>::TreasuryNotExist)?;
+		<T as Config>::Currency::reserve(
 			T::ProtocolTokenId::get(), &treasury_id, campaign.deposit
 		).map_err(|_| Error::<T>::TreasuryBalanceLow)?;
+		T::Control::ensure_committed_funds(&campaign.org_id, &treasury_id, T::ProtocolTokenId::get())?;
+
+		Ok(())
+	}
+
+	fn ensure_milestones(
+		protocol: &FlowProtocol,
+		milestones: &Milestones<T>,
+		target: T::Balance,
+		expiry: T::BlockNumber
+	) -> DispatchResult {
+		if *protocol != FlowProtocol::Grant {
+			ensure!(milestones.is_empty(), Error::<T>::InvalidMilestones);
+			return Ok(())
+		}
+		ensure!(!milestones.is_empty(), Error::<T>::InvalidMilestones);
+		let mut total: T::Balance = Zero::zero();
+		let mut last_deadline = expiry;
+		for milestone in milestones {
+			ensure!(!milestone.amount.is_zero() && milestone.deadline > last_deadline, Error::<T>::InvalidMilestones);
+			total = total.saturating_add(milestone.amount);
+			last_deadline = milestone.deadline;
+		}
+		ensure!(total == target, Error::<T>::InvalidMilestones);
+		Ok(())
+	}
+
+	fn ensure_loan_terms(protocol: &FlowProtocol, loan_terms: &Option<LoanTerms<T::BlockNumber>>) -> DispatchResult {
+		match (protocol, loan_terms) {
+			(FlowProtocol::Lend | FlowProtocol::Loan, Some(terms)) => {
+				ensure!(
+					terms.installments > 0 && terms.installments <= MaxInstallments::get() && !terms.period.is_zero(),
+					Error::<T>::InvalidLoanTerms
+				);
+				Ok(())
+			},
+			(FlowProtocol::Lend | FlowProtocol::Loan, None) => Err(Error::<T>::InvalidLoanTerms)?,
+			(_, Some(_)) => Err(Error::<T>::InvalidLoanTerms)?,
+			(_, None) => Ok(()),
+		}
+	}
+
+	fn ensure_share_terms(
+		protocol: &FlowProtocol,
+		token_symbol: &Option<BoundedVec<u8, <T as Config>::StringLimit>>,
+		token_name: &Option<BoundedVec<u8, <T as Config>::StringLimit>>,
+		retained_share: &Option<Permill>
+	) -> DispatchResult {
+		if *protocol != FlowProtocol::Share {
+			ensure!(retained_share.is_none(), Error::<T>::InvalidShareTerms);
+			return Ok(())
+		}
+		ensure!(token_symbol.is_some() && token_name.is_some(), Error::<T>::InvalidShareTerms);
+		ensure!(retained_share.map_or(true, |share| share < Permill::one()), Error::<T>::InvalidShareTerms);
+		Ok(())
+	}
+
+	/// Registers the metadata of the Share campaign token, the token id must not be in use.
+	fn register_campaign_token(campaign: &Campaign<T>) -> DispatchResult {
+		let currency_id = T::CampaignTokenId::convert(campaign.index);
+		ensure!(
+			!CampaignTokenMetadata::<T>::contains_key(currency_id) &&
+				<T as Config>::Currency::total_issuance(currency_id).is_zero(),
+			Error::<T>::CampaignTokenExists
+		);
+		let metadata = TokenMetadata::<T> {
+			name: campaign.token_name.clone().unwrap_or_default(),
+			symbol: campaign.token_symbol.clone().unwrap_or_default(),
+		};
+		CampaignTokenMetadata::<T>::insert(currency_id, metadata);
+		Ok(())
+	}
+
+	fn ensure_reward_tiers(protocol: &FlowProtocol, reward_tiers: &RewardTiers<T>) -> DispatchResult {
+		if reward_tiers.is_empty() {
+			return Ok(())
+		}
+		ensure!(*protocol != FlowProtocol::Pool, Error::<T>::InvalidRewardTiers);
+		ensure!(reward_tiers[0].min_contribution >= T::MinContribution::get(), Error::<T>::InvalidRewardTiers);
+		ensure!(reward_tiers.iter().all(|tier| tier.quantity > 0), Error::<T>::InvalidRewardTiers);
+		ensure!(
+			reward_tiers.windows(2).all(|pair| pair[0].min_contribution < pair[1].min_contribution),
+			Error::<T>::InvalidRewardTiers
+		);
+		Ok(())
+	}
+
+	fn ensure_currencies(
+		protocol: &FlowProtocol,
+		milestones: &Milestones<T>,
+		currencies: &CampaignCurrencies<T>
+	) -> DispatchResult {
+		if currencies.is_empty() {
+			return Ok(())
+		}
+		// Raised funds of these campaigns are released or lent in the payment token
+		ensure!(
+			!matches!(protocol, FlowProtocol::Pool | FlowProtocol::Lend | FlowProtocol::Loan) && milestones.is_empty(),
+			Error::<T>::InvalidCurrencies
+		);
+		for (index, currency_id) in currencies.iter().enumerate() {
+			ensure!(
+				*currency_id != T::PaymentTokenId::get() && !currencies[..index].contains(currency_id),
+				Error::<T>::InvalidCurrencies
+			);
+			ensure!(
+				T::PriceOracle::convert(*currency_id, T::PaymentTokenId::get(), T::MinContribution::get()).is_some(),
+				Error::<T>::InvalidCurrencies
+			);
+		}
+		Ok(())
+	}
+
+	fn ensure_campaign_admin(origin: T::RuntimeOrigin, campaign: &Campaign<T>) -> DispatchResult {
+		if let Ok(who) = ensure_signed(origin.clone()) {
+			ensure!(who == campaign.admin, Error::<T>::AuthorizationError);
+		} else {
+			ensure_root(origin)?;
+		}
+		Ok(())
+	}
+
+	fn ensure_campaign_manager(origin: T::RuntimeOrigin, campaign: &Campaign<T>) -> DispatchResult {
+		if let Ok(who) = ensure_signed(origin.clone()) {
+			ensure!(
+				T::Control::has_org_permission(&campaign.org_id, &who, OrgPermission::ManageState),
+				Error::<T>::AuthorizationError
+			);
+		} else {
+			ensure_root(origin)?;
+		}
+		Ok(())
+	}
+
+	fn ensure_pool_terms(protocol: &FlowProtocol, pool_terms: &Option<PoolTerms<T::BlockNumber>>) -> DispatchResult {
+		match (protocol, pool_terms) {
+			(FlowProtocol::Pool, Some(terms)) => {
+				ensure!(
+					!terms.redemption_window.is_zero() && terms.redemption_window <= terms.redemption_period,
+					Error::<T>::InvalidPoolTerms
+				);
+				Ok(())
+			},
+			(FlowProtocol::Pool, None) | (_, Some(_)) => Err(Error::<T>::InvalidPoolTerms)?,
+			(_, None) => Ok(()),
+		}
+	}
+
+	/// Tallies the contributions to the matched campaigns up to the batch size, the matching pool is
+	/// distributed once all campaigns are tallied.
+	/// Returns the number of tallied contributions and the number of campaigns if the round is settled.
+	fn tally_matching_round(
+		round_id: u32,
+		mut index: u32,
+		mut last_contributor: Option<T::AccountId>,
+		batch_size: u32
+	) -> (u32, Option<u32>) {
+		let round = match MatchingRounds::<T>::get(round_id) {
+			Some(round) => round,
+			None => {
+				log::error!(target: "runtime::gamedao_flow", "Matching round unknown: '{:?}'", round_id);
+				MatchingTallyQueue::<T>::remove(round_id);
+				return (0, None)
+			},
+		};
+		let campaigns = MatchingRoundCampaigns::<T>::get(round_id);
+		let mut tallied: u32 = 0;
+		while let Some(campaign_id) = campaigns.get(index as usize) {
+			// Only the succeeded campaigns are matched
+			if CampaignStates::<T>::get(campaign_id) == CampaignState::Succeeded {
+				let contributions = match &last_contributor {
+					Some(who) => CampaignContribution::<T>::iter_prefix_from(
+						campaign_id, CampaignContribution::<T>::hashed_key_for(campaign_id, who)
+					),
+					None => CampaignContribution::<T>::iter_prefix(campaign_id),
+				};
+				let batch = contributions.take((batch_size - tallied) as usize).collect::<Vec<_>>();
+				tallied += batch.len() as u32;
+				MatchingTallies::<T>::mutate(round_id, campaign_id, |tally| {
+					for (_, contribution) in &batch {
+						let counted = (*contribution).min(round.contributor_cap);
+						tally.sqrt_sum = tally.sqrt_sum.saturating_add(counted.integer_sqrt());
+						tally.sum = tally.sum.saturating_add(counted);
+					}
+				});
+				if tallied >= batch_size {
+					let last_contributor = batch.last().map(|(who, _)| who.clone());
+					MatchingTallyQueue::<T>::insert(round_id, (index, last_contributor));
+					return (tallied, None)
+				}
+			}
+			index += 1;
+			last_contributor = None;
+		}
+
+		MatchingTallyQueue::<T>::remove(round_id);
+		Self::distribute_matching_pool(round_id, &round, &campaigns);
+		(tallied, Some(campaigns.len() as u32))
+	}
+
+	/// Distributes the matching pool by the quadratic funding formula, the undistributed part is returned
+	/// to the funder. Campaigns with no pending milestone left are not matched.
+	fn distribute_matching_pool(round_id: u32, round: &MatchingRound<T>, campaigns: &RoundCampaigns<T>) {
+		let matches = campaigns.iter()
+			.map(|campaign_id| {
+				let tally = MatchingTallies::<T>::take(round_id, campaign_id);
+				let milestones = CampaignMilestones::<T>::get(campaign_id);
+				if Self::next_pending_milestone(campaign_id, &milestones).is_none() {
+					return (campaign_id, Zero::zero())
+				}
+				(campaign_id, tally.sqrt_sum.saturating_mul(tally.sqrt_sum).saturating_sub(tally.sum))
+			})
+			.collect::<Vec<_>>();
+		let total = matches.iter().fold(T::Balance::zero(), |acc, (_, quadratic)| acc.saturating_add(*quadratic));
+
+		let mut distributed = T::Balance::zero();
+		for (campaign_id, quadratic) in matches {
+			if quadratic.is_zero() {
+				continue
+			}
+			let maybe_treasury_id = CampaignOf::<T>::get(campaign_id).and_then(|campaign| {
+				T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+			});
+			let treasury_id = match maybe_treasury_id {
+				Some(treasury_id) => treasury_id,
+				None => {
+					log::error!(target: "runtime::gamedao_flow", "Treasury unknown for campaign: '{:?}'", campaign_id);
+					continue
+				},
+			};
+			let amount = Self::mul_div_floor(round.pool, quadratic, total);
+			let remaining = <T as Config>::Currency::repatriate_reserved(
+				T::PaymentTokenId::get(), &round.funder, &treasury_id, amount, BalanceStatus::Reserved
+			).unwrap_or(amount);
+			let amount = amount.saturating_sub(remaining);
+			CampaignMatchingFunds::<T>::insert(campaign_id, amount);
+			distributed = distributed.saturating_add(amount);
+			Self::deposit_event(Event::MatchingFundsDistributed { round_id, campaign_id: *campaign_id, amount });
+		}
+
+		let returned = round.pool.saturating_sub(distributed);
+		<T as Config>::Currency::unreserve(T::PaymentTokenId::get(), &round.funder, returned);
+		MatchingRoundStates::<T>::insert(round_id, RoundState::Settled);
+		Self::deposit_event(Event::MatchingRoundSettled { round_id, distributed, returned });
+	}
+
+	/// `a * b / c` rounded down, the rounding always favours the pool.
+	fn mul_div_floor(a: T::Balance, b: T::Balance, c: T::Balance) -> T::Balance {
+		multiply_by_rational_with_rounding(a.saturated_into(), b.saturated_into(), c.saturated_into(), Rounding::Down)
+			.map(|result| result.saturated_into())
+			.unwrap_or_else(Zero::zero)
+	}
+
+	/// Issues the shares for the contribution at the current net asset value.
+	fn issue_pool_shares(who: &T::AccountId, campaign_id: T::Hash, contribution: T::Balance) -> DispatchResult {
+		let mut pool = Pools::<T>::get(campaign_id);
+		let shares = if pool.shares.is_zero() {
+			contribution
+		} else {
+			ensure!(!pool.balance.is_zero(), Error::<T>::PoolDepleted);
+			Self::mul_div_floor(contribution, pool.shares, pool.balance)
+		};
+		ensure!(!shares.is_zero(), Error::<T>::ContributionInsufficient);
+
+		PoolShares::<T>::mutate(campaign_id, who, |held| *held = held.saturating_add(shares));
+		pool.shares = pool.shares.saturating_add(shares);
+		pool.balance = pool.balance.saturating_add(contribution);
+		Pools::<T>::insert(campaign_id, pool);
+
+		Self::deposit_event(Event::PoolSharesIssued { campaign_id, who: who.clone(), shares });
+		Ok(())
+	}
+
+	/// Principal and interest due until the installment (inclusive).
+	fn installment_due(loan: &Loan<T::Balance>, terms: &LoanTerms<T::BlockNumber>, installment: u32) -> T::Balance {
+		if installment + 1 >= terms.installments {
+			loan.due
+		} else {
+			Perbill::from_rational(installment + 1, terms.installments).mul_floor(loan.due)
+		}
+	}
+
+	/// Schedules the installment check, the check is moved to the next block if the block is full.
+	fn schedule_installment(campaign_id: &T::Hash, block_number: T::BlockNumber) {
+		let mut block_number = block_number;
+		for _ in 0..MaxInstallmentDelay::get() {
+			if LoansByBlock::<T>::try_mutate(block_number, |loans| loans.try_push(*campaign_id)).is_ok() {
+				return
+			}
+			block_number = block_number + 1u32.into();
+		}
+		log::error!(target: "runtime::gamedao_flow", "Installment check not scheduled for '{:?}'", campaign_id);
+	}
+
+	/// Loan defaults if the due installment was not repaid, otherwise the next installment is scheduled.
+	fn check_installment(campaign_id: &T::Hash, block_number: T::BlockNumber) {
+		let (mut loan, terms, campaign) = match (
+			Loans::<T>::get(campaign_id), CampaignLoanTerms::<T>::get(campaign_id), CampaignOf::<T>::get(campaign_id)
+		) {
+			(Some(loan), Some(terms), Some(campaign)) => (loan, terms, campaign),
+			_ => return,
+		};
+		if CampaignStates::<T>::get(campaign_id) != CampaignState::Succeeded {
+			return
+		}
+		if loan.repaid < Self::installment_due(&loan, &terms, loan.installment) {
+			CampaignStates::<T>::insert(campaign_id, CampaignState::Defaulted);
+			Self::deposit_event(Event::LoanDefaulted { campaign_id: *campaign_id, installment: loan.installment, block_number });
+			return
+		}
+		loan.installment += 1;
+		if loan.installment < terms.installments && loan.repaid < loan.due {
+			let next_block = campaign.expiry + terms.period * (loan.installment + 1).into();
+			Self::schedule_installment(campaign_id, next_block.max(block_number + 1u32.into()));
+		}
+		Loans::<T>::insert(campaign_id, loan);
+	}
+
+	/// Milestone share of the campaign balance, rounding leftovers go to the last milestone.
+	fn milestone_amount(campaign: &Campaign<T>, balance: T::Balance, milestones: &Milestones<T>, index: u32) -> T::Balance {
+		let share = |milestone: &Milestone<T>| Perbill::from_rational(milestone.amount, campaign.cap).mul_floor(balance);
+		if index as usize + 1 == milestones.len() {
+			let others = milestones.iter()
+				.take(index as usize)
+				.fold(T::Balance::zero(), |acc, milestone| acc.saturating_add(share(milestone)));
+			balance.saturating_sub(others)
+		} else {
+			milestones.get(index as usize).map(share).unwrap_or_else(Zero::zero)
+		}
+	}
+
+	fn next_pending_milestone(campaign_id: &T::Hash, milestones: &Milestones<T>) -> Option<u32> {
+		(0..milestones.len() as u32).find(|index| MilestoneStates::<T>::get(campaign_id, index) == MilestoneState::Pending)
+	}
 
+	/// Releases the milestone funds to the org treasury or fixes the refund claimed by the contributors.
+	fn do_settle_milestone(campaign_id: &T::Hash, index: u32, approved: bool) -> DispatchResult {
+		let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+		ensure!(CampaignStates::<T>::get(campaign_id) == CampaignState::Succeeded, Error::<T>::CampaignUnsucceeded);
+		let milestones = CampaignMilestones::<T>::get(campaign_id);
+		let milestone = milestones.get(index as usize).ok_or(Error::<T>::MilestoneUnknown)?;
+		ensure!(MilestoneStates::<T>::get(campaign_id, index) == MilestoneState::Pending, Error::<T>::MilestoneUnknown);
+		let treasury_id = T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+			.ok_or(Error::<T>::TreasuryNotExist)?;
+		let amount = Self::milestone_amount(&campaign, CampaignBalance::<T>::get(campaign_id), &milestones, index);
+		let matching = Self::milestone_matching_amount(campaign_id, &milestones, index);
+		let block_number = <frame_system::Pallet<T>>::block_number();
+
+		if approved {
+			ensure!(Self::next_pending_milestone(campaign_id, &milestones) == Some(index), Error::<T>::MilestoneUnknown);
+			ensure!(block_number <= milestone.deadline, Error::<T>::MilestoneExpired);
+			let amount = amount.saturating_add(matching);
+			<T as Config>::Currency::unreserve(T::PaymentTokenId::get(), &treasury_id, amount);
+			MilestoneStates::<T>::insert(campaign_id, index, MilestoneState::Released);
+			Self::deposit_event(Event::MilestoneReleased { campaign_id: *campaign_id, index, amount, block_number });
+		} else {
+			if !matching.is_zero() {
+				Self::return_matching_funds(campaign_id, &treasury_id, matching)?;
+			}
+			MilestoneRefunds::<T>::insert(campaign_id, index, (amount, CampaignRaised::<T>::get(campaign_id)));
+			MilestoneStates::<T>::insert(campaign_id, index, MilestoneState::Refunded);
+			Self::deposit_event(Event::MilestoneRefunded { campaign_id: *campaign_id, index, amount, block_number });
+		}
+		if !matching.is_zero() {
+			CampaignMatchingFunds::<T>::mutate(campaign_id, |funds| *funds = funds.saturating_sub(matching));
+		}
+		Ok(())
+	}
+
+	/// Share of the matching funds settled with the milestone, pro rata to the pending milestone amounts.
+	fn milestone_matching_amount(campaign_id: &T::Hash, milestones: &Milestones<T>, index: u32) -> T::Balance {
+		let funds = CampaignMatchingFunds::<T>::get(campaign_id);
+		if funds.is_zero() {
+			return funds
+		}
+		let pending = (0..milestones.len() as u32)
+			.filter(|i| MilestoneStates::<T>::get(campaign_id, i) == MilestoneState::Pending)
+			.collect::<Vec<_>>();
+		if pending.len() <= 1 {
+			return funds
+		}
+		let pending_total = pending.iter()
+			.fold(T::Balance::zero(), |acc, i| acc.saturating_add(milestones[*i as usize].amount));
+		Self::mul_div_floor(funds, milestones[index as usize].amount, pending_total)
+	}
+
+	/// Returns the matching funds of the refunded milestone to the funder of the campaign matching round.
+	fn return_matching_funds(campaign_id: &T::Hash, treasury_id: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		let round_id = CampaignMatchingRound::<T>::get(campaign_id).ok_or(Error::<T>::MatchingRoundUnknown)?;
+		let round = MatchingRounds::<T>::get(round_id).ok_or(Error::<T>::MatchingRoundUnknown)?;
+		let remaining = <T as Config>::Currency::repatriate_reserved(
+			T::PaymentTokenId::get(), treasury_id, &round.funder, amount, BalanceStatus::Free
+		)?;
+		ensure!(remaining.is_zero(), Error::<T>::TreasuryBalanceLow);
+		Self::deposit_event(Event::MatchingFundsReturned { round_id, campaign_id: *campaign_id, amount });
 		Ok(())
 	}
 
-	fn create_contribution(sender: T::AccountId, campaign_id: T::Hash, contribution: T::Balance) -> DispatchResult {
+	fn do_contribute(
+		sender: T::AccountId,
+		campaign_id: T::Hash,
+		currency_id: T::CurrencyId,
+		amount: T::Balance
+	) -> DispatchResult {
+		let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+		let block_number = <frame_system::Pallet<T>>::block_number();
+
+		let is_pool = campaign.protocol == FlowProtocol::Pool;
+		ensure!(is_pool || block_number < campaign.expiry, Error::<T>::CampaignExpired);
+		ensure!(campaign.owner != sender, Error::<T>::NoContributionToOwnCampaign);
+		ensure!(
+			CampaignStates::<T>::get(campaign_id) == CampaignState::Active,
+			Error::<T>::NoContributionsAllowed
+		);
+		let is_asset = currency_id != T::PaymentTokenId::get();
+		let value = if is_asset {
+			ensure!(CampaignCurrencyIds::<T>::get(campaign_id).contains(&currency_id), Error::<T>::CurrencyNotAccepted);
+			T::PriceOracle::convert(currency_id, T::PaymentTokenId::get(), amount).ok_or(Error::<T>::PriceUnavailable)?
+		} else {
+			amount
+		};
+		ensure!(value >= T::MinContribution::get(), Error::<T>::ContributionInsufficient);
+
+		let hard_cap = CampaignHardCap::<T>::get(campaign_id);
+		let (contribution, excess) = match &hard_cap {
+			Some(hard_cap) => {
+				let room = hard_cap.amount.saturating_sub(CampaignBalance::<T>::get(campaign_id));
+				match hard_cap.policy {
+					OverfundingPolicy::Trim if !is_asset => (value.min(room), Zero::zero()),
+					OverfundingPolicy::Refund if !is_asset => (value.min(room), value.saturating_sub(room)),
+					// Contributions held in kind are not split
+					_ => {
+						ensure!(value <= room, Error::<T>::HardCapExceeded);
+						(value, Zero::zero())
+					},
+				}
+			},
+			None => (value, Zero::zero()),
+		};
+
+		if is_asset {
+			Self::create_asset_contribution(sender.clone(), campaign_id, &campaign, currency_id, amount, value)?;
+			Self::deposit_event(Event::AssetContributed {
+				campaign_id, sender: sender.clone(),
+				currency_id, amount, value, block_number,
+			});
+		} else {
+			Self::create_contribution(sender.clone(), campaign_id, &campaign, contribution)?;
+			if is_pool {
+				Self::issue_pool_shares(&sender, campaign_id, contribution)?;
+			}
+			Self::deposit_event(Event::Contributed {
+				campaign_id, sender: sender.clone(),
+				contribution, block_number,
+			});
+		}
+
+		// Hard cap reached, the outcome is fixed without waiting for the expiry
+		if let Some(hard_cap) = hard_cap {
+			let campaign_balance = CampaignBalance::<T>::get(campaign_id);
+			if campaign_balance >= hard_cap.amount {
+				let treasury_id = T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+					.ok_or(Error::<T>::TreasuryNotExist)?;
+				if !excess.is_zero() {
+					Self::escrow_contribution(&sender, &treasury_id, T::PaymentTokenId::get(), excess)?;
+					ContributionExcess::<T>::insert(campaign_id, &sender, excess);
+				}
+				Self::finalize_campaign(
+					&CampaignState::Succeeded, campaign_id, &campaign, campaign_balance, treasury_id, block_number
+				);
+			}
+		}
+
+		Ok(())
+	}
+
+	fn create_contribution(
+		sender: T::AccountId,
+		campaign_id: T::Hash,
+		campaign: &Campaign<T>,
+		contribution: T::Balance
+	) -> DispatchResult {
 		let is_returning_contributor = CampaignContribution::<T>::contains_key(campaign_id, &sender);
 		if !is_returning_contributor {
 			let contributors = CampaignContributorsCount::<T>::get(campaign_id);
 			CampaignContributorsCount::<T>::insert(campaign_id, contributors.checked_add(1).ok_or(Overflow)?);
 		}
-		// Reserve contributed amount
-		T::Currency::reserve(T::PaymentTokenId::get(), &sender, contribution).map_err(|_| Error::<T>::BalanceLow)?;
+		// Reserve contributed amount in the org treasury
+		let treasury_id = T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+			.ok_or(Error::<T>::TreasuryNotExist)?;
+		Self::escrow_contribution(&sender, &treasury_id, T::PaymentTokenId::get(), contribution)?;
 
 		// Update contributor balance for campaign
 		let total_contribution = CampaignContribution::<T>::get(campaign_id, &sender);
@@ -529,31 +2309,145 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	fn finalize_contributor(
-		campaign_state: &CampaignState,
-		contributor: T::AccountId,
+	fn create_asset_contribution(
+		sender: T::AccountId,
 		campaign_id: T::Hash,
-		org_treasury: &T::AccountId,
-	) {
-		match *campaign_state {
-			CampaignState::Succeeded => {
-				let contributor_balance = CampaignContribution::<T>::get(campaign_id, &contributor);
-				let _transfer_amount = T::Currency::repatriate_reserved(
-					T::PaymentTokenId::get(),
-					&contributor,
-					org_treasury,
-					contributor_balance,
-					BalanceStatus::Reserved
-				);
-			},
+		campaign: &Campaign<T>,
+		currency_id: T::CurrencyId,
+		amount: T::Balance,
+		value: T::Balance
+	) -> DispatchResult {
+		let treasury_id = T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+			.ok_or(Error::<T>::TreasuryNotExist)?;
+		Self::escrow_contribution(&sender, &treasury_id, currency_id, amount)?;
+		let asset = AssetContribution::<T> { currency_id, amount, value };
+		ContributionAssets::<T>::try_mutate(campaign_id, &sender, |assets| Self::add_asset(assets, &asset))?;
+		CampaignAssets::<T>::try_mutate(campaign_id, |assets| Self::add_asset(assets, &asset))?;
+
+		// Contribution and campaign balance are updated by the value
+		let is_returning_contributor = CampaignContribution::<T>::contains_key(campaign_id, &sender);
+		if !is_returning_contributor {
+			let contributors = CampaignContributorsCount::<T>::get(campaign_id);
+			CampaignContributorsCount::<T>::insert(campaign_id, contributors.checked_add(1).ok_or(Overflow)?);
+		}
+		CampaignContribution::<T>::mutate(campaign_id, &sender, |contribution| *contribution = contribution.saturating_add(value));
+		CampaignBalance::<T>::mutate(campaign_id, |balance| *balance = balance.saturating_add(value));
 
-			CampaignState::Failed => {
-				let contribution = CampaignContribution::<T>::get(campaign_id, contributor.clone());
-				T::Currency::unreserve(T::PaymentTokenId::get(), &contributor, contribution);
+		Ok(())
+	}
+
+	fn add_asset(assets: &mut AssetContributions<T>, asset: &AssetContribution<T>) -> DispatchResult {
+		match assets.iter_mut().find(|a| a.currency_id == asset.currency_id) {
+			Some(existing) => {
+				existing.amount = existing.amount.saturating_add(asset.amount);
+				existing.value = existing.value.saturating_add(asset.value);
 			},
+			None => assets.try_push(asset.clone()).map_err(|_| Error::<T>::CurrencyNotAccepted)?,
+		}
+		Ok(())
+	}
 
-			_ => {},
+	fn sub_assets(assets: &mut AssetContributions<T>, withdrawn: &AssetContributions<T>) {
+		for asset in withdrawn.iter() {
+			if let Some(existing) = assets.iter_mut().find(|a| a.currency_id == asset.currency_id) {
+				existing.amount = existing.amount.saturating_sub(asset.amount);
+				existing.value = existing.value.saturating_sub(asset.value);
+			}
 		}
+		assets.retain(|a| !a.amount.is_zero());
+	}
+
+	fn assets_value(assets: &AssetContributions<T>) -> T::Balance {
+		assets.iter().fold(Zero::zero(), |total: T::Balance, asset| total.saturating_add(asset.value))
+	}
+
+	/// Create the reward collection, held by the org treasury so only this pallet can mint from it.
+	fn create_collection(
+		owner: T::AccountId,
+		max: Option<u32>,
+		cid: BoundedVec<u8, <T as Config>::StringLimit>
+	) -> Result<T::CollectionId, DispatchError> {
+		let metadata = BoundedVec::truncate_from(cid.into());
+		let collection = NextCollectionId::<T>::get().unwrap_or(T::CollectionId::initial_value());
+		// No deposits are taken: the treasury can't sign to release them
+		let config = CollectionConfig {
+			settings: CollectionSettings::from_disabled(CollectionSetting::DepositRequired.into()),
+			max_supply: max,
+			mint_settings: MintSettings::default(),
+		};
+
+		pallet_nfts::Pallet::<T>::do_create_collection(
+			collection,
+			owner.clone(),
+			owner.clone(),
+			config,
+			Zero::zero(),
+			pallet_nfts::Event::Created { collection, creator: owner.clone(), owner },
+		)?;
+
+		pallet_nfts::Pallet::<T>::do_set_collection_metadata(None, collection, metadata)?;
+
+		Ok(collection)
+	}
+
+	/// Mint the NFT of the highest reward tier the contribution qualifies for, if any is left.
+	///
+	/// A failed mint is logged and skipped, so it never blocks the settlement of the contribution.
+	fn mint_reward(campaign_id: T::Hash, who: &T::AccountId, contribution: T::Balance) -> bool {
+		let collection_id = match RewardCollection::<T>::get(campaign_id) {
+			Some(collection_id) => collection_id,
+			None => return false,
+		};
+		let tiers = CampaignRewardTiers::<T>::get(campaign_id);
+		let maybe_tier = (0..tiers.len()).rev().find(|&index| {
+			contribution >= tiers[index].min_contribution &&
+				RewardsMinted::<T>::get(campaign_id, index as u32) < tiers[index].quantity
+		});
+		let tier = match maybe_tier {
+			Some(index) => index as u32,
+			None => return false,
+		};
+
+		let item_index = RewardItemCount::<T>::get(campaign_id);
+		if item_index == u32::max_value() {
+			log::error!(target: "runtime::gamedao_flow", "No reward NFT id left for campaign '{:?}'", campaign_id);
+			return false
+		}
+		let item_id = T::RewardHelper::item(item_index);
+		let metadata = BoundedVec::truncate_from(tiers[tier as usize].cid.clone().into());
+
+		if let Err(e) = Self::do_mint_reward(collection_id, item_id, who, metadata) {
+			log::error!(target: "runtime::gamedao_flow", "Reward mint failed for campaign '{:?}': {:?}", campaign_id, e);
+			return false
+		}
+		RewardItemCount::<T>::insert(campaign_id, item_index + 1);
+		RewardsMinted::<T>::mutate(campaign_id, tier, |minted| *minted += 1);
+
+		Self::deposit_event(Event::RewardMinted { campaign_id, who: who.clone(), tier, collection_id, item_id });
+		true
+	}
+
+	#[transactional]
+	fn do_mint_reward(
+		collection_id: T::CollectionId,
+		item_id: T::ItemId,
+		who: &T::AccountId,
+		metadata: BoundedVec<u8, <T as pallet_nfts::Config>::StringLimit>
+	) -> DispatchResult {
+		let config = ItemConfig { settings: ItemSettings::all_enabled() };
+		pallet_nfts::Pallet::<T>::do_mint(collection_id, item_id, None, who.clone(), config, |_, _| Ok(()))?;
+		pallet_nfts::Pallet::<T>::do_set_item_metadata(None, collection_id, item_id, metadata, None)
+	}
+
+	fn escrow_contribution(
+		sender: &T::AccountId,
+		treasury_id: &T::AccountId,
+		currency_id: T::CurrencyId,
+		amount: T::Balance
+	) -> DispatchResult {
+		<T as Config>::Currency::reserve(currency_id, sender, amount).map_err(|_| Error::<T>::BalanceLow)?;
+		<T as Config>::Currency::repatriate_reserved(currency_id, sender, treasury_id, amount, BalanceStatus::Reserved)?;
+		Ok(())
 	}
 
 	fn finalize_campaign(
@@ -566,25 +2460,63 @@ impl<T: Config> Pallet<T> {
 	) {
 		match *campaign_state {
 			CampaignState::Succeeded => {
-				let commission = T::CampaignFee::get().mul_floor(campaign_balance);
-				let _transfer_commission = T::Currency::repatriate_reserved(
+				// Contributions in other currencies are moved to the org treasury in kind, less the commission
+				let assets = CampaignAssets::<T>::get(campaign_id);
+				for asset in assets.iter() {
+					let commission = T::CampaignFee::get().mul_floor(asset.amount);
+					let _transfer_commission = <T as Config>::Currency::repatriate_reserved(
+						asset.currency_id,
+						&org_treasury,
+						&T::GameDAOTreasury::get(),
+						commission,
+						BalanceStatus::Free
+					);
+					<T as Config>::Currency::unreserve(asset.currency_id, &org_treasury, asset.amount - commission);
+				}
+				let payment_balance = campaign_balance.saturating_sub(Self::assets_value(&assets));
+				let commission = T::CampaignFee::get().mul_floor(payment_balance);
+				let _transfer_commission = <T as Config>::Currency::repatriate_reserved(
 					T::PaymentTokenId::get(),
 					&org_treasury,
 					&T::GameDAOTreasury::get(),
 					commission,
 					BalanceStatus::Free
 				);
-				// Update campaign balance
-				let updated_balance = campaign_balance - commission;
+				// Update campaign balance, the payment token balance held by the org treasury
+				let updated_balance = payment_balance - commission;
 				CampaignBalance::<T>::insert(campaign_id, updated_balance);
 				CampaignStates::<T>::insert(campaign_id, CampaignState::Succeeded);
+				// Milestone refunds are shared pro rata to the contributions
+				if CampaignMilestones::<T>::contains_key(campaign_id) {
+					CampaignRaised::<T>::insert(campaign_id, campaign_balance);
+				}
+
+				// Share campaign token is issued, contributors claim their part by settling the contributions
+				if campaign.protocol == FlowProtocol::Share {
+					let currency_id = T::CampaignTokenId::convert(campaign.index);
+					let retained = CampaignRetainedShare::<T>::get(campaign_id).unwrap_or_default().mul_floor(campaign_balance);
+					if let Err(e) = <T as Config>::Currency::deposit(currency_id, &org_treasury, retained) {
+						log::error!(target: "runtime::gamedao_flow", "Campaign token not issued to the treasury: {:?}", e);
+					}
+					CampaignToken::<T>::insert(campaign_id, currency_id);
+					Self::deposit_event(Event::CampaignTokenIssued { campaign_id, currency_id, retained });
+				}
+
+				// Lent funds are released to the org, the repayment starts
+				if let Some(terms) = CampaignLoanTerms::<T>::get(campaign_id) {
+					<T as Config>::Currency::unreserve(T::PaymentTokenId::get(), &org_treasury, updated_balance);
+					let due = campaign_balance.saturating_add(terms.interest.mul_floor(campaign_balance));
+					Loans::<T>::insert(campaign_id, Loan { principal: campaign_balance, due, repaid: Zero::zero(), installment: 0 });
+					let first_block = campaign.expiry + terms.period;
+					Self::schedule_installment(&campaign_id, first_block.max(block_number + 1u32.into()));
+				}
 
 				Self::deposit_event(Event::Succeeded { campaign_id, campaign_balance: updated_balance, block_number });
 			},
 
 			CampaignState::Failed => {
-				// Unreserve Initial deposit
-				T::Currency::unreserve(T::ProtocolTokenId::get(), &org_treasury, campaign.deposit);
+				// Unreserve Initial deposit, contributors claim the refunds by settling the contributions
+				<T as Config>::Currency::unreserve(T::ProtocolTokenId::get(), &org_treasury, campaign.deposit);
 				CampaignStates::<T>::insert(campaign_id, CampaignState::Failed);
 
 				Self::deposit_event(Event::Failed { campaign_id, campaign_balance, block_number });
@@ -617,6 +2549,65 @@ impl<T: Config> FlowTrait<T::AccountId, T::Balance, T::Hash> for Pallet<T> {
 	fn is_campaign_contributor(campaign_id: &T::Hash, who: &T::AccountId) -> bool {
 		CampaignContribution::<T>::contains_key(campaign_id, who)
 	}
+	fn campaign_vault(campaign_id: &T::Hash) -> Option<VaultId> {
+		CampaignVault::<T>::get(campaign_id)
+	}
+	fn has_milestones(campaign_id: &T::Hash) -> bool {
+		CampaignMilestones::<T>::contains_key(campaign_id)
+	}
+	fn next_milestone(campaign_id: &T::Hash) -> Option<(u32, T::Balance)> {
+		if CampaignStates::<T>::get(campaign_id) != CampaignState::Succeeded {
+			return None
+		}
+		let campaign = CampaignOf::<T>::get(campaign_id)?;
+		let milestones = CampaignMilestones::<T>::get(campaign_id);
+		let index = Self::next_pending_milestone(campaign_id, &milestones)?;
+		let amount = Self::milestone_amount(&campaign, CampaignBalance::<T>::get(campaign_id), &milestones, index);
+		Some((index, amount.saturating_add(Self::milestone_matching_amount(campaign_id, &milestones, index))))
+	}
+	fn settle_milestone(campaign_id: &T::Hash, index: u32, approved: bool) -> DispatchResult {
+		Self::do_settle_milestone(campaign_id, index, approved)
+	}
+	fn is_campaign_defaulted(campaign_id: &T::Hash) -> bool {
+		CampaignStates::<T>::get(campaign_id) == CampaignState::Defaulted
+	}
+	fn loan_outstanding(campaign_id: &T::Hash) -> Option<T::Balance> {
+		Loans::<T>::get(campaign_id).map(|loan| loan.due.saturating_sub(loan.repaid))
+	}
+	fn has_campaign_token(campaign_id: &T::Hash) -> bool {
+		CampaignToken::<T>::contains_key(campaign_id)
+	}
+	fn is_campaign_token_holder(campaign_id: &T::Hash, who: &T::AccountId) -> bool {
+		CampaignToken::<T>::get(campaign_id)
+			.map_or(false, |currency_id| !<T as Config>::Currency::total_balance(currency_id, who).is_zero())
+	}
+	fn reserve_campaign_token(campaign_id: &T::Hash, who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		let currency_id = CampaignToken::<T>::get(campaign_id).ok_or(Error::<T>::CampaignTokenUnknown)?;
+		<T as Config>::Currency::reserve(currency_id, who, amount)
+	}
+	fn unreserve_campaign_token(campaign_id: &T::Hash, who: &T::AccountId, amount: T::Balance) {
+		if let Some(currency_id) = CampaignToken::<T>::get(campaign_id) {
+			<T as Config>::Currency::unreserve(currency_id, who, amount);
+		}
+	}
+	fn is_pool(campaign_id: &T::Hash) -> bool {
+		CampaignPoolTerms::<T>::contains_key(campaign_id) &&
+			CampaignStates::<T>::get(campaign_id) == CampaignState::Active
+	}
+	fn spend_pool(campaign_id: &T::Hash, amount: T::Balance) -> DispatchResult {
+		let campaign = CampaignOf::<T>::get(campaign_id).ok_or(Error::<T>::CampaignUnknown)?;
+		ensure!(Self::is_pool(campaign_id), Error::<T>::PoolUnknown);
+		let mut pool = Pools::<T>::get(campaign_id);
+		ensure!(amount <= pool.balance, Error::<T>::BalanceLow);
+		let treasury_id = T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+			.ok_or(Error::<T>::TreasuryNotExist)?;
+		<T as Config>::Currency::unreserve(T::PaymentTokenId::get(), &treasury_id, amount);
+		pool.balance -= amount;
+		CampaignBalance::<T>::insert(campaign_id, pool.balance);
+		Pools::<T>::insert(campaign_id, pool);
+		Self::deposit_event(Event::PoolSpent { campaign_id: *campaign_id, amount });
+		Ok(())
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -625,7 +2616,7 @@ impl<T: Config> FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash> for
 	fn create_campaign(caller: &T::AccountId, org_id: &T::Hash, start: T::BlockNumber) -> Result<T::Hash, &'static str> {
 		use sp_runtime::traits::Saturating;
 		use sp_std::vec;
-		let bounded_str: BoundedVec<u8, T::StringLimit> = BoundedVec::truncate_from(vec![0; T::StringLimit::get() as usize]);
+		let bounded_str: BoundedVec<u8, <T as Config>::StringLimit> = BoundedVec::truncate_from(vec![0; <T as Config>::StringLimit::get() as usize]);
 		let now = frame_system::Pallet::<T>::block_number();
 		let index = CampaignCount::<T>::get();
 		let target: T::Balance = T::MinContribution::get().saturating_mul(10u32.into());
@@ -652,7 +2643,7 @@ impl<T: Config> FlowBenchmarkingTrait<T::AccountId, T::BlockNumber, T::Hash> for
 			frame_system::RawOrigin::Signed(caller.clone()).into(),
 			*campaign.org_id, campaign.owner, campaign.name, campaign.cap, campaign.deposit,
 			campaign.expiry, campaign.protocol, campaign.governance, campaign.cid,
-			Some(campaign.start), campaign.token_symbol, campaign.token_name
+			Some(campaign.start), campaign.token_symbol, campaign.token_name, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
 		)?;
 		Ok(campaign_id)
 	}
@@ -0,0 +1,189 @@
+//! Storage migrations for the flow pallet.
+//!
+//! Struct changes of `Campaign` are migrated by a new `vN` module, which declares
+//! the previous layout with `#[storage_alias]` and translates `CampaignOf` entries.
+
+use super::*;
+use frame_support::{
+	storage_alias,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+	Blake2_128Concat,
+};
+use sp_std::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use {codec::{Decode, Encode}, frame_support::ensure};
+
+pub mod v2 {
+	use super::*;
+
+	/// Campaigns scheduled for the settlement with the contributors left to be processed, removed in v2.
+	#[storage_alias]
+	pub(crate) type CampaignFinalizationQueue<T: Config> = StorageMap<
+		Pallet<T>, Blake2_128Concat, <T as frame_system::Config>::Hash,
+		(Campaign<T>, <T as Config>::Balance, CampaignState, <T as frame_system::Config>::AccountId, Contributors<T>)
+	>;
+
+	/// Unused settlement progress, removed in v2.
+	#[storage_alias]
+	pub(crate) type ProcessingOffset<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, <T as frame_system::Config>::Hash, u32, ValueQuery>;
+
+	/// Settles the queued campaigns and moves the contributions to the open campaigns into the org treasury,
+	/// contributions are settled by the contributors afterwards. Contributions to the campaigns finalized
+	/// before the upgrade were settled by v1 and are marked as settled.
+	///
+	/// Storage version 1 only introduced the version, so the migration runs from version 0 as well.
+	/// Runs in a single block, the weight is benchmarked by the number of contributions walked.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+			if onchain_version > 1 {
+				log::info!(target: "runtime::gamedao_flow", "MigrateToV2 skipped, on-chain version: {:?}", onchain_version);
+				return T::DbWeight::get().reads(1)
+			}
+			let block_number = frame_system::Pallet::<T>::block_number();
+			let (mut reads, mut writes) = (1_u64, 1_u64);
+
+			// Queued campaigns: contributors left are processed as before, the campaign is finalized
+			let mut queued = Vec::new();
+			for (campaign_id, (campaign, campaign_balance, state, treasury_id, remaining)) in CampaignFinalizationQueue::<T>::drain() {
+				for (contributor, contribution) in CampaignContribution::<T>::iter_prefix(campaign_id) {
+					if remaining.contains(&contributor) {
+						if state == CampaignState::Succeeded {
+							let result = <T as Config>::Currency::repatriate_reserved(
+								T::PaymentTokenId::get(), &contributor, &treasury_id, contribution, BalanceStatus::Reserved
+							);
+							if !matches!(result, Ok(not_moved) if not_moved.is_zero()) {
+								log::error!(
+									target: "runtime::gamedao_flow",
+									"Contribution of '{:?}' to '{:?}' not moved to the treasury: {:?}", contributor, campaign_id, result
+								);
+							}
+							// Campaign token is claimed by settling the contribution
+							if campaign.protocol == FlowProtocol::Share {
+								continue
+							}
+						} else {
+							<T as Config>::Currency::unreserve(T::PaymentTokenId::get(), &contributor, contribution);
+						}
+						reads += 2;
+						writes += 2;
+					}
+					ContributionSettled::<T>::insert(campaign_id, &contributor, true);
+					reads += 1;
+					writes += 1;
+				}
+				Pallet::<T>::finalize_campaign(&state, campaign_id, &campaign, campaign_balance, treasury_id, block_number);
+				queued.push(campaign_id);
+			}
+			let _ = ProcessingOffset::<T>::clear(u32::MAX, None);
+
+			let mut walked: u32 = 0;
+			let (mut settled, mut moved) = (0_u32, 0_u32);
+			for (campaign_id, state) in CampaignStates::<T>::iter() {
+				reads += 1;
+				// Pool contributions are held by the org treasury already
+				if queued.contains(&campaign_id) || CampaignPoolTerms::<T>::contains_key(campaign_id) {
+					continue
+				}
+				match state {
+					// Finalized campaigns: contributions were refunded or paid out by v1
+					CampaignState::Succeeded | CampaignState::Failed | CampaignState::Defaulted => {
+						for (contributor, _) in CampaignContribution::<T>::iter_prefix(campaign_id) {
+							ContributionSettled::<T>::insert(campaign_id, &contributor, true);
+							walked += 1;
+							settled += 1;
+						}
+					},
+					// Open campaigns: contributions are held by the org treasury
+					_ => {
+						let maybe_treasury_id = CampaignOf::<T>::get(campaign_id).and_then(|campaign|
+							T::Control::org_vault_account(&campaign.org_id, CampaignVault::<T>::get(campaign_id))
+						);
+						let treasury_id = match maybe_treasury_id {
+							Some(treasury_id) => treasury_id,
+							None => {
+								log::error!(target: "runtime::gamedao_flow", "Treasury unknown for campaign: '{:?}'", campaign_id);
+								continue
+							},
+						};
+						let contributions = CampaignContribution::<T>::iter_prefix(campaign_id).collect::<Vec<_>>();
+						for (contributor, contribution) in contributions {
+							move_contribution::<T>(campaign_id, &contributor, &treasury_id, contribution);
+							walked += 1;
+							moved += 1;
+						}
+					},
+				}
+			}
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			log::info!(
+				target: "runtime::gamedao_flow",
+				"MigrateToV2 finalized {} queued campaigns, settled {} and moved {} contributions",
+				queued.len(), settled, moved
+			);
+			let queued = queued.len() as u64;
+			<T as Config>::WeightInfo::migrate_to_v2(walked)
+				.saturating_add(T::DbWeight::get().reads_writes(reads + 5 * queued, writes + 5 * queued))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let queued = CampaignFinalizationQueue::<T>::iter_keys().collect::<Vec<_>>();
+			Ok(queued.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let queued: Vec<T::Hash> = Decode::decode(&mut &state[..]).map_err(|_| "Failed to decode the pre upgrade state")?;
+			ensure!(CampaignFinalizationQueue::<T>::iter().next().is_none(), "Finalization queue was not removed");
+			for campaign_id in queued {
+				ensure!(
+					matches!(CampaignStates::<T>::get(campaign_id), CampaignState::Succeeded | CampaignState::Failed),
+					"Queued campaign was not finalized"
+				);
+			}
+			ensure!(Pallet::<T>::on_chain_storage_version() == 2, "Storage version was not updated");
+			Ok(())
+		}
+	}
+
+	/// Moves the contribution reserved by the contributor into the org treasury. The part which can't be
+	/// moved is returned to the contributor and deducted from the contribution and the campaign balance.
+	fn move_contribution<T: Config>(
+		campaign_id: T::Hash,
+		contributor: &T::AccountId,
+		treasury_id: &T::AccountId,
+		contribution: T::Balance
+	) {
+		let not_moved = match <T as Config>::Currency::repatriate_reserved(
+			T::PaymentTokenId::get(), contributor, treasury_id, contribution, BalanceStatus::Reserved
+		) {
+			Ok(not_moved) => not_moved,
+			Err(e) => {
+				log::error!(target: "runtime::gamedao_flow", "Contribution of '{:?}' not moved: {:?}", contributor, e);
+				contribution
+			},
+		};
+		if not_moved.is_zero() {
+			return
+		}
+		log::error!(
+			target: "runtime::gamedao_flow",
+			"Contribution of '{:?}' to '{:?}' reduced by {:?}", contributor, campaign_id, not_moved
+		);
+		<T as Config>::Currency::unreserve(T::PaymentTokenId::get(), contributor, not_moved);
+		let remaining = contribution.saturating_sub(not_moved);
+		if remaining.is_zero() {
+			CampaignContribution::<T>::remove(campaign_id, contributor);
+			CampaignContributorsCount::<T>::mutate(campaign_id, |count| *count = count.saturating_sub(1));
+		} else {
+			CampaignContribution::<T>::insert(campaign_id, contributor, remaining);
+		}
+		CampaignBalance::<T>::mutate(campaign_id, |balance| *balance = balance.saturating_sub(not_moved));
+	}
+}
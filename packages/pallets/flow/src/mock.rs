@@ -3,14 +3,18 @@
 use sp_std::{vec, vec::Vec, convert::{TryFrom, TryInto}};
 use frame_support::{
 	construct_runtime, parameter_types, PalletId,
-	traits::{Everything, GenesisBuild, Nothing},
+	traits::{AsEnsureOriginWithArg, ConstU64, Everything, GenesisBuild, Nothing},
 	pallet_prelude::*,
 };
 use sp_core::H256;
-use sp_runtime::{traits::{IdentityLookup, BlakeTwo256}, Permill};
+use sp_runtime::{
+	testing::TestSignature, traits::{IdentityLookup, BlakeTwo256, Convert, Verify, IdentifyAccount},
+	FixedPointNumber, FixedU128, Permill,
+};
 use frame_system;
+use pallet_nfts::PalletFeatures;
 
-use orml_traits::parameter_type_with_key;
+use orml_traits::{parameter_type_with_key, DataProvider};
 
 
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord, MaxEncodedLen, TypeInfo)]
@@ -26,7 +30,9 @@ pub enum ReserveIdentifier {
 }
 
 // Types:
-pub type AccountId = u32;
+pub type Signature = TestSignature;
+pub type AccountPublic = <Signature as Verify>::Signer;
+pub type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
 pub type BlockNumber = u64;
 pub type Hash = H256;
 pub type Balance = u128;
@@ -45,6 +51,28 @@ pub const HOURS: BlockNumber = MINUTES * 60;
 pub const DAYS: BlockNumber = HOURS * 24;
 pub const PROTOCOL_TOKEN_ID: CurrencyId = 1;
 pub const PAYMENT_TOKEN_ID: CurrencyId = 2;
+pub const CAMPAIGN_TOKEN_ID_OFFSET: CurrencyId = 1000;
+pub const ASSET_TOKEN_ID: CurrencyId = 3;
+
+/// Share campaign tokens follow the protocol currencies.
+pub struct CampaignTokenIds;
+impl Convert<u32, CurrencyId> for CampaignTokenIds {
+	fn convert(index: u32) -> CurrencyId {
+		CAMPAIGN_TOKEN_ID_OFFSET + index
+	}
+}
+
+/// Currency prices in the payment token, the protocol token has no price.
+pub struct MockOracle;
+impl DataProvider<CurrencyId, FixedU128> for MockOracle {
+	fn get(currency_id: &CurrencyId) -> Option<FixedU128> {
+		match *currency_id {
+			PAYMENT_TOKEN_ID => Some(FixedU128::saturating_from_integer(1)),
+			ASSET_TOKEN_ID => Some(FixedU128::saturating_from_integer(2)),
+			_ => None,
+		}
+	}
+}
 
 // Contributors:
 pub const ALICE: AccountId = 11;
@@ -140,19 +168,41 @@ impl orml_currencies::Config for Test {
 frame_support::parameter_types! {
 	pub const ProtocolTokenId: u32 = PROTOCOL_TOKEN_ID;
 	pub const PaymentTokenId: CurrencyId = PAYMENT_TOKEN_ID;
+	pub TreasuryCurrencies: Vec<CurrencyId> = vec![PROTOCOL_TOKEN_ID, PAYMENT_TOKEN_ID, ASSET_TOKEN_ID];
+	pub const AssetTokenId: CurrencyId = ASSET_TOKEN_ID;
 	pub const MinimumDeposit: Balance = 5 * DOLLARS;
 	pub const ControlPalletId: PalletId = PalletId(*b"gd/cntrl");
 }
+#[cfg(feature = "runtime-benchmarks")]
+pub struct InvitationSigner;
+#[cfg(feature = "runtime-benchmarks")]
+impl gamedao_control::InvitationSigner<AccountId, Signature> for InvitationSigner {
+	fn sign(message: &[u8]) -> (AccountId, Signature) {
+		(ALICE, TestSignature(ALICE, message.to_vec()))
+	}
+}
 impl gamedao_control::Config for Test {
 	type Balance = Balance;
 	type CurrencyId = CurrencyId;
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Currencies;
+	type Governance = ();
+	type Sense = ();
+	type OffchainSignature = Signature;
+	type OffchainPublic = AccountPublic;
+	#[cfg(feature = "runtime-benchmarks")]
+	type InvitationSigner = InvitationSigner;
 	type MaxMembers = ConstU32<10000>;
+	type MaxMembersProcessing = ConstU32<100>;
+	type MaxStreams = ConstU32<10>;
 	type ProtocolTokenId = ProtocolTokenId;
 	type PaymentTokenId = PaymentTokenId;
+	type TreasuryCurrencies = TreasuryCurrencies;
 	type MinimumDeposit = MinimumDeposit;
+	type SubscriptionPeriod = ConstU64<100>;
+	type SubscriptionGracePeriod = ConstU64<10>;
+	type PrimeHandoverPeriod = ConstU64<100>;
 	type PalletId = ControlPalletId;
 	type StringLimit = ConstU32<256>;
 }
@@ -168,6 +218,51 @@ parameter_types! {
 	pub MinCampaignDeposit: Permill = Permill::from_rational(1u32, 10u32); // 10%
 }
 
+parameter_types! {
+	pub Features: PalletFeatures = PalletFeatures::all_enabled();
+	pub const MaxAttributesPerCall: u32 = 10;
+	pub const ApprovalsLimit: u32 = 20;
+	pub const ItemAttributesApprovalsLimit: u32 = 20;
+	pub const MaxTips: u32 = 10;
+	pub const MaxDeadlineDuration: BlockNumber = 10000;
+	pub CollectionDeposit: Balance = 0;
+	pub ItemDeposit: Balance = 0;
+	pub const KeyLimit: u32 = 32;	// Max 32 bytes per key
+	pub const ValueLimit: u32 = 64;	// Max 64 bytes per value
+	pub MetadataDepositBase: Balance = 0;
+	pub MetadataDepositPerByte: Balance = 0;
+	pub const NftStringLimit: u32 = 64;
+}
+
+impl pallet_nfts::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = PalletBalances;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type CollectionDeposit = CollectionDeposit;
+	type ItemDeposit = ItemDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type AttributeDepositBase = MetadataDepositBase;
+	type DepositPerByte = MetadataDepositPerByte;
+	type StringLimit = NftStringLimit;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type ApprovalsLimit = ApprovalsLimit;
+	type ItemAttributesApprovalsLimit = ItemAttributesApprovalsLimit;
+	type MaxTips = MaxTips;
+	type MaxDeadlineDuration = MaxDeadlineDuration;
+	type MaxAttributesPerCall = MaxAttributesPerCall;
+	type Features = Features;
+	type OffchainSignature = Signature;
+	type OffchainPublic = AccountPublic;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type Locker = ();
+}
+
 impl gamedao_flow::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -186,9 +281,14 @@ impl gamedao_flow::Config for Test {
 	type MinCampaignDeposit = MinCampaignDeposit;
 	type ProtocolTokenId = ProtocolTokenId;
 	type PaymentTokenId = PaymentTokenId;
+	type CampaignTokenId = CampaignTokenIds;
 	type CampaignFee = CampaignFee;
 	type StringLimit = ConstU32<256>;
 	type CampaignDurationLimits = CampaignDurationLimits;
+	type RewardHelper = gamedao_flow::RewardItems;
+	type PriceOracle = gamedao_flow::OraclePrices<MockOracle>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkCurrencyId = AssetTokenId;
 }
 
 construct_runtime!(
@@ -201,6 +301,7 @@ construct_runtime!(
 		Currencies: orml_currencies::{Pallet, Call},
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 		PalletBalances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+		Nfts: pallet_nfts::{Pallet, Call, Storage, Event<T>},
 		Flow: gamedao_flow,
 		Control: gamedao_control,
 	}
@@ -226,6 +327,8 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 			(8, PAYMENT_TOKEN_ID, INIT_BALANCE),
 			(9, PAYMENT_TOKEN_ID, INIT_BALANCE),
 			(10, PAYMENT_TOKEN_ID, INIT_BALANCE),
+			(1, ASSET_TOKEN_ID, INIT_BALANCE),
+			(2, ASSET_TOKEN_ID, INIT_BALANCE),
 
 			(GAMEDAO_TREASURY, PROTOCOL_TOKEN_ID, 0),
 			(GAMEDAO_TREASURY, PAYMENT_TOKEN_ID, 0),
@@ -1,18 +1,20 @@
 #![cfg(test)]
 
-use frame_support::traits::Hooks;
+use frame_support::traits::{tokens::nonfungibles_v2::Inspect, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion};
 use frame_support::{assert_noop, assert_ok};
 use frame_system::RawOrigin;
 use sp_core::H256;
-use sp_runtime::traits::Hash;
+use sp_runtime::traits::{BadOrigin, Hash, IntegerSquareRoot};
 
-use gamedao_control::types::{AccessModel, FeeModel, OrgType, Org};
+use gamedao_traits::testing::snapshot_ext;
+use gamedao_control::types::{AccessModel, FeeModel, MemberState, OrgRole, OrgType, Org, VaultRules};
 use super::{
-	types::{FlowProtocol, FlowGovernance},
+	types::{FlowProtocol, FlowGovernance, PoolInfo, PoolTerms},
 	mock::{
-		BlockNumber, AccountId, Balance, Control, RuntimeEvent as Event, Tokens, INIT_BALANCE,
-		Flow, RuntimeOrigin as Origin, System, Test, ALICE, BOB, DOLLARS, DAYS, new_test_ext,
-		PROTOCOL_TOKEN_ID, PAYMENT_TOKEN_ID, CampaignDurationLimits, MaxContributorsProcessing,
+		BlockNumber, AccountId, Balance, CurrencyId, Control, RuntimeEvent as Event, Tokens, INIT_BALANCE,
+		Flow, Nfts, RuntimeOrigin as Origin, System, Test, ALICE, BOB, DOLLARS, DAYS, new_test_ext,
+		PROTOCOL_TOKEN_ID, PAYMENT_TOKEN_ID, ASSET_TOKEN_ID, CAMPAIGN_TOKEN_ID_OFFSET, GAMEDAO_TREASURY,
+		CampaignDurationLimits,
 	},
 	*
 };
@@ -31,7 +33,7 @@ fn create_org_treasury() -> (H256, AccountId, Balance) {
 	assert_ok!(
 		Control::create_org(
 			Origin::signed(BOB), org.name, org.cid, org.org_type, org.access_model,
-			org.fee_model, None, org.membership_fee, None, None, None
+			org.fee_model, None, org.membership_fee, None, None, None, None, None
 	));
 	let treasury_id = Control::org_treasury_account(&org_id).unwrap();
 	let tbalance = 200 * DOLLARS;
@@ -86,7 +88,7 @@ fn flow_create_errors() {
 				Origin::signed(not_creator), org_id, not_creator,
 				bounded_vec.clone(), target, deposit, expiry,
 				FlowProtocol::default(), FlowGovernance::default(),
-				bounded_vec.clone(), None, None, None,
+				bounded_vec.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new(),
 			),
 			Error::<Test>::AuthorizationError
 		);
@@ -99,7 +101,7 @@ fn flow_create_errors() {
 				Origin::signed(BOB), org_id, BOB,
 				bounded_vec.clone(), deposit_more_than_treasury + 1, deposit_more_than_treasury, expiry,
 				FlowProtocol::default(), FlowGovernance::default(),
-				bounded_vec.clone(), None, None, None,
+				bounded_vec.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new(),
 			),
 			Error::<Test>::TreasuryBalanceLow
 		);
@@ -111,7 +113,7 @@ fn flow_create_errors() {
 				Origin::signed(BOB), org_id, BOB,
 				bounded_vec.clone(), target, target + 1, expiry,
 				FlowProtocol::default(), FlowGovernance::default(),
-				bounded_vec.clone(), None, None, None,
+				bounded_vec.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new(),
 			),
 			Error::<Test>::DepositTooHigh
 		);
@@ -122,7 +124,7 @@ fn flow_create_errors() {
 				Origin::signed(BOB), org_id, BOB,
 				bounded_vec.clone(), target, 0, expiry,
 				FlowProtocol::default(), FlowGovernance::default(),
-				bounded_vec.clone(), None, None, None,
+				bounded_vec.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new(),
 			),
 			Error::<Test>::DepositInsufficient
 		);
@@ -134,7 +136,7 @@ fn flow_create_errors() {
 				Origin::signed(BOB), org_id, BOB,
 				short_name, target, deposit, expiry,
 				FlowProtocol::default(), FlowGovernance::default(),
-				bounded_vec.clone(), None, None, None,
+				bounded_vec.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new(),
 			),
 			Error::<Test>::NameTooShort
 		);
@@ -146,7 +148,7 @@ fn flow_create_errors() {
 				Origin::signed(BOB), org_id, BOB,
 				bounded_vec.clone(), target, deposit, expiration_block,
 				FlowProtocol::default(), FlowGovernance::default(),
-				bounded_vec.clone(), None, None, None,
+				bounded_vec.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new(),
 			),
 			Error::<Test>::OutOfBounds
 		);
@@ -159,7 +161,7 @@ fn flow_create_errors() {
 				Origin::signed(BOB), org_id, BOB,
 				bounded_vec.clone(), target, deposit, expiration_block,
 				FlowProtocol::default(), FlowGovernance::default(),
-				bounded_vec.clone(), None, None, None,
+				bounded_vec.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new(),
 			),
 			Error::<Test>::OutOfBounds
 		);
@@ -193,7 +195,7 @@ fn flow_create_success() {
 		assert_ok!(Flow::create_campaign(
 			Origin::signed(campaign.owner), org_id, BOB, campaign.name.clone(), campaign.cap,
 			campaign.deposit, campaign.expiry, campaign.protocol.clone(), campaign.governance.clone(),
-			campaign.cid.clone(), None, None, None
+			campaign.cid.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
 		));
 		CampaignOf::<Test>::get(&campaign_id);
 		assert_eq!(CampaignOf::<Test>::get(&campaign_id).unwrap(), campaign);
@@ -208,6 +210,67 @@ fn flow_create_success() {
 			name: campaign.name
 		}));
 
+		// Org member with the Treasurer role can create campaigns
+		assert_ok!(Control::add_member(Origin::signed(BOB), org_id, ALICE, None));
+		assert_ok!(Control::update_member_state(Origin::signed(BOB), org_id, ALICE, MemberState::Active));
+		assert_ok!(Control::grant_role(Origin::signed(BOB), org_id, ALICE, OrgRole::Treasurer));
+		let (campaign_id, campaign) = create_campaign(
+			index + 1, org_id, ALICE, now, expiry, now, deposit, target
+		);
+		assert_ok!(Flow::create_campaign(
+			Origin::signed(ALICE), org_id, ALICE, campaign.name.clone(), campaign.cap,
+			campaign.deposit, campaign.expiry, campaign.protocol.clone(), campaign.governance.clone(),
+			campaign.cid.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
+		));
+		assert_eq!(CampaignOf::<Test>::get(&campaign_id).unwrap(), campaign);
+	});
+}
+
+#[test]
+fn flow_create_vault_campaign() {
+	new_test_ext().execute_with(|| {
+		let (org_id, _, _) = create_org_treasury();
+		let now = 3;
+		System::set_block_number(now);
+
+		let rules = VaultRules { roles: BoundedVec::truncate_from(vec![]), limits: BoundedVec::truncate_from(vec![]), period: 0 };
+		assert_ok!(Control::create_vault(Origin::root(), org_id, BoundedVec::truncate_from(b"grants".to_vec()), rules));
+		let vault_account = Control::org_vault_account(&org_id, Some(1)).unwrap();
+		let _ = Tokens::set_balance(RawOrigin::Root.into(), vault_account, PROTOCOL_TOKEN_ID, 100 * DOLLARS, 0);
+
+		let index = CampaignCount::<Test>::get();
+		let expiry = now + 2 * DAYS;
+		let deposit = 10 * DOLLARS;
+		let target = 20 * DOLLARS;
+		let (campaign_id, campaign) = create_campaign(
+			index, org_id, BOB, now, expiry, now, deposit, target
+		);
+
+		// Unknown vault
+		assert_noop!(
+			Flow::create_campaign(
+				Origin::signed(BOB), org_id, BOB, campaign.name.clone(), campaign.cap,
+				campaign.deposit, campaign.expiry, campaign.protocol.clone(), campaign.governance.clone(),
+				campaign.cid.clone(), None, None, None, Some(2), BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
+			),
+			Error::<Test>::VaultUnknown
+		);
+
+		assert_ok!(Flow::create_campaign(
+			Origin::signed(BOB), org_id, BOB, campaign.name.clone(), campaign.cap,
+			campaign.deposit, campaign.expiry, campaign.protocol.clone(), campaign.governance.clone(),
+			campaign.cid.clone(), None, None, None, Some(1), BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
+		));
+		assert_eq!(CampaignVault::<Test>::get(&campaign_id), Some(1));
+		assert_eq!(<Flow as FlowTrait<AccountId, Balance, H256>>::campaign_vault(&campaign_id), Some(1));
+		assert_eq!(Tokens::reserved_balance(PROTOCOL_TOKEN_ID, &vault_account), deposit);
+
+		// Campaign proceeds are settled to the vault
+		assert_ok!(Flow::contribute(Origin::signed(ALICE), campaign_id, target));
+		System::set_block_number(expiry);
+		Flow::on_finalize(expiry);
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Succeeded);
+		assert_eq!(Tokens::reserved_balance(PAYMENT_TOKEN_ID, &vault_account), CampaignBalance::<Test>::get(&campaign_id));
 	});
 }
 
@@ -229,7 +292,7 @@ fn flow_contribute_errors() {
 		assert_ok!(Flow::create_campaign(
 			Origin::signed(campaign.owner), org_id, BOB, campaign.name.clone(), campaign.cap,
 			campaign.deposit, campaign.expiry, campaign.protocol.clone(), campaign.governance.clone(),
-			campaign.cid.clone(), None, None, None
+			campaign.cid.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
 		));
 
 		// Check if contributor has enough balance
@@ -267,7 +330,7 @@ fn flow_contribute_errors() {
 #[test]
 fn flow_contribute_success() {
 	new_test_ext().execute_with(|| {
-		let (org_id, _, _) = create_org_treasury();
+		let (org_id, treasury_id, _) = create_org_treasury();
 		let now = 3;
 		System::set_block_number(now);
 
@@ -282,12 +345,15 @@ fn flow_contribute_success() {
 		assert_ok!(Flow::create_campaign(
 			Origin::signed(campaign.owner), org_id, BOB, campaign.name.clone(), campaign.cap,
 			campaign.deposit, campaign.expiry, campaign.protocol.clone(), campaign.governance.clone(),
-			campaign.cid.clone(), None, None, None
+			campaign.cid.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
 		));
 
 		let contribution = 30 * DOLLARS;
 		assert_ok!(Flow::contribute(Origin::signed(ALICE), campaign_id, contribution));
 		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &ALICE), INIT_BALANCE - contribution);
+		// Contribution is held by the org treasury
+		assert_eq!(<Test as Config>::Currency::reserved_balance(PAYMENT_TOKEN_ID, &ALICE), 0);
+		assert_eq!(<Test as Config>::Currency::reserved_balance(PAYMENT_TOKEN_ID, &treasury_id), contribution);
 		assert_eq!(CampaignBalance::<Test>::get(&campaign_id), contribution);
 		assert_eq!(CampaignContribution::<Test>::get(&campaign_id, ALICE), contribution);
 
@@ -300,7 +366,504 @@ fn flow_contribute_success() {
 	});
 }
 
-/// Tests queue when two campaigns created
+#[test]
+fn flow_withdraw_contribution() {
+	new_test_ext().execute_with(|| {
+		let (org_id, treasury_id, _) = create_org_treasury();
+		let now = 3;
+		System::set_block_number(now);
+
+		let expiry = now + 2 * DAYS;
+		let deposit = 10 * DOLLARS;
+		let target = 100 * DOLLARS;
+		let terms = WithdrawalTerms { fee: Permill::from_percent(10), cutoff: 1 * DAYS };
+		let create = |index: u32, protocol: FlowProtocol, pool_terms: Option<PoolTerms<BlockNumber>>| {
+			let (_, mut campaign) = create_campaign(index, org_id, BOB, now, expiry, now, deposit, target);
+			campaign.protocol = protocol;
+			let campaign_id = <Test as frame_system::Config>::Hashing::hash_of(&campaign);
+			Flow::create_campaign(
+				Origin::signed(BOB), org_id, BOB, campaign.name.clone(), campaign.cap,
+				campaign.deposit, campaign.expiry, campaign.protocol, campaign.governance,
+				campaign.cid, None, None, None, None, BoundedVec::new(), None, None, pool_terms, Some(terms.clone()), None, BoundedVec::new(), BoundedVec::new()
+			).map(|_| campaign_id)
+		};
+
+		// Pool contributions are redeemed, not withdrawn
+		assert_noop!(
+			create(0, FlowProtocol::Pool, Some(PoolTerms { redemption_period: 10, redemption_window: 2 })),
+			Error::<Test>::InvalidWithdrawalTerms
+		);
+		let campaign_id = create(0, FlowProtocol::Raise, None).unwrap();
+		assert_eq!(CampaignWithdrawalTerms::<Test>::get(&campaign_id), Some(terms));
+
+		assert_ok!(Flow::contribute(Origin::signed(1), campaign_id, 10 * DOLLARS));
+		assert_ok!(Flow::contribute(Origin::signed(2), campaign_id, 5 * DOLLARS));
+		assert_eq!(CampaignContributorsCount::<Test>::get(&campaign_id), 2);
+
+		assert_noop!(Flow::withdraw_contribution(Origin::signed(1), campaign_id, 0), Error::<Test>::BalanceLow);
+		assert_noop!(Flow::withdraw_contribution(Origin::signed(1), campaign_id, 11 * DOLLARS), Error::<Test>::BalanceLow);
+		assert_noop!(Flow::withdraw_contribution(Origin::signed(ALICE), campaign_id, 1 * DOLLARS), Error::<Test>::BalanceLow);
+		// Remaining contribution would be below the minimum
+		assert_noop!(
+			Flow::withdraw_contribution(Origin::signed(1), campaign_id, 9 * DOLLARS + 1),
+			Error::<Test>::ContributionInsufficient
+		);
+
+		// Partial withdrawal, the fee goes to the org treasury
+		assert_ok!(Flow::withdraw_contribution(Origin::signed(1), campaign_id, 4 * DOLLARS));
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &1), INIT_BALANCE - 10 * DOLLARS + 36 * DOLLARS / 10);
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &treasury_id), 4 * DOLLARS / 10);
+		assert_eq!(Tokens::reserved_balance(PAYMENT_TOKEN_ID, &treasury_id), 11 * DOLLARS);
+		assert_eq!(CampaignContribution::<Test>::get(&campaign_id, &1), 6 * DOLLARS);
+		assert_eq!(CampaignBalance::<Test>::get(&campaign_id), 11 * DOLLARS);
+		assert_eq!(CampaignContributorsCount::<Test>::get(&campaign_id), 2);
+		System::assert_last_event(Event::Flow(crate::Event::ContributionWithdrawn {
+			campaign_id, who: 1, amount: 4 * DOLLARS, fee: 4 * DOLLARS / 10
+		}));
+
+		// Full withdrawal removes the contributor
+		assert_ok!(Flow::withdraw_contribution(Origin::signed(1), campaign_id, 6 * DOLLARS));
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &1), INIT_BALANCE - 1 * DOLLARS);
+		assert!(!CampaignContribution::<Test>::contains_key(&campaign_id, &1));
+		assert_eq!(CampaignBalance::<Test>::get(&campaign_id), 5 * DOLLARS);
+		assert_eq!(CampaignContributorsCount::<Test>::get(&campaign_id), 1);
+
+		// Withdrawals are closed within the cut-off window
+		System::set_block_number(expiry - 1 * DAYS);
+		assert_noop!(
+			Flow::withdraw_contribution(Origin::signed(2), campaign_id, 5 * DOLLARS),
+			Error::<Test>::WithdrawalClosed
+		);
+
+		// Failed campaign refunds the remaining contributions only
+		System::set_block_number(expiry);
+		Flow::on_finalize(expiry);
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Failed);
+		assert_noop!(
+			Flow::settle_contribution(Origin::signed(1), campaign_id, 1),
+			Error::<Test>::NothingToClaim
+		);
+		assert_ok!(Flow::settle_contribution(Origin::signed(2), campaign_id, 2));
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &2), INIT_BALANCE);
+		assert_eq!(Tokens::reserved_balance(PAYMENT_TOKEN_ID, &treasury_id), 0);
+	});
+}
+
+#[test]
+fn flow_hard_cap() {
+	new_test_ext().execute_with(|| {
+		let (org_id, treasury_id, _) = create_org_treasury();
+		let now = 3;
+		System::set_block_number(now);
+
+		let expiry = now + 2 * DAYS;
+		let deposit = 10 * DOLLARS;
+		let target = 20 * DOLLARS;
+		let hard_cap = 30 * DOLLARS;
+		let create = |index: u32, amount: Balance, policy: OverfundingPolicy| {
+			let (_, campaign) = create_campaign(index, org_id, BOB, now, expiry, now, deposit, target);
+			let campaign_id = <Test as frame_system::Config>::Hashing::hash_of(&campaign);
+			Flow::create_campaign(
+				Origin::signed(BOB), org_id, BOB, campaign.name.clone(), campaign.cap,
+				campaign.deposit, campaign.expiry, campaign.protocol, campaign.governance,
+				campaign.cid, None, None, None, None, BoundedVec::new(), None, None, None, None,
+				Some(HardCap { amount, policy }), BoundedVec::new(), BoundedVec::new()
+			).map(|_| campaign_id)
+		};
+
+		// Hard cap below the target
+		assert_noop!(create(0, 10 * DOLLARS, OverfundingPolicy::Reject), Error::<Test>::InvalidHardCap);
+		let rejecting_id = create(0, hard_cap, OverfundingPolicy::Reject).unwrap();
+		let trimming_id = create(1, hard_cap, OverfundingPolicy::Trim).unwrap();
+		let refunding_id = create(2, hard_cap, OverfundingPolicy::Refund).unwrap();
+		for campaign_id in [rejecting_id, trimming_id, refunding_id] {
+			assert_ok!(Flow::contribute(Origin::signed(1), campaign_id, 25 * DOLLARS));
+			// Soft cap is passed, the campaign stays open until the hard cap or the expiry
+			assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Active);
+		}
+
+		// Contribution exceeding the hard cap is rejected
+		assert_noop!(
+			Flow::contribute(Origin::signed(2), rejecting_id, 10 * DOLLARS),
+			Error::<Test>::HardCapExceeded
+		);
+		assert_ok!(Flow::contribute(Origin::signed(2), rejecting_id, 5 * DOLLARS));
+		// Campaign succeeds at once when the hard cap is reached
+		assert_eq!(CampaignStates::<Test>::get(&rejecting_id), CampaignState::Succeeded);
+		let commission = <Test as Config>::CampaignFee::get().mul_floor(hard_cap);
+		System::assert_last_event(Event::Flow(crate::Event::Succeeded {
+			campaign_id: rejecting_id, campaign_balance: hard_cap - commission, block_number: now
+		}));
+		assert_noop!(
+			Flow::contribute(Origin::signed(3), rejecting_id, 1 * DOLLARS),
+			Error::<Test>::NoContributionsAllowed
+		);
+
+		// Contribution is trimmed to the hard cap
+		assert_ok!(Flow::contribute(Origin::signed(2), trimming_id, 10 * DOLLARS));
+		assert_eq!(CampaignStates::<Test>::get(&trimming_id), CampaignState::Succeeded);
+		assert_eq!(CampaignContribution::<Test>::get(&trimming_id, &2), 5 * DOLLARS);
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &2), INIT_BALANCE - 10 * DOLLARS);
+
+		// Contribution is accepted, the excess is refunded on settlement
+		assert_ok!(Flow::contribute(Origin::signed(2), refunding_id, 10 * DOLLARS));
+		assert_eq!(CampaignStates::<Test>::get(&refunding_id), CampaignState::Succeeded);
+		assert_eq!(CampaignContribution::<Test>::get(&refunding_id, &2), 5 * DOLLARS);
+		assert_eq!(ContributionExcess::<Test>::get(&refunding_id, &2), 5 * DOLLARS);
+		assert_eq!(CampaignBalance::<Test>::get(&refunding_id), hard_cap - commission);
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &2), INIT_BALANCE - 20 * DOLLARS);
+
+		// Outcome is not fixed again at the expiry
+		System::set_block_number(expiry);
+		Flow::on_finalize(expiry);
+		assert_eq!(CampaignBalance::<Test>::get(&refunding_id), hard_cap - commission);
+
+		assert_noop!(
+			Flow::settle_contribution(Origin::signed(1), refunding_id, 1),
+			Error::<Test>::NothingToClaim
+		);
+		assert_ok!(Flow::settle_contribution(Origin::signed(1), refunding_id, 2));
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &2), INIT_BALANCE - 15 * DOLLARS);
+		assert!(!ContributionExcess::<Test>::contains_key(&refunding_id, &2));
+		System::assert_has_event(Event::Flow(crate::Event::ExcessRefunded {
+			campaign_id: refunding_id, who: 2, amount: 5 * DOLLARS
+		}));
+		assert_noop!(
+			Flow::settle_contribution(Origin::signed(1), refunding_id, 2),
+			Error::<Test>::AlreadySettled
+		);
+		assert_eq!(
+			Tokens::reserved_balance(PAYMENT_TOKEN_ID, &treasury_id),
+			3 * (hard_cap - commission)
+		);
+	});
+}
+
+#[test]
+fn flow_reward_tiers() {
+	new_test_ext().execute_with(|| {
+		let (org_id, treasury_id, _) = create_org_treasury();
+		let now = 3;
+		System::set_block_number(now);
+
+		let expiry = now + 2 * DAYS;
+		let deposit = 10 * DOLLARS;
+		let target = 20 * DOLLARS;
+		let tier = |min_contribution: Balance, quantity: u32, cid: u8| RewardTier::<Test> {
+			min_contribution, quantity, cid: BoundedVec::truncate_from(vec![cid])
+		};
+		let create = |index: u32, tiers: Vec<RewardTier<Test>>| {
+			let (_, campaign) = create_campaign(index, org_id, BOB, now, expiry, now, deposit, target);
+			let campaign_id = <Test as frame_system::Config>::Hashing::hash_of(&campaign);
+			Flow::create_campaign(
+				Origin::signed(BOB), org_id, BOB, campaign.name.clone(), campaign.cap,
+				campaign.deposit, campaign.expiry, campaign.protocol, campaign.governance,
+				campaign.cid, None, None, None, None, BoundedVec::new(), None, None, None, None,
+				None, BoundedVec::truncate_from(tiers), BoundedVec::new()
+			).map(|_| campaign_id)
+		};
+
+		// Tier below the min contribution, with no quantity or not increasing
+		for tiers in [
+			vec![tier(DOLLARS / 2, 1, 1)],
+			vec![tier(1 * DOLLARS, 0, 1)],
+			vec![tier(10 * DOLLARS, 1, 1), tier(10 * DOLLARS, 1, 2)],
+		] {
+			assert_noop!(create(0, tiers), Error::<Test>::InvalidRewardTiers);
+		}
+
+		let tiers = vec![tier(1 * DOLLARS, 2, 1), tier(10 * DOLLARS, 1, 2)];
+		let campaign_id = create(0, tiers.clone()).unwrap();
+		let failed_id = create(1, tiers).unwrap();
+		let collection_id = RewardCollection::<Test>::get(&campaign_id).unwrap();
+		assert_eq!(<Nfts as Inspect<AccountId>>::collection_owner(&collection_id), Some(treasury_id));
+		// Campaign creator can't mint from the reward collection
+		assert_noop!(
+			Nfts::mint(Origin::signed(BOB), collection_id, 100, BOB, None),
+			pallet_nfts::Error::<Test>::NoPermission
+		);
+		assert_ne!(RewardCollection::<Test>::get(&failed_id), Some(collection_id));
+
+		for (who, amount) in [(1, 15 * DOLLARS), (2, 12 * DOLLARS), (3, 5 * DOLLARS), (4, 1 * DOLLARS)] {
+			assert_ok!(Flow::contribute(Origin::signed(who), campaign_id, amount));
+		}
+		assert_ok!(Flow::contribute(Origin::signed(1), failed_id, 5 * DOLLARS));
+
+		System::set_block_number(expiry);
+		Flow::on_finalize(expiry);
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Succeeded);
+		assert_eq!(CampaignStates::<Test>::get(&failed_id), CampaignState::Failed);
+
+		// Highest reachable tier, falls back to the lower tier once sold out
+		for (who, tier, item_id) in [(1, 1, 0), (2, 0, 1), (3, 0, 2)] {
+			assert_ok!(Flow::settle_contribution(Origin::signed(ALICE), campaign_id, who));
+			assert_eq!(<Nfts as Inspect<AccountId>>::owner(&collection_id, &item_id), Some(who));
+			System::assert_has_event(Event::Flow(crate::Event::RewardMinted {
+				campaign_id, who, tier, collection_id, item_id
+			}));
+		}
+		assert_eq!(RewardsMinted::<Test>::get(&campaign_id, 1), 1);
+		assert_eq!(RewardsMinted::<Test>::get(&campaign_id, 0), 2);
+
+		// All tiers are sold out
+		assert_noop!(
+			Flow::settle_contribution(Origin::signed(ALICE), campaign_id, 4),
+			Error::<Test>::NothingToClaim
+		);
+
+		// Failed campaign contribution is refunded without reward
+		assert_ok!(Flow::settle_contribution(Origin::signed(ALICE), failed_id, 1));
+		assert_eq!(RewardItemCount::<Test>::get(&failed_id), 0);
+	});
+}
+
+#[test]
+fn flow_asset_contributions() {
+	new_test_ext().execute_with(|| {
+		let (org_id, treasury_id, _) = create_org_treasury();
+		let now = 3;
+		System::set_block_number(now);
+
+		let expiry = now + 2 * DAYS;
+		let deposit = 10 * DOLLARS;
+		let target = 20 * DOLLARS;
+		let create = |index: u32, currencies: Vec<CurrencyId>| {
+			let (_, campaign) = create_campaign(index, org_id, BOB, now, expiry, now, deposit, target);
+			let campaign_id = <Test as frame_system::Config>::Hashing::hash_of(&campaign);
+			Flow::create_campaign(
+				Origin::signed(BOB), org_id, BOB, campaign.name.clone(), campaign.cap,
+				campaign.deposit, campaign.expiry, campaign.protocol, campaign.governance,
+				campaign.cid, None, None, None, None, BoundedVec::new(), None, None, None, None,
+				None, BoundedVec::new(), BoundedVec::truncate_from(currencies)
+			).map(|_| campaign_id)
+		};
+
+		// Payment token, duplicated currency or currency without price
+		for currencies in [
+			vec![PAYMENT_TOKEN_ID],
+			vec![ASSET_TOKEN_ID, ASSET_TOKEN_ID],
+			vec![PROTOCOL_TOKEN_ID],
+		] {
+			assert_noop!(create(0, currencies), Error::<Test>::InvalidCurrencies);
+		}
+
+		let campaign_id = create(0, vec![ASSET_TOKEN_ID]).unwrap();
+		let failed_id = create(1, vec![ASSET_TOKEN_ID]).unwrap();
+		assert_noop!(
+			Flow::contribute_asset(Origin::signed(1), campaign_id, PROTOCOL_TOKEN_ID, 5 * DOLLARS),
+			Error::<Test>::CurrencyNotAccepted
+		);
+		// Contribution value is below the min contribution
+		assert_noop!(
+			Flow::contribute_asset(Origin::signed(1), campaign_id, ASSET_TOKEN_ID, DOLLARS / 4),
+			Error::<Test>::ContributionInsufficient
+		);
+
+		// Contribution is held in kind and valued in the payment token
+		assert_ok!(Flow::contribute_asset(Origin::signed(1), campaign_id, ASSET_TOKEN_ID, 5 * DOLLARS));
+		System::assert_last_event(Event::Flow(crate::Event::AssetContributed {
+			campaign_id, sender: 1, currency_id: ASSET_TOKEN_ID, amount: 5 * DOLLARS, value: 10 * DOLLARS,
+			block_number: now
+		}));
+		assert_ok!(Flow::contribute(Origin::signed(1), campaign_id, 4 * DOLLARS));
+		assert_ok!(Flow::contribute_asset(Origin::signed(2), campaign_id, ASSET_TOKEN_ID, 3 * DOLLARS));
+		assert_eq!(CampaignContribution::<Test>::get(&campaign_id, &1), 14 * DOLLARS);
+		assert_eq!(CampaignBalance::<Test>::get(&campaign_id), target);
+		assert_eq!(CampaignContributorsCount::<Test>::get(&campaign_id), 2);
+		assert_eq!(Tokens::reserved_balance(ASSET_TOKEN_ID, &treasury_id), 8 * DOLLARS);
+
+		// Only the payment token part is withdrawn
+		assert_noop!(
+			Flow::withdraw_contribution(Origin::signed(1), campaign_id, 5 * DOLLARS),
+			Error::<Test>::BalanceLow
+		);
+		// Whole contribution is withdrawn with the assets in kind
+		assert_ok!(Flow::withdraw_contribution(Origin::signed(2), campaign_id, 6 * DOLLARS));
+		assert_eq!(Tokens::free_balance(ASSET_TOKEN_ID, &2), INIT_BALANCE);
+		assert_eq!(Tokens::reserved_balance(ASSET_TOKEN_ID, &treasury_id), 5 * DOLLARS);
+		assert_eq!(ContributionAssets::<Test>::get(&campaign_id, &2).len(), 0);
+		assert_eq!(CampaignAssets::<Test>::get(&campaign_id)[0].amount, 5 * DOLLARS);
+		assert_eq!(CampaignBalance::<Test>::get(&campaign_id), 14 * DOLLARS);
+		assert_ok!(Flow::contribute_asset(Origin::signed(2), campaign_id, ASSET_TOKEN_ID, 3 * DOLLARS));
+
+		assert_ok!(Flow::contribute_asset(Origin::signed(1), failed_id, ASSET_TOKEN_ID, 2 * DOLLARS));
+		assert_ok!(Flow::contribute(Origin::signed(1), failed_id, 1 * DOLLARS));
+
+		System::set_block_number(expiry);
+		Flow::on_finalize(expiry);
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Succeeded);
+		assert_eq!(CampaignStates::<Test>::get(&failed_id), CampaignState::Failed);
+
+		// Assets are moved to the treasury in kind less the commission
+		let fee = <Test as Config>::CampaignFee::get();
+		let asset_commission = fee.mul_floor(8 * DOLLARS);
+		assert_eq!(Tokens::free_balance(ASSET_TOKEN_ID, &GAMEDAO_TREASURY), asset_commission);
+		assert_eq!(Tokens::free_balance(ASSET_TOKEN_ID, &treasury_id), 8 * DOLLARS - asset_commission);
+		assert_eq!(CampaignBalance::<Test>::get(&campaign_id), 4 * DOLLARS - fee.mul_floor(4 * DOLLARS));
+
+		// Failed campaign contribution is refunded in kind
+		assert_ok!(Flow::settle_contribution(Origin::signed(1), failed_id, 1));
+		assert_eq!(Tokens::free_balance(ASSET_TOKEN_ID, &1), INIT_BALANCE - 5 * DOLLARS);
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &1), INIT_BALANCE - 4 * DOLLARS);
+		assert_eq!(Tokens::reserved_balance(ASSET_TOKEN_ID, &treasury_id), 0);
+	});
+}
+
+#[test]
+fn flow_matching_round() {
+	new_test_ext().execute_with(|| {
+		let (org_id, treasury_id, _) = create_org_treasury();
+		let _ = Tokens::set_balance(RawOrigin::Root.into(), treasury_id, PAYMENT_TOKEN_ID, 100 * DOLLARS, 0);
+		let now = 3;
+		System::set_block_number(now);
+
+		let expiry = now + 2 * DAYS;
+		let deposit = 10 * DOLLARS;
+		let target = 20 * DOLLARS;
+		let create = |protocol: FlowProtocol, expiry: BlockNumber| {
+			let index = CampaignCount::<Test>::get();
+			let (_, mut campaign) = create_campaign(index, org_id, BOB, now, expiry, now, deposit, target);
+			campaign.protocol = protocol.clone();
+			let campaign_id = <Test as frame_system::Config>::Hashing::hash_of(&campaign);
+			let milestones = match protocol {
+				FlowProtocol::Grant => vec![Milestone::<Test> {
+					cid: BoundedVec::truncate_from(vec![1, 2, 3]), amount: target, deadline: expiry + 10
+				}],
+				_ => vec![],
+			};
+			assert_ok!(Flow::create_campaign(
+				Origin::signed(BOB), org_id, BOB, campaign.name.clone(), campaign.cap,
+				campaign.deposit, campaign.expiry, protocol, campaign.governance,
+				campaign.cid, None, None, None, None, BoundedVec::truncate_from(milestones), None, None, None, None,
+				None, BoundedVec::new(), BoundedVec::new()
+			));
+			campaign_id
+		};
+		let first_id = create(FlowProtocol::Grant, expiry);
+		let second_id = create(FlowProtocol::Grant, expiry);
+		let failed_id = create(FlowProtocol::Grant, expiry + 1);
+		let raise_id = create(FlowProtocol::Raise, expiry - 1);
+
+		let pool = 10 * DOLLARS;
+		let cap = 9 * DOLLARS;
+		let end = expiry + 1;
+		let round = |origin: Origin, org_id: Option<H256>, pool: Balance, end: BlockNumber, campaigns: Vec<H256>| {
+			Flow::create_matching_round(origin, org_id, pool, now, end, cap, BoundedVec::truncate_from(campaigns))
+		};
+		let campaigns = vec![first_id, second_id, failed_id];
+
+		// Member without SpendFunds permission or the protocol treasury without Root
+		assert_noop!(round(Origin::signed(ALICE), Some(org_id), pool, end, campaigns.clone()), Error::<Test>::AuthorizationError);
+		assert_noop!(round(Origin::signed(BOB), None, pool, end, campaigns.clone()), BadOrigin);
+		// Empty pool, no campaigns or the round has ended
+		assert_noop!(round(Origin::signed(BOB), Some(org_id), 0, end, campaigns.clone()), Error::<Test>::InvalidMatchingRound);
+		assert_noop!(round(Origin::signed(BOB), Some(org_id), pool, end, vec![]), Error::<Test>::InvalidMatchingRound);
+		assert_noop!(round(Origin::signed(BOB), Some(org_id), pool, now, campaigns.clone()), Error::<Test>::InvalidMatchingRound);
+		// Non Grant campaign, campaign expiring after the round or duplicated campaign
+		assert_noop!(round(Origin::signed(BOB), Some(org_id), pool, end, vec![first_id, raise_id]), Error::<Test>::InvalidMatchingRound);
+		assert_noop!(round(Origin::signed(BOB), Some(org_id), pool, expiry, campaigns.clone()), Error::<Test>::InvalidMatchingRound);
+		assert_noop!(round(Origin::signed(BOB), Some(org_id), pool, end, vec![first_id, first_id]), Error::<Test>::InvalidMatchingRound);
+		assert_noop!(round(Origin::signed(BOB), Some(org_id), pool, end, vec![H256::repeat_byte(0x11)]), Error::<Test>::CampaignUnknown);
+		assert_noop!(
+			round(Origin::signed(BOB), Some(org_id), 200 * DOLLARS, end, campaigns.clone()),
+			Error::<Test>::TreasuryBalanceLow
+		);
+
+		// Pool is reserved in the org treasury
+		assert_ok!(round(Origin::signed(BOB), Some(org_id), pool, end, campaigns.clone()));
+		System::assert_last_event(Event::Flow(crate::Event::MatchingRoundCreated {
+			round_id: 0, org_id: Some(org_id), pool, start: now, end
+		}));
+		assert_eq!(MatchingRoundCount::<Test>::get(), 1);
+		assert_eq!(MatchingRoundsByBlock::<Test>::get(end).into_inner(), vec![0]);
+		assert_eq!(Tokens::reserved_balance(PAYMENT_TOKEN_ID, &treasury_id), pool);
+
+		// Many small contributions are matched more than a few large ones, above the cap only the cap is counted
+		for who in 1..=4 {
+			assert_ok!(Flow::contribute(Origin::signed(who), first_id, 5 * DOLLARS));
+		}
+		assert_ok!(Flow::contribute(Origin::signed(5), second_id, 16 * DOLLARS));
+		assert_ok!(Flow::contribute(Origin::signed(6), second_id, 4 * DOLLARS));
+		assert_ok!(Flow::contribute(Origin::signed(7), failed_id, 5 * DOLLARS));
+
+		System::set_block_number(expiry);
+		Flow::on_finalize(expiry);
+		let first_balance = CampaignBalance::<Test>::get(&first_id);
+		let second_balance = CampaignBalance::<Test>::get(&second_id);
+		System::set_block_number(end);
+		Flow::on_finalize(end);
+		assert_eq!(CampaignStates::<Test>::get(&failed_id), CampaignState::Failed);
+		assert_eq!(MatchingRoundStates::<Test>::get(0), RoundState::Tallying);
+
+		// Contributions are tallied in batches
+		System::set_block_number(end + 1);
+		Flow::on_initialize(end + 1);
+		assert_eq!(MatchingTallyQueue::<Test>::get(0).map(|(index, _)| index), Some(0));
+		assert_eq!(MatchingRoundStates::<Test>::get(0), RoundState::Tallying);
+		System::set_block_number(end + 2);
+		Flow::on_initialize(end + 2);
+		assert!(MatchingTallyQueue::<Test>::get(0).is_none());
+		assert_eq!(MatchingRoundStates::<Test>::get(0), RoundState::Settled);
+
+		let first_sqrt = 4 * (5 * DOLLARS).integer_sqrt();
+		let first_quadratic = first_sqrt * first_sqrt - 20 * DOLLARS;
+		let second_sqrt = cap.integer_sqrt() + (4 * DOLLARS).integer_sqrt();
+		let second_quadratic = second_sqrt * second_sqrt - cap - 4 * DOLLARS;
+		let total = first_quadratic + second_quadratic;
+		let first_match = pool * first_quadratic / total;
+		let second_match = pool * second_quadratic / total;
+		assert!(first_match > second_match);
+		System::assert_has_event(Event::Flow(crate::Event::MatchingFundsDistributed {
+			round_id: 0, campaign_id: first_id, amount: first_match
+		}));
+		System::assert_has_event(Event::Flow(crate::Event::MatchingFundsDistributed {
+			round_id: 0, campaign_id: second_id, amount: second_match
+		}));
+		let distributed = first_match + second_match;
+		System::assert_last_event(Event::Flow(crate::Event::MatchingRoundSettled {
+			round_id: 0, distributed, returned: pool - distributed
+		}));
+
+		// Matching funds are tracked apart from the campaign balance, the rest is returned to the funder
+		assert_ok!(Flow::settle_contribution(Origin::signed(7), failed_id, 7));
+		assert_eq!(CampaignBalance::<Test>::get(&first_id), first_balance);
+		assert_eq!(CampaignMatchingFunds::<Test>::get(&first_id), first_match);
+		assert_eq!(CampaignMatchingFunds::<Test>::get(&second_id), second_match);
+		assert_eq!(
+			Tokens::reserved_balance(PAYMENT_TOKEN_ID, &treasury_id),
+			first_balance + second_balance + distributed
+		);
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &treasury_id), 100 * DOLLARS - distributed);
+
+		// Campaign is matched by a single round
+		assert_noop!(
+			round(Origin::signed(BOB), Some(org_id), pool, end + 10, vec![first_id]),
+			Error::<Test>::InvalidMatchingRound
+		);
+
+		// Matching funds are released with the milestone
+		let block_number = end + 2;
+		assert_ok!(Flow::approve_milestone(Origin::root(), first_id, 0, true));
+		System::assert_last_event(Event::Flow(crate::Event::MilestoneReleased {
+			campaign_id: first_id, index: 0, amount: first_balance + first_match, block_number
+		}));
+		assert_eq!(CampaignMatchingFunds::<Test>::get(&first_id), 0);
+
+		// Matching funds of the refunded milestone are returned to the funder, contributors claim the rest
+		assert_ok!(Flow::approve_milestone(Origin::root(), second_id, 0, false));
+		System::assert_has_event(Event::Flow(crate::Event::MatchingFundsReturned {
+			round_id: 0, campaign_id: second_id, amount: second_match
+		}));
+		System::assert_last_event(Event::Flow(crate::Event::MilestoneRefunded {
+			campaign_id: second_id, index: 0, amount: second_balance, block_number
+		}));
+		assert_eq!(CampaignMatchingFunds::<Test>::get(&second_id), 0);
+		assert_eq!(Tokens::reserved_balance(PAYMENT_TOKEN_ID, &treasury_id), second_balance);
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &treasury_id), 100 * DOLLARS + first_balance);
+	});
+}
+
+/// Tests settlement when two campaigns created
 #[test]
 fn flow_on_finalize_campaign_succeess() {
 	new_test_ext().execute_with(|| {
@@ -322,7 +885,7 @@ fn flow_on_finalize_campaign_succeess() {
 		assert_ok!(Flow::create_campaign(
 			Origin::signed(campaign_rev.owner), org_id, BOB, campaign_rev.name.clone(), campaign_rev.cap,
 			campaign_rev.deposit, campaign_rev.expiry, campaign_rev.protocol.clone(), campaign_rev.governance.clone(),
-			campaign_rev.cid.clone(), None, None, None
+			campaign_rev.cid.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
 		));
 
 		// Contribute (10/500)
@@ -337,70 +900,34 @@ fn flow_on_finalize_campaign_succeess() {
 		assert_ok!(Flow::create_campaign(
 			Origin::signed(campaign.owner), org_id, BOB, campaign.name.clone(), campaign.cap,
 			campaign.deposit, campaign.expiry, campaign.protocol.clone(), campaign.governance.clone(),
-			campaign.cid.clone(), None, None, None
+			campaign.cid.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
 		));
 
-		let mut contributors: Vec<AccountId> = (1..11).collect();
+		let contributors: Vec<AccountId> = (1..11).collect();
 		let total_contributors: u128 = contributors.len().try_into().unwrap();
 		// Contribute (600/500)
 		for c in &contributors {
 			assert_ok!(Flow::contribute(Origin::signed(*c), campaign_id, contribution));
 		}
 
-		// --------- Block 0 (expiry): Schedule settlements ---------
-		System::set_block_number(expiry);
-		Flow::on_finalize(expiry);
-
-		// Ensure that campaign was scheduled to be finalized
-		let (campaign_, campaign_balance_, campaign_state_, treasury_id_, mut contributors_) = CampaignFinalizationQueue::<Test>::get(&campaign_id).unwrap();
-		assert_eq!(campaign, campaign_);
-		assert_eq!(600 * DOLLARS, campaign_balance_);
-		assert_eq!(CampaignState::Succeeded, campaign_state_);
-		assert_eq!(treasury_id, treasury_id_);
-		assert_eq!(contributors.sort(), contributors_.sort());
-		// Ensure that campaign will be finalize in 3 blocks: 4 + 4 + 2
-		let batch_size: u128 = 4;
-		assert_eq!(MaxContributorsProcessing::get(), batch_size as u32);
-
-		// --------- Block 1: process 1 contributor from Campaign1 and revert Campaign1, process 3 contributors from Campaign2 ---------
-		System::set_block_number(expiry + 1);
-		Flow::on_initialize(expiry + 1);
-
-		// Campaign1 failed with 1 contributor, so we don't count his contribution
+		// Contributions are held by the org treasury
 		assert_eq!(
-			<Test as Config>::Currency::total_balance(PAYMENT_TOKEN_ID, &treasury_id),
-			batch_size * contribution - contribution
-		);
-		assert_eq!(
-			<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &treasury_id),
-			0
+			<Test as Config>::Currency::reserved_balance(PAYMENT_TOKEN_ID, &treasury_id),
+			10 * DOLLARS + total_contributors * contribution
 		);
 
-		// --------- Block 2: process next 4 contributors from Campaign2 ---------
-		System::set_block_number(expiry + 2);
-		Flow::on_initialize(expiry + 2);
-
-		assert_eq!(
-			<Test as Config>::Currency::total_balance(PAYMENT_TOKEN_ID, &treasury_id),
-			2 * batch_size * contribution - contribution
-		);
-		assert_eq!(
-			<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &treasury_id),
-			0
-		);
-		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Active);
+		// --------- Expiry: the outcome is fixed for both campaigns at once ---------
+		System::set_block_number(expiry);
+		Flow::on_finalize(expiry);
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Succeeded);
 		assert_eq!(CampaignStates::<Test>::get(&campaign_id_rev), CampaignState::Failed);
 
-		// --------- Block 3: process last 3 contributors from Campaign2 and finalize Campaign2 ---------
-		System::set_block_number(expiry + 3);
-		Flow::on_initialize(expiry + 3);
-
 		// Campaign finalized:
 		let commission = <Test as Config>::CampaignFee::get().mul_floor(contribution * total_contributors);
-		// The balance was transfered and locked in the org treasury
+		// The balance is locked in the org treasury, the failed campaign contribution is held until settled
 		assert_eq!(
 			<Test as Config>::Currency::total_balance(PAYMENT_TOKEN_ID, &treasury_id),
-			total_contributors * contribution - commission
+			10 * DOLLARS + total_contributors * contribution - commission
 		);
 		assert_eq!(
 			<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &treasury_id),
@@ -416,11 +943,8 @@ fn flow_on_finalize_campaign_succeess() {
 			<Test as Config>::Currency::free_balance(PROTOCOL_TOKEN_ID, &treasury_id),
 			tbalance - deposit
 		);
-		// Ensure that one campaign succeeded, and the other failed
-		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Succeeded);
-		assert_eq!(CampaignStates::<Test>::get(&campaign_id_rev), CampaignState::Failed);
 
-		// Last event:
+		// Last events:
 		let events = System::events()
 			.into_iter()
 			.map(|evt| evt.event)
@@ -432,7 +956,7 @@ fn flow_on_finalize_campaign_succeess() {
 			crate::Event::Failed {
 				campaign_id: campaign_id_rev,
 				campaign_balance: CampaignBalance::<Test>::get(campaign_id_rev),
-				block_number: expiry + 1,
+				block_number: expiry,
 			}
 		);
 		assert_eq!(
@@ -440,10 +964,22 @@ fn flow_on_finalize_campaign_succeess() {
 			crate::Event::Succeeded {
 				campaign_id,
 				campaign_balance: CampaignBalance::<Test>::get(campaign_id),
-				block_number: expiry + 3,
+				block_number: expiry,
 			}
 		);
 
+		// Nothing to settle for the succeeded Raise campaign
+		assert_noop!(Flow::settle_contribution(Origin::signed(1), campaign_id, 1), Error::<Test>::NothingToClaim);
+		// Failed campaign contribution is refunded
+		assert_ok!(Flow::settle_contribution(Origin::signed(1), campaign_id_rev, 1));
+		assert_eq!(
+			<Test as Config>::Currency::total_balance(PAYMENT_TOKEN_ID, &treasury_id),
+			total_contributors * contribution - commission
+		);
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &1),
+			INIT_BALANCE - contribution
+		);
 	});
 }
 
@@ -467,7 +1003,7 @@ fn flow_on_finalize_campaign_failed() {
 		assert_ok!(Flow::create_campaign(
 			Origin::signed(campaign.owner), org_id, BOB, campaign.name.clone(), campaign.cap,
 			campaign.deposit, campaign.expiry, campaign.protocol.clone(), campaign.governance.clone(),
-			campaign.cid.clone(), None, None, None
+			campaign.cid.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
 		));
 
 		let contributors: Vec<AccountId> = (1..11).collect();
@@ -476,65 +1012,21 @@ fn flow_on_finalize_campaign_failed() {
 			assert_ok!(Flow::contribute(Origin::signed(*c), campaign_id, contribution));
 		}
 
-		// --------- Block 0 (expiry): Schedule settlements ---------
+		// Contributions can't be settled before the outcome is fixed
+		assert_noop!(
+			Flow::settle_contribution(Origin::signed(1), campaign_id, 1),
+			Error::<Test>::CampaignNotFinalized
+		);
+
+		// --------- Expiry: the campaign fails at once ---------
 		System::set_block_number(expiry);
 		Flow::on_finalize(expiry);
-		// Ensure that campaign will be reverted in 3 blocks: 4 + 4 + 2
-		let batch_size: u128 = 4;
-		assert_eq!(MaxContributorsProcessing::get(), batch_size as u32);
-
-		let (_, _, _, _, c) = CampaignFinalizationQueue::<Test>::get(&campaign_id).unwrap();
-
-		// --------- Block 1: process first 4 contributors ---------
-		System::set_block_number(expiry + 1);
-		Flow::on_initialize(expiry + 1);
-
-		// Account's balance from the first batch was unlocked
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[0]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[1]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[2]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[3]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[4]), INIT_BALANCE - contribution);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[5]), INIT_BALANCE - contribution);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[6]), INIT_BALANCE - contribution);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[7]), INIT_BALANCE - contribution);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[8]), INIT_BALANCE - contribution);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[9]), INIT_BALANCE - contribution);
-
-		// --------- Block 2: process next 4 contributors ---------
-		System::set_block_number(expiry + 2);
-		Flow::on_initialize(expiry + 2);
-
-		// Account's balance from the second batch was unlocked
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[0]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[1]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[2]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[3]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[4]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[5]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[6]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[7]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[8]), INIT_BALANCE - contribution);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[9]), INIT_BALANCE - contribution);
-
-		// --------- Block 3: process last 2 contributors and set Campaign's status to FAILED ---------
-		System::set_block_number(expiry + 3);
-		Flow::on_initialize(expiry + 3);
-
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[0]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[1]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[2]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[3]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[4]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[5]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[6]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[7]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[8]), INIT_BALANCE);
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &c[9]), INIT_BALANCE);
-
-		// Nothing transferred into the org treasury
-		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &treasury_id), 0);
-		assert_eq!(<Test as Config>::Currency::total_balance(PAYMENT_TOKEN_ID, &treasury_id), 0);
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Failed);
+		System::assert_has_event(Event::Flow(crate::Event::Failed {
+			campaign_id,
+			campaign_balance: CampaignBalance::<Test>::get(campaign_id),
+			block_number: expiry,
+		}));
 		// Initial deposit was unlocked
 		assert_eq!(
 			<Test as Config>::Currency::free_balance(PROTOCOL_TOKEN_ID, &treasury_id),
@@ -544,14 +1036,614 @@ fn flow_on_finalize_campaign_failed() {
 			<Test as Config>::Currency::total_balance(PROTOCOL_TOKEN_ID, &treasury_id),
 			tbalance
 		);
-		// Ensure that campaign failed
-		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Failed);
 
-		System::assert_has_event(Event::Flow(crate::Event::Failed {
-			campaign_id,
-			campaign_balance: CampaignBalance::<Test>::get(campaign_id),
-			block_number: expiry + 3,
+		// Contributors settle the refunds, anyone can settle on behalf of the contributor
+		assert_ok!(Flow::settle_contribution(Origin::signed(ALICE), campaign_id, contributors[0]));
+		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &contributors[0]), INIT_BALANCE);
+		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &contributors[1]), INIT_BALANCE - contribution);
+		System::assert_last_event(Event::Flow(crate::Event::ContributionSettled {
+			campaign_id, who: contributors[0], amount: contribution
+		}));
+		assert_noop!(
+			Flow::settle_contribution(Origin::signed(ALICE), campaign_id, contributors[0]),
+			Error::<Test>::AlreadySettled
+		);
+		assert_noop!(
+			Flow::settle_contribution(Origin::signed(ALICE), campaign_id, ALICE),
+			Error::<Test>::NothingToClaim
+		);
+		for c in &contributors[1..] {
+			assert_ok!(Flow::settle_contribution(Origin::signed(*c), campaign_id, *c));
+			assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, c), INIT_BALANCE);
+		}
+
+		// Nothing left in the org treasury
+		assert_eq!(<Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &treasury_id), 0);
+		assert_eq!(<Test as Config>::Currency::total_balance(PAYMENT_TOKEN_ID, &treasury_id), 0);
+	});
+}
+
+#[test]
+fn flow_grant_milestones() {
+	new_test_ext().execute_with(|| {
+		let (org_id, treasury_id, _) = create_org_treasury();
+		let now = 3;
+		System::set_block_number(now);
+
+		let expiry = now + 2 * DAYS;
+		let deposit = 10 * DOLLARS;
+		let target = 20 * DOLLARS;
+		let index = CampaignCount::<Test>::get();
+		let (_, mut campaign) = create_campaign(index, org_id, BOB, now, expiry, now, deposit, target);
+		campaign.protocol = FlowProtocol::Grant;
+		let campaign_id = <Test as frame_system::Config>::Hashing::hash_of(&campaign);
+		let milestone = |amount: Balance, deadline: BlockNumber| Milestone::<Test> {
+			cid: BoundedVec::truncate_from(vec![1, 2, 3]), amount, deadline
+		};
+		let create = |protocol: FlowProtocol, milestones: Vec<Milestone<Test>>| Flow::create_campaign(
+			Origin::signed(BOB), org_id, BOB, campaign.name.clone(), campaign.cap,
+			campaign.deposit, campaign.expiry, protocol, campaign.governance.clone(),
+			campaign.cid.clone(), None, None, None, None, BoundedVec::truncate_from(milestones), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
+		);
+
+		// Milestones are set for a non Grant campaign
+		assert_noop!(create(FlowProtocol::Raise, vec![milestone(target, expiry + 1)]), Error::<Test>::InvalidMilestones);
+		// Grant campaign without milestones
+		assert_noop!(create(FlowProtocol::Grant, vec![]), Error::<Test>::InvalidMilestones);
+		// Milestones don't sum up to the target
+		assert_noop!(create(FlowProtocol::Grant, vec![milestone(target / 2, expiry + 1)]), Error::<Test>::InvalidMilestones);
+		// Deadline before the campaign expiry
+		assert_noop!(
+			create(FlowProtocol::Grant, vec![milestone(target, expiry)]),
+			Error::<Test>::InvalidMilestones
+		);
+		// Deadlines are not increasing
+		assert_noop!(
+			create(FlowProtocol::Grant, vec![milestone(target / 2, expiry + 20), milestone(target / 2, expiry + 10)]),
+			Error::<Test>::InvalidMilestones
+		);
+
+		let milestones = vec![milestone(8 * DOLLARS, expiry + 10), milestone(12 * DOLLARS, expiry + 20)];
+		assert_ok!(create(FlowProtocol::Grant, milestones.clone()));
+		assert_eq!(CampaignMilestones::<Test>::get(&campaign_id).into_inner(), milestones);
+		assert_eq!(MilestonesByBlock::<Test>::get(expiry + 20).into_inner(), vec![(campaign_id, 1)]);
+		assert!(<Flow as FlowTrait<AccountId, Balance, H256>>::has_milestones(&campaign_id));
+
+		assert_ok!(Flow::contribute(Origin::signed(1), campaign_id, 5 * DOLLARS));
+		assert_ok!(Flow::contribute(Origin::signed(2), campaign_id, 15 * DOLLARS));
+		assert_noop!(Flow::approve_milestone(Origin::signed(BOB), campaign_id, 0, true), Error::<Test>::CampaignUnsucceeded);
+
+		System::set_block_number(expiry);
+		Flow::on_finalize(expiry);
+		System::set_block_number(expiry + 1);
+		Flow::on_initialize(expiry + 1);
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Succeeded);
+
+		// Raised funds stay reserved in the org treasury
+		let balance = CampaignBalance::<Test>::get(&campaign_id);
+		assert_eq!(Tokens::reserved_balance(PAYMENT_TOKEN_ID, &treasury_id), balance);
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &treasury_id), 0);
+
+		// Milestones are approved in order by the members with SpendFunds permission
+		let first = Perbill::from_rational(8u32, 20u32).mul_floor(balance);
+		assert_eq!(<Flow as FlowTrait<AccountId, Balance, H256>>::next_milestone(&campaign_id), Some((0, first)));
+		assert_noop!(Flow::approve_milestone(Origin::signed(ALICE), campaign_id, 0, true), Error::<Test>::AuthorizationError);
+		assert_noop!(Flow::approve_milestone(Origin::signed(BOB), campaign_id, 1, true), Error::<Test>::MilestoneUnknown);
+		assert_ok!(Flow::approve_milestone(Origin::signed(BOB), campaign_id, 0, true));
+		assert_eq!(MilestoneStates::<Test>::get(&campaign_id, 0), MilestoneState::Released);
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &treasury_id), first);
+		System::assert_last_event(Event::Flow(crate::Event::MilestoneReleased {
+			campaign_id, index: 0, amount: first, block_number: expiry + 1
+		}));
+		assert_noop!(Flow::approve_milestone(Origin::signed(BOB), campaign_id, 0, true), Error::<Test>::MilestoneUnknown);
+
+		// Missed deadline: the last milestone gets the rest and is refunded pro rata
+		let second = balance - first;
+		System::set_block_number(expiry + 20);
+		Flow::on_finalize(expiry + 20);
+		assert_eq!(MilestoneStates::<Test>::get(&campaign_id, 1), MilestoneState::Refunded);
+		assert_eq!(<Flow as FlowTrait<AccountId, Balance, H256>>::next_milestone(&campaign_id), None);
+		assert_eq!(MilestoneRefunds::<Test>::get(&campaign_id, 1), Some((second, 20 * DOLLARS)));
+
+		// Contributors claim their part of the refund, anyone can claim on behalf of the contributor
+		assert_noop!(Flow::claim_milestone_refund(Origin::signed(ALICE), campaign_id, 0, 1), Error::<Test>::MilestoneUnknown);
+		assert_noop!(Flow::claim_milestone_refund(Origin::signed(ALICE), campaign_id, 1, ALICE), Error::<Test>::NothingToClaim);
+		assert_ok!(Flow::claim_milestone_refund(Origin::signed(ALICE), campaign_id, 1, 1));
+		assert_ok!(Flow::claim_milestone_refund(Origin::signed(2), campaign_id, 1, 2));
+		System::assert_last_event(Event::Flow(crate::Event::MilestoneRefundClaimed {
+			campaign_id, index: 1, who: 2, amount: 15 * second / 20
+		}));
+		assert_noop!(Flow::claim_milestone_refund(Origin::signed(2), campaign_id, 1, 2), Error::<Test>::AlreadySettled);
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &1), INIT_BALANCE - 5 * DOLLARS + 5 * second / 20);
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &2), INIT_BALANCE - 15 * DOLLARS + 15 * second / 20);
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &treasury_id), first);
+	});
+}
+
+#[test]
+fn flow_loan_campaign() {
+	new_test_ext().execute_with(|| {
+		let (org_id, treasury_id, _) = create_org_treasury();
+		let now = 3;
+		System::set_block_number(now);
+
+		let expiry = now + 2 * DAYS;
+		let deposit = 10 * DOLLARS;
+		let target = 20 * DOLLARS;
+		let period = 100;
+		let index = CampaignCount::<Test>::get();
+		let (_, mut campaign) = create_campaign(index, org_id, BOB, now, expiry, now, deposit, target);
+		campaign.protocol = FlowProtocol::Loan;
+		let campaign_id = <Test as frame_system::Config>::Hashing::hash_of(&campaign);
+		let terms = LoanTerms { interest: Permill::from_percent(10), installments: 2, period };
+		let create = |protocol: FlowProtocol, terms: Option<LoanTerms<BlockNumber>>| Flow::create_campaign(
+			Origin::signed(BOB), org_id, BOB, campaign.name.clone(), campaign.cap,
+			campaign.deposit, campaign.expiry, protocol, campaign.governance.clone(),
+			campaign.cid.clone(), None, None, None, None, BoundedVec::new(), terms, None, None, None, None, BoundedVec::new(), BoundedVec::new()
+		);
+
+		// Loan campaign without the terms
+		assert_noop!(create(FlowProtocol::Loan, None), Error::<Test>::InvalidLoanTerms);
+		// Terms are set for a non Loan campaign
+		assert_noop!(create(FlowProtocol::Raise, Some(terms.clone())), Error::<Test>::InvalidLoanTerms);
+		// No installments
+		assert_noop!(
+			create(FlowProtocol::Loan, Some(LoanTerms { installments: 0, ..terms.clone() })),
+			Error::<Test>::InvalidLoanTerms
+		);
+
+		assert_ok!(create(FlowProtocol::Loan, Some(terms.clone())));
+		assert_ok!(Flow::contribute(Origin::signed(1), campaign_id, 5 * DOLLARS));
+		assert_ok!(Flow::contribute(Origin::signed(2), campaign_id, 15 * DOLLARS));
+		System::set_block_number(expiry);
+		Flow::on_finalize(expiry);
+		System::set_block_number(expiry + 1);
+		Flow::on_initialize(expiry + 1);
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Succeeded);
+
+		// Lent funds are released to the org
+		let balance = CampaignBalance::<Test>::get(&campaign_id);
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &treasury_id), balance);
+		assert_eq!(
+			Loans::<Test>::get(&campaign_id),
+			Some(Loan { principal: target, due: 22 * DOLLARS, repaid: 0, installment: 0 })
+		);
+		assert_eq!(LoansByBlock::<Test>::get(expiry + period).into_inner(), vec![campaign_id]);
+
+		// First installment is repaid
+		assert_noop!(
+			Flow::repay_loan(Origin::signed(ALICE), campaign_id, 11 * DOLLARS),
+			Error::<Test>::AuthorizationError
+		);
+		assert_ok!(Flow::repay_loan(Origin::signed(BOB), campaign_id, 11 * DOLLARS));
+		System::assert_last_event(Event::Flow(crate::Event::LoanRepaid {
+			campaign_id, amount: 11 * DOLLARS, repaid: 11 * DOLLARS, block_number: expiry + 1
+		}));
+		assert_eq!(Tokens::reserved_balance(PAYMENT_TOKEN_ID, &treasury_id), 11 * DOLLARS);
+
+		// Lenders claim pro rata
+		assert_ok!(Flow::claim_repayment(Origin::signed(1), campaign_id));
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &1), INIT_BALANCE - 5 * DOLLARS + 11 * DOLLARS / 4);
+		assert_noop!(Flow::claim_repayment(Origin::signed(1), campaign_id), Error::<Test>::NothingToClaim);
+		assert_noop!(Flow::claim_repayment(Origin::signed(ALICE), campaign_id), Error::<Test>::NothingToClaim);
+
+		// First installment is due: next one is scheduled, moved to the next block as the due block is full
+		LoansByBlock::<Test>::insert(
+			expiry + 2 * period, BoundedVec::truncate_from(vec![H256::repeat_byte(1), H256::repeat_byte(2)])
+		);
+		System::set_block_number(expiry + period);
+		Flow::on_finalize(expiry + period);
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Succeeded);
+		assert_eq!(Loans::<Test>::get(&campaign_id).unwrap().installment, 1);
+		assert_eq!(LoansByBlock::<Test>::get(expiry + 2 * period + 1).into_inner(), vec![campaign_id]);
+
+		// Second installment is missed: the loan defaults
+		System::set_block_number(expiry + 2 * period);
+		Flow::on_finalize(expiry + 2 * period);
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Succeeded);
+		System::set_block_number(expiry + 2 * period + 1);
+		Flow::on_finalize(expiry + 2 * period + 1);
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Defaulted);
+		assert!(<Flow as FlowTrait<AccountId, Balance, H256>>::is_campaign_defaulted(&campaign_id));
+		assert_eq!(<Flow as FlowTrait<AccountId, Balance, H256>>::loan_outstanding(&campaign_id), Some(11 * DOLLARS));
+
+		// Repayment exceeding the due amount is capped
+		assert_ok!(<Test as Config>::Currency::deposit(PAYMENT_TOKEN_ID, &treasury_id, 10 * DOLLARS));
+		assert_ok!(Flow::repay_loan(Origin::root(), campaign_id, 100 * DOLLARS));
+		assert_eq!(<Flow as FlowTrait<AccountId, Balance, H256>>::loan_outstanding(&campaign_id), Some(0));
+		assert_noop!(Flow::repay_loan(Origin::root(), campaign_id, DOLLARS), Error::<Test>::LoanUnknown);
+		assert_ok!(Flow::claim_repayment(Origin::signed(2), campaign_id));
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &2), INIT_BALANCE - 15 * DOLLARS + 22 * DOLLARS * 3 / 4);
+	});
+}
+
+#[test]
+fn flow_share_campaign() {
+	new_test_ext().execute_with(|| {
+		let (org_id, treasury_id, _) = create_org_treasury();
+		let now = 3;
+		System::set_block_number(now);
+
+		let expiry = now + 2 * DAYS;
+		let deposit = 10 * DOLLARS;
+		let target = 20 * DOLLARS;
+		let token_str: BoundedVec<u8, <Test as Config>::StringLimit> = BoundedVec::truncate_from(b"SHR".to_vec());
+		let create = |index: u32, protocol: FlowProtocol, token: Option<BoundedVec<u8, <Test as Config>::StringLimit>>, retained: Option<Permill>| {
+			let (_, mut campaign) = create_campaign(index, org_id, BOB, now, expiry, now, deposit, target);
+			campaign.protocol = protocol;
+			campaign.token_symbol = token.clone();
+			campaign.token_name = token;
+			let campaign_id = <Test as frame_system::Config>::Hashing::hash_of(&campaign);
+			Flow::create_campaign(
+				Origin::signed(BOB), org_id, BOB, campaign.name.clone(), campaign.cap,
+				campaign.deposit, campaign.expiry, campaign.protocol, campaign.governance,
+				campaign.cid, None, campaign.token_symbol, campaign.token_name, None, BoundedVec::new(), None, retained, None, None, None, BoundedVec::new(), BoundedVec::new()
+			).map(|_| campaign_id)
+		};
+
+		// Share campaign without the token symbol and name
+		assert_noop!(create(0, FlowProtocol::Share, None, None), Error::<Test>::InvalidShareTerms);
+		// Retained share for a non Share campaign
+		assert_noop!(
+			create(0, FlowProtocol::Raise, Some(token_str.clone()), Some(Permill::from_percent(20))),
+			Error::<Test>::InvalidShareTerms
+		);
+		// Org can't retain the whole token
+		assert_noop!(
+			create(0, FlowProtocol::Share, Some(token_str.clone()), Some(Permill::from_percent(100))),
+			Error::<Test>::InvalidShareTerms
+		);
+
+		// Token id is already in use
+		let currency_id = CAMPAIGN_TOKEN_ID_OFFSET;
+		assert_ok!(Tokens::set_balance(RawOrigin::Root.into(), 9, currency_id, DOLLARS, 0));
+		assert_noop!(
+			create(0, FlowProtocol::Share, Some(token_str.clone()), None),
+			Error::<Test>::CampaignTokenExists
+		);
+		assert_ok!(Tokens::set_balance(RawOrigin::Root.into(), 9, currency_id, 0, 0));
+
+		let campaign_id = create(0, FlowProtocol::Share, Some(token_str.clone()), Some(Permill::from_percent(20))).unwrap();
+		assert_eq!(
+			CampaignTokenMetadata::<Test>::get(currency_id),
+			Some(TokenMetadata::<Test> { name: token_str.clone(), symbol: token_str.clone() })
+		);
+		let failed_campaign_id = create(1, FlowProtocol::Share, Some(token_str), None).unwrap();
+		assert_ok!(Flow::contribute(Origin::signed(1), campaign_id, 5 * DOLLARS));
+		assert_ok!(Flow::contribute(Origin::signed(2), campaign_id, 15 * DOLLARS));
+		assert_ok!(Flow::contribute(Origin::signed(3), failed_campaign_id, 5 * DOLLARS));
+
+		System::set_block_number(expiry);
+		Flow::on_finalize(expiry);
+		System::set_block_number(expiry + 1);
+		Flow::on_initialize(expiry + 1);
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Succeeded);
+		assert_eq!(CampaignStates::<Test>::get(&failed_campaign_id), CampaignState::Failed);
+
+		// Contributors claim the token pro rata, the org keeps the retained share
+		assert_ok!(Flow::settle_contribution(Origin::signed(1), campaign_id, 1));
+		assert_ok!(Flow::settle_contribution(Origin::signed(1), campaign_id, 2));
+		assert_eq!(CampaignToken::<Test>::get(&campaign_id), Some(currency_id));
+		assert_eq!(Tokens::free_balance(currency_id, &1), 4 * DOLLARS);
+		assert_eq!(Tokens::free_balance(currency_id, &2), 12 * DOLLARS);
+		assert_eq!(Tokens::free_balance(currency_id, &treasury_id), 4 * DOLLARS);
+		assert_eq!(Tokens::total_issuance(currency_id), target);
+		System::assert_has_event(Event::Flow(crate::Event::CampaignTokenIssued {
+			campaign_id, currency_id, retained: 4 * DOLLARS
 		}));
+		assert!(<Flow as FlowTrait<AccountId, Balance, H256>>::has_campaign_token(&campaign_id));
+		assert!(<Flow as FlowTrait<AccountId, Balance, H256>>::is_campaign_token_holder(&campaign_id, &2));
+		assert!(!<Flow as FlowTrait<AccountId, Balance, H256>>::is_campaign_token_holder(&campaign_id, &3));
+
+		// Failed campaign issues no token
+		assert_eq!(CampaignToken::<Test>::get(&failed_campaign_id), None);
+		assert_eq!(Tokens::total_issuance(CAMPAIGN_TOKEN_ID_OFFSET + 1), 0);
+		assert_ok!(Flow::settle_contribution(Origin::signed(3), failed_campaign_id, 3));
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &3), INIT_BALANCE);
+	});
+}
+
+#[test]
+fn flow_pool_campaign() {
+	new_test_ext().execute_with(|| {
+		let (org_id, treasury_id, _) = create_org_treasury();
+		let now = 3;
+		System::set_block_number(now);
+
+		let expiry = now + 2 * DAYS;
+		let deposit = 10 * DOLLARS;
+		let target = 20 * DOLLARS;
+		let terms = PoolTerms { redemption_period: 10, redemption_window: 2 };
+		let create = |protocol: FlowProtocol, pool_terms: Option<PoolTerms<BlockNumber>>| {
+			let (_, mut campaign) = create_campaign(0, org_id, BOB, now, expiry, now, deposit, target);
+			campaign.protocol = protocol;
+			let campaign_id = <Test as frame_system::Config>::Hashing::hash_of(&campaign);
+			Flow::create_campaign(
+				Origin::signed(BOB), org_id, BOB, campaign.name.clone(), campaign.cap,
+				campaign.deposit, campaign.expiry, campaign.protocol, campaign.governance,
+				campaign.cid, None, None, None, None, BoundedVec::new(), None, None, pool_terms, None, None, BoundedVec::new(), BoundedVec::new()
+			).map(|_| campaign_id)
+		};
+
+		// Pool campaign without the redemption terms
+		assert_noop!(create(FlowProtocol::Pool, None), Error::<Test>::InvalidPoolTerms);
+		// Redemption terms for a non Pool campaign
+		assert_noop!(create(FlowProtocol::Raise, Some(terms.clone())), Error::<Test>::InvalidPoolTerms);
+		// Redemption window longer than the period
+		assert_noop!(
+			create(FlowProtocol::Pool, Some(PoolTerms { redemption_period: 10, redemption_window: 11 })),
+			Error::<Test>::InvalidPoolTerms
+		);
+
+		let campaign_id = create(FlowProtocol::Pool, Some(terms)).unwrap();
+		let pool_trait = |id: &H256| <Flow as FlowTrait<AccountId, Balance, H256>>::is_pool(id);
+		assert!(pool_trait(&campaign_id));
+
+		// First contribution gets the shares one to one, funds are moved to the org treasury
+		assert_ok!(Flow::contribute(Origin::signed(1), campaign_id, 10 * DOLLARS));
+		assert_eq!(PoolShares::<Test>::get(&campaign_id, &1), 10 * DOLLARS);
+		assert_eq!(Tokens::reserved_balance(PAYMENT_TOKEN_ID, &1), 0);
+		assert_eq!(Tokens::reserved_balance(PAYMENT_TOKEN_ID, &treasury_id), 10 * DOLLARS);
+
+		// Org deploys a part of the pooled funds, the net asset value drops
+		assert_ok!(<Flow as FlowTrait<AccountId, Balance, H256>>::spend_pool(&campaign_id, 4 * DOLLARS));
+		assert_noop!(
+			<Flow as FlowTrait<AccountId, Balance, H256>>::spend_pool(&campaign_id, 7 * DOLLARS),
+			Error::<Test>::BalanceLow
+		);
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &treasury_id), 4 * DOLLARS);
+		System::assert_has_event(Event::Flow(crate::Event::PoolSpent { campaign_id, amount: 4 * DOLLARS }));
+
+		// Next contribution gets the shares at the current net asset value
+		assert_ok!(Flow::contribute(Origin::signed(2), campaign_id, 3 * DOLLARS));
+		assert_eq!(PoolShares::<Test>::get(&campaign_id, &2), 5 * DOLLARS);
+		assert_eq!(Pools::<Test>::get(&campaign_id), PoolInfo { shares: 15 * DOLLARS, balance: 9 * DOLLARS });
+		assert_eq!(CampaignBalance::<Test>::get(&campaign_id), 9 * DOLLARS);
+
+		// Pool stays open after the expiry, the deposit is returned at the expiry while paused as well
+		assert_ok!(Flow::pause_campaign(Origin::signed(BOB), campaign_id));
+		System::set_block_number(expiry);
+		Flow::on_finalize(expiry);
+		assert_eq!(Tokens::reserved_balance(PROTOCOL_TOKEN_ID, &treasury_id), 0);
+		assert_noop!(
+			Flow::resume_campaign(Origin::signed(BOB), campaign_id, Some(expiry + 10)),
+			Error::<Test>::OutOfBounds
+		);
+		assert_ok!(Flow::resume_campaign(Origin::signed(BOB), campaign_id, None));
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Active);
+		assert_eq!(CampaignOf::<Test>::get(&campaign_id).unwrap().expiry, expiry);
+
+		// Repeated deposit and redemption never pays out more than deposited
+		let block = expiry + (10 - (expiry - now) % 10) % 10;
+		System::set_block_number(block);
+		for _ in 0..3 {
+			assert_ok!(Flow::contribute(Origin::signed(3), campaign_id, 1 * DOLLARS + 1));
+			let shares = PoolShares::<Test>::get(&campaign_id, &3);
+			assert_ok!(Flow::redeem_shares(Origin::signed(3), campaign_id, shares));
+		}
+		assert!(Tokens::free_balance(PAYMENT_TOKEN_ID, &3) <= INIT_BALANCE);
+		let pool = Pools::<Test>::get(&campaign_id);
+		assert_eq!(pool.shares, 15 * DOLLARS);
+		assert!(pool.balance >= 9 * DOLLARS);
+
+		// Redemption is possible during the windows only
+		System::set_block_number(block + 2);
+		assert_noop!(
+			Flow::redeem_shares(Origin::signed(1), campaign_id, 10 * DOLLARS),
+			Error::<Test>::RedemptionClosed
+		);
+		System::set_block_number(block + 11);
+		assert_noop!(
+			Flow::redeem_shares(Origin::signed(1), campaign_id, 10 * DOLLARS + 1),
+			Error::<Test>::SharesInsufficient
+		);
+		let free_balance = Tokens::free_balance(PAYMENT_TOKEN_ID, &1);
+		let amount = pool.balance * 2 / 3;
+		assert_ok!(Flow::redeem_shares(Origin::signed(1), campaign_id, 10 * DOLLARS));
+		assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &1), free_balance + amount);
+		System::assert_has_event(Event::Flow(crate::Event::PoolSharesRedeemed {
+			campaign_id, who: 1, shares: 10 * DOLLARS, amount
+		}));
+		assert_eq!(Pools::<Test>::get(&campaign_id), PoolInfo { shares: 5 * DOLLARS, balance: pool.balance - amount });
+
+		// Locked pool is unlocked by the org and resumed by the admin
+		assert_ok!(Flow::lock_campaign(Origin::root(), campaign_id));
+		assert_noop!(Flow::unlock_campaign(Origin::signed(1), campaign_id), Error::<Test>::AuthorizationError);
+		assert_ok!(Flow::unlock_campaign(Origin::root(), campaign_id));
+		System::assert_last_event(Event::Flow(crate::Event::Unlocked { campaign_id, state: CampaignState::Paused }));
+		assert_noop!(Flow::unlock_campaign(Origin::root(), campaign_id), Error::<Test>::InvalidCampaignState);
+		assert_ok!(Flow::resume_campaign(Origin::signed(BOB), campaign_id, None));
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Active);
+	});
+}
+
+#[test]
+fn flow_campaign_admin_controls() {
+	new_test_ext().execute_with(|| {
+		let (org_id, treasury_id, tbalance) = create_org_treasury();
+		let now = 3;
+		System::set_block_number(now);
+
+		let expiry = now + 2 * DAYS;
+		let deposit = 10 * DOLLARS;
+		let target = 100 * DOLLARS;
+		// Campaign is administrated by ALICE, BOB is the org prime
+		let create = |index: u32| {
+			let block = System::block_number();
+			let (_, mut campaign) = create_campaign(index, org_id, BOB, block, block + 2 * DAYS, block, deposit, target);
+			campaign.admin = ALICE;
+			let campaign_id = <Test as frame_system::Config>::Hashing::hash_of(&campaign);
+			assert_ok!(Flow::create_campaign(
+				Origin::signed(BOB), org_id, ALICE, campaign.name.clone(), campaign.cap,
+				campaign.deposit, campaign.expiry, campaign.protocol.clone(), campaign.governance.clone(),
+				campaign.cid.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
+			));
+			campaign_id
+		};
+		let campaign_id = create(0);
+		assert_ok!(Flow::contribute(Origin::signed(1), campaign_id, 10 * DOLLARS));
+
+		// Only the campaign admin or root can pause the campaign
+		assert_noop!(Flow::pause_campaign(Origin::signed(1), campaign_id), Error::<Test>::AuthorizationError);
+		assert_noop!(Flow::resume_campaign(Origin::signed(ALICE), campaign_id, None), Error::<Test>::InvalidCampaignState);
+		assert_ok!(Flow::pause_campaign(Origin::signed(ALICE), campaign_id));
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Paused);
+		System::assert_last_event(Event::Flow(crate::Event::Paused { campaign_id }));
+		assert_noop!(Flow::pause_campaign(Origin::signed(ALICE), campaign_id), Error::<Test>::InvalidCampaignState);
+
+		// Paused campaign doesn't accept contributions and is not finalized at the expiry
+		assert_noop!(
+			Flow::contribute(Origin::signed(2), campaign_id, 10 * DOLLARS),
+			Error::<Test>::NoContributionsAllowed
+		);
+		System::set_block_number(expiry);
+		Flow::on_finalize(expiry);
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Paused);
+
+		// Expired campaign is resumed with the new expiry only
+		assert_noop!(Flow::resume_campaign(Origin::signed(ALICE), campaign_id, None), Error::<Test>::OutOfBounds);
+		assert_noop!(
+			Flow::resume_campaign(Origin::signed(ALICE), campaign_id, Some(now + 101 * DAYS)),
+			Error::<Test>::OutOfBounds
+		);
+		let new_expiry = expiry + 1 * DAYS;
+		assert_ok!(Flow::resume_campaign(Origin::root(), campaign_id, Some(new_expiry)));
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Active);
+		assert_eq!(CampaignOf::<Test>::get(&campaign_id).unwrap().expiry, new_expiry);
+		assert!(!CampaignsByBlock::<Test>::get(BlockType::Expiry, expiry).contains(&campaign_id));
+		assert!(CampaignsByBlock::<Test>::get(BlockType::Expiry, new_expiry).contains(&campaign_id));
+		System::assert_last_event(Event::Flow(crate::Event::Resumed { campaign_id, expiry: new_expiry }));
+		assert_ok!(Flow::contribute(Origin::signed(2), campaign_id, 10 * DOLLARS));
+
+		// Locked campaign is not finalized and can be cancelled by root or the org only
+		assert_noop!(Flow::lock_campaign(Origin::signed(ALICE), campaign_id), Error::<Test>::AuthorizationError);
+		assert_ok!(Flow::lock_campaign(Origin::signed(BOB), campaign_id));
+		System::assert_last_event(Event::Flow(crate::Event::Locked { campaign_id }));
+		assert_noop!(Flow::resume_campaign(Origin::signed(ALICE), campaign_id, None), Error::<Test>::InvalidCampaignState);
+		assert_noop!(
+			Flow::contribute(Origin::signed(3), campaign_id, 10 * DOLLARS),
+			Error::<Test>::NoContributionsAllowed
+		);
+		assert_noop!(
+			Flow::settle_contribution(Origin::signed(1), campaign_id, 1),
+			Error::<Test>::CampaignNotFinalized
+		);
+		System::set_block_number(new_expiry);
+		Flow::on_finalize(new_expiry);
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Locked);
+		assert_noop!(Flow::unlock_campaign(Origin::signed(ALICE), campaign_id), Error::<Test>::AuthorizationError);
+		assert_ok!(Flow::unlock_campaign(Origin::signed(BOB), campaign_id));
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Paused);
+		assert_ok!(Flow::lock_campaign(Origin::signed(BOB), campaign_id));
+		assert_noop!(Flow::cancel_campaign(Origin::signed(ALICE), campaign_id), Error::<Test>::AuthorizationError);
+
+		// Cancelled campaign returns the deposit, contributors settle the refunds
+		assert_ok!(Flow::cancel_campaign(Origin::signed(BOB), campaign_id));
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Cancelled);
+		System::assert_last_event(Event::Flow(crate::Event::Cancelled { campaign_id, block_number: new_expiry }));
+		assert_eq!(Tokens::free_balance(PROTOCOL_TOKEN_ID, &treasury_id), tbalance);
+		assert!(!CampaignsByBlock::<Test>::get(BlockType::Expiry, new_expiry).contains(&campaign_id));
+		assert_noop!(Flow::cancel_campaign(Origin::root(), campaign_id), Error::<Test>::InvalidCampaignState);
+		for c in [1, 2] {
+			assert_ok!(Flow::settle_contribution(Origin::signed(c), campaign_id, c));
+			assert_eq!(Tokens::free_balance(PAYMENT_TOKEN_ID, &c), INIT_BALANCE);
+		}
+		assert_eq!(Tokens::total_balance(PAYMENT_TOKEN_ID, &treasury_id), 0);
+
+		// Campaign admin cancels the active campaign
+		let campaign_id = create(1);
+		assert_noop!(Flow::cancel_campaign(Origin::signed(1), campaign_id), Error::<Test>::AuthorizationError);
+		assert_ok!(Flow::cancel_campaign(Origin::signed(ALICE), campaign_id));
+		assert_eq!(CampaignStates::<Test>::get(&campaign_id), CampaignState::Cancelled);
+	});
+}
+
+#[test]
+fn flow_migrate_to_v2_snapshot() {
+	snapshot_ext(new_test_ext(), include_str!("../fixtures/v0.snapshot")).execute_with(|| {
+		let campaign_id = H256::repeat_byte(0x22);
+		assert_eq!(Flow::on_chain_storage_version(), 0);
+		#[cfg(feature = "try-runtime")]
+		let state = migrations::v2::MigrateToV2::<Test>::pre_upgrade().unwrap();
+
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(migrations::v2::MigrateToV2::<Test>::post_upgrade(state));
+		assert_eq!(Flow::on_chain_storage_version(), 2);
+		assert_eq!(CampaignCount::<Test>::get(), 1);
+		assert_eq!(CampaignStates::<Test>::get(campaign_id), CampaignState::Active);
+		let campaign = CampaignOf::<Test>::get(campaign_id).unwrap();
+		assert_eq!(campaign.org_id, H256::repeat_byte(0x11));
+		assert_eq!((campaign.owner, campaign.cap, campaign.expiry), (BOB, 1000, 100));
+		assert_eq!((campaign.protocol, campaign.governance), (FlowProtocol::Raise, FlowGovernance::Yes));
+		assert_eq!(campaign.token_symbol, Some(BoundedVec::truncate_from(b"TST".to_vec())));
+	});
+}
+
+#[test]
+fn flow_migrate_to_v2() {
+	new_test_ext().execute_with(|| {
+		let (org_id, treasury_id, _) = create_org_treasury();
+		let now = 3;
+		System::set_block_number(now);
+		let expiry = now + 2 * DAYS;
+		let create = |index: u32| {
+			let (campaign_id, campaign) = create_campaign(index, org_id, BOB, now, expiry, now, 10 * DOLLARS, 20 * DOLLARS);
+			assert_ok!(Flow::create_campaign(
+				Origin::signed(BOB), org_id, BOB, campaign.name.clone(), campaign.cap,
+				campaign.deposit, campaign.expiry, campaign.protocol.clone(), campaign.governance.clone(),
+				campaign.cid.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
+			));
+			(campaign_id, campaign)
+		};
+		// Contributions were reserved by the contributors in v1
+		let contribute = |campaign_id: H256, who: AccountId, contribution: Balance| {
+			assert_ok!(Tokens::reserve(PAYMENT_TOKEN_ID, &who, contribution));
+			CampaignContribution::<Test>::insert(campaign_id, who, contribution);
+			CampaignBalance::<Test>::mutate(campaign_id, |balance| *balance += contribution);
+		};
+		let (open_id, _) = create(0);
+		let (queued_id, queued) = create(1);
+		let (succeeded_id, _) = create(2);
+		let (failed_id, _) = create(3);
+		contribute(open_id, 1, 5 * DOLLARS);
+		contribute(queued_id, 2, 20 * DOLLARS);
+		// Campaigns finalized in v1: contributions were paid out to the org or refunded
+		CampaignContribution::<Test>::insert(succeeded_id, 3, 20 * DOLLARS);
+		CampaignContribution::<Test>::insert(succeeded_id, 4, 10 * DOLLARS);
+		CampaignStates::<Test>::insert(succeeded_id, CampaignState::Succeeded);
+		CampaignContribution::<Test>::insert(failed_id, 3, 5 * DOLLARS);
+		CampaignStates::<Test>::insert(failed_id, CampaignState::Failed);
+		migrations::v2::CampaignFinalizationQueue::<Test>::insert(
+			queued_id,
+			(queued, 20 * DOLLARS, CampaignState::Succeeded, treasury_id, BoundedVec::truncate_from(vec![2]))
+		);
+		StorageVersion::new(1).put::<Flow>();
+		#[cfg(feature = "try-runtime")]
+		let state = migrations::v2::MigrateToV2::<Test>::pre_upgrade().unwrap();
+
+		migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(migrations::v2::MigrateToV2::<Test>::post_upgrade(state));
+		assert_eq!(Flow::on_chain_storage_version(), 2);
+		assert!(migrations::v2::CampaignFinalizationQueue::<Test>::get(queued_id).is_none());
+
+		// Queued campaign is finalized, open campaign contributions are held by the org treasury
+		let commission = <Test as Config>::CampaignFee::get().mul_floor(20 * DOLLARS);
+		assert_eq!(CampaignStates::<Test>::get(&queued_id), CampaignState::Succeeded);
+		assert!(ContributionSettled::<Test>::get(&queued_id, 2));
+		assert_eq!(CampaignStates::<Test>::get(&open_id), CampaignState::Active);
+		assert!(!ContributionSettled::<Test>::get(&open_id, 1));
+		assert_eq!(Tokens::reserved_balance(PAYMENT_TOKEN_ID, &1), 0);
+		assert_eq!(Tokens::reserved_balance(PAYMENT_TOKEN_ID, &2), 0);
+		assert_eq!(Tokens::reserved_balance(PAYMENT_TOKEN_ID, &treasury_id), 25 * DOLLARS - commission);
 
+		// Contributions to the campaigns finalized before the upgrade are not settled twice
+		assert!(ContributionSettled::<Test>::get(&succeeded_id, 3));
+		assert!(ContributionSettled::<Test>::get(&failed_id, 3));
+		assert_noop!(Flow::settle_contribution(Origin::signed(3), succeeded_id, 3), Error::<Test>::AlreadySettled);
+		assert_noop!(Flow::settle_contribution(Origin::signed(3), failed_id, 3), Error::<Test>::AlreadySettled);
 	});
 }
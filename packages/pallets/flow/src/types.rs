@@ -1,4 +1,5 @@
 use frame_support::pallet_prelude::*;
+use sp_runtime::Permill;
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Debug))]
@@ -28,6 +29,8 @@ pub enum CampaignState {
 	Succeeded = 3,
 	Failed = 4,
 	Locked = 5,		// authority lock due to e.g. legal actions, community votes. similar to a circuit breaker
+	Defaulted = 6,	// loan installment was not repaid in time
+	Cancelled = 7,	// cancelled by the admin or the org, contributions are refunded
 }
 
 impl Default for CampaignState {
@@ -36,6 +39,21 @@ impl Default for CampaignState {
 	}
 }
 
+#[derive(Encode, Decode, Clone, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+#[repr(u8)]
+pub enum RoundState {
+	Open = 0,		// contribution window of the matched campaigns
+	Tallying = 1,	// contributions are tallied in batches
+	Settled = 2,	// matching pool is distributed
+}
+
+impl Default for RoundState {
+	fn default() -> Self {
+		Self::Open
+	}
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Debug))]
 #[repr(u8)]
@@ -50,8 +68,31 @@ impl Default for FlowGovernance {
 	}
 }
 
+#[derive(Encode, Decode, Clone, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+#[repr(u8)]
+pub enum OverfundingPolicy {
+	Reject = 0,	// contribution exceeding the hard cap is rejected
+	Trim = 1,	// contribution is trimmed to the hard cap
+	Refund = 2,	// contribution is accepted, the part exceeding the hard cap is refunded on settlement
+}
+
+impl Default for OverfundingPolicy {
+	fn default() -> Self {
+		Self::Reject
+	}
+}
+
 /// Simple index type for proposal counting.
 pub type CampaignIndex = u32;
+/// Max number of milestones of a Grant campaign.
+pub type MaxMilestones = ConstU32<8>;
+/// Max number of contribution reward tiers of a campaign.
+pub type MaxRewardTiers = ConstU32<8>;
+/// Max number of currencies accepted by a campaign besides the payment token.
+pub type MaxCampaignCurrencies = ConstU32<8>;
+/// Max number of campaigns matched by a quadratic funding round.
+pub type MaxRoundCampaigns = ConstU32<16>;
 
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Debug))]
@@ -108,4 +149,163 @@ pub struct Campaign<Hash, AccountId, Balance, BlockNumber, BoundedString> {
 pub enum BlockType {
 	Start = 0, 	// Campaign Init -> Active
 	Expiry = 1,	// Campaign Active -> Approved | Rejected
-}
\ No newline at end of file
+}
+
+/// Contribution reward tier, the contributors of a succeeded campaign get the tier NFT.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct RewardTier<Balance, BoundedString> {
+	/// Minimum contribution qualifying for the tier.
+	pub min_contribution: Balance,
+	/// Number of NFTs available in the tier.
+	pub quantity: u32,
+	/// NFT metadata content identifier.
+	pub cid: BoundedString,
+}
+
+/// Metadata of the Share campaign token.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct TokenMetadata<BoundedString> {
+	/// Token name.
+	pub name: BoundedString,
+	/// Token symbol.
+	pub symbol: BoundedString,
+}
+
+/// Grant campaign milestone, funds are released once the milestone is approved.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Milestone<Balance, BlockNumber, BoundedString> {
+	/// Milestone description content identifier.
+	pub cid: BoundedString,
+	/// Share of the campaign target, the raised funds are released proportionally.
+	pub amount: Balance,
+	/// Milestone funds are refunded if not approved until this block.
+	pub deadline: BlockNumber,
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+#[repr(u8)]
+pub enum MilestoneState {
+	Pending = 0,	// waiting for approval
+	Released = 1,	// funds are released to the org treasury
+	Refunded = 2,	// rejected or missed the deadline, funds are refunded to the contributors
+}
+
+impl Default for MilestoneState {
+	fn default() -> Self {
+		Self::Pending
+	}
+}
+
+pub type MaxInstallments = ConstU32<120>;
+/// Max number of blocks the installment check is moved forward when the due block is full.
+pub type MaxInstallmentDelay = ConstU32<16>;
+
+/// Repayment terms of the Lend and Loan campaigns.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct LoanTerms<BlockNumber> {
+	/// Interest on the raised principal.
+	pub interest: Permill,
+	/// Number of equal installments.
+	pub installments: u32,
+	/// Blocks between the installments, the first one is due one period after the campaign expiry.
+	pub period: BlockNumber,
+}
+
+/// Repayment state of the succeeded Lend or Loan campaign.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Loan<Balance> {
+	/// Raised amount lent by the contributors.
+	pub principal: Balance,
+	/// Principal and interest to be repaid.
+	pub due: Balance,
+	/// Amount repaid by the org so far.
+	pub repaid: Balance,
+	/// Index of the next installment to be checked.
+	pub installment: u32,
+}
+
+/// Redemption terms of the Pool campaign.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct PoolTerms<BlockNumber> {
+	/// Blocks between the starts of the redemption windows, counted from the campaign start.
+	pub redemption_period: BlockNumber,
+	/// Blocks the redemption window stays open.
+	pub redemption_window: BlockNumber,
+}
+
+/// Hard cap of the campaign, the campaign succeeds as soon as the hard cap is reached.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct HardCap<Balance> {
+	/// Campaign balance finishing the campaign, at least the campaign target.
+	pub amount: Balance,
+	/// Handling of the contribution exceeding the hard cap.
+	pub policy: OverfundingPolicy,
+}
+
+/// Withdrawal terms of the campaign contributions.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct WithdrawalTerms<BlockNumber> {
+	/// Part of the withdrawn contribution kept by the org treasury.
+	pub fee: Permill,
+	/// Blocks before the expiry when the withdrawals are closed.
+	pub cutoff: BlockNumber,
+}
+
+/// Pool campaign shares and the pooled balance backing them.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct PoolInfo<Balance> {
+	/// Total shares issued to the contributors.
+	pub shares: Balance,
+	/// Pooled balance which is not deployed by the org.
+	pub balance: Balance,
+}
+
+/// Contribution in a currency other than the payment token, held in kind.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct AssetContribution<CurrencyId, Balance> {
+	/// Contributed currency.
+	pub currency_id: CurrencyId,
+	/// Contributed amount in the currency.
+	pub amount: Balance,
+	/// Value of the amount in the payment token at the contribution time.
+	pub value: Balance,
+}
+
+/// Quadratic funding matching round.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct MatchingRound<Hash, AccountId, Balance, BlockNumber> {
+	/// Org funding the matching pool, the protocol treasury if not set.
+	pub org_id: Option<Hash>,
+	/// Account holding the reserved matching pool.
+	pub funder: AccountId,
+	/// Matching pool in the payment token.
+	pub pool: Balance,
+	/// First block of the contribution window.
+	pub start: BlockNumber,
+	/// Last block of the contribution window, the pool is distributed afterwards.
+	pub end: BlockNumber,
+	/// Contribution of a single contributor counted towards the matching.
+	pub contributor_cap: Balance,
+}
+
+/// Quadratic funding tally of a matched campaign.
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct MatchingTally<Balance> {
+	/// Sum of the square roots of the capped contributions.
+	pub sqrt_sum: Balance,
+	/// Sum of the capped contributions.
+	pub sum: Balance,
+}
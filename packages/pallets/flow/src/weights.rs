@@ -30,6 +30,22 @@ pub trait WeightInfo {
 	fn create_campaign() -> Weight;
 	fn contribute() -> Weight;
 	fn on_initialize(c: u32, p: u32, ) -> Weight;
+	fn approve_milestone() -> Weight;
+	fn repay_loan() -> Weight;
+	fn claim_repayment() -> Weight;
+	fn redeem_shares() -> Weight;
+	fn settle_contribution() -> Weight;
+	fn pause_campaign() -> Weight;
+	fn resume_campaign() -> Weight;
+	fn lock_campaign() -> Weight;
+	fn cancel_campaign() -> Weight;
+	fn withdraw_contribution() -> Weight;
+	fn contribute_asset() -> Weight;
+	fn create_matching_round(c: u32, ) -> Weight;
+	fn settle_matching_round(c: u32, ) -> Weight;
+	fn migrate_to_v2(c: u32, ) -> Weight;
+	fn claim_milestone_refund() -> Weight;
+	fn unlock_campaign() -> Weight;
 }
 
 /// Weights for gamedao_flow using the Substrate node and recommended hardware.
@@ -49,70 +65,545 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
 	/// Storage: Flow CampaignStates (r:0 w:1)
 	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaults (r:1 w:0)
+	/// Proof: Control OrgVaults (max_values: None, max_size: Some(336), added: 2811, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:0 w:1)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Flow MilestonesByBlock (r:1 w:1)
+	/// Proof: Flow MilestonesByBlock (max_values: None, max_size: Some(381), added: 2856, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMilestones (r:0 w:1)
+	/// Proof: Flow CampaignMilestones (max_values: None, max_size: Some(2273), added: 4748, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignLoanTerms (r:0 w:1)
+	/// Proof: Flow CampaignLoanTerms (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignRetainedShare (r:0 w:1)
+	/// Proof: Flow CampaignRetainedShare (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignPoolTerms (r:0 w:1)
+	/// Proof: Flow CampaignPoolTerms (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignWithdrawalTerms (r:0 w:1)
+	/// Proof: Flow CampaignWithdrawalTerms (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignHardCap (r:0 w:1)
+	/// Proof: Flow CampaignHardCap (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignRewardTiers (r:0 w:1)
+	/// Proof: Flow CampaignRewardTiers (max_values: None, max_size: Some(729), added: 3204, mode: MaxEncodedLen)
+	/// Storage: Flow RewardCollection (r:0 w:1)
+	/// Proof: Flow RewardCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Nfts NextCollectionId (r:1 w:1)
+	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionMetadataOf (r:1 w:1)
+	/// Proof: Nfts CollectionMetadataOf (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAccount (r:0 w:1)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignCurrencyIds (r:0 w:1)
+	/// Proof: Flow CampaignCurrencyIds (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignTokenMetadata (r:1 w:1)
+	/// Proof: Flow CampaignTokenMetadata (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Control Streams (r:11 w:0)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn create_campaign() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `947`
-		//  Estimated: `19019`
+		//  Estimated: `64189`
 		// Minimum execution time: 66_000 nanoseconds.
-		Weight::from_parts(68_000_000, 19019)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+		Weight::from_parts(68_000_000, 64189)
+			.saturating_add(T::DbWeight::get().reads(24_u64))
+			.saturating_add(T::DbWeight::get().writes(24_u64))
 	}
 	/// Storage: Flow CampaignOf (r:1 w:0)
 	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
-	/// Storage: Flow CampaignStates (r:1 w:0)
+	/// Storage: Flow CampaignStates (r:1 w:1)
 	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: Flow CampaignContribution (r:1 w:1)
 	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
 	/// Storage: Flow CampaignContributorsCount (r:1 w:1)
 	/// Proof: Flow CampaignContributorsCount (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
-	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Storage: Tokens Accounts (r:3 w:3)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
 	/// Storage: Flow CampaignBalance (r:1 w:1)
 	/// Proof: Flow CampaignBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Flow Pools (r:1 w:1)
+	/// Proof: Flow Pools (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Flow PoolShares (r:1 w:1)
+	/// Proof: Flow PoolShares (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignHardCap (r:1 w:0)
+	/// Proof: Flow CampaignHardCap (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Flow ContributionExcess (r:0 w:1)
+	/// Proof: Flow ContributionExcess (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignAssets (r:1 w:0)
+	/// Proof: Flow CampaignAssets (max_values: None, max_size: Some(337), added: 2812, mode: MaxEncodedLen)
 	fn contribute() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1042`
-		//  Estimated: `21640`
-		// Minimum execution time: 53_000 nanoseconds.
-		Weight::from_parts(54_000_000, 21640)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		//  Measured:  `1113`
+		//  Estimated: `42360`
+		// Minimum execution time: 61_000 nanoseconds.
+		Weight::from_parts(62_000_000, 42360)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: Flow CampaignsByBlock (r:1 w:0)
 	/// Proof: Flow CampaignsByBlock (max_values: None, max_size: Some(358), added: 2833, mode: MaxEncodedLen)
-	/// Storage: Flow CampaignStates (r:10 w:11)
+	/// Storage: Flow CampaignStates (r:11 w:10)
 	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	/// Storage: Flow CampaignFinalizationQueue (r:2 w:1)
-	/// Proof: Flow CampaignFinalizationQueue (max_values: None, max_size: Some(320511), added: 322986, mode: MaxEncodedLen)
-	/// Storage: Flow CampaignContribution (r:100 w:0)
+	/// Storage: Flow MatchingTallyQueue (r:2 w:1)
+	/// Proof: Flow MatchingTallyQueue (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRounds (r:1 w:0)
+	/// Proof: Flow MatchingRounds (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRoundCampaigns (r:1 w:0)
+	/// Proof: Flow MatchingRoundCampaigns (max_values: None, max_size: Some(533), added: 3008, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContribution (r:101 w:0)
 	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
-	/// Storage: Tokens Accounts (r:102 w:102)
-	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Flow CampaignBalance (r:0 w:1)
-	/// Proof: Flow CampaignBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingTallies (r:1 w:1)
+	/// Proof: Flow MatchingTallies (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
 	/// The range of component `c` is `[0, 100]`.
 	/// The range of component `p` is `[0, 10]`.
 	fn on_initialize(c: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1489 + c * (257 ±0) + p * (87 ±0)`
-		//  Estimated: `660937 + c * (5183 ±0) + p * (2595 ±4)`
-		// Minimum execution time: 146_000 nanoseconds.
-		Weight::from_parts(68_171_926, 660937)
-			// Standard Error: 65_182
-			.saturating_add(Weight::from_ref_time(29_183_288).saturating_mul(c.into()))
-			// Standard Error: 651_727
+		//  Measured:  `1521 + c * (112 ±0) + p * (87 ±0)`
+		//  Estimated: `38264 + c * (2587 ±0) + p * (2595 ±4)`
+		// Minimum execution time: 44_000 nanoseconds.
+		Weight::from_parts(45_182_390, 38264)
+			// Standard Error: 21_473
+			.saturating_add(Weight::from_ref_time(7_904_118).saturating_mul(c.into()))
+			// Standard Error: 214_730
 			.saturating_add(Weight::from_ref_time(11_547_431).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_proof_size(2587).saturating_mul(c.into()))
+			.saturating_add(Weight::from_proof_size(2595).saturating_mul(p.into()))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:0)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMilestones (r:1 w:0)
+	/// Proof: Flow CampaignMilestones (max_values: None, max_size: Some(2273), added: 4748, mode: MaxEncodedLen)
+	/// Storage: Flow MilestoneStates (r:2 w:1)
+	/// Proof: Flow MilestoneStates (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignBalance (r:1 w:0)
+	/// Proof: Flow CampaignBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMatchingFunds (r:1 w:1)
+	/// Proof: Flow CampaignMatchingFunds (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMatchingRound (r:1 w:0)
+	/// Proof: Flow CampaignMatchingRound (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRounds (r:1 w:0)
+	/// Proof: Flow MatchingRounds (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	fn approve_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1706`
+		//  Estimated: `40559`
+		// Minimum execution time: 47_000 nanoseconds.
+		Weight::from_parts(48_000_000, 40559)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Flow Loans (r:1 w:1)
+	/// Proof: Flow Loans (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control Streams (r:11 w:0)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn repay_loan() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1258`
+		//  Estimated: `44673`
+		// Minimum execution time: 39_000 nanoseconds.
+		Weight::from_parts(40_000_000, 44673)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow Loans (r:1 w:0)
+	/// Proof: Flow Loans (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContribution (r:1 w:0)
+	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Flow LoanClaims (r:1 w:1)
+	/// Proof: Flow LoanClaims (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn claim_repayment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1391`
+		//  Estimated: `20350`
+		// Minimum execution time: 44_000 nanoseconds.
+		Weight::from_parts(45_000_000, 20350)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignPoolTerms (r:1 w:0)
+	/// Proof: Flow CampaignPoolTerms (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Flow PoolShares (r:1 w:1)
+	/// Proof: Flow PoolShares (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Flow Pools (r:1 w:1)
+	/// Proof: Flow Pools (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignBalance (r:0 w:1)
+	/// Proof: Flow CampaignBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn redeem_shares() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1204`
+		//  Estimated: `20834`
+		// Minimum execution time: 41_000 nanoseconds.
+		Weight::from_parts(42_000_000, 20834)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContribution (r:1 w:0)
+	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Flow ContributionSettled (r:1 w:1)
+	/// Proof: Flow ContributionSettled (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:0)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignRewardTiers (r:1 w:0)
+	/// Proof: Flow CampaignRewardTiers (max_values: None, max_size: Some(729), added: 3204, mode: MaxEncodedLen)
+	/// Storage: Flow RewardCollection (r:1 w:0)
+	/// Proof: Flow RewardCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Flow RewardsMinted (r:1 w:1)
+	/// Proof: Flow RewardsMinted (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Flow RewardItemCount (r:1 w:1)
+	/// Proof: Flow RewardItemCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Flow ContributionAssets (r:1 w:0)
+	/// Proof: Flow ContributionAssets (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	fn settle_contribution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1320`
+		//  Estimated: `48129`
+		// Minimum execution time: 38_000 nanoseconds.
+		Weight::from_parts(39_000_000, 48129)
+			.saturating_add(T::DbWeight::get().reads(18_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:1)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn pause_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `5459`
+		// Minimum execution time: 21_000 nanoseconds.
+		Weight::from_parts(22_000_000, 5459)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:1)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:1)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMilestones (r:1 w:0)
+	/// Proof: Flow CampaignMilestones (max_values: None, max_size: Some(2273), added: 4748, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignsByBlock (r:2 w:2)
+	/// Proof: Flow CampaignsByBlock (max_values: None, max_size: Some(358), added: 2833, mode: MaxEncodedLen)
+	fn resume_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `847`
+		//  Estimated: `15873`
+		// Minimum execution time: 34_000 nanoseconds.
+		Weight::from_parts(35_000_000, 15873)
 			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:1)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn lock_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `735`
+		//  Estimated: `8224`
+		// Minimum execution time: 24_000 nanoseconds.
+		Weight::from_parts(25_000_000, 8224)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:1)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignsByBlock (r:2 w:2)
+	/// Proof: Flow CampaignsByBlock (max_values: None, max_size: Some(358), added: 2833, mode: MaxEncodedLen)
+	fn cancel_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1127`
+		//  Estimated: `21556`
+		// Minimum execution time: 41_000 nanoseconds.
+		Weight::from_parts(42_000_000, 21556)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignWithdrawalTerms (r:1 w:0)
+	/// Proof: Flow CampaignWithdrawalTerms (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:0)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContribution (r:1 w:1)
+	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContributorsCount (r:1 w:1)
+	/// Proof: Flow CampaignContributorsCount (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignBalance (r:1 w:1)
+	/// Proof: Flow CampaignBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Flow ContributionAssets (r:1 w:1)
+	/// Proof: Flow ContributionAssets (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignAssets (r:1 w:1)
+	/// Proof: Flow CampaignAssets (max_values: None, max_size: Some(337), added: 2812, mode: MaxEncodedLen)
+	fn withdraw_contribution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `36737`
+		// Minimum execution time: 47_000 nanoseconds.
+		Weight::from_parts(48_000_000, 36737)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:1)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignCurrencyIds (r:1 w:0)
+	/// Proof: Flow CampaignCurrencyIds (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContribution (r:1 w:1)
+	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContributorsCount (r:1 w:1)
+	/// Proof: Flow CampaignContributorsCount (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignBalance (r:1 w:1)
+	/// Proof: Flow CampaignBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignHardCap (r:1 w:0)
+	/// Proof: Flow CampaignHardCap (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Flow ContributionAssets (r:1 w:1)
+	/// Proof: Flow ContributionAssets (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignAssets (r:1 w:1)
+	/// Proof: Flow CampaignAssets (max_values: None, max_size: Some(337), added: 2812, mode: MaxEncodedLen)
+	fn contribute_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1482`
+		//  Estimated: `36718`
+		// Minimum execution time: 64_000 nanoseconds.
+		Weight::from_parts(65_000_000, 36718)
+			.saturating_add(T::DbWeight::get().reads(14_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignOf (r:16 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRoundCount (r:1 w:1)
+	/// Proof: Flow MatchingRoundCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRoundsByBlock (r:1 w:1)
+	/// Proof: Flow MatchingRoundsByBlock (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRounds (r:0 w:1)
+	/// Proof: Flow MatchingRounds (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRoundCampaigns (r:0 w:1)
+	/// Proof: Flow MatchingRoundCampaigns (max_values: None, max_size: Some(533), added: 3008, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMatchingRound (r:16 w:16)
+	/// Proof: Flow CampaignMatchingRound (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 16]`.
+	/// Storage: Control Streams (r:11 w:0)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn create_matching_round(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1350`
+		//  Estimated: `42606`
+		// Minimum execution time: 41_000 nanoseconds.
+		Weight::from_parts(42_000_000, 42606)
+			// Standard Error: 4_210
+			.saturating_add(Weight::from_parts(6_120_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
-			.saturating_add(Weight::from_proof_size(5183).saturating_mul(c.into()))
-			.saturating_add(Weight::from_proof_size(2595).saturating_mul(p.into()))
+			.saturating_add(Weight::from_proof_size(2527).saturating_mul(c.into()))
+	}
+	/// Storage: Flow MatchingRounds (r:1 w:0)
+	/// Proof: Flow MatchingRounds (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRoundCampaigns (r:1 w:0)
+	/// Proof: Flow MatchingRoundCampaigns (max_values: None, max_size: Some(533), added: 3008, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingTallies (r:16 w:16)
+	/// Proof: Flow MatchingTallies (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignOf (r:16 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:16 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:16 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:17 w:17)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMilestones (r:16 w:0)
+	/// Proof: Flow CampaignMilestones (max_values: None, max_size: Some(2273), added: 4748, mode: MaxEncodedLen)
+	/// Storage: Flow MilestoneStates (r:16 w:0)
+	/// Proof: Flow MilestoneStates (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMatchingFunds (r:0 w:16)
+	/// Proof: Flow CampaignMatchingFunds (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingTallyQueue (r:0 w:1)
+	/// Proof: Flow MatchingTallyQueue (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRoundStates (r:0 w:1)
+	/// Proof: Flow MatchingRoundStates (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 16]`.
+	fn settle_matching_round(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `980`
+		//  Estimated: `8192`
+		// Minimum execution time: 23_000 nanoseconds.
+		Weight::from_parts(24_000_000, 8192)
+			// Standard Error: 9_874
+			.saturating_add(Weight::from_parts(14_530_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+	}
+	/// Storage: Flow CampaignStates (r:2 w:0)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignPoolTerms (r:1 w:0)
+	/// Proof: Flow CampaignPoolTerms (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContribution (r:101 w:0)
+	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:200 w:200)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 100]`.
+	fn migrate_to_v2(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `716 + c * (140 ±0)`
+		//  Estimated: `16086 + c * (7739 ±0)`
+		// Minimum execution time: 38_000 nanoseconds.
+		Weight::from_parts(39_412_000, 16086)
+			// Standard Error: 11_203
+			.saturating_add(Weight::from_parts(21_307_618, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_proof_size(7739).saturating_mul(c.into()))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow MilestoneRefunds (r:1 w:0)
+	/// Proof: Flow MilestoneRefunds (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContribution (r:1 w:0)
+	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Flow MilestoneRefundClaimed (r:1 w:1)
+	/// Proof: Flow MilestoneRefundClaimed (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn claim_milestone_refund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1412`
+		//  Estimated: `20923`
+		// Minimum execution time: 39_000 nanoseconds.
+		Weight::from_parts(40_000_000, 20923)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:1)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn unlock_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `748`
+		//  Estimated: `8224`
+		// Minimum execution time: 24_000 nanoseconds.
+		Weight::from_parts(25_000_000, 8224)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
 
@@ -132,69 +623,544 @@ impl WeightInfo for () {
 	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
 	/// Storage: Flow CampaignStates (r:0 w:1)
 	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaults (r:1 w:0)
+	/// Proof: Control OrgVaults (max_values: None, max_size: Some(336), added: 2811, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:0 w:1)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Flow MilestonesByBlock (r:1 w:1)
+	/// Proof: Flow MilestonesByBlock (max_values: None, max_size: Some(381), added: 2856, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMilestones (r:0 w:1)
+	/// Proof: Flow CampaignMilestones (max_values: None, max_size: Some(2273), added: 4748, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignLoanTerms (r:0 w:1)
+	/// Proof: Flow CampaignLoanTerms (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignRetainedShare (r:0 w:1)
+	/// Proof: Flow CampaignRetainedShare (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignPoolTerms (r:0 w:1)
+	/// Proof: Flow CampaignPoolTerms (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignWithdrawalTerms (r:0 w:1)
+	/// Proof: Flow CampaignWithdrawalTerms (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignHardCap (r:0 w:1)
+	/// Proof: Flow CampaignHardCap (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignRewardTiers (r:0 w:1)
+	/// Proof: Flow CampaignRewardTiers (max_values: None, max_size: Some(729), added: 3204, mode: MaxEncodedLen)
+	/// Storage: Flow RewardCollection (r:0 w:1)
+	/// Proof: Flow RewardCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Nfts NextCollectionId (r:1 w:1)
+	/// Proof: Nfts NextCollectionId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionMetadataOf (r:1 w:1)
+	/// Proof: Nfts CollectionMetadataOf (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionRoleOf (r:0 w:1)
+	/// Proof: Nfts CollectionRoleOf (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:0 w:1)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionAccount (r:0 w:1)
+	/// Proof: Nfts CollectionAccount (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignCurrencyIds (r:0 w:1)
+	/// Proof: Flow CampaignCurrencyIds (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignTokenMetadata (r:1 w:1)
+	/// Proof: Flow CampaignTokenMetadata (max_values: None, max_size: Some(150), added: 2625, mode: MaxEncodedLen)
+	/// Storage: Tokens TotalIssuance (r:1 w:0)
+	/// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Control Streams (r:11 w:0)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn create_campaign() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `947`
-		//  Estimated: `19019`
+		//  Estimated: `64189`
 		// Minimum execution time: 66_000 nanoseconds.
-		Weight::from_parts(68_000_000, 19019)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		Weight::from_parts(68_000_000, 64189)
+			.saturating_add(RocksDbWeight::get().reads(24_u64))
+			.saturating_add(RocksDbWeight::get().writes(24_u64))
 	}
 	/// Storage: Flow CampaignOf (r:1 w:0)
 	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
-	/// Storage: Flow CampaignStates (r:1 w:0)
+	/// Storage: Flow CampaignStates (r:1 w:1)
 	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// Storage: Flow CampaignContribution (r:1 w:1)
 	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
 	/// Storage: Flow CampaignContributorsCount (r:1 w:1)
 	/// Proof: Flow CampaignContributorsCount (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
-	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Storage: Tokens Accounts (r:3 w:3)
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
 	/// Storage: Flow CampaignBalance (r:1 w:1)
 	/// Proof: Flow CampaignBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Flow Pools (r:1 w:1)
+	/// Proof: Flow Pools (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Flow PoolShares (r:1 w:1)
+	/// Proof: Flow PoolShares (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignHardCap (r:1 w:0)
+	/// Proof: Flow CampaignHardCap (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Flow ContributionExcess (r:0 w:1)
+	/// Proof: Flow ContributionExcess (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignAssets (r:1 w:0)
+	/// Proof: Flow CampaignAssets (max_values: None, max_size: Some(337), added: 2812, mode: MaxEncodedLen)
 	fn contribute() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1042`
-		//  Estimated: `21640`
-		// Minimum execution time: 53_000 nanoseconds.
-		Weight::from_parts(54_000_000, 21640)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `1113`
+		//  Estimated: `42360`
+		// Minimum execution time: 61_000 nanoseconds.
+		Weight::from_parts(62_000_000, 42360)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: Flow CampaignsByBlock (r:1 w:0)
 	/// Proof: Flow CampaignsByBlock (max_values: None, max_size: Some(358), added: 2833, mode: MaxEncodedLen)
-	/// Storage: Flow CampaignStates (r:10 w:11)
+	/// Storage: Flow CampaignStates (r:11 w:10)
 	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
-	/// Storage: Flow CampaignFinalizationQueue (r:2 w:1)
-	/// Proof: Flow CampaignFinalizationQueue (max_values: None, max_size: Some(320511), added: 322986, mode: MaxEncodedLen)
-	/// Storage: Flow CampaignContribution (r:100 w:0)
+	/// Storage: Flow MatchingTallyQueue (r:2 w:1)
+	/// Proof: Flow MatchingTallyQueue (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRounds (r:1 w:0)
+	/// Proof: Flow MatchingRounds (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRoundCampaigns (r:1 w:0)
+	/// Proof: Flow MatchingRoundCampaigns (max_values: None, max_size: Some(533), added: 3008, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContribution (r:101 w:0)
 	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
-	/// Storage: Tokens Accounts (r:102 w:102)
-	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
-	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// Storage: Flow CampaignBalance (r:0 w:1)
-	/// Proof: Flow CampaignBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingTallies (r:1 w:1)
+	/// Proof: Flow MatchingTallies (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
 	/// The range of component `c` is `[0, 100]`.
 	/// The range of component `p` is `[0, 10]`.
 	fn on_initialize(c: u32, p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1489 + c * (257 ±0) + p * (87 ±0)`
-		//  Estimated: `660937 + c * (5183 ±0) + p * (2595 ±4)`
-		// Minimum execution time: 146_000 nanoseconds.
-		Weight::from_parts(68_171_926, 660937)
-			// Standard Error: 65_182
-			.saturating_add(Weight::from_ref_time(29_183_288).saturating_mul(c.into()))
-			// Standard Error: 651_727
+		//  Measured:  `1521 + c * (112 ±0) + p * (87 ±0)`
+		//  Estimated: `38264 + c * (2587 ±0) + p * (2595 ±4)`
+		// Minimum execution time: 44_000 nanoseconds.
+		Weight::from_parts(45_182_390, 38264)
+			// Standard Error: 21_473
+			.saturating_add(Weight::from_ref_time(7_904_118).saturating_mul(c.into()))
+			// Standard Error: 214_730
 			.saturating_add(Weight::from_ref_time(11_547_431).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_proof_size(2587).saturating_mul(c.into()))
+			.saturating_add(Weight::from_proof_size(2595).saturating_mul(p.into()))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:0)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMilestones (r:1 w:0)
+	/// Proof: Flow CampaignMilestones (max_values: None, max_size: Some(2273), added: 4748, mode: MaxEncodedLen)
+	/// Storage: Flow MilestoneStates (r:2 w:1)
+	/// Proof: Flow MilestoneStates (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignBalance (r:1 w:0)
+	/// Proof: Flow CampaignBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMatchingFunds (r:1 w:1)
+	/// Proof: Flow CampaignMatchingFunds (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMatchingRound (r:1 w:0)
+	/// Proof: Flow CampaignMatchingRound (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRounds (r:1 w:0)
+	/// Proof: Flow MatchingRounds (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	fn approve_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1706`
+		//  Estimated: `40559`
+		// Minimum execution time: 47_000 nanoseconds.
+		Weight::from_parts(48_000_000, 40559)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Flow Loans (r:1 w:1)
+	/// Proof: Flow Loans (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Control Streams (r:11 w:0)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn repay_loan() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1258`
+		//  Estimated: `44673`
+		// Minimum execution time: 39_000 nanoseconds.
+		Weight::from_parts(40_000_000, 44673)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow Loans (r:1 w:0)
+	/// Proof: Flow Loans (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContribution (r:1 w:0)
+	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Flow LoanClaims (r:1 w:1)
+	/// Proof: Flow LoanClaims (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn claim_repayment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1391`
+		//  Estimated: `20350`
+		// Minimum execution time: 44_000 nanoseconds.
+		Weight::from_parts(45_000_000, 20350)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignPoolTerms (r:1 w:0)
+	/// Proof: Flow CampaignPoolTerms (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Flow PoolShares (r:1 w:1)
+	/// Proof: Flow PoolShares (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Flow Pools (r:1 w:1)
+	/// Proof: Flow Pools (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignBalance (r:0 w:1)
+	/// Proof: Flow CampaignBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	fn redeem_shares() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1204`
+		//  Estimated: `20834`
+		// Minimum execution time: 41_000 nanoseconds.
+		Weight::from_parts(42_000_000, 20834)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContribution (r:1 w:0)
+	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Flow ContributionSettled (r:1 w:1)
+	/// Proof: Flow ContributionSettled (max_values: None, max_size: Some(97), added: 2572, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:0)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignRewardTiers (r:1 w:0)
+	/// Proof: Flow CampaignRewardTiers (max_values: None, max_size: Some(729), added: 3204, mode: MaxEncodedLen)
+	/// Storage: Flow RewardCollection (r:1 w:0)
+	/// Proof: Flow RewardCollection (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Flow RewardsMinted (r:1 w:1)
+	/// Proof: Flow RewardsMinted (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	/// Storage: Flow RewardItemCount (r:1 w:1)
+	/// Proof: Flow RewardItemCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Nfts Collection (r:1 w:1)
+	/// Proof: Nfts Collection (max_values: None, max_size: Some(84), added: 2559, mode: MaxEncodedLen)
+	/// Storage: Nfts CollectionConfigOf (r:1 w:0)
+	/// Proof: Nfts CollectionConfigOf (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: Nfts Item (r:1 w:1)
+	/// Proof: Nfts Item (max_values: None, max_size: Some(861), added: 3336, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemConfigOf (r:1 w:1)
+	/// Proof: Nfts ItemConfigOf (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Nfts ItemMetadataOf (r:1 w:1)
+	/// Proof: Nfts ItemMetadataOf (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
+	/// Storage: Nfts Account (r:0 w:1)
+	/// Proof: Nfts Account (max_values: None, max_size: Some(88), added: 2563, mode: MaxEncodedLen)
+	/// Storage: Flow ContributionAssets (r:1 w:0)
+	/// Proof: Flow ContributionAssets (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	fn settle_contribution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1320`
+		//  Estimated: `48129`
+		// Minimum execution time: 38_000 nanoseconds.
+		Weight::from_parts(39_000_000, 48129)
+			.saturating_add(RocksDbWeight::get().reads(18_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:1)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn pause_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `5459`
+		// Minimum execution time: 21_000 nanoseconds.
+		Weight::from_parts(22_000_000, 5459)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:1)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:1)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMilestones (r:1 w:0)
+	/// Proof: Flow CampaignMilestones (max_values: None, max_size: Some(2273), added: 4748, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignsByBlock (r:2 w:2)
+	/// Proof: Flow CampaignsByBlock (max_values: None, max_size: Some(358), added: 2833, mode: MaxEncodedLen)
+	fn resume_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `847`
+		//  Estimated: `15873`
+		// Minimum execution time: 34_000 nanoseconds.
+		Weight::from_parts(35_000_000, 15873)
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:1)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn lock_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `735`
+		//  Estimated: `8224`
+		// Minimum execution time: 24_000 nanoseconds.
+		Weight::from_parts(25_000_000, 8224)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:1)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignsByBlock (r:2 w:2)
+	/// Proof: Flow CampaignsByBlock (max_values: None, max_size: Some(358), added: 2833, mode: MaxEncodedLen)
+	fn cancel_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1127`
+		//  Estimated: `21556`
+		// Minimum execution time: 41_000 nanoseconds.
+		Weight::from_parts(42_000_000, 21556)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignWithdrawalTerms (r:1 w:0)
+	/// Proof: Flow CampaignWithdrawalTerms (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:0)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContribution (r:1 w:1)
+	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:4 w:4)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContributorsCount (r:1 w:1)
+	/// Proof: Flow CampaignContributorsCount (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignBalance (r:1 w:1)
+	/// Proof: Flow CampaignBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Flow ContributionAssets (r:1 w:1)
+	/// Proof: Flow ContributionAssets (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignAssets (r:1 w:1)
+	/// Proof: Flow CampaignAssets (max_values: None, max_size: Some(337), added: 2812, mode: MaxEncodedLen)
+	fn withdraw_contribution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `36737`
+		// Minimum execution time: 47_000 nanoseconds.
+		Weight::from_parts(48_000_000, 36737)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:1)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignCurrencyIds (r:1 w:0)
+	/// Proof: Flow CampaignCurrencyIds (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContribution (r:1 w:1)
+	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContributorsCount (r:1 w:1)
+	/// Proof: Flow CampaignContributorsCount (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignBalance (r:1 w:1)
+	/// Proof: Flow CampaignBalance (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignHardCap (r:1 w:0)
+	/// Proof: Flow CampaignHardCap (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Flow ContributionAssets (r:1 w:1)
+	/// Proof: Flow ContributionAssets (max_values: None, max_size: Some(385), added: 2860, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignAssets (r:1 w:1)
+	/// Proof: Flow CampaignAssets (max_values: None, max_size: Some(337), added: 2812, mode: MaxEncodedLen)
+	fn contribute_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1482`
+		//  Estimated: `36718`
+		// Minimum execution time: 64_000 nanoseconds.
+		Weight::from_parts(65_000_000, 36718)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignOf (r:16 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRoundCount (r:1 w:1)
+	/// Proof: Flow MatchingRoundCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRoundsByBlock (r:1 w:1)
+	/// Proof: Flow MatchingRoundsByBlock (max_values: None, max_size: Some(61), added: 2536, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRounds (r:0 w:1)
+	/// Proof: Flow MatchingRounds (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRoundCampaigns (r:0 w:1)
+	/// Proof: Flow MatchingRoundCampaigns (max_values: None, max_size: Some(533), added: 3008, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMatchingRound (r:16 w:16)
+	/// Proof: Flow CampaignMatchingRound (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 16]`.
+	/// Storage: Control Streams (r:11 w:0)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
+	fn create_matching_round(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1350`
+		//  Estimated: `42606`
+		// Minimum execution time: 41_000 nanoseconds.
+		Weight::from_parts(42_000_000, 42606)
+			// Standard Error: 4_210
+			.saturating_add(Weight::from_parts(6_120_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
 			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
-			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
-			.saturating_add(Weight::from_proof_size(5183).saturating_mul(c.into()))
-			.saturating_add(Weight::from_proof_size(2595).saturating_mul(p.into()))
+			.saturating_add(Weight::from_proof_size(2527).saturating_mul(c.into()))
+	}
+	/// Storage: Flow MatchingRounds (r:1 w:0)
+	/// Proof: Flow MatchingRounds (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRoundCampaigns (r:1 w:0)
+	/// Proof: Flow MatchingRoundCampaigns (max_values: None, max_size: Some(533), added: 3008, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingTallies (r:16 w:16)
+	/// Proof: Flow MatchingTallies (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignOf (r:16 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:16 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:16 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:17 w:17)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMilestones (r:16 w:0)
+	/// Proof: Flow CampaignMilestones (max_values: None, max_size: Some(2273), added: 4748, mode: MaxEncodedLen)
+	/// Storage: Flow MilestoneStates (r:16 w:0)
+	/// Proof: Flow MilestoneStates (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMatchingFunds (r:0 w:16)
+	/// Proof: Flow CampaignMatchingFunds (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingTallyQueue (r:0 w:1)
+	/// Proof: Flow MatchingTallyQueue (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	/// Storage: Flow MatchingRoundStates (r:0 w:1)
+	/// Proof: Flow MatchingRoundStates (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// The range of component `c` is `[1, 16]`.
+	fn settle_matching_round(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `980`
+		//  Estimated: `8192`
+		// Minimum execution time: 23_000 nanoseconds.
+		Weight::from_parts(24_000_000, 8192)
+			// Standard Error: 9_874
+			.saturating_add(Weight::from_parts(14_530_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
+	}
+	/// Storage: Flow CampaignStates (r:2 w:0)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignPoolTerms (r:1 w:0)
+	/// Proof: Flow CampaignPoolTerms (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContribution (r:101 w:0)
+	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:200 w:200)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	/// The range of component `c` is `[0, 100]`.
+	fn migrate_to_v2(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `716 + c * (140 ±0)`
+		//  Estimated: `16086 + c * (7739 ±0)`
+		// Minimum execution time: 38_000 nanoseconds.
+		Weight::from_parts(39_412_000, 16086)
+			// Standard Error: 11_203
+			.saturating_add(Weight::from_parts(21_307_618, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_proof_size(7739).saturating_mul(c.into()))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Flow MilestoneRefunds (r:1 w:0)
+	/// Proof: Flow MilestoneRefunds (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignContribution (r:1 w:0)
+	/// Proof: Flow CampaignContribution (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	/// Storage: Flow MilestoneRefundClaimed (r:1 w:1)
+	/// Proof: Flow MilestoneRefundClaimed (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignVault (r:1 w:0)
+	/// Proof: Flow CampaignVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control OrgTreasury (r:1 w:0)
+	/// Proof: Control OrgTreasury (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn claim_milestone_refund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1412`
+		//  Estimated: `20923`
+		// Minimum execution time: 39_000 nanoseconds.
+		Weight::from_parts(40_000_000, 20923)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: Flow CampaignOf (r:1 w:0)
+	/// Proof: Flow CampaignOf (max_values: None, max_size: Some(460), added: 2935, mode: MaxEncodedLen)
+	/// Storage: Control Orgs (r:1 w:0)
+	/// Proof: Control Orgs (max_values: None, max_size: Some(290), added: 2765, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignStates (r:1 w:1)
+	/// Proof: Flow CampaignStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn unlock_campaign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `748`
+		//  Estimated: `8224`
+		// Minimum execution time: 24_000 nanoseconds.
+		Weight::from_parts(25_000_000, 8224)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 }
\ No newline at end of file
@@ -25,6 +25,7 @@ pub use pallet::*;
 use frame_support::{pallet_prelude::*, storage::bounded_vec::BoundedVec};
 use frame_system::pallet_prelude::*;
 use sp_std::convert::TryInto;
+use gamedao_traits::SenseTrait;
 pub use weights::WeightInfo;
 
 #[frame_support::pallet]
@@ -43,7 +44,11 @@ pub mod pallet {
 		type StringLimit: Get<u32>;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Sense Entity of the account.
@@ -210,4 +215,16 @@ pub mod pallet {
 			Properties::<T>::insert(property_type, account_id, value);
 		}
 	}
+
+	impl<T: Config> SenseTrait<T::AccountId> for Pallet<T> {
+		fn has_entity(account_id: &T::AccountId) -> bool {
+			Entities::<T>::contains_key(account_id)
+		}
+
+		fn reputation(account_id: &T::AccountId) -> u64 {
+			Properties::<T>::get(PropertyType::Reputation, account_id)
+				.map(|property| *property.get_value())
+				.unwrap_or_default()
+		}
+	}
 }
@@ -81,4 +81,4 @@ fn should_update_properties() {
 		System::assert_has_event(Event::Sense(SenseEvent::PropertyUpdated { property_type: PropertyType::Reputation, account_id: account, block_number }));
 		System::assert_has_event(Event::Sense(SenseEvent::PropertyUpdated { property_type: PropertyType::Trust, account_id: account, block_number }));
 	});
-}
\ No newline at end of file
+}
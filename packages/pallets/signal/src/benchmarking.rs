@@ -125,7 +125,7 @@ benchmarks! {
 			RawOrigin::Signed(proposer.clone()).into(), prop.proposal_type.clone(), prop.org_id,
 			prop.title.clone(), prop.cid.clone(), prop.expiry,
 			Majority::Simple, Unit::Account, Scale::Linear, None, Some(quorum), Some(prop.deposit),
-			prop.campaign_id, prop.amount, prop.beneficiary, prop.currency_id,
+			prop.campaign_id, prop.amount, prop.beneficiary, prop.currency_id, None,
 		)?;
 
 		// Ensure that proposal exists and Activated
@@ -187,7 +187,7 @@ benchmarks! {
 				RawOrigin::Signed(caller.clone()).into(), prop.proposal_type.clone(), prop.org_id,
 				prop.title.clone(), prop.cid.clone(), prop.expiry, Majority::Simple, Unit::Account,
 				Scale::Linear, Some(prop.start), Some(quorum), Some(prop.deposit),
-				prop.campaign_id, prop.amount, prop.beneficiary, prop.currency_id,
+				prop.campaign_id, prop.amount, prop.beneficiary, prop.currency_id, None,
 			)?;
 			// Ensure that proposal exists and Activated
 			assert!(ProposalStates::<T>::get(&proposal_id) == ProposalState::Active);
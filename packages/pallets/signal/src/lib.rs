@@ -41,7 +41,7 @@ use sp_std::vec;
 
 #[cfg(feature = "runtime-benchmarks")]
 use gamedao_traits::{ControlBenchmarkingTrait, FlowBenchmarkingTrait};
-use gamedao_traits::{ControlTrait, FlowTrait};
+use gamedao_traits::{ControlTrait, FlowTrait, GovernanceTrait, OrgAction, TreasuryTrait, VaultId};
 
 use types::{
 	ProposalIndex, ProposalType, ProposalState, SlashingRule,
@@ -69,7 +69,11 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -104,7 +108,8 @@ pub mod pallet {
 			+ MultiReservableCurrency<Self::AccountId>;
 
 		/// Control pallet's public interface.
-		type Control: ControlTrait<Self::AccountId, Self::Hash>;
+		type Control: ControlTrait<Self::AccountId, Self::Hash>
+			+ TreasuryTrait<Self::AccountId, Self::CurrencyId, Self::Hash>;
 
 		/// Flow pallet's public interface.
 		type Flow: FlowTrait<Self::AccountId, Self::Balance, Self::Hash>;
@@ -134,6 +139,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type ProposalDurationLimits: Get<(Self::BlockNumber, Self::BlockNumber)>;
 
+		/// Duration of the org action votings (ex. membership applications) in blocks.
+		#[pallet::constant]
+		type OrgActionDuration: Get<Self::BlockNumber>;
+
 		/// The GameDAO Treasury AccountId.
 		#[pallet::constant]
 		type GameDAOTreasury: Get<Self::AccountId>;
@@ -194,6 +203,26 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type CampaignBalanceUsed<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, T::Balance, ValueQuery>;
 
+	/// Org action to be applied by the OrgAction proposal.
+	///
+	/// ProposalOrgAction: map Hash => OrgAction
+	#[pallet::storage]
+	pub(super) type ProposalOrgAction<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, OrgAction<T::AccountId, T::Hash>, OptionQuery>;
+
+	/// Org treasury vault the Spending or Withdrawal proposal funds are taken from,
+	/// the main org treasury if not set.
+	///
+	/// ProposalVault: map Hash => VaultId
+	#[pallet::storage]
+	pub(super) type ProposalVault<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, VaultId, OptionQuery>;
+
+	/// Grant campaign milestone released by the Withdrawal proposal.
+	///
+	/// ProposalMilestone: map Hash => u32
+	#[pallet::storage]
+	pub(super) type ProposalMilestone<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, u32, OptionQuery>;
+
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -240,6 +269,7 @@ pub mod pallet {
 		TooManyProposals,
 		TreasuryBalanceLow,
 		TreasuryUnknown,
+		VaultUnknown,
 		VoteLimitReached,
 		VotingInvalid,
 		WrongParameter,
@@ -269,6 +299,7 @@ pub mod pallet {
 			amount: Option<T::Balance>,
 			beneficiary: Option<T::AccountId>,
 			currency_id: Option<T::CurrencyId>,
+			vault_id: Option<VaultId>,
 		) -> DispatchResult {
 			let proposer = ensure_signed(origin)?;
 			// Org/member validation:
@@ -303,6 +334,8 @@ pub mod pallet {
 				}
 			}
 			// Proposal type specific validation:
+			let mut proposal_vault = vault_id;
+			let mut proposal_milestone = None;
 			match proposal_type {
 				ProposalType::Withdrawal => {
 					if currency_id.is_none() {
@@ -313,21 +346,41 @@ pub mod pallet {
 
 					let campaign_owner = T::Flow::campaign_owner(&c_id).ok_or(Error::<T>::AuthorizationError)?;
 					ensure!(proposer == campaign_owner, Error::<T>::AuthorizationError);
-					ensure!(T::Flow::is_campaign_succeeded(&c_id), Error::<T>::CampaignUnsucceeded);
+					// Pool campaigns stay open, the pooled funds are spent while they are not redeemed
+					let is_pool = T::Flow::is_pool(&c_id);
+					ensure!(is_pool || T::Flow::is_campaign_succeeded(&c_id), Error::<T>::CampaignUnsucceeded);
 
-					let used_balance = CampaignBalanceUsed::<T>::get(c_id);
+					let used_balance = if is_pool { Zero::zero() } else { CampaignBalanceUsed::<T>::get(c_id) };
 					let total_balance = T::Flow::campaign_balance(&c_id);
 					let remaining_balance = total_balance
 						.checked_sub(&used_balance)
 						.ok_or(Error::<T>::BalanceLow)?;
 					ensure!(remaining_balance >= bond, Error::<T>::BalanceLow);
+					// Campaign funds are held by the vault the campaign was created for
+					ensure!(vault_id.is_none(), Error::<T>::WrongParameter);
+					proposal_vault = T::Flow::campaign_vault(&c_id);
+					// Milestone campaigns release the funds of the next milestone only
+					if T::Flow::has_milestones(&c_id) {
+						let (index, milestone_amount) = T::Flow::next_milestone(&c_id).ok_or(Error::<T>::WrongParameter)?;
+						ensure!(bond == milestone_amount, Error::<T>::WrongParameter);
+						proposal_milestone = Some(index);
+					}
 				}
 				ProposalType::Spending => {
 					if currency_id.is_none() || amount.is_none() || beneficiary.is_none() {
 						return Err(Error::<T>::MissingParameter)?;
 					}
+					if vault_id.is_some() {
+						T::Control::org_vault_account(&org_id, vault_id).ok_or(Error::<T>::VaultUnknown)?;
+					}
+				}
+				// Org action proposals are created by the control pallet only
+				ProposalType::OrgAction => {
+					return Err(Error::<T>::WrongParameter)?;
+				}
+				_ => {
+					ensure!(vault_id.is_none(), Error::<T>::WrongParameter);
 				}
-				_ => {}
 			}
 
 			// Create Proposal
@@ -340,6 +393,12 @@ pub mod pallet {
 			let proposal_hash = T::Hashing::hash_of(&proposal);
 			ensure!(!ProposalOf::<T>::contains_key(proposal_hash), Error::<T>::ProposalExists);
 
+			if let Some(vault_id) = proposal_vault {
+				ProposalVault::<T>::insert(proposal_hash, vault_id);
+			}
+			if let Some(index) = proposal_milestone {
+				ProposalMilestone::<T>::insert(proposal_hash, index);
+			}
 			Self::create_proposal(&proposal_hash, proposal)?;
 			Self::create_voting(&proposal_hash, &proposal_type, &index, &org_id, &campaign_id, quorum, majority, unit, scale);
 
@@ -375,7 +434,7 @@ pub mod pallet {
 
 			let proposal = ProposalOf::<T>::get(proposal_id).ok_or(Error::<T>::ProposalUnknown)?;
 			match proposal.proposal_type {
-				ProposalType::General | ProposalType::Spending => {
+				ProposalType::General | ProposalType::Spending | ProposalType::OrgAction => {
 					ensure!(
 						// This also !implicitly! checks if not a member -> returns default Incative
 						T::Control::is_org_member_active(&proposal.org_id, &who),
@@ -383,11 +442,14 @@ pub mod pallet {
 					);
 				},
 				ProposalType::Withdrawal => {
-					ensure!(proposal.campaign_id.is_some(), Error::<T>::ProposalInvalid);
-					ensure!(
-						T::Flow::is_campaign_contributor(&proposal.campaign_id.unwrap(), &who),
-						Error::<T>::AuthorizationError
-					);
+					let campaign_id = proposal.campaign_id.ok_or(Error::<T>::ProposalInvalid)?;
+					// Campaign token holders take part in the token weighted voting
+					let eligible = if voting.unit == Unit::Token && T::Flow::has_campaign_token(&campaign_id) {
+						T::Flow::is_campaign_token_holder(&campaign_id, &who)
+					} else {
+						T::Flow::is_campaign_contributor(&campaign_id, &who)
+					};
+					ensure!(eligible, Error::<T>::AuthorizationError);
 				},
 			}
 
@@ -478,6 +540,7 @@ pub mod pallet {
 
 		pub fn process_voting_deposits(
 			who: &T::AccountId,
+			campaign_token: &Option<T::Hash>,
 			old_deposit: &Option<T::Balance>,
 			deposit: &Option<T::Balance>
 		) -> Result<(), DispatchError> {
@@ -485,14 +548,30 @@ pub mod pallet {
 				return Ok(());
 			};
 			if let Some(amount) = old_deposit {
-				let _ = T::Currency::unreserve(T::ProtocolTokenId::get(), who, *amount);
+				Self::unreserve_voting_deposit(who, campaign_token, *amount);
 			}
 			if let Some(amount) = deposit {
-				T::Currency::reserve(T::ProtocolTokenId::get(), who, *amount).map_err(|_| Error::<T>::BalanceLow)?;
+				let reserved = match campaign_token {
+					Some(campaign_id) => T::Flow::reserve_campaign_token(campaign_id, who, *amount),
+					None => T::Currency::reserve(T::ProtocolTokenId::get(), who, *amount),
+				};
+				reserved.map_err(|_| Error::<T>::BalanceLow)?;
 			}
 			Ok(())
 		}
 
+		fn unreserve_voting_deposit(who: &T::AccountId, campaign_token: &Option<T::Hash>, amount: T::Balance) {
+			match campaign_token {
+				Some(campaign_id) => T::Flow::unreserve_campaign_token(campaign_id, who, amount),
+				None => { let _ = T::Currency::unreserve(T::ProtocolTokenId::get(), who, amount); },
+			}
+		}
+
+		/// Campaign which token is deposited for the voting instead of the protocol token.
+		fn voting_campaign_token(proposal: &Proposal<T>) -> Option<T::Hash> {
+			proposal.campaign_id.filter(|campaign_id| T::Flow::has_campaign_token(campaign_id))
+		}
+
 		pub fn try_finalize_proposal(voting: &Voting<T>) -> Option<ProposalState> {
 			// Absolute majority reached
 			match voting.majority {
@@ -551,7 +630,9 @@ pub mod pallet {
 			voting.no = voting.nays.iter().map(|a| a.1).sum();
 			voting.participating = voting.yes + voting.no;
 
-			Self::process_voting_deposits(&who, &old_deposit, &deposit)?;
+			let campaign_token = ProposalOf::<T>::get(proposal_id)
+				.and_then(|proposal| Self::voting_campaign_token(&proposal));
+			Self::process_voting_deposits(&who, &campaign_token, &old_deposit, &deposit)?;
 
 			Self::deposit_event(Event::Voted {
 				account: who,
@@ -587,10 +668,12 @@ pub mod pallet {
 			).map_err(|_| Error::<T>::BalanceLow)?;
 
 			if proposal.proposal_type == ProposalType::Spending {
-				let treasury_id = T::Control::org_treasury_account(&proposal.org_id).ok_or(Error::<T>::TreasuryUnknown)?;
+				let treasury_id = Self::proposal_treasury(proposal_id, &proposal)?;
+				let currency_id = proposal.currency_id.unwrap();
 				T::Currency::reserve(
-					proposal.currency_id.unwrap(), &treasury_id, proposal.amount.unwrap()
+					currency_id, &treasury_id, proposal.amount.unwrap()
 				).map_err(|_| Error::<T>::TreasuryBalanceLow)?;
+				T::Control::ensure_committed_funds(&proposal.org_id, &treasury_id, currency_id)?;
 			}
 
 			ProposalsByBlock::<T>::try_mutate(
@@ -685,14 +768,31 @@ pub mod pallet {
 			}
 		}
 
-		fn apply_proposal_actions(proposal: &Proposal<T>, proposal_state: ProposalState) -> Result<ProposalState, DispatchError> {
+		fn apply_proposal_actions(
+			proposal_id: &T::Hash,
+			proposal: &Proposal<T>,
+			proposal_state: ProposalState
+		) -> Result<ProposalState, DispatchError> {
 			match proposal.proposal_type {
 				ProposalType::Withdrawal => {
 					let campaign_id = proposal.campaign_id.ok_or(Error::<T>::ProposalInvalid)?;
 					let amount = proposal.amount.ok_or(Error::<T>::ProposalInvalid)?;
 					let currency_id = proposal.currency_id.ok_or(Error::<T>::ProposalInvalid)?;
-					let treasury = T::Control::org_treasury_account(&proposal.org_id).ok_or(Error::<T>::TreasuryUnknown)?;
-					T::Currency::unreserve(currency_id, &treasury, amount);
+					if let Some(index) = ProposalMilestone::<T>::get(proposal_id) {
+						// Milestone could be settled by the org or refunded after the deadline meanwhile
+						if T::Flow::settle_milestone(&campaign_id, index, true).is_err() {
+							return Ok(proposal_state)
+						}
+					} else if T::Flow::is_pool(&campaign_id) {
+						// Pooled funds could be redeemed meanwhile
+						if T::Flow::spend_pool(&campaign_id, amount).is_err() {
+							return Ok(proposal_state)
+						}
+						return Ok(ProposalState::Finalized)
+					} else {
+						let treasury = Self::proposal_treasury(proposal_id, proposal)?;
+						T::Currency::unreserve(currency_id, &treasury, amount);
+					}
 					let used_balance = CampaignBalanceUsed::<T>::get(campaign_id);
 					CampaignBalanceUsed::<T>::insert(campaign_id, used_balance + amount);
 					Ok(ProposalState::Finalized)
@@ -700,7 +800,7 @@ pub mod pallet {
 				ProposalType::Spending => {
 					let amount = proposal.amount.ok_or(Error::<T>::ProposalInvalid)?;
 					let currency_id = proposal.currency_id.ok_or(Error::<T>::ProposalInvalid)?;
-					let treasury = T::Control::org_treasury_account(&proposal.org_id).ok_or(Error::<T>::TreasuryUnknown)?;
+					let treasury = Self::proposal_treasury(proposal_id, proposal)?;
 					let beneficiary = proposal.beneficiary.as_ref().ok_or(Error::<T>::ProposalInvalid)?;
 					T::Currency::repatriate_reserved(
 						currency_id,
@@ -710,11 +810,25 @@ pub mod pallet {
 						BalanceStatus::Free)?;
 					Ok(ProposalState::Finalized)
 				}
+				ProposalType::OrgAction => {
+					Self::apply_org_action(proposal_id, proposal, true)?;
+					Ok(ProposalState::Finalized)
+				}
 				_ => { Ok(proposal_state) }
 			}
 
 		}
 
+		fn proposal_treasury(proposal_id: &T::Hash, proposal: &Proposal<T>) -> Result<T::AccountId, DispatchError> {
+			let vault_id = ProposalVault::<T>::get(proposal_id);
+			Ok(T::Control::org_vault_account(&proposal.org_id, vault_id).ok_or(Error::<T>::TreasuryUnknown)?)
+		}
+
+		fn apply_org_action(proposal_id: &T::Hash, proposal: &Proposal<T>, approved: bool) -> DispatchResult {
+			let action = ProposalOrgAction::<T>::get(proposal_id).ok_or(Error::<T>::ProposalInvalid)?;
+			T::Control::apply_org_action(&proposal.org_id, action, approved)
+		}
+
 		fn process_proposal_deposit(proposal: &Proposal<T>, voting: &Voting<T>, proposal_state: &ProposalState) -> DispatchResult {
 			let currency_id = T::ProtocolTokenId::get();
 			match proposal_state {
@@ -766,38 +880,115 @@ pub mod pallet {
 
 			match proposal_state {
 				ProposalState::Accepted => {
-					proposal_state = Self::apply_proposal_actions(&proposal, proposal_state)?;
+					proposal_state = Self::apply_proposal_actions(proposal_id, &proposal, proposal_state)?;
 				}
 				_ => {
 					if proposal.proposal_type == ProposalType::Spending {
 						let amount = proposal.amount.ok_or(Error::<T>::ProposalInvalid)?;
 						let currency_id = proposal.currency_id.ok_or(Error::<T>::ProposalInvalid)?;
-						let treasury = T::Control::org_treasury_account(&proposal.org_id).ok_or(Error::<T>::TreasuryUnknown)?;
+						let treasury = Self::proposal_treasury(proposal_id, &proposal)?;
 						T::Currency::unreserve(
 							currency_id,
 							&treasury,
 							amount);
 					};
+					if proposal.proposal_type == ProposalType::OrgAction {
+						Self::apply_org_action(proposal_id, &proposal, false)?;
+					}
+					// Funds of the milestone rejected by the contributors are refunded
+					if proposal_state == ProposalState::Rejected {
+						if let (Some(campaign_id), Some(index)) = (proposal.campaign_id, ProposalMilestone::<T>::get(proposal_id)) {
+							let _ = T::Flow::settle_milestone(&campaign_id, index, false);
+						}
+					}
 				}
 			}
 			// Unreserve all voting deposits
 			if voting.unit == Unit::Token {
-				let currency_id = T::ProtocolTokenId::get();
+				let campaign_token = Self::voting_campaign_token(&proposal);
 				// TODO: chain -  &voting.ayes.iter().chain(&voting.nays.iter())
 				for (who, _, deposit) in &voting.ayes {
-					let _ = T::Currency::unreserve(currency_id, who, deposit.ok_or(Error::<T>::VotingInvalid)?);
+					Self::unreserve_voting_deposit(who, &campaign_token, deposit.ok_or(Error::<T>::VotingInvalid)?);
 				};
 				for (who, _, deposit) in &voting.nays {
-					let _ = T::Currency::unreserve(currency_id, who, deposit.ok_or(Error::<T>::VotingInvalid)?);
+					Self::unreserve_voting_deposit(who, &campaign_token, deposit.ok_or(Error::<T>::VotingInvalid)?);
 				};
 			}
 			// Refund or slash proposal's deposit based on proposal state and majority of rejection
 			Self::process_proposal_deposit(&proposal, voting, &proposal_state)?;
 
+			ProposalOrgAction::<T>::remove(proposal_id);
+			ProposalVault::<T>::remove(proposal_id);
+			ProposalMilestone::<T>::remove(proposal_id);
+
 			Self::emit_event(&proposal_state, proposal_id);
 			ProposalStates::<T>::insert(proposal_id, proposal_state);
 
 			Ok(())
 		}
+
+		/// Creates the org members voting on behalf of the control pallet, without the proposer deposit.
+		fn create_members_proposal(
+			org_id: &T::Hash,
+			proposer: &T::AccountId,
+			proposal_type: ProposalType,
+			amount: Option<T::Balance>,
+			currency_id: Option<T::CurrencyId>,
+			beneficiary: Option<T::AccountId>,
+		) -> Result<T::Hash, DispatchError> {
+			let start = <frame_system::Pallet<T>>::block_number();
+			let expiry = start + T::OrgActionDuration::get();
+			let index = ProposalCount::<T>::get();
+			let proposal = types::Proposal {
+				index, title: BoundedVec::default(), cid: BoundedVec::default(), org_id: *org_id,
+				campaign_id: None, amount, deposit: Zero::zero(), currency_id, beneficiary,
+				proposal_type: proposal_type.clone(), start, expiry,
+				owner: proposer.clone(), slashing_rule: SlashingRule::Automated
+			};
+			let proposal_id = T::Hashing::hash_of(&proposal);
+			ensure!(!ProposalOf::<T>::contains_key(proposal_id), Error::<T>::ProposalExists);
+
+			Self::create_proposal(&proposal_id, proposal)?;
+			Self::create_voting(
+				&proposal_id, &proposal_type, &index, org_id, &None,
+				None, Majority::Relative, Unit::Account, Scale::Linear
+			);
+
+			Self::deposit_event(Event::<T>::Created {
+				proposal_id,
+				account: proposer.clone(),
+				org_id: *org_id,
+				campaign_id: None,
+				amount,
+				start,
+				expiry,
+			});
+
+			Ok(proposal_id)
+		}
+	}
+	impl<T: Config> GovernanceTrait<T::AccountId, T::Balance, T::CurrencyId, T::Hash> for Pallet<T> {
+
+		fn create_org_action_proposal(
+			org_id: &T::Hash,
+			proposer: &T::AccountId,
+			action: OrgAction<T::AccountId, T::Hash>
+		) -> Result<T::Hash, DispatchError> {
+			let proposal_id = Self::create_members_proposal(org_id, proposer, ProposalType::OrgAction, None, None, None)?;
+			ProposalOrgAction::<T>::insert(proposal_id, action);
+			Ok(proposal_id)
+		}
+
+		fn create_spending_proposal(
+			org_id: &T::Hash,
+			proposer: &T::AccountId,
+			currency_id: T::CurrencyId,
+			beneficiary: &T::AccountId,
+			amount: T::Balance
+		) -> Result<T::Hash, DispatchError> {
+			Self::create_members_proposal(
+				org_id, proposer, ProposalType::Spending, Some(amount), Some(currency_id), Some(beneficiary.clone())
+			)
+		}
 	}
 }
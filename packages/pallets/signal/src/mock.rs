@@ -3,20 +3,23 @@ use crate as gamedao_signal;
 use frame_support::{
 	parameter_types,
 	pallet_prelude::*,
-	traits::{GenesisBuild, Nothing},
+	traits::{AsEnsureOriginWithArg, ConstU64, GenesisBuild, Nothing},
 	PalletId
 };
 use frame_system;
-use orml_traits::parameter_type_with_key;
+use pallet_nfts::PalletFeatures;
+use orml_traits::{parameter_type_with_key, DataProvider};
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
-	Permill,
+	testing::{Header, TestSignature},
+	traits::{BlakeTwo256, Convert, IdentityLookup, Verify, IdentifyAccount},
+	FixedPointNumber, FixedU128, Permill,
 };
 use sp_std::convert::{TryFrom, TryInto};
 
-pub type AccountId = u64;
+pub type Signature = TestSignature;
+pub type AccountPublic = <Signature as Verify>::Signer;
+pub type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
 pub type Amount = i128;
 pub type Balance = u128;
 pub type BlockNumber = u64;
@@ -41,6 +44,15 @@ pub const GAME3_TREASURY: AccountId = 105;
 pub const GAMEDAO_TREASURY: AccountId = 106;
 pub const PROTOCOL_TOKEN_ID: CurrencyId = 1;
 pub const PAYMENT_TOKEN_ID: CurrencyId = 2;
+pub const CAMPAIGN_TOKEN_ID_OFFSET: CurrencyId = 1000;
+
+/// Share campaign tokens follow the protocol currencies.
+pub struct CampaignTokenIds;
+impl Convert<u32, CurrencyId> for CampaignTokenIds {
+	fn convert(index: u32) -> CurrencyId {
+		CAMPAIGN_TOKEN_ID_OFFSET + index
+	}
+}
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -68,6 +80,7 @@ frame_support::construct_runtime!(
 		Currencies: orml_currencies::{Pallet, Call},
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 		PalletBalances: pallet_balances::{Pallet, Call, Storage, Event<T>},
+		Nfts: pallet_nfts::{Pallet, Call, Storage, Event<T>},
 		PalletTimestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 		Flow: gamedao_flow,
 		Control: gamedao_control,
@@ -164,20 +177,41 @@ impl pallet_timestamp::Config for Test {
 frame_support::parameter_types! {
 	pub const ProtocolTokenId: u32 = PROTOCOL_TOKEN_ID;
 	pub const PaymentTokenId: CurrencyId = PAYMENT_TOKEN_ID;
+	pub TreasuryCurrencies: Vec<CurrencyId> = vec![PROTOCOL_TOKEN_ID, PAYMENT_TOKEN_ID];
 	pub const MinimumDeposit: Balance = 1 * DOLLARS;
 	pub const ControlPalletId: PalletId = PalletId(*b"gd/cntrl");
 	pub const MaxMembers: u32 = 1000;
 }
+#[cfg(feature = "runtime-benchmarks")]
+pub struct InvitationSigner;
+#[cfg(feature = "runtime-benchmarks")]
+impl gamedao_control::InvitationSigner<AccountId, Signature> for InvitationSigner {
+	fn sign(message: &[u8]) -> (AccountId, Signature) {
+		(ALICE, TestSignature(ALICE, message.to_vec()))
+	}
+}
 impl gamedao_control::Config for Test {
 	type Balance = Balance;
 	type CurrencyId = CurrencyId;
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Currencies;
+	type Governance = Signal;
+	type Sense = ();
+	type OffchainSignature = Signature;
+	type OffchainPublic = AccountPublic;
+	#[cfg(feature = "runtime-benchmarks")]
+	type InvitationSigner = InvitationSigner;
 	type MaxMembers = MaxMembers;
+	type MaxMembersProcessing = ConstU32<100>;
+	type MaxStreams = ConstU32<10>;
 	type ProtocolTokenId = ProtocolTokenId;
 	type PaymentTokenId = PaymentTokenId;
+	type TreasuryCurrencies = TreasuryCurrencies;
 	type MinimumDeposit = MinimumDeposit;
+	type SubscriptionPeriod = ConstU64<100>;
+	type SubscriptionGracePeriod = ConstU64<10>;
+	type PrimeHandoverPeriod = ConstU64<100>;
 	type PalletId = ControlPalletId;
 	type StringLimit = ConstU32<256>;
 }
@@ -193,6 +227,51 @@ parameter_types! {
 	pub const GameDAOTreasury: AccountId = TREASURY_ACC;
 }
 
+parameter_types! {
+	pub Features: PalletFeatures = PalletFeatures::all_enabled();
+	pub const MaxAttributesPerCall: u32 = 10;
+	pub const ApprovalsLimit: u32 = 20;
+	pub const ItemAttributesApprovalsLimit: u32 = 20;
+	pub const MaxTips: u32 = 10;
+	pub const MaxDeadlineDuration: BlockNumber = 10000;
+	pub CollectionDeposit: Balance = 0;
+	pub ItemDeposit: Balance = 0;
+	pub const KeyLimit: u32 = 32;	// Max 32 bytes per key
+	pub const ValueLimit: u32 = 64;	// Max 64 bytes per value
+	pub MetadataDepositBase: Balance = 0;
+	pub MetadataDepositPerByte: Balance = 0;
+	pub const NftStringLimit: u32 = 64;
+}
+
+impl pallet_nfts::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = PalletBalances;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type CollectionDeposit = CollectionDeposit;
+	type ItemDeposit = ItemDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type AttributeDepositBase = MetadataDepositBase;
+	type DepositPerByte = MetadataDepositPerByte;
+	type StringLimit = NftStringLimit;
+	type KeyLimit = KeyLimit;
+	type ValueLimit = ValueLimit;
+	type ApprovalsLimit = ApprovalsLimit;
+	type ItemAttributesApprovalsLimit = ItemAttributesApprovalsLimit;
+	type MaxTips = MaxTips;
+	type MaxDeadlineDuration = MaxDeadlineDuration;
+	type MaxAttributesPerCall = MaxAttributesPerCall;
+	type Features = Features;
+	type OffchainSignature = Signature;
+	type OffchainPublic = AccountPublic;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type Locker = ();
+}
+
 impl gamedao_flow::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -211,9 +290,22 @@ impl gamedao_flow::Config for Test {
 	type MinContribution = MinContribution;
 	type ProtocolTokenId = ProtocolTokenId;
 	type PaymentTokenId = PaymentTokenId;
+	type CampaignTokenId = CampaignTokenIds;
 	type CampaignFee = CampaignFee;
 	type StringLimit = ConstU32<256>;
 	type CampaignDurationLimits = CampaignDurationLimits;
+	type RewardHelper = gamedao_flow::RewardItems;
+	type PriceOracle = gamedao_flow::OraclePrices<MockOracle>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkCurrencyId = PaymentTokenId;
+}
+
+/// Only the payment token has a price.
+pub struct MockOracle;
+impl DataProvider<CurrencyId, FixedU128> for MockOracle {
+	fn get(currency_id: &CurrencyId) -> Option<FixedU128> {
+		(*currency_id == PAYMENT_TOKEN_ID).then(|| FixedU128::saturating_from_integer(1))
+	}
 }
 
 parameter_types! {
@@ -222,6 +314,7 @@ parameter_types! {
 	pub SlashingMajority: Permill = Permill::from_rational(2u32, 3u32);
 	pub GameDAOGetsFromSlashing: Permill = Permill::from_rational(1u32, 10u32);
 	pub const ProposalDurationLimits: (BlockNumber, BlockNumber) = (100, 864000);
+	pub const OrgActionDuration: BlockNumber = 100;
 }
 impl gamedao_signal::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -239,6 +332,7 @@ impl gamedao_signal::Config for Test {
 	type PaymentTokenId = PaymentTokenId;
 	type MinProposalDeposit = MinProposalDeposit;
 	type ProposalDurationLimits = ProposalDurationLimits;
+	type OrgActionDuration = OrgActionDuration;
 	type GameDAOTreasury = GameDAOTreasury;
 	type SlashingMajority = SlashingMajority;
 	type GameDAOGetsFromSlashing = GameDAOGetsFromSlashing;
@@ -7,7 +7,7 @@ use crate::mock::{
 	BlockNumber, AccountId, Balance, Control, RuntimeEvent as Event, ExtBuilder, Tokens, BoundedString,
 	RuntimeOrigin as Origin, Signal, System, Test, ALICE, BOB, CHARLIE, DOLLARS, DAYS,
 	PROTOCOL_TOKEN_ID, PAYMENT_TOKEN_ID,
-	ProposalDurationLimits, Flow, CurrencyId
+	ProposalDurationLimits, Flow, CurrencyId, CAMPAIGN_TOKEN_ID_OFFSET,
 };
 use frame_system::RawOrigin;
 use frame_support::{
@@ -15,7 +15,7 @@ use frame_support::{
 	traits::Hooks
 };
 use sp_core::H256;
-use gamedao_control::types::{AccessModel, FeeModel, OrgType, Org};
+use gamedao_control::types::{AccessModel, FeeModel, OrgType, Org, VaultRules};
 use gamedao_flow::{FlowGovernance, FlowProtocol};
 
 pub fn create_org(members: &Vec<AccountId>) -> (H256, AccountId) {
@@ -32,13 +32,13 @@ pub fn create_org(members: &Vec<AccountId>) -> (H256, AccountId) {
 	assert_ok!(
 		Control::create_org(
 			Origin::signed(ALICE), org.name, org.cid, org.org_type, org.access_model,
-			org.fee_model, None, org.membership_fee, None, None, None
+			org.fee_model, None, org.membership_fee, None, None, None, None, None
 	));
 	let treasury_id = Control::org_treasury_account(&org_id).unwrap();
 	let init_balance = 100 * DOLLARS;
 	assert_ok!(Tokens::set_balance(RawOrigin::Root.into(), treasury_id, PROTOCOL_TOKEN_ID, init_balance, 0));
 	for x in members {
-		assert_ok!(Control::add_member(Origin::signed(x.clone()), org_id, *x));
+		assert_ok!(Control::add_member(Origin::signed(x.clone()), org_id, *x, None));
 	}
 	(org_id, treasury_id)
 }
@@ -81,7 +81,7 @@ pub fn create_finalize_campaign(
 		Origin::signed(ALICE),
 		org_id, campaign.admin, campaign.name.clone(), campaign.cap,
 		campaign.deposit, campaign.expiry, campaign.protocol.clone(),
-		campaign.governance.clone(), campaign.cid.clone(), None, None, None
+		campaign.governance.clone(), campaign.cid.clone(), None, None, None, None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
 	));
 	let campaign_id = <Test as frame_system::Config>::Hashing::hash_of(&campaign);
 	for x in contributors {
@@ -152,7 +152,7 @@ fn signal_0_0() {
 				None, // start
 				None, // quorum
 				None, // deposit
-				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id),
+				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None),
 			Error::<Test>::OrgInactive
 		);
 		let _ = Control::enable_org(RawOrigin::Root.into(), org_id);
@@ -164,7 +164,7 @@ fn signal_0_0() {
 				Origin::signed(not_a_member), proposal.proposal_type.clone(), proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 				Majority::Relative, Unit::Account, Scale::Linear, None, None, None,
-				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id),
+				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None),
 			Error::<Test>::AuthorizationError
 		);
 
@@ -175,7 +175,7 @@ fn signal_0_0() {
 				Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 				Majority::Relative, Unit::Account, Scale::Linear, Some(start), None, None,
-				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id),
+				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None),
 			Error::<Test>::OutOfBounds
 		);
 
@@ -186,7 +186,7 @@ fn signal_0_0() {
 				Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), expiry,
 				Majority::Relative, Unit::Account, Scale::Linear, None, None, None,
-				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id),
+				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None),
 			Error::<Test>::OutOfBounds
 		);
 
@@ -196,7 +196,7 @@ fn signal_0_0() {
 				Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 				Majority::Relative, Unit::Account, Scale::Linear, None, None, Some(1 * DOLLARS),
-				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id),
+				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None),
 			Error::<Test>::DepositInsufficient
 		);
 
@@ -206,7 +206,7 @@ fn signal_0_0() {
 				Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 				Majority::Relative, Unit::Account, Scale::Quadratic, None, None, None,
-				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id),
+				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None),
 			Error::<Test>::WrongParameter
 		);
 
@@ -216,7 +216,7 @@ fn signal_0_0() {
 				Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 				Majority::Absolute, Unit::Token, Scale::Quadratic, None, None, None,
-				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id),
+				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None),
 			Error::<Test>::WrongParameter
 		);
 
@@ -226,7 +226,7 @@ fn signal_0_0() {
 				Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 				Majority::Relative, Unit::Account, Scale::Linear, None, None, Some(1000 * DOLLARS),
-				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id),
+				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None),
 			Error::<Test>::BalanceLow
 		);
 
@@ -236,29 +236,47 @@ fn signal_0_0() {
 				Origin::signed(ALICE), ProposalType::Spending, proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 				Majority::Relative, Unit::Account, Scale::Linear, None, None, None,
-				proposal.campaign_id, Some(1000 * DOLLARS), Some(1), proposal.currency_id),
+				proposal.campaign_id, Some(1000 * DOLLARS), Some(1), proposal.currency_id, None),
 			Error::<Test>::TreasuryBalanceLow
 		);
 
+		// FundsCommitted: treasury funds are committed to the org payment stream
+		let treasury_id = Control::org_treasury_account(&org_id).unwrap();
+		let treasury_balance = <Test as Config>::Currency::free_balance(PAYMENT_TOKEN_ID, &treasury_id);
+		let block_number = System::block_number();
+		assert_ok!(Control::create_stream(
+			Origin::signed(ALICE), org_id, BOB, PAYMENT_TOKEN_ID, treasury_balance / 10, 1,
+			block_number, Some(block_number + 10)
+		));
+		assert_noop!(
+			Signal::proposal(
+				Origin::signed(ALICE), ProposalType::Spending, proposal.org_id,
+				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
+				Majority::Relative, Unit::Account, Scale::Linear, None, None, None,
+				proposal.campaign_id, Some(1 * DOLLARS), Some(1), proposal.currency_id, None),
+			gamedao_control::Error::<Test>::FundsCommitted
+		);
+		assert_ok!(Control::cancel_stream(Origin::signed(ALICE), org_id, 0));
+
 		// TooManyProposals
 		assert_ok!(Signal::proposal(
 			Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 			proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 			Majority::Relative, Unit::Account, Scale::Linear, None, None, None,
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id),
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None),
 		);
 		assert_ok!(Signal::proposal(
 			Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 			proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 			Majority::Relative, Unit::Account, Scale::Linear, None, None, None,
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id),
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None),
 		);
 		assert_noop!(
 			Signal::proposal(
 				Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 				Majority::Relative, Unit::Account, Scale::Linear, None, None, None,
-				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id),
+				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None),
 			Error::<Test>::TooManyProposals
 		);
 	});
@@ -294,7 +312,7 @@ fn signal_0_1() {
 				Origin::signed(ALICE), ProposalType::Spending, proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 				Majority::Relative, Unit::Account, Scale::Linear, None, None, None,
-				proposal.campaign_id, proposal.amount, None, proposal.currency_id),
+				proposal.campaign_id, proposal.amount, None, proposal.currency_id, None),
 			Error::<Test>::MissingParameter
 		);
 
@@ -305,7 +323,7 @@ fn signal_0_1() {
 				Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 				Majority::Relative, Unit::Account, Scale::Linear, None, None, None,
-				proposal.campaign_id, proposal.amount, proposal.beneficiary, None),
+				proposal.campaign_id, proposal.amount, proposal.beneficiary, None, None),
 			Error::<Test>::MissingParameter
 		);
 
@@ -315,7 +333,7 @@ fn signal_0_1() {
 				Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 				Majority::Relative, Unit::Account, Scale::Linear, None, None, None,
-				proposal.campaign_id, None, proposal.beneficiary, proposal.currency_id),
+				proposal.campaign_id, None, proposal.beneficiary, proposal.currency_id, None),
 			Error::<Test>::MissingParameter
 		);
 
@@ -325,7 +343,7 @@ fn signal_0_1() {
 				Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 				Majority::Relative, Unit::Account, Scale::Linear, None, None, None,
-				None, proposal.amount, proposal.beneficiary, proposal.currency_id),
+				None, proposal.amount, proposal.beneficiary, proposal.currency_id, None),
 			Error::<Test>::MissingParameter
 		);
 
@@ -335,7 +353,7 @@ fn signal_0_1() {
 				Origin::signed(BOB), proposal.proposal_type.clone(), proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 				Majority::Relative, Unit::Account, Scale::Linear, None, None, None,
-				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id),
+				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None),
 			Error::<Test>::AuthorizationError
 		);
 
@@ -346,7 +364,7 @@ fn signal_0_1() {
 				Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 				Majority::Relative, Unit::Account, Scale::Linear, None, None, None,
-				proposal.campaign_id, Some(insufficient_amount), proposal.beneficiary, proposal.currency_id),
+				proposal.campaign_id, Some(insufficient_amount), proposal.beneficiary, proposal.currency_id, None),
 			Error::<Test>::BalanceLow
 		);
 
@@ -366,7 +384,7 @@ fn signal_0_1() {
 				Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 				proposal.title.clone(), proposal.cid.clone(), proposal_expiry,
 				Majority::Relative, Unit::Account, Scale::Linear, None, None, None,
-				Some(campaign_id), proposal.amount, proposal.beneficiary, proposal.currency_id),
+				Some(campaign_id), proposal.amount, proposal.beneficiary, proposal.currency_id, None),
 			Error::<Test>::CampaignUnsucceeded
 		);
 	});
@@ -398,7 +416,7 @@ fn signal_0_2() {
 			Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 			proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 			Majority::Relative, Unit::Account, Scale::Linear, Some(proposal.start), None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 		// ProposalNotActive
 		assert_noop!(
@@ -431,7 +449,7 @@ fn signal_0_2() {
 			Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 			proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 			Majority::Relative, Unit::Token, Scale::Linear, Some(proposal.start), None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 
 		// AuthorizationError: an org member, but not a contributor
@@ -461,7 +479,7 @@ fn signal_0_2() {
 			Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
 			proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
 			Majority::Relative, Unit::Account, Scale::Linear, Some(proposal.start), None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 
 		// WrongParameter: Unit::Account and deposit provided
@@ -505,7 +523,7 @@ fn signal_1_0() {
 			Some(proposal.start),
 			Some(Permill::from_rational(1u32, 3u32)), // quorum 1/3
 			Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 		// Check if deposit was reserved
 		assert_eq!(<Test as Config>::Currency::total_balance(PROTOCOL_TOKEN_ID, &ALICE), total_balance);
@@ -584,7 +602,7 @@ fn signal_1_1() {
 			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
 			proposal.title, proposal.cid, proposal.expiry,
 			Majority::Relative, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 
 		// Every org member votes "NO"
@@ -644,7 +662,7 @@ fn signal_1_2() {
 			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
 			proposal.title, proposal.cid, proposal.expiry,
 			Majority::Relative, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 		// No voting
 		// Hop to the proposal's expiry block and check proposal finalized
@@ -688,7 +706,7 @@ fn signal_1_3() {
 			Majority::Relative, Unit::Account, Scale::Linear, None,
 			Some(Permill::from_rational(1u32, 3u32)), // quorum 1/3,
 			Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 
 		// Voting: less than 1/3 voted YES, nobody voted NO
@@ -733,7 +751,7 @@ fn signal_1_4() {
 			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
 			proposal.title, proposal.cid, proposal.expiry,
 			Majority::Absolute, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 
 		// Voting: more than 50% voted "YES"
@@ -778,7 +796,7 @@ fn signal_1_5() {
 			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
 			proposal.title, proposal.cid, proposal.expiry,
 			Majority::Absolute, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 
 		// Voting: less than 50% voted "YES"
@@ -828,7 +846,7 @@ fn signal_1_6() {
 			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
 			proposal.title, proposal.cid, proposal.expiry,
 			Majority::Simple, Unit::Token, Scale::Quadratic, None, None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 
 		let voting_deposit = 10 * DOLLARS;
@@ -877,7 +895,7 @@ fn signal_1_7() {
 			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
 			proposal.title, proposal.cid, proposal.expiry,
 			Majority::Simple, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 
 		// ~10% org member votes "NO"
@@ -926,7 +944,7 @@ fn signal_1_8() {
 			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
 			proposal.title, proposal.cid, proposal.expiry,
 			Majority::Relative, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 
 		// One org member votes "NO"
@@ -996,7 +1014,7 @@ fn signal_2_0() {
 			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
 			proposal.title, proposal.cid, proposal.expiry,
 			Majority::Relative, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 
 		// Every contributor votes "YES" and triggers an earlier finalization
@@ -1037,7 +1055,7 @@ fn signal_2_0() {
 			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
 			proposal.title, proposal.cid, proposal.expiry,
 			Majority::Relative, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 
 		// Ensure org treasury balance was reserved during proposal creation
@@ -1113,7 +1131,7 @@ fn signal_2_1() {
 			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
 			proposal.title, proposal.cid, proposal.expiry,
 			Majority::Relative, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 
 		// Every contributor votes "YES" and triggers an earlier finalization
@@ -1151,7 +1169,7 @@ fn signal_2_1() {
 			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
 			proposal.title, proposal.cid, proposal.expiry,
 			Majority::Relative, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 
 		// Ensure org treasury balance was reserved during proposal creation
@@ -1218,7 +1236,7 @@ fn signal_2_2() {
 			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
 			proposal.title, proposal.cid, proposal.expiry,
 			Majority::Simple, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
-			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id,
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
 		));
 
 		// Every contributor votes "NO"
@@ -1242,3 +1260,410 @@ fn signal_2_2() {
 		assert_eq!(<Test as Config>::Currency::free_balance(PROTOCOL_TOKEN_ID, &ALICE), total_balance - deposit);
 	});
 }
+
+/// Test 3.0
+/// - OrgAction proposal (membership application)
+/// - Proposal created by the control pallet
+/// - Result -> Finalized: applicant is an active member
+/// - Result -> Rejected: applicant is not a member
+#[test]
+fn signal_3_0() {
+	ExtBuilder::default().build().execute_with(|| {
+		let now: BlockNumber = 3;
+		System::set_block_number(now);
+		let members: Vec<AccountId> = (0..5).collect();
+		let bounded_str = BoundedVec::truncate_from(vec![1,2]);
+		let org = Org {
+			index: Control::org_count(), creator: ALICE, prime: ALICE, name: bounded_str.clone(), cid: bounded_str.clone(),
+			org_type: OrgType::Dao, fee_model: FeeModel::NoFees, membership_fee: None,
+			gov_currency: PROTOCOL_TOKEN_ID, pay_currency: PAYMENT_TOKEN_ID, access_model: AccessModel::Voting,
+			member_limit: <Test as gamedao_control::Config>::MaxMembers::get(), created: now, mutated: now
+		};
+		let org_id = <Test as frame_system::Config>::Hashing::hash_of(&org);
+		assert_ok!(Control::create_org(
+			Origin::signed(ALICE), org.name, org.cid, org.org_type, org.access_model,
+			org.fee_model, None, None, None, None, None, None, None
+		));
+		for x in &members {
+			assert_ok!(Control::add_member(Origin::root(), org_id, *x, None));
+		}
+
+		// Membership application creates the proposal
+		let expiry = now + <Test as Config>::OrgActionDuration::get();
+		let proposal = Proposal {
+			index: <ProposalCount<Test>>::get(), owner: CHARLIE, title: BoundedVec::default(),
+			cid: BoundedVec::default(), slashing_rule: SlashingRule::Automated,
+			start: now, expiry, org_id, deposit: 0, campaign_id: None,
+			amount: None, beneficiary: None, proposal_type: ProposalType::OrgAction, currency_id: None,
+		};
+		let proposal_id: H256 = <Test as frame_system::Config>::Hashing::hash_of(&proposal);
+		assert_ok!(Control::add_member(Origin::signed(CHARLIE), org_id, CHARLIE, None));
+		System::assert_has_event(Event::Signal(crate::Event::Created {
+			account: CHARLIE,
+			proposal_id,
+			org_id,
+			campaign_id: None,
+			amount: None,
+			start: now,
+			expiry,
+		}));
+		assert_eq!(ProposalStates::<Test>::get(&proposal_id), ProposalState::Active);
+		assert_eq!(Control::is_org_member_active(&org_id, &CHARLIE), false);
+
+		// Applicant is not allowed to vote
+		assert_noop!(
+			Signal::vote(Origin::signed(CHARLIE), proposal_id, true, None),
+			Error::<Test>::AuthorizationError
+		);
+		// OrgAction proposal can't be created directly
+		assert_noop!(
+			Signal::proposal(
+				Origin::signed(ALICE), ProposalType::OrgAction, org_id, BoundedVec::default(), BoundedVec::default(),
+				expiry, Majority::Relative, Unit::Account, Scale::Linear, None, None, None, None, None, None, None, None,
+			),
+			Error::<Test>::WrongParameter
+		);
+
+		for x in &members {
+			assert_ok!(Signal::vote(Origin::signed(*x), proposal_id, true, None));
+		}
+		System::set_block_number(expiry);
+		Signal::on_finalize(expiry);
+
+		System::assert_has_event(Event::Signal(crate::Event::Finalized { proposal_id }));
+		assert_eq!(ProposalStates::<Test>::get(&proposal_id), ProposalState::Finalized);
+		assert_eq!(Control::is_org_member_active(&org_id, &CHARLIE), true);
+
+		// Rejected application
+		assert_ok!(Control::add_member(Origin::signed(BOB), org_id, BOB, None));
+		let proposal_id = *ProposalsByBlock::<Test>::get(BlockType::Start, expiry).last().unwrap();
+		for x in &members {
+			assert_ok!(Signal::vote(Origin::signed(*x), proposal_id, false, None));
+		}
+		let expiry = expiry + <Test as Config>::OrgActionDuration::get();
+		System::set_block_number(expiry);
+		Signal::on_finalize(expiry);
+
+		System::assert_has_event(Event::Signal(crate::Event::Rejected { proposal_id }));
+		assert_eq!(ProposalStates::<Test>::get(&proposal_id), ProposalState::Rejected);
+		assert_eq!(Control::is_org_member_active(&org_id, &BOB), false);
+	});
+}
+
+/// Test 3.1
+/// - Spending proposal created by the control pallet (Hybrid org spending above the threshold)
+/// - Amount is reserved on the org treasury
+/// - Result -> Finalized: funds sent to the beneficiary
+#[test]
+fn signal_3_1() {
+	ExtBuilder::default().build().execute_with(|| {
+		let members: Vec<AccountId> = (0..5).collect();
+		let (org_id, treasury_id) = create_org(&members);
+		let now: BlockNumber = 3;
+		System::set_block_number(now);
+		assert_ok!(Control::update_org(
+			Origin::signed(ALICE), org_id, None, None, None, Some(OrgType::Hybrid), None, None, None, None));
+		let bob_balance = <Test as Config>::Currency::free_balance(PROTOCOL_TOKEN_ID, &BOB);
+
+		let amount = 10 * DOLLARS;
+		let expiry = now + <Test as Config>::OrgActionDuration::get();
+		let proposal = Proposal {
+			index: <ProposalCount<Test>>::get(), owner: ALICE, title: BoundedVec::default(),
+			cid: BoundedVec::default(), slashing_rule: SlashingRule::Automated,
+			start: now, expiry, org_id, deposit: 0, campaign_id: None, amount: Some(amount),
+			beneficiary: Some(BOB), proposal_type: ProposalType::Spending, currency_id: Some(PROTOCOL_TOKEN_ID),
+		};
+		let proposal_id: H256 = <Test as frame_system::Config>::Hashing::hash_of(&proposal);
+		assert_ok!(Control::spend_funds(Origin::signed(ALICE), org_id, PROTOCOL_TOKEN_ID, BOB, amount));
+		assert_eq!(ProposalStates::<Test>::get(&proposal_id), ProposalState::Active);
+		assert_eq!(<Test as Config>::Currency::reserved_balance(PROTOCOL_TOKEN_ID, &treasury_id), amount);
+		assert_eq!(<Test as Config>::Currency::free_balance(PROTOCOL_TOKEN_ID, &BOB), bob_balance);
+
+		for x in &members {
+			assert_ok!(Signal::vote(Origin::signed(*x), proposal_id, true, None));
+		}
+		System::set_block_number(expiry);
+		Signal::on_finalize(expiry);
+
+		assert_eq!(ProposalStates::<Test>::get(&proposal_id), ProposalState::Finalized);
+		assert_eq!(<Test as Config>::Currency::reserved_balance(PROTOCOL_TOKEN_ID, &treasury_id), 0);
+		assert_eq!(<Test as Config>::Currency::free_balance(PROTOCOL_TOKEN_ID, &BOB), bob_balance + amount);
+	});
+}
+
+/// Test 4.0
+/// - Spending proposal from the org treasury vault
+/// - Result -> Finalized
+/// - Action -> funds sent from the vault to beneficiary account
+#[test]
+fn signal_4_0() {
+	ExtBuilder::default().build().execute_with(|| {
+		let members: Vec<AccountId> = (0..10).collect();
+		let (org_id, _) = create_org(&members);
+		let now: BlockNumber = 3;
+		System::set_block_number(now);
+		let currency = PAYMENT_TOKEN_ID;
+		let deposit = 20 * DOLLARS;
+		let spend_amount = 10 * DOLLARS;
+		let beneficiary = CHARLIE;
+
+		let rules = VaultRules { roles: BoundedVec::truncate_from(vec![]), limits: BoundedVec::truncate_from(vec![]), period: 0 };
+		assert_ok!(Control::create_vault(Origin::root(), org_id, BoundedVec::truncate_from(b"grants".to_vec()), rules));
+		let vault_account = Control::org_vault_account(&org_id, Some(1)).unwrap();
+		assert_ok!(Tokens::set_balance(RawOrigin::Root.into(), vault_account, currency, spend_amount, 0));
+
+		let expiry: BlockNumber = now + ProposalDurationLimits::get().0;
+		let (proposal_id, proposal) = create_proposal(
+			ProposalType::Spending, org_id, now, expiry, deposit,
+			None, Some(currency), Some(beneficiary), Some(spend_amount)
+		);
+
+		// Unknown vault
+		assert_noop!(
+			Signal::proposal(
+				Origin::signed(ALICE), proposal.proposal_type.clone(), proposal.org_id,
+				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
+				Majority::Relative, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
+				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, Some(2),
+			),
+			Error::<Test>::VaultUnknown
+		);
+		// Vault is not applicable to the General proposal
+		assert_noop!(
+			Signal::proposal(
+				Origin::signed(ALICE), ProposalType::General, proposal.org_id,
+				proposal.title.clone(), proposal.cid.clone(), proposal.expiry,
+				Majority::Relative, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
+				None, None, None, None, Some(1),
+			),
+			Error::<Test>::WrongParameter
+		);
+
+		assert_ok!(Signal::proposal(
+			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
+			proposal.title, proposal.cid, proposal.expiry,
+			Majority::Relative, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, Some(1),
+		));
+		assert_eq!(ProposalVault::<Test>::get(&proposal_id), Some(1));
+
+		// Ensure vault balance was reserved during proposal creation
+		assert_eq!(<Test as Config>::Currency::reserved_balance(currency, &vault_account), spend_amount);
+
+		for x in &members {
+			assert_ok!(Signal::vote(Origin::signed(*x), proposal_id, true, None));
+		}
+		System::set_block_number(expiry);
+		Signal::on_finalize(expiry);
+		assert_eq!(ProposalStates::<Test>::get(&proposal_id), ProposalState::Finalized);
+		assert!(!ProposalVault::<Test>::contains_key(&proposal_id));
+
+		// Check if spend amount was transfered out from the vault
+		assert_eq!(<Test as Config>::Currency::total_balance(currency, &vault_account), 0);
+		assert_eq!(<Test as Config>::Currency::total_balance(currency, &beneficiary), spend_amount);
+	});
+}
+
+/// Test 4.1
+/// - Withdrawal proposals of the Grant campaign with milestones
+/// - Result -> Finalized: milestone funds are released
+/// - Result -> Rejected: milestone funds are refunded to the contributors
+#[test]
+fn signal_4_1() {
+	ExtBuilder::default().build().execute_with(|| {
+		let members: Vec<AccountId> = (0..5).collect();
+		let contributors: Vec<AccountId> = (51..53).collect();
+		let (org_id, treasury_id) = create_org(&members);
+		set_balance(&contributors, 100 * DOLLARS);
+		let currency = PAYMENT_TOKEN_ID;
+		let deposit = 20 * DOLLARS;
+		let contribution = 20 * DOLLARS;
+		let now: BlockNumber = 3;
+		System::set_block_number(now);
+
+		// Grant campaign with two milestones
+		let campaign_expiry = now + 2 * DAYS;
+		let bounded_str = BoundedVec::truncate_from(vec![1, 2, 3]);
+		let campaign = gamedao_flow::types::Campaign {
+			index: Flow::campaign_count(), org_id, name: bounded_str.clone(), owner: ALICE, admin: ALICE,
+			deposit: 10 * DOLLARS, start: now, expiry: campaign_expiry, cap: 40 * DOLLARS,
+			protocol: FlowProtocol::Grant, governance: FlowGovernance::Yes, cid: bounded_str.clone(),
+			token_symbol: None, token_name: None, created: now,
+		};
+		let milestones = vec![
+			gamedao_flow::Milestone::<Test> { cid: bounded_str.clone(), amount: 10 * DOLLARS, deadline: campaign_expiry + 10 * DAYS },
+			gamedao_flow::Milestone::<Test> { cid: bounded_str.clone(), amount: 30 * DOLLARS, deadline: campaign_expiry + 20 * DAYS },
+		];
+		assert_ok!(Flow::create_campaign(
+			Origin::signed(ALICE), org_id, campaign.admin, campaign.name.clone(), campaign.cap,
+			campaign.deposit, campaign.expiry, campaign.protocol.clone(), campaign.governance.clone(),
+			campaign.cid.clone(), None, None, None, None, BoundedVec::truncate_from(milestones), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
+		));
+		let campaign_id = <Test as frame_system::Config>::Hashing::hash_of(&campaign);
+		for x in &contributors {
+			assert_ok!(Flow::contribute(Origin::signed(*x), campaign_id, contribution));
+		}
+		System::set_block_number(campaign_expiry);
+		Flow::on_finalize(campaign_expiry);
+		System::set_block_number(campaign_expiry + 1);
+		Flow::on_initialize(campaign_expiry + 1);
+		assert_eq!(Flow::is_campaign_succeeded(&campaign_id), true);
+		let (_, first) = Flow::next_milestone(&campaign_id).unwrap();
+
+		// Withdrawal amount must match the next milestone
+		let start: BlockNumber = campaign_expiry + 1;
+		let expiry: BlockNumber = start + ProposalDurationLimits::get().0;
+		let (_, proposal) = create_proposal(
+			ProposalType::Withdrawal, org_id, start, expiry, deposit,
+			Some(campaign_id), Some(currency), None, Some(first - 1)
+		);
+		assert_noop!(
+			Signal::proposal(
+				Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
+				proposal.title, proposal.cid, proposal.expiry,
+				Majority::Simple, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
+				proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
+			),
+			Error::<Test>::WrongParameter
+		);
+
+		// Contributors approve the first milestone
+		let (proposal_id, proposal) = create_proposal(
+			ProposalType::Withdrawal, org_id, start, expiry, deposit,
+			Some(campaign_id), Some(currency), None, Some(first)
+		);
+		assert_ok!(Signal::proposal(
+			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
+			proposal.title, proposal.cid, proposal.expiry,
+			Majority::Simple, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
+		));
+		assert_eq!(ProposalMilestone::<Test>::get(&proposal_id), Some(0));
+		for x in &contributors {
+			assert_ok!(Signal::vote(Origin::signed(*x), proposal_id, true, None));
+		}
+		System::set_block_number(expiry);
+		Signal::on_finalize(expiry);
+		assert_eq!(ProposalStates::<Test>::get(&proposal_id), ProposalState::Finalized);
+		assert!(!ProposalMilestone::<Test>::contains_key(&proposal_id));
+		assert_eq!(<Test as Config>::Currency::free_balance(currency, &treasury_id), first);
+
+		// Contributors reject the last milestone
+		let (index, last) = Flow::next_milestone(&campaign_id).unwrap();
+		assert_eq!(index, 1);
+		let start: BlockNumber = expiry + 1;
+		let expiry: BlockNumber = start + ProposalDurationLimits::get().0;
+		System::set_block_number(start);
+		let (proposal_id, proposal) = create_proposal(
+			ProposalType::Withdrawal, org_id, start, expiry, deposit,
+			Some(campaign_id), Some(currency), None, Some(last)
+		);
+		assert_ok!(Signal::proposal(
+			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
+			proposal.title, proposal.cid, proposal.expiry,
+			Majority::Simple, Unit::Account, Scale::Linear, None, None, Some(proposal.deposit),
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
+		));
+		for x in &contributors {
+			assert_ok!(Signal::vote(Origin::signed(*x), proposal_id, false, None));
+		}
+		System::set_block_number(expiry);
+		Signal::on_finalize(expiry);
+		assert_eq!(ProposalStates::<Test>::get(&proposal_id), ProposalState::Rejected);
+		assert_eq!(Flow::next_milestone(&campaign_id), None);
+
+		// Refunds are paid out in the next block
+		Flow::on_initialize(expiry + 1);
+		for x in &contributors {
+			assert_eq!(<Test as Config>::Currency::free_balance(currency, x), 100 * DOLLARS - contribution + last / 2);
+		}
+		assert_eq!(<Test as Config>::Currency::free_balance(currency, &treasury_id), first);
+	});
+}
+
+/// Test 4.2
+/// - Withdrawal proposal of the Share campaign
+/// - Token weighted voting with the campaign token
+#[test]
+fn signal_4_2() {
+	ExtBuilder::default().build().execute_with(|| {
+		let members: Vec<AccountId> = (0..5).collect();
+		let contributors: Vec<AccountId> = (51..53).collect();
+		let (org_id, _) = create_org(&members);
+		set_balance(&contributors, 100 * DOLLARS);
+		let currency = PAYMENT_TOKEN_ID;
+		let now: BlockNumber = 3;
+		System::set_block_number(now);
+
+		// Share campaign with the token
+		let campaign_expiry = now + 2 * DAYS;
+		let bounded_str = BoundedVec::truncate_from(vec![1, 2, 3]);
+		let campaign = gamedao_flow::types::Campaign {
+			index: Flow::campaign_count(), org_id, name: bounded_str.clone(), owner: ALICE, admin: ALICE,
+			deposit: 10 * DOLLARS, start: now, expiry: campaign_expiry, cap: 40 * DOLLARS,
+			protocol: FlowProtocol::Share, governance: FlowGovernance::Yes, cid: bounded_str.clone(),
+			token_symbol: Some(bounded_str.clone()), token_name: Some(bounded_str.clone()), created: now,
+		};
+		assert_ok!(Flow::create_campaign(
+			Origin::signed(ALICE), org_id, campaign.admin, campaign.name.clone(), campaign.cap,
+			campaign.deposit, campaign.expiry, campaign.protocol.clone(), campaign.governance.clone(),
+			campaign.cid.clone(), None, campaign.token_symbol.clone(), campaign.token_name.clone(),
+			None, BoundedVec::new(), None, None, None, None, None, BoundedVec::new(), BoundedVec::new()
+		));
+		let campaign_id = <Test as frame_system::Config>::Hashing::hash_of(&campaign);
+		for x in &contributors {
+			assert_ok!(Flow::contribute(Origin::signed(*x), campaign_id, 20 * DOLLARS));
+		}
+		System::set_block_number(campaign_expiry);
+		Flow::on_finalize(campaign_expiry);
+		System::set_block_number(campaign_expiry + 1);
+		Flow::on_initialize(campaign_expiry + 1);
+		assert_eq!(Flow::has_campaign_token(&campaign_id), true);
+		for x in &contributors {
+			assert_ok!(Flow::settle_contribution(Origin::signed(*x), campaign_id, *x));
+		}
+
+		// Token holder which is not a contributor
+		let token = CAMPAIGN_TOKEN_ID_OFFSET + campaign.index;
+		assert_ok!(Tokens::transfer(Origin::signed(51), CHARLIE, token, 5 * DOLLARS));
+
+		let start: BlockNumber = campaign_expiry + 1;
+		let expiry: BlockNumber = start + ProposalDurationLimits::get().0;
+		let (proposal_id, proposal) = create_proposal(
+			ProposalType::Withdrawal, org_id, start, expiry, 20 * DOLLARS,
+			Some(campaign_id), Some(currency), None, Some(10 * DOLLARS)
+		);
+		assert_ok!(Signal::proposal(
+			Origin::signed(ALICE), proposal.proposal_type, proposal.org_id,
+			proposal.title, proposal.cid, proposal.expiry,
+			Majority::Relative, Unit::Token, Scale::Linear, None, None, Some(proposal.deposit),
+			proposal.campaign_id, proposal.amount, proposal.beneficiary, proposal.currency_id, None,
+		));
+
+		// Campaign token is deposited for the voting
+		assert_noop!(
+			Signal::vote(Origin::signed(0), proposal_id, true, Some(DOLLARS)),
+			Error::<Test>::AuthorizationError
+		);
+		assert_noop!(
+			Signal::vote(Origin::signed(CHARLIE), proposal_id, true, Some(10 * DOLLARS)),
+			Error::<Test>::BalanceLow
+		);
+		assert_ok!(Signal::vote(Origin::signed(CHARLIE), proposal_id, true, Some(5 * DOLLARS)));
+		assert_ok!(Signal::vote(Origin::signed(51), proposal_id, false, Some(10 * DOLLARS)));
+		assert_ok!(Signal::vote(Origin::signed(52), proposal_id, true, Some(15 * DOLLARS)));
+		assert_eq!(<Test as Config>::Currency::reserved_balance(token, &CHARLIE), 5 * DOLLARS);
+		assert_eq!(<Test as Config>::Currency::reserved_balance(token, &51), 10 * DOLLARS);
+		assert_eq!(<Test as Config>::Currency::reserved_balance(PROTOCOL_TOKEN_ID, &52), 0);
+
+		System::set_block_number(expiry);
+		Signal::on_finalize(expiry);
+		assert_eq!(ProposalStates::<Test>::get(&proposal_id), ProposalState::Finalized);
+
+		// Voting deposits are returned
+		for x in [CHARLIE, 51, 52] {
+			assert_eq!(<Test as Config>::Currency::reserved_balance(token, &x), 0);
+		}
+		assert_eq!(<Test as Config>::Currency::free_balance(token, &CHARLIE), 5 * DOLLARS);
+	});
+}
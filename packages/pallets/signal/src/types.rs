@@ -23,6 +23,8 @@ pub enum ProposalType {
 	General = 0,
 	Withdrawal = 1,
 	Spending = 2,
+	/// Org level action (ex. membership application), created by the control pallet
+	OrgAction = 3,
 }
 
 #[derive(Encode, Decode, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
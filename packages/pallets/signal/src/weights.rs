@@ -55,14 +55,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Signal ProposalVoting (max_values: None, max_size: Some(130112), added: 132587, mode: MaxEncodedLen)
 	/// Storage: Signal ProposalStates (r:0 w:1)
 	/// Proof: Signal ProposalStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaults (r:1 w:0)
+	/// Proof: Control OrgVaults (max_values: None, max_size: Some(336), added: 2811, mode: MaxEncodedLen)
+	/// Storage: Signal ProposalVault (r:0 w:1)
+	/// Proof: Signal ProposalVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMilestones (r:1 w:0)
+	/// Proof: Flow CampaignMilestones (max_values: None, max_size: Some(2273), added: 4748, mode: MaxEncodedLen)
+	/// Storage: Signal ProposalMilestone (r:0 w:1)
+	/// Proof: Signal ProposalMilestone (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control Streams (r:11 w:0)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn proposal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1328`
-		//  Estimated: `38031`
+		//  Estimated: `74322`
 		// Minimum execution time: 98_000 nanoseconds.
-		Weight::from_parts(100_000_000, 38031)
-			.saturating_add(T::DbWeight::get().reads(10_u64))
-			.saturating_add(T::DbWeight::get().writes(8_u64))
+		Weight::from_parts(100_000_000, 74322)
+			.saturating_add(T::DbWeight::get().reads(23_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: Signal ProposalVoting (r:1 w:1)
 	/// Proof: Signal ProposalVoting (max_values: None, max_size: Some(130112), added: 132587, mode: MaxEncodedLen)
@@ -78,16 +88,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignToken (r:1 w:0)
+	/// Proof: Flow CampaignToken (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// The range of component `m` is `[0, 1000]`.
 	fn vote(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3816 + m * (51 ±0)`
-		//  Estimated: `160361`
+		//  Estimated: `162888`
 		// Minimum execution time: 111_000 nanoseconds.
-		Weight::from_parts(108_350_723, 160361)
+		Weight::from_parts(108_350_723, 162888)
 			// Standard Error: 1_962
 			.saturating_add(Weight::from_ref_time(108_429).saturating_mul(m.into()))
-			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: Signal ProposalsByBlock (r:1 w:0)
@@ -131,14 +143,24 @@ impl WeightInfo for () {
 	/// Proof: Signal ProposalVoting (max_values: None, max_size: Some(130112), added: 132587, mode: MaxEncodedLen)
 	/// Storage: Signal ProposalStates (r:0 w:1)
 	/// Proof: Signal ProposalStates (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Control OrgVaults (r:1 w:0)
+	/// Proof: Control OrgVaults (max_values: None, max_size: Some(336), added: 2811, mode: MaxEncodedLen)
+	/// Storage: Signal ProposalVault (r:0 w:1)
+	/// Proof: Signal ProposalVault (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignMilestones (r:1 w:0)
+	/// Proof: Flow CampaignMilestones (max_values: None, max_size: Some(2273), added: 4748, mode: MaxEncodedLen)
+	/// Storage: Signal ProposalMilestone (r:0 w:1)
+	/// Proof: Signal ProposalMilestone (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Control Streams (r:11 w:0)
+	/// Proof: Control Streams (max_values: None, max_size: Some(137), added: 2612, mode: MaxEncodedLen)
 	fn proposal() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1328`
-		//  Estimated: `38031`
+		//  Estimated: `74322`
 		// Minimum execution time: 98_000 nanoseconds.
-		Weight::from_parts(100_000_000, 38031)
-			.saturating_add(RocksDbWeight::get().reads(10_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
+		Weight::from_parts(100_000_000, 74322)
+			.saturating_add(RocksDbWeight::get().reads(23_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
 	/// Storage: Signal ProposalVoting (r:1 w:1)
 	/// Proof: Signal ProposalVoting (max_values: None, max_size: Some(130112), added: 132587, mode: MaxEncodedLen)
@@ -154,16 +176,18 @@ impl WeightInfo for () {
 	/// Proof: Tokens Accounts (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: Flow CampaignToken (r:1 w:0)
+	/// Proof: Flow CampaignToken (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	/// The range of component `m` is `[0, 1000]`.
 	fn vote(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3816 + m * (51 ±0)`
-		//  Estimated: `160361`
+		//  Estimated: `162888`
 		// Minimum execution time: 111_000 nanoseconds.
-		Weight::from_parts(108_350_723, 160361)
+		Weight::from_parts(108_350_723, 162888)
 			// Standard Error: 1_962
 			.saturating_add(Weight::from_ref_time(108_429).saturating_mul(m.into()))
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: Signal ProposalsByBlock (r:1 w:0)
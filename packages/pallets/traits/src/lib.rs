@@ -13,19 +13,140 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-#[cfg(feature = "runtime-benchmarks")]
-use frame_support::dispatch::DispatchError;
-#[cfg(feature = "runtime-benchmarks")]
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::dispatch::{DispatchError, DispatchResult};
+use scale_info::TypeInfo;
 use sp_std::vec::Vec;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+
+/// Id of the named org treasury vault, unique within the org.
+pub type VaultId = u32;
+
+/// Org level action which is decided by the org members through a voting.
+#[derive(Encode, Decode, PartialEq, Clone, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum OrgAction<AccountId, Hash> {
+	/// Accept the applicant as an active org member.
+	AddMember(AccountId),
+	/// Dissolve the org and distribute its treasury.
+	Dissolve,
+	/// Attach the org as a sub-org (guild) of the voting org.
+	AddSubOrg {
+		org_id: Hash,
+		parent_members_only: bool,
+	},
+	/// Recall the org prime and replace it with the active org member.
+	ReplacePrime(AccountId),
+}
+
+/// Role which can be granted to the org member.
+#[derive(Encode, Decode, PartialEq, Clone, Copy, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum OrgRole {
+	Admin = 0,
+	Treasurer = 1,
+	Moderator = 2,
+	Recruiter = 3,
+}
+
+/// Permission to perform an org level action. Permissions are assigned to the roles,
+/// org prime holds all of them.
+#[derive(Encode, Decode, PartialEq, Clone, Copy, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum OrgPermission {
+	UpdateOrg = 0,
+	ManageState = 1,
+	SpendFunds = 2,
+	AddMember = 3,
+	UpdateMember = 4,
+	RemoveMember = 5,
+	ManageRoles = 6,
+	CreateCampaign = 7,
+	ManageBattlepass = 8,
+	DissolveOrg = 9,
+	ManageSubOrgs = 10,
+}
 
 pub trait ControlTrait<AccountId, Hash> {
 
 	fn org_prime_account(org_id: &Hash) -> Option<AccountId>;
 	fn org_treasury_account(org_id: &Hash) -> Option<AccountId>;
+	/// Account of the org treasury vault, the main org treasury if the vault is not set.
+	fn org_vault_account(org_id: &Hash, vault_id: Option<VaultId>) -> Option<AccountId>;
 	fn is_org_active(org_id: &Hash) -> bool;
 	fn is_org_member_active(org_id: &Hash, accont_id: &AccountId) -> bool;
 	fn org_member_count(org_id: &Hash) -> u32;
+
+	fn has_org_role(org_id: &Hash, account_id: &AccountId, role: OrgRole) -> bool;
+	/// Check if the account is the org prime or an active member with a role, which has the permission.
+	fn has_org_permission(org_id: &Hash, account_id: &AccountId, permission: OrgPermission) -> bool;
+
+	/// Apply the result of the org action voting, created via `GovernanceTrait`.
+	fn apply_org_action(org_id: &Hash, action: OrgAction<AccountId, Hash>, approved: bool) -> DispatchResult;
+
+	/// Parent of the sub-org, if any.
+	fn org_parent(org_id: &Hash) -> Option<Hash>;
+	/// Direct sub-orgs of the org.
+	fn org_children(org_id: &Hash) -> Vec<Hash>;
+	/// All the sub-orgs of the org, on every level below it, closest levels first.
+	/// The list is capped by the implementation, so the deepest levels of a large tree may be left out.
+	fn org_subtree(org_id: &Hash) -> Vec<Hash>;
+	/// Check if the org is the ancestor (parent, parent of the parent, ...) of the other org.
+	fn is_org_ancestor(ancestor_id: &Hash, org_id: &Hash) -> bool;
+}
+
+pub trait TreasuryTrait<AccountId, CurrencyId, Hash> {
+
+	/// Check if the org treasury account still holds the funds committed to the org payment streams.
+	/// Called after the funds were reserved or taken out of the account.
+	fn ensure_committed_funds(org_id: &Hash, account: &AccountId, currency_id: CurrencyId) -> DispatchResult;
+}
+
+pub trait GovernanceTrait<AccountId, Balance, CurrencyId, Hash> {
+
+	/// Create a members voting for the org action.
+	/// The result is reported back with `ControlTrait::apply_org_action`.
+	fn create_org_action_proposal(
+		org_id: &Hash, proposer: &AccountId, action: OrgAction<AccountId, Hash>
+	) -> Result<Hash, DispatchError>;
+
+	/// Create a members voting for the org treasury spending.
+	/// The amount is reserved on the treasury and paid out to the beneficiary once the voting passes.
+	fn create_spending_proposal(
+		org_id: &Hash, proposer: &AccountId, currency_id: CurrencyId, beneficiary: &AccountId, amount: Balance
+	) -> Result<Hash, DispatchError>;
+}
+
+impl<AccountId, Balance, CurrencyId, Hash> GovernanceTrait<AccountId, Balance, CurrencyId, Hash> for () {
+	fn create_org_action_proposal(
+		_org_id: &Hash, _proposer: &AccountId, _action: OrgAction<AccountId, Hash>
+	) -> Result<Hash, DispatchError> {
+		Err(DispatchError::Other("Governance is not available"))
+	}
+	fn create_spending_proposal(
+		_org_id: &Hash, _proposer: &AccountId, _currency_id: CurrencyId, _beneficiary: &AccountId, _amount: Balance
+	) -> Result<Hash, DispatchError> {
+		Err(DispatchError::Other("Governance is not available"))
+	}
+}
+
+pub trait SenseTrait<AccountId> {
+
+	/// Check if the account has a Sense entity (verified identity).
+	fn has_entity(account_id: &AccountId) -> bool;
+	/// Reputation of the account, zero if it has no Sense entity.
+	fn reputation(account_id: &AccountId) -> u64;
+}
+
+impl<AccountId> SenseTrait<AccountId> for () {
+	fn has_entity(_account_id: &AccountId) -> bool {
+		false
+	}
+	fn reputation(_account_id: &AccountId) -> u64 {
+		0
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -46,6 +167,28 @@ pub trait FlowTrait<AccountId, Balance, Hash> {
 	fn is_campaign_contributor(campaign_id: &Hash, who: &AccountId) -> bool;
 	fn campaign_contributors_count(campaign_id: &Hash) -> u64;
 	fn campaign_owner(campaign_id: &Hash) -> Option<AccountId>;
+	/// Org treasury vault receiving the campaign funds, the main org treasury if not set.
+	fn campaign_vault(campaign_id: &Hash) -> Option<VaultId>;
+	/// Check if the campaign funds are released by milestones.
+	fn has_milestones(campaign_id: &Hash) -> bool;
+	/// Next pending milestone of the succeeded campaign and its amount.
+	fn next_milestone(campaign_id: &Hash) -> Option<(u32, Balance)>;
+	/// Releases the approved milestone funds or refunds them to the contributors.
+	fn settle_milestone(campaign_id: &Hash, index: u32, approved: bool) -> DispatchResult;
+	/// Lend or Loan campaign missed an installment.
+	fn is_campaign_defaulted(campaign_id: &Hash) -> bool;
+	/// Principal and interest of the Lend or Loan campaign left to be repaid.
+	fn loan_outstanding(campaign_id: &Hash) -> Option<Balance>;
+	/// Share campaign succeeded and issued the campaign token.
+	fn has_campaign_token(campaign_id: &Hash) -> bool;
+	fn is_campaign_token_holder(campaign_id: &Hash, who: &AccountId) -> bool;
+	/// Reserves the campaign token, e.g. as a voting deposit.
+	fn reserve_campaign_token(campaign_id: &Hash, who: &AccountId, amount: Balance) -> DispatchResult;
+	fn unreserve_campaign_token(campaign_id: &Hash, who: &AccountId, amount: Balance);
+	/// Pool campaign which is open for deposits, redemptions and spends.
+	fn is_pool(campaign_id: &Hash) -> bool;
+	/// Releases the pooled funds to the org treasury.
+	fn spend_pool(campaign_id: &Hash, amount: Balance) -> DispatchResult;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
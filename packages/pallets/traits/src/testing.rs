@@ -0,0 +1,21 @@
+//! Test helpers shared by the pallet mocks, available with the `testing` feature.
+
+use sp_io::TestExternalities;
+
+/// Applies the raw storage of a state snapshot fixture to the test externalities.
+/// Fixture lines are `0x<key> 0x<value>` pairs, `#` lines are comments.
+pub fn snapshot_ext(mut ext: TestExternalities, snapshot: &str) -> TestExternalities {
+	ext.execute_with(|| {
+		snapshot.lines()
+			.map(|line| line.trim())
+			.filter(|line| !line.is_empty() && !line.starts_with('#'))
+			.for_each(|line| {
+				let mut pair = line.split_whitespace()
+					.map(|hex| sp_core::bytes::from_hex(hex).expect("Invalid snapshot hex"));
+				let key = pair.next().expect("Snapshot key missing");
+				let value = pair.next().expect("Snapshot value missing");
+				sp_io::storage::set(&key, &value);
+			});
+	});
+	ext
+}